@@ -43,6 +43,9 @@ def py_to_c(value: Any, c_type, type_name: Union[RuntimeType, str] = None):
     if isinstance(value, c_type):
         return value
 
+    if c_type == CallbackFn:
+        return _wrap_py_func(value, type_name)
+
     # check that the type name is consistent with the value
     if type_name is not None:
         RuntimeType.assert_is_similar(RuntimeType.parse(type_name), RuntimeType.infer(value))
@@ -85,6 +88,55 @@ def py_to_c(value: Any, c_type, type_name: Union[RuntimeType, str] = None):
     return value
 
 
+# Callbacks must outlive any transformation that references them,
+# including transformations chained from them.
+# The library calls a callback with a null argument once it no longer holds it,
+# at which point the callback is released.
+_CALLBACKS = {}
+# A callback may not be freed while it is running, so released callbacks are freed on the next registration.
+_RELEASED = []
+# A Measurement or Transformation returned by a callback is borrowed by the library until the callback is next called.
+_RETURNED = {}
+
+
+def _wrap_py_func(func, type_name: RuntimeType) -> CallbackFn:
+    """Wrap a python function `func` so that it can be called from the library.
+
+    :param func: python function that takes one argument
+    :param type_name: rust type of the return value of `func`
+    :return: a CallbackFn that calls `func`
+    """
+    from opendp.core import _callback_ok, _callback_err
+
+    def wrapper(c_arg):
+        if not c_arg:
+            _RELEASED.append(_CALLBACKS.pop(id(callback)))
+            _RETURNED.pop(id(callback), None)
+            return None
+        try:
+            c_arg = ctypes.cast(c_arg, AnyObjectPtr)
+            try:
+                arg = c_to_py(c_arg)
+            finally:
+                # the argument is borrowed, so avoid calling AnyObjectPtr.__del__, which would free it
+                c_arg.__class__ = ctypes.POINTER(AnyObject)
+
+            out = func(arg)
+            c_out = py_to_c(out, c_type=AnyObjectPtr, type_name=type_name)
+            if isinstance(out, (Transformation, Measurement)):
+                _RETURNED[id(callback)] = out
+            # ownership of the output is transferred to the library
+            c_out.__class__ = ctypes.POINTER(AnyObject)
+            return _callback_ok(c_out)
+        except Exception as err:
+            return _callback_err(f"{type(err).__name__}: {err}")
+
+    _RELEASED.clear()
+    callback = CallbackFn(wrapper)
+    _CALLBACKS[id(callback)] = callback
+    return callback
+
+
 def c_to_py(value):
     """Map from ctypes `value` to python value.
     It is assumed that the c type is simpler than in py_to_c, as the library returns fewer types.
@@ -183,11 +235,18 @@ def _py_to_slice(value: Any, type_name: Union[RuntimeType, str]) -> FfiSlicePtr:
 def _scalar_to_slice(val, type_name: str) -> FfiSlicePtr:
     if np is not None and isinstance(val, np.ndarray):
         val = val.item()
+    if isinstance(val, (Transformation, Measurement)):
+        # chains are loaded by pointer, and remain owned by python
+        return _wrap_in_slice(ctypes.pointer(val), 1)
     # ctypes.byref has edge-cases that cause use-after-free errors. ctypes.pointer fixes these edge-cases
     return _wrap_in_slice(ctypes.pointer(ATOM_MAP[type_name](val)), 1)
 
 
 def _slice_to_scalar(raw: FfiSlicePtr, type_name: str):
+    if type_name in ("AnyMeasurementPtr", "AnyTransformationPtr"):
+        # the library transfers ownership of returned chains, which are freed when the python object is dropped
+        address = ctypes.cast(raw.contents.ptr, ctypes.POINTER(ctypes.c_void_p)).contents.value
+        return ctypes.cast(address, ATOM_MAP[type_name])
     return ctypes.cast(raw.contents.ptr, ctypes.POINTER(ATOM_MAP[type_name])).contents.value
 
 
@@ -103,6 +103,11 @@ class FfiResult(ctypes.Structure):
     ]
 
 
+# A function implemented in python that is called from the library.
+# The argument is a borrowed AnyObject, and the return is an FfiResult owned by the library.
+CallbackFn = ctypes.CFUNCTYPE(ctypes.c_void_p, ctypes.c_void_p)
+
+
 # def _str_to_c_char_p(s: Optional[str]) -> Optional[bytes]:
 #     return s and s.encode("utf-8")
 def _c_char_p_to_str(s: Optional[bytes]) -> Optional[str]:
@@ -3,12 +3,19 @@ from opendp._convert import *
 from opendp._lib import *
 from opendp.mod import *
 from opendp.typing import *
+from opendp.core import *
 
 __all__ = [
     "laplacian_scale_to_accuracy",
     "accuracy_to_laplacian_scale",
     "gaussian_scale_to_accuracy",
-    "accuracy_to_gaussian_scale"
+    "accuracy_to_gaussian_scale",
+    "discrete_laplacian_scale_to_accuracy",
+    "accuracy_to_discrete_laplacian_scale",
+    "discrete_gaussian_scale_to_accuracy",
+    "accuracy_to_discrete_gaussian_scale",
+    "simulate_utility",
+    "compare_utility"
 ]
 
 
@@ -142,3 +149,221 @@ def accuracy_to_gaussian_scale(
     function.restype = FfiResult
     
     return c_to_py(unwrap(function(accuracy, alpha, T), AnyObjectPtr))
+
+
+def discrete_laplacian_scale_to_accuracy(
+    scale,
+    alpha,
+    T: RuntimeTypeDescriptor = None
+) -> Any:
+    """Convert a discrete laplacian scale into an accuracy estimate (tolerance) at a statistical significance level `alpha`.
+    
+    :param scale: Discrete laplacian noise scale.
+    :param alpha: Statistical significance, level-`alpha`, or (1. - `alpha`)100% confidence. Must be within (0, 1].
+    :param T: Data type of `scale` and `alpha`
+    :type T: :ref:`RuntimeTypeDescriptor`
+    :return: Accuracy estimate. Maximum amount a value is expected to diverge at the given level-`alpha`.
+    :rtype: Any
+    :raises AssertionError: if an argument's type differs from the expected type
+    :raises UnknownTypeError: if a type-argument fails to parse
+    :raises OpenDPException: packaged error from the core OpenDP library
+    """
+    # Standardize type arguments.
+    T = RuntimeType.parse_or_infer(type_name=T, public_example=scale)
+    
+    # Convert arguments to c types.
+    scale = py_to_c(scale, c_type=ctypes.c_void_p, type_name=T)
+    alpha = py_to_c(alpha, c_type=ctypes.c_void_p, type_name=T)
+    T = py_to_c(T, c_type=ctypes.c_char_p)
+    
+    # Call library function.
+    function = lib.opendp_accuracy__discrete_laplacian_scale_to_accuracy
+    function.argtypes = [ctypes.c_void_p, ctypes.c_void_p, ctypes.c_char_p]
+    function.restype = FfiResult
+    
+    return c_to_py(unwrap(function(scale, alpha, T), AnyObjectPtr))
+
+
+def accuracy_to_discrete_laplacian_scale(
+    accuracy,
+    alpha,
+    T: RuntimeTypeDescriptor = None
+) -> Any:
+    """Convert a desired `accuracy` (tolerance) into a discrete laplacian noise scale at a statistical significance level `alpha`.
+    
+    :param accuracy: Desired accuracy. A tolerance for how far values may diverge from the input to the mechanism.
+    :param alpha: Statistical significance, level-`alpha`, or (1. - `alpha`)100% confidence. Must be within (0, 1].
+    :param T: Data type of `accuracy` and `alpha`
+    :type T: :ref:`RuntimeTypeDescriptor`
+    :return: Discrete laplacian noise scale that meets the `accuracy` requirement at a given level-`alpha`.
+    :rtype: Any
+    :raises AssertionError: if an argument's type differs from the expected type
+    :raises UnknownTypeError: if a type-argument fails to parse
+    :raises OpenDPException: packaged error from the core OpenDP library
+    """
+    # Standardize type arguments.
+    T = RuntimeType.parse_or_infer(type_name=T, public_example=accuracy)
+    
+    # Convert arguments to c types.
+    accuracy = py_to_c(accuracy, c_type=ctypes.c_void_p, type_name=T)
+    alpha = py_to_c(alpha, c_type=ctypes.c_void_p, type_name=T)
+    T = py_to_c(T, c_type=ctypes.c_char_p)
+    
+    # Call library function.
+    function = lib.opendp_accuracy__accuracy_to_discrete_laplacian_scale
+    function.argtypes = [ctypes.c_void_p, ctypes.c_void_p, ctypes.c_char_p]
+    function.restype = FfiResult
+    
+    return c_to_py(unwrap(function(accuracy, alpha, T), AnyObjectPtr))
+
+
+def discrete_gaussian_scale_to_accuracy(
+    scale,
+    alpha,
+    T: RuntimeTypeDescriptor = None
+) -> Any:
+    """Convert a discrete gaussian scale into an accuracy estimate (tolerance) at a statistical significance level `alpha`.
+    
+    :param scale: Discrete gaussian noise scale.
+    :param alpha: Statistical significance, level-`alpha`, or (1. - `alpha`)100% confidence. Must be within (0, 1].
+    :param T: Data type of `scale` and `alpha`
+    :type T: :ref:`RuntimeTypeDescriptor`
+    :return: Accuracy estimate. Maximum amount a value is expected to diverge at the given level-`alpha`.
+    :rtype: Any
+    :raises AssertionError: if an argument's type differs from the expected type
+    :raises UnknownTypeError: if a type-argument fails to parse
+    :raises OpenDPException: packaged error from the core OpenDP library
+    """
+    # Standardize type arguments.
+    T = RuntimeType.parse_or_infer(type_name=T, public_example=scale)
+    
+    # Convert arguments to c types.
+    scale = py_to_c(scale, c_type=ctypes.c_void_p, type_name=T)
+    alpha = py_to_c(alpha, c_type=ctypes.c_void_p, type_name=T)
+    T = py_to_c(T, c_type=ctypes.c_char_p)
+    
+    # Call library function.
+    function = lib.opendp_accuracy__discrete_gaussian_scale_to_accuracy
+    function.argtypes = [ctypes.c_void_p, ctypes.c_void_p, ctypes.c_char_p]
+    function.restype = FfiResult
+    
+    return c_to_py(unwrap(function(scale, alpha, T), AnyObjectPtr))
+
+
+def accuracy_to_discrete_gaussian_scale(
+    accuracy,
+    alpha,
+    T: RuntimeTypeDescriptor = None
+) -> Any:
+    """Convert a desired `accuracy` (tolerance) into a discrete gaussian noise scale at a statistical significance level `alpha`.
+    
+    :param accuracy: Desired accuracy. A tolerance for how far values may diverge from the input to the mechanism.
+    :param alpha: Statistical significance, level-`alpha`, or (1. - `alpha`)100% confidence. Must be within (0, 1).
+    :param T: Data type of `accuracy` and `alpha`
+    :type T: :ref:`RuntimeTypeDescriptor`
+    :return: Discrete gaussian noise scale that meets the `accuracy` requirement at a given level-`alpha`.
+    :rtype: Any
+    :raises AssertionError: if an argument's type differs from the expected type
+    :raises UnknownTypeError: if a type-argument fails to parse
+    :raises OpenDPException: packaged error from the core OpenDP library
+    """
+    # Standardize type arguments.
+    T = RuntimeType.parse_or_infer(type_name=T, public_example=accuracy)
+    
+    # Convert arguments to c types.
+    accuracy = py_to_c(accuracy, c_type=ctypes.c_void_p, type_name=T)
+    alpha = py_to_c(alpha, c_type=ctypes.c_void_p, type_name=T)
+    T = py_to_c(T, c_type=ctypes.c_char_p)
+    
+    # Call library function.
+    function = lib.opendp_accuracy__accuracy_to_discrete_gaussian_scale
+    function.argtypes = [ctypes.c_void_p, ctypes.c_void_p, ctypes.c_char_p]
+    function.restype = FfiResult
+    
+    return c_to_py(unwrap(function(accuracy, alpha, T), AnyObjectPtr))
+
+
+def simulate_utility(
+    measurement: Measurement,
+    data: Any,
+    loss: Callable[[Any], float],
+    quantiles: Any,
+    trials: int = 1000
+) -> Any:
+    """Estimate the utility of `measurement` on `data` by simulating many releases. 
+    The releases are not accounted for in any privacy budget, so `data` must be public or synthetic.
+    
+    :param measurement: Measurement to simulate.
+    :type measurement: Measurement
+    :param data: Public or synthetic dataset to invoke `measurement` on.
+    :type data: Any
+    :param loss: Function to compute the loss of each release, typically its signed error.
+    :type loss: Callable[[Any], float]
+    :param trials: Number of releases to simulate.
+    :type trials: int
+    :param quantiles: Quantiles of the absolute loss to report, each within [0, 1].
+    :type quantiles: Any
+    :return: A list of the number of trials, the bias (mean loss), the rmse (root mean squared loss), and a list of each requested quantile paired with that quantile of the absolute loss, in the order requested.
+    :rtype: Any
+    :raises AssertionError: if an argument's type differs from the expected type
+    :raises UnknownTypeError: if a type-argument fails to parse
+    :raises OpenDPException: packaged error from the core OpenDP library
+    """
+    # No type arguments to standardize.
+    # Convert arguments to c types.
+    measurement = py_to_c(measurement, c_type=Measurement)
+    data = py_to_c(data, c_type=AnyObjectPtr, type_name=measurement_input_carrier_type(measurement))
+    loss = py_to_c(loss, c_type=CallbackFn, type_name=f64)
+    trials = py_to_c(trials, c_type=ctypes.c_uint)
+    quantiles = py_to_c(quantiles, c_type=AnyObjectPtr, type_name=RuntimeType(origin='Vec', args=[f64]))
+    
+    # Call library function.
+    function = lib.opendp_accuracy__simulate_utility
+    function.argtypes = [Measurement, AnyObjectPtr, CallbackFn, ctypes.c_uint, AnyObjectPtr]
+    function.restype = FfiResult
+    
+    return c_to_py(unwrap(function(measurement, data, loss, trials, quantiles), AnyObjectPtr))
+
+
+def compare_utility(
+    measurements: Any,
+    data: Any,
+    loss: Callable[[Any], float],
+    quantiles: Any,
+    trials: int = 1000
+) -> Any:
+    """Estimate the utility of each of `measurements` on the same `data` by simulating many releases of each. 
+    The releases are not accounted for in any privacy budget, so `data` must be public or synthetic.
+    
+    :param measurements: Measurements to simulate. All must share the input carrier type of the first.
+    :type measurements: Any
+    :param data: Public or synthetic dataset to invoke each of `measurements` on.
+    :type data: Any
+    :param loss: Function to compute the loss of each release, typically its signed error.
+    :type loss: Callable[[Any], float]
+    :param trials: Number of releases to simulate per measurement.
+    :type trials: int
+    :param quantiles: Quantiles of the absolute loss to report, each within [0, 1].
+    :type quantiles: Any
+    :return: A list with one summary per measurement, in the order given, each formatted as in `simulate_utility`.
+    :rtype: Any
+    :raises AssertionError: if an argument's type differs from the expected type
+    :raises UnknownTypeError: if a type-argument fails to parse
+    :raises OpenDPException: packaged error from the core OpenDP library
+    """
+    # Standardize type arguments.
+    T = measurement_input_carrier_type(measurements[0])
+    
+    # Convert arguments to c types.
+    measurements = py_to_c(measurements, c_type=AnyObjectPtr, type_name=RuntimeType(origin='Vec', args=["AnyMeasurementPtr"]))
+    data = py_to_c(data, c_type=AnyObjectPtr, type_name=T)
+    loss = py_to_c(loss, c_type=CallbackFn, type_name=f64)
+    trials = py_to_c(trials, c_type=ctypes.c_uint)
+    quantiles = py_to_c(quantiles, c_type=AnyObjectPtr, type_name=RuntimeType(origin='Vec', args=[f64]))
+    
+    # Call library function.
+    function = lib.opendp_accuracy__compare_utility
+    function.argtypes = [AnyObjectPtr, AnyObjectPtr, CallbackFn, ctypes.c_uint, AnyObjectPtr]
+    function.restype = FfiResult
+    
+    return c_to_py(unwrap(function(measurements, data, loss, trials, quantiles), AnyObjectPtr))
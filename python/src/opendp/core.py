@@ -11,21 +11,27 @@ __all__ = [
     "transformation_check",
     "measurement_map",
     "measurement_check",
+    "measurement_accuracy",
     "transformation_input_carrier_type",
     "measurement_input_carrier_type",
     "transformation_input_distance_type",
     "transformation_output_distance_type",
     "measurement_input_distance_type",
     "measurement_output_distance_type",
+    "set_entropy_source",
+    "reset_entropy_source",
     "_error_free",
     "_transformation_free",
-    "_measurement_free"
+    "_measurement_free",
+    "_callback_ok",
+    "_callback_err"
 ]
 
 
 def measurement_invoke(
     measurement: Measurement,
-    arg: Any
+    arg: Any,
+    check_domain: bool = False
 ) -> Any:
     """Invoke the `measurement` with `arg`. Returns a differentially private release.
     
@@ -33,6 +39,8 @@ def measurement_invoke(
     :type measurement: Measurement
     :param arg: Input data to supply to the measurement. A member of the measurement's input domain.
     :type arg: Any
+    :param check_domain: Set to true to check that `arg` is a member of the input domain before invoking, and fail with `DomainMismatch` otherwise.
+    :type check_domain: bool
     :return: Differentially private release.
     :rtype: Any
     :raises AssertionError: if an argument's type differs from the expected type
@@ -43,18 +51,20 @@ def measurement_invoke(
     # Convert arguments to c types.
     measurement = py_to_c(measurement, c_type=Measurement)
     arg = py_to_c(arg, c_type=AnyObjectPtr, type_name=measurement_input_carrier_type(measurement))
+    check_domain = py_to_c(check_domain, c_type=ctypes.c_bool)
     
     # Call library function.
     function = lib.opendp_core__measurement_invoke
-    function.argtypes = [Measurement, AnyObjectPtr]
+    function.argtypes = [Measurement, AnyObjectPtr, ctypes.c_bool]
     function.restype = FfiResult
     
-    return c_to_py(unwrap(function(measurement, arg), AnyObjectPtr))
+    return c_to_py(unwrap(function(measurement, arg, check_domain), AnyObjectPtr))
 
 
 def transformation_invoke(
     transformation: Transformation,
-    arg: Any
+    arg: Any,
+    check_domain: bool = False
 ) -> Any:
     """Invoke the `transformation` with `arg`. 
     The response is not differentially private as it has not been chained with a measurement.
@@ -63,6 +73,8 @@ def transformation_invoke(
     :type transformation: Transformation
     :param arg: Input data to supply to the measurement. A member of the transformations's input domain.
     :type arg: Any
+    :param check_domain: Set to true to check that `arg` is a member of the input domain before invoking, and fail with `DomainMismatch` otherwise.
+    :type check_domain: bool
     :return: Non-differentially private answer to the query.
     :rtype: Any
     :raises AssertionError: if an argument's type differs from the expected type
@@ -73,13 +85,14 @@ def transformation_invoke(
     # Convert arguments to c types.
     transformation = py_to_c(transformation, c_type=Transformation)
     arg = py_to_c(arg, c_type=AnyObjectPtr, type_name=transformation_input_carrier_type(transformation))
+    check_domain = py_to_c(check_domain, c_type=ctypes.c_bool)
     
     # Call library function.
     function = lib.opendp_core__transformation_invoke
-    function.argtypes = [Transformation, AnyObjectPtr]
+    function.argtypes = [Transformation, AnyObjectPtr, ctypes.c_bool]
     function.restype = FfiResult
     
-    return c_to_py(unwrap(function(transformation, arg), AnyObjectPtr))
+    return c_to_py(unwrap(function(transformation, arg, check_domain), AnyObjectPtr))
 
 
 def transformation_map(
@@ -206,6 +219,36 @@ def measurement_check(
     return c_to_py(unwrap(function(measurement, d_in, d_out), BoolPtr))
 
 
+def measurement_accuracy(
+    measurement: Measurement,
+    alpha: Any
+) -> Any:
+    """Get the accuracy of the `measurement` at a statistical significance level of `alpha`. 
+    Each release differs from its noiseless value by more than the accuracy with probability at most `alpha`.
+    
+    :param measurement: Measurement to get the accuracy of.
+    :type measurement: Measurement
+    :param alpha: Statistical significance level, in (0, 1].
+    :type alpha: Any
+    :return: The accuracy of each release.
+    :rtype: Any
+    :raises AssertionError: if an argument's type differs from the expected type
+    :raises UnknownTypeError: if a type-argument fails to parse
+    :raises OpenDPException: packaged error from the core OpenDP library
+    """
+    # No type arguments to standardize.
+    # Convert arguments to c types.
+    measurement = py_to_c(measurement, c_type=Measurement)
+    alpha = py_to_c(alpha, c_type=AnyObjectPtr, type_name=f64)
+    
+    # Call library function.
+    function = lib.opendp_core__measurement_accuracy
+    function.argtypes = [Measurement, AnyObjectPtr]
+    function.restype = FfiResult
+    
+    return c_to_py(unwrap(function(measurement, alpha), AnyObjectPtr))
+
+
 def transformation_input_carrier_type(
     transformation: Transformation
 ) -> str:
@@ -350,6 +393,52 @@ def measurement_output_distance_type(
     return c_to_py(unwrap(function(measurement), ctypes.c_char_p))
 
 
+def set_entropy_source(
+    source: Callable[[int], List[int]]
+):
+    """Set the process-wide source of randomness for all samplers. 
+    The privacy guarantees of every measurement rely on `source` returning uniformly random and unpredictable bytes.
+    
+    :param source: function that takes a number of bytes, and returns a list of that many random bytes
+    :type source: Callable[[int], List[int]]
+    :raises AssertionError: if an argument's type differs from the expected type
+    :raises UnknownTypeError: if a type-argument fails to parse
+    :raises OpenDPException: packaged error from the core OpenDP library
+    """
+    assert_features("contrib")
+    
+    # No type arguments to standardize.
+    # Convert arguments to c types.
+    source = py_to_c(source, c_type=CallbackFn, type_name="Vec<u8>")
+    
+    # Call library function.
+    function = lib.opendp_core__set_entropy_source
+    function.argtypes = [CallbackFn]
+    function.restype = FfiResult
+    
+    return c_to_py(unwrap(function(source), ctypes.c_void_p))
+
+
+def reset_entropy_source(
+    
+):
+    """Restore the default source of randomness, chosen when the library is compiled.
+    
+    
+    :raises AssertionError: if an argument's type differs from the expected type
+    :raises UnknownTypeError: if a type-argument fails to parse
+    :raises OpenDPException: packaged error from the core OpenDP library
+    """
+    # No type arguments to standardize.
+    # No arguments to convert to c types.
+    # Call library function.
+    function = lib.opendp_core__reset_entropy_source
+    function.argtypes = []
+    function.restype = FfiResult
+    
+    return c_to_py(unwrap(function(), ctypes.c_void_p))
+
+
 def _error_free(
     error: FfiError
 ) -> bool:
@@ -412,3 +501,45 @@ def _measurement_free(
     function.restype = FfiResult
     
     return c_to_py(unwrap(function(measurement), ctypes.c_void_p))
+
+
+def _callback_ok(
+    value: Any
+):
+    """Internal function. Package the output of a callback into a result owned by the library.
+    
+    :param value: 
+    :type value: Any
+    :raises AssertionError: if an argument's type differs from the expected type
+    :raises UnknownTypeError: if a type-argument fails to parse
+    """
+    # No type arguments to standardize.
+    # No arguments to convert to c types.
+    # Call library function.
+    function = lib.opendp_core___callback_ok
+    function.argtypes = [AnyObjectPtr]
+    function.restype = ctypes.c_void_p
+    
+    return function(value)
+
+
+def _callback_err(
+    message: str
+):
+    """Internal function. Package an error raised in a callback into a result owned by the library.
+    
+    :param message: 
+    :type message: str
+    :raises AssertionError: if an argument's type differs from the expected type
+    :raises UnknownTypeError: if a type-argument fails to parse
+    """
+    # No type arguments to standardize.
+    # Convert arguments to c types.
+    message = py_to_c(message, c_type=ctypes.c_char_p)
+    
+    # Call library function.
+    function = lib.opendp_core___callback_err
+    function.argtypes = [ctypes.c_char_p]
+    function.restype = ctypes.c_void_p
+    
+    return function(message)
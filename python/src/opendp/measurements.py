@@ -6,15 +6,22 @@ from opendp.typing import *
 
 __all__ = [
     "make_base_laplace",
+    "make_base_laplace_fixed_iteration",
     "make_base_gaussian",
+    "make_base_gaussian_fixed_iteration",
     "make_base_geometric",
     "make_base_discrete_laplace_linear",
     "make_base_discrete_laplace_cks20",
+    "make_base_discrete_laplace_cks20_fixed_iteration",
     "make_base_discrete_laplace",
     "make_base_discrete_gaussian",
+    "make_base_discrete_gaussian_fixed_iteration",
     "make_randomized_response_bool",
     "make_randomized_response",
-    "make_base_ptr"
+    "make_base_ptr",
+    "make_sized_bounded_winsorized_mean",
+    "make_mwem",
+    "make_synthetic_dataset"
 ]
 
 
@@ -57,6 +64,48 @@ def make_base_laplace(
     return c_to_py(unwrap(function(scale, k, D), Measurement))
 
 
+def make_base_laplace_fixed_iteration(
+    scale,
+    k: int = -1074,
+    D: RuntimeTypeDescriptor = "AllDomain<T>"
+) -> Measurement:
+    """Make a Measurement that adds noise from the laplace(`scale`) distribution to a scalar value.
+    Adjust D to noise vector-valued data.
+    The number of loop iterations and random draws made by the noise sampler does not depend on the noise, but its arithmetic and rounding still take variable time.
+    Each release fails, or deviates from the distribution of `make_base_laplace`, with probability less than 2^-43 per element.
+    Sampling is much slower, especially when `scale` is large relative to 2^k.
+    
+    :param scale: Noise scale parameter for the laplace distribution. `scale` == sqrt(2) * standard_deviation.
+    :param k: The noise granularity in terms of 2^k. Larger values are more computationally efficient, but have a looser privacy map. Defaults to the smallest granularity.
+    :type k: int
+    :param D: Domain of the data type to be privatized. Valid values are VectorDomain<AllDomain<T>> or AllDomain<T>
+    :type D: :ref:`RuntimeTypeDescriptor`
+    :return: A base_laplace_fixed_iteration step.
+    :rtype: Measurement
+    :raises AssertionError: if an argument's type differs from the expected type
+    :raises UnknownTypeError: if a type-argument fails to parse
+    :raises OpenDPException: packaged error from the core OpenDP library
+    """
+    assert_features("floating-point", "contrib")
+    
+    # Standardize type arguments.
+    D = RuntimeType.parse(type_name=D, generics=["T"])
+    T = get_atom_or_infer(D, scale)
+    D = D.substitute(T=T)
+    
+    # Convert arguments to c types.
+    scale = py_to_c(scale, c_type=ctypes.c_void_p, type_name=T)
+    k = py_to_c(k, c_type=ctypes.c_int32)
+    D = py_to_c(D, c_type=ctypes.c_char_p)
+    
+    # Call library function.
+    function = lib.opendp_measurements__make_base_laplace_fixed_iteration
+    function.argtypes = [ctypes.c_void_p, ctypes.c_int32, ctypes.c_char_p]
+    function.restype = FfiResult
+    
+    return c_to_py(unwrap(function(scale, k, D), Measurement))
+
+
 def make_base_gaussian(
     scale,
     k: int = -1074,
@@ -103,6 +152,55 @@ def make_base_gaussian(
     return c_to_py(unwrap(function(scale, k, D, MO), Measurement))
 
 
+def make_base_gaussian_fixed_iteration(
+    scale,
+    k: int = -1074,
+    D: RuntimeTypeDescriptor = "AllDomain<T>",
+    MO: RuntimeTypeDescriptor = "ZeroConcentratedDivergence<T>"
+) -> Measurement:
+    """Make a Measurement that adds noise from the gaussian(`scale`) distribution to the input.
+    Adjust D to noise vector-valued data.
+    The output epsilon may be no greater than one.
+    The number of loop iterations and random draws made by the noise sampler does not depend on the noise, but its arithmetic and rounding still take variable time.
+    Each release fails, or deviates from the distribution of `make_base_gaussian`, with probability less than 2^-36 per element.
+    Sampling is much slower, especially when `scale` is large relative to 2^k.
+    
+    :param scale: noise scale parameter for the gaussian distribution. `scale` == standard_deviation.
+    :param k: The noise granularity in terms of 2^k. Larger values are more computationally efficient, but have a looser privacy map. Defaults to the smallest granularity.
+    :type k: int
+    :param D: Domain of the data type to be privatized. Valid values are VectorDomain<AllDomain<T>> or AllDomain<T>
+    :type D: :ref:`RuntimeTypeDescriptor`
+    :param MO: Output measure. The only valid measure is ZeroConcentratedDivergence<T>.
+    :type MO: :ref:`RuntimeTypeDescriptor`
+    :return: A base_gaussian_fixed_iteration step.
+    :rtype: Measurement
+    :raises AssertionError: if an argument's type differs from the expected type
+    :raises UnknownTypeError: if a type-argument fails to parse
+    :raises OpenDPException: packaged error from the core OpenDP library
+    """
+    assert_features("floating-point", "contrib")
+    
+    # Standardize type arguments.
+    D = RuntimeType.parse(type_name=D, generics=["T"])
+    MO = RuntimeType.parse(type_name=MO, generics=["T"])
+    T = get_atom_or_infer(D, scale)
+    D = D.substitute(T=T)
+    MO = MO.substitute(T=T)
+    
+    # Convert arguments to c types.
+    scale = py_to_c(scale, c_type=ctypes.c_void_p, type_name=T)
+    k = py_to_c(k, c_type=ctypes.c_int32)
+    D = py_to_c(D, c_type=ctypes.c_char_p)
+    MO = py_to_c(MO, c_type=ctypes.c_char_p)
+    
+    # Call library function.
+    function = lib.opendp_measurements__make_base_gaussian_fixed_iteration
+    function.argtypes = [ctypes.c_void_p, ctypes.c_int32, ctypes.c_char_p, ctypes.c_char_p]
+    function.restype = FfiResult
+    
+    return c_to_py(unwrap(function(scale, k, D, MO), Measurement))
+
+
 def make_base_geometric(
     scale,
     bounds: Any = None,
@@ -230,6 +328,48 @@ def make_base_discrete_laplace_cks20(
     return c_to_py(unwrap(function(scale, D, QO), Measurement))
 
 
+def make_base_discrete_laplace_cks20_fixed_iteration(
+    scale,
+    D: RuntimeTypeDescriptor = "AllDomain<int>",
+    QO: RuntimeTypeDescriptor = None
+) -> Measurement:
+    """Make a Measurement that adds noise from the discrete_laplace(`scale`) distribution to the input.
+    Uses the sampling algorithm from CKS20, The Discrete Gaussian for Differential Privacy.
+    Adjust D to noise vector-valued data.
+    The number of loop iterations and random draws made by the noise sampler does not depend on the noise, but its arithmetic and rounding still take variable time.
+    Each release fails, or deviates from the distribution of `make_base_discrete_laplace_cks20`, with probability less than 2^-43 per element.
+    Sampling is much slower.
+    
+    :param scale: noise scale parameter for the distribution. `scale` == sqrt(2) * standard_deviation.
+    :param D: Domain of the data type to be privatized. Valid values are VectorDomain<AllDomain<T>> or AllDomain<T>
+    :type D: :ref:`RuntimeTypeDescriptor`
+    :param QO: Data type of the sensitivity, scale, and budget.
+    :type QO: :ref:`RuntimeTypeDescriptor`
+    :return: A base_discrete_laplace_cks20_fixed_iteration step.
+    :rtype: Measurement
+    :raises AssertionError: if an argument's type differs from the expected type
+    :raises UnknownTypeError: if a type-argument fails to parse
+    :raises OpenDPException: packaged error from the core OpenDP library
+    """
+    assert_features("contrib")
+    
+    # Standardize type arguments.
+    D = RuntimeType.parse(type_name=D)
+    QO = RuntimeType.parse_or_infer(type_name=QO, public_example=scale)
+    
+    # Convert arguments to c types.
+    scale = py_to_c(scale, c_type=ctypes.c_void_p, type_name=QO)
+    D = py_to_c(D, c_type=ctypes.c_char_p)
+    QO = py_to_c(QO, c_type=ctypes.c_char_p)
+    
+    # Call library function.
+    function = lib.opendp_measurements__make_base_discrete_laplace_cks20_fixed_iteration
+    function.argtypes = [ctypes.c_void_p, ctypes.c_char_p, ctypes.c_char_p]
+    function.restype = FfiResult
+    
+    return c_to_py(unwrap(function(scale, D, QO), Measurement))
+
+
 def make_base_discrete_laplace(
     scale,
     D: RuntimeTypeDescriptor = "AllDomain<int>",
@@ -309,6 +449,49 @@ def make_base_discrete_gaussian(
     return c_to_py(unwrap(function(scale, D, MO), Measurement))
 
 
+def make_base_discrete_gaussian_fixed_iteration(
+    scale,
+    D: RuntimeTypeDescriptor = "AllDomain<int>",
+    MO: RuntimeTypeDescriptor = "ZeroConcentratedDivergence<Q>"
+) -> Measurement:
+    """Make a Measurement that adds noise from the discrete_gaussian(`scale`) distribution to the input.
+    Adjust D to noise vector-valued data.
+    The number of loop iterations and random draws made by the noise sampler does not depend on the noise, but its arithmetic and rounding still take variable time.
+    Each release fails, or deviates from the distribution of `make_base_discrete_gaussian`, with probability less than 2^-36 per element.
+    Sampling is much slower.
+    
+    :param scale: noise scale parameter for the distribution. `scale` == standard_deviation.
+    :param D: Domain of the data type to be privatized. Valid values are VectorDomain<AllDomain<T>> or AllDomain<T>
+    :type D: :ref:`RuntimeTypeDescriptor`
+    :param MO: Output measure. The only valid measure is ZeroConcentratedDivergence<Q>, but Q can be f32 or f64
+    :type MO: :ref:`RuntimeTypeDescriptor`
+    :return: A base_discrete_gaussian_fixed_iteration step.
+    :rtype: Measurement
+    :raises AssertionError: if an argument's type differs from the expected type
+    :raises UnknownTypeError: if a type-argument fails to parse
+    :raises OpenDPException: packaged error from the core OpenDP library
+    """
+    assert_features("contrib")
+    
+    # Standardize type arguments.
+    D = RuntimeType.parse(type_name=D)
+    MO = RuntimeType.parse(type_name=MO, generics=["Q"])
+    Q = get_atom_or_infer(MO, scale)
+    MO = MO.substitute(Q=Q)
+    
+    # Convert arguments to c types.
+    scale = py_to_c(scale, c_type=ctypes.c_void_p, type_name=Q)
+    D = py_to_c(D, c_type=ctypes.c_char_p)
+    MO = py_to_c(MO, c_type=ctypes.c_char_p)
+    
+    # Call library function.
+    function = lib.opendp_measurements__make_base_discrete_gaussian_fixed_iteration
+    function.argtypes = [ctypes.c_void_p, ctypes.c_char_p, ctypes.c_char_p]
+    function.restype = FfiResult
+    
+    return c_to_py(unwrap(function(scale, D, MO), Measurement))
+
+
 def make_randomized_response_bool(
     prob,
     constant_time: bool = False,
@@ -352,7 +535,8 @@ def make_randomized_response(
     T: RuntimeTypeDescriptor = None,
     Q: RuntimeTypeDescriptor = None
 ) -> Measurement:
-    """Make a Measurement that implements randomized response on a categorical value.
+    """Make a Measurement that implements randomized response on a categorical value. 
+    Values that are not a category are always released as a lie.
     
     :param categories: Set of valid outcomes
     :type categories: Any
@@ -432,3 +616,154 @@ def make_base_ptr(
     function.restype = FfiResult
     
     return c_to_py(unwrap(function(scale, threshold, k, TK, TV), Measurement))
+
+
+def make_sized_bounded_winsorized_mean(
+    size: int,
+    bounds: Tuple[Any, Any],
+    bin_edges: Any,
+    alpha,
+    quantile_scale,
+    scale,
+    T: RuntimeTypeDescriptor = None
+) -> Measurement:
+    """Make a Measurement that releases the mean of bounded data, after clipping each record to the `alpha` and `1 - alpha` quantiles of the data. 
+    The quantiles are first estimated privately from a histogram over `bin_edges`. 
+    The privacy loss is the sum of the privacy losses of the quantile and mean releases.
+    
+    :param size: Number of records in input data.
+    :type size: int
+    :param bounds: Tuple of inclusive lower and upper bounds of the input data.
+    :type bounds: Tuple[Any, Any]
+    :param bin_edges: Strictly increasing edges of the histogram used to estimate the quantiles.
+    :type bin_edges: Any
+    :param alpha: Fraction of records to clip in each tail. Must be in [0, 0.5].
+    :param quantile_scale: Noise scale for the histogram counts.
+    :param scale: Noise scale for the clipped mean.
+    :param T: atomic data type
+    :type T: :ref:`RuntimeTypeDescriptor`
+    :return: A sized_bounded_winsorized_mean step.
+    :rtype: Measurement
+    :raises AssertionError: if an argument's type differs from the expected type
+    :raises UnknownTypeError: if a type-argument fails to parse
+    :raises OpenDPException: packaged error from the core OpenDP library
+    """
+    assert_features("floating-point", "contrib")
+    
+    # Standardize type arguments.
+    T = RuntimeType.parse_or_infer(type_name=T, public_example=get_first(bounds))
+    
+    # Convert arguments to c types.
+    size = py_to_c(size, c_type=ctypes.c_uint)
+    bounds = py_to_c(bounds, c_type=AnyObjectPtr, type_name=RuntimeType(origin='Tuple', args=[T, T]))
+    bin_edges = py_to_c(bin_edges, c_type=AnyObjectPtr, type_name=RuntimeType(origin='Vec', args=[T]))
+    alpha = py_to_c(alpha, c_type=ctypes.c_void_p, type_name=T)
+    quantile_scale = py_to_c(quantile_scale, c_type=ctypes.c_void_p, type_name=T)
+    scale = py_to_c(scale, c_type=ctypes.c_void_p, type_name=T)
+    T = py_to_c(T, c_type=ctypes.c_char_p)
+    
+    # Call library function.
+    function = lib.opendp_measurements__make_sized_bounded_winsorized_mean
+    function.argtypes = [ctypes.c_uint, AnyObjectPtr, AnyObjectPtr, ctypes.c_void_p, ctypes.c_void_p, ctypes.c_void_p, ctypes.c_char_p]
+    function.restype = FfiResult
+    
+    return c_to_py(unwrap(function(size, bounds, bin_edges, alpha, quantile_scale, scale, T), Measurement))
+
+
+def make_mwem(
+    size: int,
+    categories: Any,
+    queries: List[List[int]],
+    rounds: int,
+    selection_scale,
+    scale,
+    TA: RuntimeTypeDescriptor = None,
+    MO: RuntimeTypeDescriptor = "MaxDivergence<float>"
+) -> Measurement:
+    """Make a Measurement that releases a synthetic distribution over `categories` with the Multiplicative Weights Exponential Mechanism (MWEM). 
+    In each round, the query worst answered by the synthetic distribution is selected with an exponential mechanism, 
+    and a noisy answer to the query is used to update the synthetic distribution with multiplicative weights. 
+    The released distribution is the average of the synthetic distributions in each round, and sums to `size`.
+    
+    :param size: Number of records in input data.
+    :type size: int
+    :param categories: The set of categories the data may take on.
+    :type categories: Any
+    :param queries: Workload of counting queries. Each query is a set of distinct indices into `categories`.
+    :type queries: List[List[int]]
+    :param rounds: Number of queries to select and measure.
+    :type rounds: int
+    :param selection_scale: Noise scale for the exponential mechanism.
+    :param scale: Noise scale for the query answers.
+    :param TA: categorical/hashable data type of the input data
+    :type TA: :ref:`RuntimeTypeDescriptor`
+    :param MO: Output measure. Either MaxDivergence<T> for Laplace noise, or ZeroConcentratedDivergence<T> for Gaussian noise.
+    :type MO: :ref:`RuntimeTypeDescriptor`
+    :return: A mwem step.
+    :rtype: Measurement
+    :raises AssertionError: if an argument's type differs from the expected type
+    :raises UnknownTypeError: if a type-argument fails to parse
+    :raises OpenDPException: packaged error from the core OpenDP library
+    """
+    assert_features("floating-point", "contrib")
+    
+    # Standardize type arguments.
+    TA = RuntimeType.parse_or_infer(type_name=TA, public_example=get_first(categories))
+    MO = RuntimeType.parse(type_name=MO)
+    T = get_atom(MO)
+    
+    # Convert arguments to c types.
+    size = py_to_c(size, c_type=ctypes.c_uint)
+    categories = py_to_c(categories, c_type=AnyObjectPtr, type_name=RuntimeType(origin='Vec', args=[TA]))
+    queries = py_to_c(queries, c_type=AnyObjectPtr, type_name=RuntimeType(origin='Vec', args=[RuntimeType(origin='Vec', args=["usize"])]))
+    rounds = py_to_c(rounds, c_type=ctypes.c_uint)
+    selection_scale = py_to_c(selection_scale, c_type=ctypes.c_void_p, type_name=T)
+    scale = py_to_c(scale, c_type=ctypes.c_void_p, type_name=T)
+    TA = py_to_c(TA, c_type=ctypes.c_char_p)
+    MO = py_to_c(MO, c_type=ctypes.c_char_p)
+    
+    # Call library function.
+    function = lib.opendp_measurements__make_mwem
+    function.argtypes = [ctypes.c_uint, AnyObjectPtr, AnyObjectPtr, ctypes.c_uint, ctypes.c_void_p, ctypes.c_void_p, ctypes.c_char_p, ctypes.c_char_p]
+    function.restype = FfiResult
+    
+    return c_to_py(unwrap(function(size, categories, queries, rounds, selection_scale, scale, TA, MO), Measurement))
+
+
+def make_synthetic_dataset(
+    categories: Any,
+    TA: RuntimeTypeDescriptor = None,
+    T: RuntimeTypeDescriptor = "float"
+) -> Transformation:
+    """Make a postprocessor that expands a synthetic distribution over `categories` into a synthetic dataset. 
+    Each weight is rounded to a whole number of records, such that the total number of records is the rounded sum of the weights.
+    
+    :param categories: The set of categories, in the same order as the weights.
+    :type categories: Any
+    :param TA: categorical/hashable data type of the synthetic data
+    :type TA: :ref:`RuntimeTypeDescriptor`
+    :param T: data type of the weights
+    :type T: :ref:`RuntimeTypeDescriptor`
+    :return: A synthetic_dataset step.
+    :rtype: Transformation
+    :raises AssertionError: if an argument's type differs from the expected type
+    :raises UnknownTypeError: if a type-argument fails to parse
+    :raises OpenDPException: packaged error from the core OpenDP library
+    """
+    assert_features("floating-point", "contrib")
+    
+    # Standardize type arguments.
+    TA = RuntimeType.parse_or_infer(type_name=TA, public_example=get_first(categories))
+    T = RuntimeType.parse(type_name=T)
+    
+    # Convert arguments to c types.
+    categories = py_to_c(categories, c_type=AnyObjectPtr, type_name=RuntimeType(origin='Vec', args=[TA]))
+    TA = py_to_c(TA, c_type=ctypes.c_char_p)
+    T = py_to_c(T, c_type=ctypes.c_char_p)
+    
+    # Call library function.
+    function = lib.opendp_measurements__make_synthetic_dataset
+    function.argtypes = [AnyObjectPtr, ctypes.c_char_p, ctypes.c_char_p]
+    function.restype = FfiResult
+    
+    return c_to_py(unwrap(function(categories, TA, T), Transformation))
@@ -1,7 +1,7 @@
 import ctypes
-from typing import Union, Tuple, Callable, Optional
+from typing import Any, Union, Tuple, Callable, Optional
 
-from opendp._lib import AnyMeasurement, AnyTransformation
+from opendp._lib import AnyMeasurement, AnyObjectPtr, AnyTransformation
 
 
 class Measurement(ctypes.POINTER(AnyMeasurement)):
@@ -44,25 +44,37 @@ class Measurement(ctypes.POINTER(AnyMeasurement)):
 
     def __call__(self, arg):
         from opendp.core import measurement_invoke
-        return measurement_invoke(self, arg)
+        return measurement_invoke(self, arg, check_domain="checked-invoke" in GLOBAL_FEATURES)
 
     def invoke(self, arg):
         """Create a differentially-private release with `arg`.
 
         If `self` is (d_in, d_out)-close, then each invocation of this function is a d_out-DP release. 
+        When the "checked-invoke" feature is enabled, `arg` is first checked for membership in the input domain.
         
         :param arg: Input to the measurement.
         :return: differentially-private release
         :raises OpenDPException: packaged error from the core OpenDP library
         """
         from opendp.core import measurement_invoke
-        return measurement_invoke(self, arg)
+        return measurement_invoke(self, arg, check_domain="checked-invoke" in GLOBAL_FEATURES)
 
     def map(self, d_in):
         """Map an input distance `d_in` to an output distance."""
         from opendp.core import measurement_map
         return measurement_map(self, d_in)
 
+    def accuracy(self, alpha: float) -> float:
+        """Get the accuracy of the measurement at a statistical significance level of `alpha`.
+        Each release differs from its noiseless value by more than the accuracy with probability at most `alpha`.
+
+        :param alpha: Statistical significance level, in (0, 1].
+        :return: The accuracy of each release.
+        :raises OpenDPException: if the distribution of the noise is not known
+        """
+        from opendp.core import measurement_accuracy
+        return measurement_accuracy(self, alpha)
+
     def check(self, d_in, d_out, *, debug=False) -> bool:
         """Check if the measurement is (`d_in`, `d_out`)-close.
         If true, implies that if the distance between inputs is at most `d_in`, then the privacy usage is at most `d_out`.
@@ -176,16 +188,18 @@ class Transformation(ctypes.POINTER(AnyTransformation)):
     def invoke(self, arg):
         """Execute a non-differentially-private query with `arg`.
 
+        When the "checked-invoke" feature is enabled, `arg` is first checked for membership in the input domain.
+
         :param arg: Input to the transformation.
         :return: non-differentially-private answer
         :raises OpenDPException: packaged error from the core OpenDP library
         """
         from opendp.core import transformation_invoke
-        return transformation_invoke(self, arg)
+        return transformation_invoke(self, arg, check_domain="checked-invoke" in GLOBAL_FEATURES)
 
     def __call__(self, arg):
         from opendp.core import transformation_invoke
-        return transformation_invoke(self, arg)
+        return transformation_invoke(self, arg, check_domain="checked-invoke" in GLOBAL_FEATURES)
 
     def map(self, d_in):
         """Map an input distance `d_in` to an output distance."""
@@ -407,6 +421,7 @@ def binary_search_param(
     
     Optimizes a parameterized chain `make_chain` within float or integer `bounds`,
     subject to the chained relation being (`d_in`, `d_out`)-close.
+    The search runs in the library, where arguments for which `make_chain` or the check raises are considered not to pass.
 
     :param make_chain: a unary function that maps from a number to a Transformation or Measurement
     :param d_in: desired input distance of the computation chain
@@ -415,7 +430,8 @@ def binary_search_param(
     :param T: type of argument to `make_chain`, one of {float, int}
     :return: the nearest passing value to the decision point of the relation
     :raises TypeError: if the type is not inferrable (pass T) or the type is invalid
-    :raises ValueError: if the predicate function is constant, bounds cannot be inferred, or decision boundary is not within `bounds`.
+    :raises ValueError: if `make_chain` fails at each of `bounds`, or near zero when no bounds are passed
+    :raises OpenDPException: if the relation is constant, bounds cannot be inferred, or decision boundary is not within `bounds`.
 
     :example:
 
@@ -454,7 +470,29 @@ def binary_search_param(
     ...     bounds=(1, 1000000))
     1498
     """
-    return binary_search(lambda param: make_chain(param).check(d_in, d_out), bounds, T)
+    from opendp.search import binary_search_param as _binary_search_param
+    from opendp._convert import py_to_c
+    from opendp.typing import RuntimeType
+
+    if T is None:
+        T = type(bounds[0]) if bounds is not None else \
+            _infer_search_type(lambda param: make_chain(param).check(d_in, d_out))
+    T = RuntimeType.parse(T)
+
+    # load the distances with the types of a chain built at the bounds, or near zero
+    params = bounds or ([0., 1.] if T in ("f32", "f64") else [0, 1])
+    for param in params:
+        try:
+            chain = make_chain(param)
+        except Exception:
+            continue
+        d_in = py_to_c(d_in, c_type=AnyObjectPtr, type_name=chain.input_distance_type)
+        d_out = py_to_c(d_out, c_type=AnyObjectPtr, type_name=chain.output_distance_type)
+        break
+    else:
+        raise ValueError(f"unable to infer the distance types, as `make_chain` fails at each of {params}; pass bounds within which `make_chain` succeeds")
+
+    return _binary_search_param(make_chain, d_in, d_out, bounds=bounds, T=T)
 
 
 def binary_search(
@@ -565,6 +603,30 @@ def binary_search(
     return value
 
 
+def _infer_search_type(func: Callable[[Union[float, int]], Any]) -> type:
+    """Infer whether `func` takes a float or an int, by whether it raises a type error at zero.
+
+    :param func: a unary function from a number
+    :return: one of {float, int}
+    :raises TypeError: if the type is not inferrable (pass T)
+    """
+    def check_type(v):
+        try:
+            func(v)
+        except TypeError as e:
+            return False
+        except OpenDPException as e:
+            if "No match for concrete type" in e.message:
+                return False
+        return True
+
+    if check_type(0.):
+        return float
+    if check_type(0):
+        return int
+    raise TypeError("unable to infer type `T`; pass the type `T` or bounds")
+
+
 def exponential_bounds_search(
     predicate: Callable[[Union[float, int]], bool], 
     T: Optional[type]) -> Optional[Union[Tuple[float, float], Tuple[int, int]]]:
@@ -581,24 +643,8 @@ def exponential_bounds_search(
     :raises ValueError: if the predicate function is constant
     """
 
-    # try to infer T
     if T is None:
-        def check_type(v):
-            try:
-                predicate(v)
-            except TypeError as e:
-                return False
-            except OpenDPException as e:
-                if "No match for concrete type" in e.message:
-                    return False
-            return True
-        
-        if check_type(0.):
-            T = float
-        elif check_type(0):
-            T = int
-        else:
-            raise TypeError("unable to infer type `T`; pass the type `T` or bounds")
+        T = _infer_search_type(predicate)
 
     # core search functionality
     def signed_band_search(center, at_center, sign):
@@ -0,0 +1,139 @@
+# Auto-generated. Do not edit.
+from opendp._convert import *
+from opendp._lib import *
+from opendp.mod import *
+from opendp.typing import *
+
+__all__ = [
+    "binary_search",
+    "binary_search_param",
+    "binary_search_chain"
+]
+
+
+def binary_search(
+    predicate: Callable[[Any], bool],
+    T: RuntimeTypeDescriptor,
+    bounds: Any = None
+) -> Any:
+    """Find the nearest passing value to the decision boundary of `predicate` within `bounds`. 
+    Values at which `predicate` fails are considered not to pass. 
+    If the lower bound passes, the largest passing value is returned, otherwise the smallest.
+    
+    :param predicate: A monotonic function from a number to a boolean.
+    :type predicate: Callable[[Any], bool]
+    :param bounds: Tuple of lower and upper bounds on the decision boundary. If not passed, the bounds are found by an exponential search.
+    :type bounds: Any
+    :param T: Type of the argument to `predicate`. One of u32, u64, i32, i64, usize, f32 or f64.
+    :type T: :ref:`RuntimeTypeDescriptor`
+    :return: The passing value nearest to the decision boundary.
+    :rtype: Any
+    :raises AssertionError: if an argument's type differs from the expected type
+    :raises UnknownTypeError: if a type-argument fails to parse
+    :raises OpenDPException: packaged error from the core OpenDP library
+    """
+    # Standardize type arguments.
+    T = RuntimeType.parse(type_name=T)
+    OptionT = RuntimeType(origin='Option', args=[RuntimeType(origin='Tuple', args=[T, T])])
+    
+    # Convert arguments to c types.
+    predicate = py_to_c(predicate, c_type=CallbackFn, type_name=bool)
+    bounds = py_to_c(bounds, c_type=AnyObjectPtr, type_name=OptionT)
+    T = py_to_c(T, c_type=ctypes.c_char_p)
+    
+    # Call library function.
+    function = lib.opendp_search__binary_search
+    function.argtypes = [CallbackFn, AnyObjectPtr, ctypes.c_char_p]
+    function.restype = FfiResult
+    
+    return c_to_py(unwrap(function(predicate, bounds, T), AnyObjectPtr))
+
+
+def binary_search_param(
+    make_chain: Callable[[Any], Union[Measurement, Transformation]],
+    d_in: Any,
+    d_out: Any,
+    T: RuntimeTypeDescriptor,
+    bounds: Any = None
+) -> Any:
+    """Find the tightest argument to `make_chain` for which the chain is (`d_in`, `d_out`)-close. 
+    Arguments for which `make_chain` or the check fails are considered not to pass.
+    
+    :param make_chain: A function from the argument to a Measurement or Transformation.
+    :type make_chain: Callable[[Any], Union[Measurement, Transformation]]
+    :param d_in: Desired input distance of the computation chain, with the type of the chain's input distance.
+    :type d_in: Any
+    :param d_out: Desired output distance of the computation chain, with the type of the chain's output distance.
+    :type d_out: Any
+    :param bounds: Tuple of lower and upper bounds on the argument. If not passed, the bounds are found by an exponential search.
+    :type bounds: Any
+    :param T: Type of the argument to `make_chain`. One of u32, u64, i32, i64, usize, f32 or f64.
+    :type T: :ref:`RuntimeTypeDescriptor`
+    :return: The passing argument nearest to the decision boundary.
+    :rtype: Any
+    :raises AssertionError: if an argument's type differs from the expected type
+    :raises UnknownTypeError: if a type-argument fails to parse
+    :raises OpenDPException: packaged error from the core OpenDP library
+    """
+    # Standardize type arguments.
+    T = RuntimeType.parse(type_name=T)
+    OptionT = RuntimeType(origin='Option', args=[RuntimeType(origin='Tuple', args=[T, T])])
+    
+    # Convert arguments to c types.
+    make_chain = py_to_c(make_chain, c_type=CallbackFn)
+    d_in = py_to_c(d_in, c_type=AnyObjectPtr)
+    d_out = py_to_c(d_out, c_type=AnyObjectPtr)
+    bounds = py_to_c(bounds, c_type=AnyObjectPtr, type_name=OptionT)
+    T = py_to_c(T, c_type=ctypes.c_char_p)
+    
+    # Call library function.
+    function = lib.opendp_search__binary_search_param
+    function.argtypes = [CallbackFn, AnyObjectPtr, AnyObjectPtr, AnyObjectPtr, ctypes.c_char_p]
+    function.restype = FfiResult
+    
+    return c_to_py(unwrap(function(make_chain, d_in, d_out, bounds, T), AnyObjectPtr))
+
+
+def binary_search_chain(
+    make_chain: Callable[[Any], Union[Measurement, Transformation]],
+    d_in: Any,
+    d_out: Any,
+    T: RuntimeTypeDescriptor,
+    bounds: Any = None
+) -> Any:
+    """Find the computation chain built by `make_chain` with the tightest argument for which it is (`d_in`, `d_out`)-close. 
+    Arguments for which `make_chain` or the check fails are considered not to pass.
+    
+    :param make_chain: A function from the argument to a Measurement or Transformation.
+    :type make_chain: Callable[[Any], Union[Measurement, Transformation]]
+    :param d_in: Desired input distance of the computation chain, with the type of the chain's input distance.
+    :type d_in: Any
+    :param d_out: Desired output distance of the computation chain, with the type of the chain's output distance.
+    :type d_out: Any
+    :param bounds: Tuple of lower and upper bounds on the argument. If not passed, the bounds are found by an exponential search.
+    :type bounds: Any
+    :param T: Type of the argument to `make_chain`. One of u32, u64, i32, i64, usize, f32 or f64.
+    :type T: :ref:`RuntimeTypeDescriptor`
+    :return: The Measurement or Transformation built at the passing argument nearest to the decision boundary.
+    :rtype: Any
+    :raises AssertionError: if an argument's type differs from the expected type
+    :raises UnknownTypeError: if a type-argument fails to parse
+    :raises OpenDPException: packaged error from the core OpenDP library
+    """
+    # Standardize type arguments.
+    T = RuntimeType.parse(type_name=T)
+    OptionT = RuntimeType(origin='Option', args=[RuntimeType(origin='Tuple', args=[T, T])])
+    
+    # Convert arguments to c types.
+    make_chain = py_to_c(make_chain, c_type=CallbackFn)
+    d_in = py_to_c(d_in, c_type=AnyObjectPtr)
+    d_out = py_to_c(d_out, c_type=AnyObjectPtr)
+    bounds = py_to_c(bounds, c_type=AnyObjectPtr, type_name=OptionT)
+    T = py_to_c(T, c_type=ctypes.c_char_p)
+    
+    # Call library function.
+    function = lib.opendp_search__binary_search_chain
+    function.argtypes = [CallbackFn, AnyObjectPtr, AnyObjectPtr, AnyObjectPtr, ctypes.c_char_p]
+    function.restype = FfiResult
+    
+    return c_to_py(unwrap(function(make_chain, d_in, d_out, bounds, T), AnyObjectPtr))
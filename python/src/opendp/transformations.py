@@ -14,6 +14,8 @@ __all__ = [
     "make_df_is_equal",
     "make_subset_by",
     "make_is_equal",
+    "make_filter_map",
+    "make_filter",
     "make_is_null",
     "make_cast_inherent",
     "make_ordered_random",
@@ -32,6 +34,9 @@ __all__ = [
     "make_quantiles_from_counts",
     "make_count_by",
     "make_count_by_categories",
+    "make_histogram",
+    "make_uniform_histogram",
+    "make_contingency_tables",
     "make_split_lines",
     "make_split_records",
     "make_create_dataframe",
@@ -46,15 +51,20 @@ __all__ = [
     "make_index",
     "make_lipschitz_float_mul",
     "make_sized_bounded_mean",
+    "make_size_bounded_mean",
+    "make_sized_bounded_trimmed_mean",
     "make_resize",
     "make_bounded_resize",
+    "make_truncate",
     "make_bounded_sum",
     "make_sized_bounded_sum",
     "make_bounded_float_checked_sum",
     "make_sized_bounded_float_checked_sum",
+    "make_size_bounded_float_checked_sum",
     "make_bounded_float_ordered_sum",
     "make_sized_bounded_float_ordered_sum",
     "make_sized_bounded_int_checked_sum",
+    "make_size_bounded_int_checked_sum",
     "make_bounded_int_monotonic_sum",
     "make_sized_bounded_int_monotonic_sum",
     "make_bounded_int_ordered_sum",
@@ -62,7 +72,9 @@ __all__ = [
     "make_bounded_int_split_sum",
     "make_sized_bounded_int_split_sum",
     "make_sized_bounded_sum_of_squared_deviations",
-    "make_sized_bounded_variance"
+    "make_sized_bounded_variance",
+    "make_psd_projection",
+    "make_regression_solver"
 ]
 
 
@@ -396,6 +408,79 @@ def make_is_equal(
     return c_to_py(unwrap(function(value, TIA), Transformation))
 
 
+def make_filter_map(
+    func: Callable[[Any], Any],
+    TIA: RuntimeTypeDescriptor,
+    TOA: RuntimeTypeDescriptor
+) -> Transformation:
+    """Make a Transformation that applies `func` to each element, and drops the elements for which `func` fails. 
+    Dropping, rather than failing the whole release, keeps failures from revealing which elements were in the data.
+    
+    :param func: function to apply to each element
+    :type func: Callable[[Any], Any]
+    :param TIA: atomic input data type
+    :type TIA: :ref:`RuntimeTypeDescriptor`
+    :param TOA: atomic output data type
+    :type TOA: :ref:`RuntimeTypeDescriptor`
+    :return: A filter_map step.
+    :rtype: Transformation
+    :raises AssertionError: if an argument's type differs from the expected type
+    :raises UnknownTypeError: if a type-argument fails to parse
+    :raises OpenDPException: packaged error from the core OpenDP library
+    """
+    assert_features("contrib")
+    
+    # Standardize type arguments.
+    TIA = RuntimeType.parse(type_name=TIA)
+    TOA = RuntimeType.parse(type_name=TOA)
+    
+    # Convert arguments to c types.
+    func = py_to_c(func, c_type=CallbackFn, type_name=TOA)
+    TIA = py_to_c(TIA, c_type=ctypes.c_char_p)
+    TOA = py_to_c(TOA, c_type=ctypes.c_char_p)
+    
+    # Call library function.
+    function = lib.opendp_transformations__make_filter_map
+    function.argtypes = [CallbackFn, ctypes.c_char_p, ctypes.c_char_p]
+    function.restype = FfiResult
+    
+    return c_to_py(unwrap(function(func, TIA, TOA), Transformation))
+
+
+def make_filter(
+    predicate: Callable[[Any], bool],
+    T: RuntimeTypeDescriptor
+) -> Transformation:
+    """Make a Transformation that keeps only the elements for which `predicate` returns true. 
+    Elements for which `predicate` fails are dropped.
+    
+    :param predicate: function that returns true for elements to keep
+    :type predicate: Callable[[Any], bool]
+    :param T: atomic data type
+    :type T: :ref:`RuntimeTypeDescriptor`
+    :return: A filter step.
+    :rtype: Transformation
+    :raises AssertionError: if an argument's type differs from the expected type
+    :raises UnknownTypeError: if a type-argument fails to parse
+    :raises OpenDPException: packaged error from the core OpenDP library
+    """
+    assert_features("contrib")
+    
+    # Standardize type arguments.
+    T = RuntimeType.parse(type_name=T)
+    
+    # Convert arguments to c types.
+    predicate = py_to_c(predicate, c_type=CallbackFn, type_name=bool)
+    T = py_to_c(T, c_type=ctypes.c_char_p)
+    
+    # Call library function.
+    function = lib.opendp_transformations__make_filter
+    function.argtypes = [CallbackFn, ctypes.c_char_p]
+    function.restype = FfiResult
+    
+    return c_to_py(unwrap(function(predicate, T), Transformation))
+
+
 def make_is_null(
     DIA: RuntimeTypeDescriptor
 ) -> Transformation:
@@ -1066,6 +1151,163 @@ def make_count_by_categories(
     return c_to_py(unwrap(function(categories, null_category, MO, TIA, TOA), Transformation))
 
 
+def make_histogram(
+    edges: Any,
+    MO: SensitivityMetric = "L1Distance<int>",
+    TA: RuntimeTypeDescriptor = None,
+    TC: RuntimeTypeDescriptor = "int"
+) -> Transformation:
+    """Make a Transformation that computes a histogram of the data over bins defined by `edges`. 
+    The output includes an underflow bin at the beginning and an overflow bin at the end, so there is one more count than edges. 
+    From FFI, only the counts are returned, and they may be passed to `make_cdf`, or to `make_quantiles_from_counts` with the same `edges`.
+    
+    :param edges: Strictly increasing bin edges.
+    :type edges: Any
+    :param MO: output sensitivity metric
+    :type MO: SensitivityMetric
+    :param TA: atomic input data type
+    :type TA: :ref:`RuntimeTypeDescriptor`
+    :param TC: express counts in terms of this numeric type
+    :type TC: :ref:`RuntimeTypeDescriptor`
+    :return: A histogram step.
+    :rtype: Transformation
+    :raises AssertionError: if an argument's type differs from the expected type
+    :raises UnknownTypeError: if a type-argument fails to parse
+    :raises OpenDPException: packaged error from the core OpenDP library
+    """
+    assert_features("contrib")
+    
+    # Standardize type arguments.
+    MO = RuntimeType.parse(type_name=MO)
+    TA = RuntimeType.parse_or_infer(type_name=TA, public_example=next(iter(edges), None))
+    TC = RuntimeType.parse(type_name=TC)
+    
+    # Convert arguments to c types.
+    edges = py_to_c(edges, c_type=AnyObjectPtr, type_name=RuntimeType(origin='Vec', args=[TA]))
+    MO = py_to_c(MO, c_type=ctypes.c_char_p)
+    TA = py_to_c(TA, c_type=ctypes.c_char_p)
+    TC = py_to_c(TC, c_type=ctypes.c_char_p)
+    
+    # Call library function.
+    function = lib.opendp_transformations__make_histogram
+    function.argtypes = [AnyObjectPtr, ctypes.c_char_p, ctypes.c_char_p, ctypes.c_char_p]
+    function.restype = FfiResult
+    
+    return c_to_py(unwrap(function(edges, MO, TA, TC), Transformation))
+
+
+def make_uniform_histogram(
+    bounds: Tuple[Any, Any],
+    bins: int,
+    MO: SensitivityMetric = "L1Distance<int>",
+    TA: RuntimeTypeDescriptor = None,
+    TC: RuntimeTypeDescriptor = "int"
+) -> Transformation:
+    """Make a Transformation that computes a histogram of the data over `bins` equal-width bins spanning `bounds`. 
+    The output includes an underflow bin at the beginning and an overflow bin at the end.
+    
+    :param bounds: Tuple of lower and upper edges of the bins.
+    :type bounds: Tuple[Any, Any]
+    :param bins: Number of bins between the bounds.
+    :type bins: int
+    :param MO: output sensitivity metric
+    :type MO: SensitivityMetric
+    :param TA: atomic input data type
+    :type TA: :ref:`RuntimeTypeDescriptor`
+    :param TC: express counts in terms of this numeric type
+    :type TC: :ref:`RuntimeTypeDescriptor`
+    :return: A uniform_histogram step.
+    :rtype: Transformation
+    :raises AssertionError: if an argument's type differs from the expected type
+    :raises UnknownTypeError: if a type-argument fails to parse
+    :raises OpenDPException: packaged error from the core OpenDP library
+    """
+    assert_features("contrib")
+    
+    # Standardize type arguments.
+    MO = RuntimeType.parse(type_name=MO)
+    TA = RuntimeType.parse_or_infer(type_name=TA, public_example=get_first(bounds))
+    TC = RuntimeType.parse(type_name=TC)
+    
+    # Convert arguments to c types.
+    bounds = py_to_c(bounds, c_type=AnyObjectPtr, type_name=RuntimeType(origin='Tuple', args=[TA, TA]))
+    bins = py_to_c(bins, c_type=ctypes.c_uint)
+    MO = py_to_c(MO, c_type=ctypes.c_char_p)
+    TA = py_to_c(TA, c_type=ctypes.c_char_p)
+    TC = py_to_c(TC, c_type=ctypes.c_char_p)
+    
+    # Call library function.
+    function = lib.opendp_transformations__make_uniform_histogram
+    function.argtypes = [AnyObjectPtr, ctypes.c_uint, ctypes.c_char_p, ctypes.c_char_p, ctypes.c_char_p]
+    function.restype = FfiResult
+    
+    return c_to_py(unwrap(function(bounds, bins, MO, TA, TC), Transformation))
+
+
+def make_contingency_tables(
+    keys: Any,
+    categories: List[List[Any]],
+    marginals: List[List[Any]],
+    MI: DatasetMetric = "SymmetricDistance",
+    MO: SensitivityMetric = "L1Distance<int>",
+    K: RuntimeTypeDescriptor = None,
+    TA: RuntimeTypeDescriptor = None,
+    TC: RuntimeTypeDescriptor = "int"
+) -> Transformation:
+    """Make a Transformation that computes many marginals of a dataframe at once. 
+    Each marginal is a flattened contingency table over a subset of `keys`, laid out in row-major order. 
+    Records with a value outside of the categories of any key are not counted. 
+    The sensitivity scales linearly in the number of marginals under the L1 distance, and by the square root under the L2 distance.
+    
+    :param keys: Column names of the categorical columns.
+    :type keys: Any
+    :param categories: The set of categories for each key.
+    :type categories: List[List[Any]]
+    :param marginals: Subsets of `keys` to compute contingency tables over.
+    :type marginals: List[List[Any]]
+    :param MI: input dataset metric. Either SymmetricDistance or ChangeOneDistance
+    :type MI: DatasetMetric
+    :param MO: output sensitivity metric
+    :type MO: SensitivityMetric
+    :param K: categorical/hashable data type of the key/column name. One of String, i32, i64 or bool
+    :type K: :ref:`RuntimeTypeDescriptor`
+    :param TA: categorical/hashable data type of the columns. One of String, i32, i64 or bool
+    :type TA: :ref:`RuntimeTypeDescriptor`
+    :param TC: express counts in terms of this integral type
+    :type TC: :ref:`RuntimeTypeDescriptor`
+    :return: A contingency_tables step.
+    :rtype: Transformation
+    :raises AssertionError: if an argument's type differs from the expected type
+    :raises UnknownTypeError: if a type-argument fails to parse
+    :raises OpenDPException: packaged error from the core OpenDP library
+    """
+    assert_features("contrib")
+    
+    # Standardize type arguments.
+    MI = RuntimeType.parse(type_name=MI)
+    MO = RuntimeType.parse(type_name=MO)
+    K = RuntimeType.parse_or_infer(type_name=K, public_example=get_first(keys))
+    TA = RuntimeType.parse_or_infer(type_name=TA, public_example=get_first(get_first(categories)))
+    TC = RuntimeType.parse(type_name=TC)
+    
+    # Convert arguments to c types.
+    keys = py_to_c(keys, c_type=AnyObjectPtr, type_name=RuntimeType(origin='Vec', args=[K]))
+    categories = py_to_c(categories, c_type=AnyObjectPtr, type_name=RuntimeType(origin='Vec', args=[RuntimeType(origin='Vec', args=[TA])]))
+    marginals = py_to_c(marginals, c_type=AnyObjectPtr, type_name=RuntimeType(origin='Vec', args=[RuntimeType(origin='Vec', args=[K])]))
+    MI = py_to_c(MI, c_type=ctypes.c_char_p)
+    MO = py_to_c(MO, c_type=ctypes.c_char_p)
+    K = py_to_c(K, c_type=ctypes.c_char_p)
+    TA = py_to_c(TA, c_type=ctypes.c_char_p)
+    TC = py_to_c(TC, c_type=ctypes.c_char_p)
+    
+    # Call library function.
+    function = lib.opendp_transformations__make_contingency_tables
+    function.argtypes = [AnyObjectPtr, AnyObjectPtr, AnyObjectPtr, ctypes.c_char_p, ctypes.c_char_p, ctypes.c_char_p, ctypes.c_char_p, ctypes.c_char_p]
+    function.restype = FfiResult
+    
+    return c_to_py(unwrap(function(keys, categories, marginals, MI, MO, K, TA, TC), Transformation))
+
+
 def make_split_lines(
     
 ) -> Transformation:
@@ -1363,7 +1605,8 @@ def make_find(
     categories: Any,
     TIA: RuntimeTypeDescriptor = None
 ) -> Transformation:
-    """Find the index of a data value in a set of categories.
+    """Find the index of a data value in a set of categories. 
+    Values that are not a category map to null.
     
     :param categories: The set of categories to find indexes from.
     :type categories: Any
@@ -1430,7 +1673,8 @@ def make_index(
     null: Any,
     TOA: RuntimeTypeDescriptor = None
 ) -> Transformation:
-    """Index into a vector of categories.
+    """Index into a vector of categories. 
+    Indexes that are out of range map to `null`.
     
     :param categories: The set of categories to index into.
     :type categories: Any
@@ -1550,6 +1794,90 @@ def make_sized_bounded_mean(
     return c_to_py(unwrap(function(size, bounds, MI, T), Transformation))
 
 
+def make_size_bounded_mean(
+    size_bounds: Tuple[int, int],
+    bounds: Tuple[Any, Any],
+    S: RuntimeTypeDescriptor = "Pairwise<T>"
+) -> Transformation:
+    """Make a Transformation that computes the mean of bounded floats, where the dataset size is bounded. 
+    The sensitivity shrinks with the lower size bound, while the upper size bound rules out overflow and bounds the floating-point error. 
+    The mean of an empty dataset is the midpoint of `bounds`.
+    
+    :param size_bounds: Tuple of inclusive lower and upper bounds on the number of records in input data.
+    :type size_bounds: Tuple[int, int]
+    :param bounds: Tuple of inclusive lower and upper bounds of the input data.
+    :type bounds: Tuple[Any, Any]
+    :param S: summation algorithm to use on data type T. One of Sequential<T> or Pairwise<T>.
+    :type S: :ref:`RuntimeTypeDescriptor`
+    :return: A size_bounded_mean step.
+    :rtype: Transformation
+    :raises AssertionError: if an argument's type differs from the expected type
+    :raises UnknownTypeError: if a type-argument fails to parse
+    :raises OpenDPException: packaged error from the core OpenDP library
+    """
+    assert_features("contrib")
+    
+    # Standardize type arguments.
+    S = RuntimeType.parse(type_name=S, generics=["T"])
+    T = get_atom_or_infer(S, get_first(bounds))
+    S = S.substitute(T=T)
+    
+    # Convert arguments to c types.
+    size_bounds = py_to_c(size_bounds, c_type=AnyObjectPtr, type_name=RuntimeType(origin='Tuple', args=["usize", "usize"]))
+    bounds = py_to_c(bounds, c_type=AnyObjectPtr, type_name=RuntimeType(origin='Tuple', args=[T, T]))
+    S = py_to_c(S, c_type=ctypes.c_char_p)
+    
+    # Call library function.
+    function = lib.opendp_transformations__make_size_bounded_mean
+    function.argtypes = [AnyObjectPtr, AnyObjectPtr, ctypes.c_char_p]
+    function.restype = FfiResult
+    
+    return c_to_py(unwrap(function(size_bounds, bounds, S), Transformation))
+
+
+def make_sized_bounded_trimmed_mean(
+    size: int,
+    bounds: Tuple[Any, Any],
+    trim,
+    S: RuntimeTypeDescriptor = "Pairwise<T>"
+) -> Transformation:
+    """Make a Transformation that computes the mean of bounded data, after dropping the `trim` fraction of smallest and largest records. 
+    Use `make_clamp` to bound data and `make_bounded_resize` to establish dataset size.
+    
+    :param size: Number of records in input data.
+    :type size: int
+    :param bounds: Tuple of inclusive lower and upper bounds of the input data.
+    :type bounds: Tuple[Any, Any]
+    :param trim: Fraction of records to drop from each tail. Must be in [0, 0.5).
+    :param S: summation algorithm to use on data type T. One of Sequential<T> or Pairwise<T>.
+    :type S: :ref:`RuntimeTypeDescriptor`
+    :return: A sized_bounded_trimmed_mean step.
+    :rtype: Transformation
+    :raises AssertionError: if an argument's type differs from the expected type
+    :raises UnknownTypeError: if a type-argument fails to parse
+    :raises OpenDPException: packaged error from the core OpenDP library
+    """
+    assert_features("contrib")
+    
+    # Standardize type arguments.
+    S = RuntimeType.parse(type_name=S, generics=["T"])
+    T = get_atom_or_infer(S, get_first(bounds))
+    S = S.substitute(T=T)
+    
+    # Convert arguments to c types.
+    size = py_to_c(size, c_type=ctypes.c_uint)
+    bounds = py_to_c(bounds, c_type=AnyObjectPtr, type_name=RuntimeType(origin='Tuple', args=[T, T]))
+    trim = py_to_c(trim, c_type=ctypes.c_void_p, type_name=T)
+    S = py_to_c(S, c_type=ctypes.c_char_p)
+    
+    # Call library function.
+    function = lib.opendp_transformations__make_sized_bounded_trimmed_mean
+    function.argtypes = [ctypes.c_uint, AnyObjectPtr, ctypes.c_void_p, ctypes.c_char_p]
+    function.restype = FfiResult
+    
+    return c_to_py(unwrap(function(size, bounds, trim, S), Transformation))
+
+
 def make_resize(
     size: int,
     constant: Any,
@@ -1647,6 +1975,54 @@ def make_bounded_resize(
     return c_to_py(unwrap(function(size, bounds, constant, MI, MO, TA), Transformation))
 
 
+def make_truncate(
+    max_size: int,
+    bounds: Tuple[Any, Any],
+    MI: RuntimeTypeDescriptor = "SymmetricDistance",
+    MO: RuntimeTypeDescriptor = "SymmetricDistance",
+    TA: RuntimeTypeDescriptor = None
+) -> Transformation:
+    """Make a Transformation that truncates a Vec<`TA`> to at most `max_size` records. 
+    The output domain has a size upper bound of `max_size`, which may be used by `make_size_bounded_float_checked_sum`, `make_size_bounded_int_checked_sum` and `make_size_bounded_mean`.
+    
+    :param max_size: Maximum number of records in output data.
+    :type max_size: int
+    :param bounds: Tuple of lower and upper bounds for data in the input domain
+    :type bounds: Tuple[Any, Any]
+    :param MI: Input Metric. One of `InsertDeleteDistance` or `SymmetricDistance`
+    :type MI: :ref:`RuntimeTypeDescriptor`
+    :param MO: Output Metric. One of `InsertDeleteDistance` or `SymmetricDistance`
+    :type MO: :ref:`RuntimeTypeDescriptor`
+    :param TA: Atomic type. If not passed, TA is inferred from the lower bound.
+    :type TA: :ref:`RuntimeTypeDescriptor`
+    :return: A vector of the same type `TA`, with at most `max_size` records.
+    :rtype: Transformation
+    :raises AssertionError: if an argument's type differs from the expected type
+    :raises UnknownTypeError: if a type-argument fails to parse
+    :raises OpenDPException: packaged error from the core OpenDP library
+    """
+    assert_features("contrib")
+    
+    # Standardize type arguments.
+    MI = RuntimeType.parse(type_name=MI)
+    MO = RuntimeType.parse(type_name=MO)
+    TA = RuntimeType.parse_or_infer(type_name=TA, public_example=get_first(bounds))
+    
+    # Convert arguments to c types.
+    max_size = py_to_c(max_size, c_type=ctypes.c_uint)
+    bounds = py_to_c(bounds, c_type=AnyObjectPtr, type_name=RuntimeType(origin='Tuple', args=[TA, TA]))
+    MI = py_to_c(MI, c_type=ctypes.c_char_p)
+    MO = py_to_c(MO, c_type=ctypes.c_char_p)
+    TA = py_to_c(TA, c_type=ctypes.c_char_p)
+    
+    # Call library function.
+    function = lib.opendp_transformations__make_truncate
+    function.argtypes = [ctypes.c_uint, AnyObjectPtr, ctypes.c_char_p, ctypes.c_char_p, ctypes.c_char_p]
+    function.restype = FfiResult
+    
+    return c_to_py(unwrap(function(max_size, bounds, MI, MO, TA), Transformation))
+
+
 def make_bounded_sum(
     bounds: Tuple[Any, Any],
     MI: RuntimeTypeDescriptor = "SymmetricDistance",
@@ -1810,6 +2186,46 @@ def make_sized_bounded_float_checked_sum(
     return c_to_py(unwrap(function(size, bounds, S), Transformation))
 
 
+def make_size_bounded_float_checked_sum(
+    size_bounds: Tuple[int, int],
+    bounds: Tuple[Any, Any],
+    S: RuntimeTypeDescriptor = "Pairwise<T>"
+) -> Transformation:
+    """Make a Transformation that computes the sum of bounded floats, where the dataset size is bounded. 
+    The upper size bound is used to rule out overflow and to bound the floating-point error, so an exact size is not needed.
+    
+    :param size_bounds: Tuple of inclusive lower and upper bounds on the number of records in input data.
+    :type size_bounds: Tuple[int, int]
+    :param bounds: Tuple of lower and upper bounds for input data
+    :type bounds: Tuple[Any, Any]
+    :param S: summation algorithm to use on data type T. One of Sequential<T> or Pairwise<T>.
+    :type S: :ref:`RuntimeTypeDescriptor`
+    :return: A size_bounded_float_checked_sum step.
+    :rtype: Transformation
+    :raises AssertionError: if an argument's type differs from the expected type
+    :raises UnknownTypeError: if a type-argument fails to parse
+    :raises OpenDPException: packaged error from the core OpenDP library
+    """
+    assert_features("contrib")
+    
+    # Standardize type arguments.
+    S = RuntimeType.parse(type_name=S, generics=["T"])
+    T = get_atom_or_infer(S, get_first(bounds))
+    S = S.substitute(T=T)
+    
+    # Convert arguments to c types.
+    size_bounds = py_to_c(size_bounds, c_type=AnyObjectPtr, type_name=RuntimeType(origin='Tuple', args=["usize", "usize"]))
+    bounds = py_to_c(bounds, c_type=AnyObjectPtr, type_name=RuntimeType(origin='Tuple', args=[T, T]))
+    S = py_to_c(S, c_type=ctypes.c_char_p)
+    
+    # Call library function.
+    function = lib.opendp_transformations__make_size_bounded_float_checked_sum
+    function.argtypes = [AnyObjectPtr, AnyObjectPtr, ctypes.c_char_p]
+    function.restype = FfiResult
+    
+    return c_to_py(unwrap(function(size_bounds, bounds, S), Transformation))
+
+
 def make_bounded_float_ordered_sum(
     size_limit: int,
     bounds: Tuple[Any, Any],
@@ -1929,6 +2345,44 @@ def make_sized_bounded_int_checked_sum(
     return c_to_py(unwrap(function(size, bounds, T), Transformation))
 
 
+def make_size_bounded_int_checked_sum(
+    size_bounds: Tuple[int, int],
+    bounds: Tuple[Any, Any],
+    T: RuntimeTypeDescriptor = None
+) -> Transformation:
+    """Make a Transformation that computes the sum of bounded ints, where the dataset size is bounded. 
+    The effective range is reduced, as (bounds * upper size bound) must not overflow.
+    
+    :param size_bounds: Tuple of inclusive lower and upper bounds on the number of records in input data.
+    :type size_bounds: Tuple[int, int]
+    :param bounds: Tuple of lower and upper bounds for input data
+    :type bounds: Tuple[Any, Any]
+    :param T: atomic type of data
+    :type T: :ref:`RuntimeTypeDescriptor`
+    :return: A size_bounded_int_checked_sum step.
+    :rtype: Transformation
+    :raises AssertionError: if an argument's type differs from the expected type
+    :raises UnknownTypeError: if a type-argument fails to parse
+    :raises OpenDPException: packaged error from the core OpenDP library
+    """
+    assert_features("contrib")
+    
+    # Standardize type arguments.
+    T = RuntimeType.parse_or_infer(type_name=T, public_example=get_first(bounds))
+    
+    # Convert arguments to c types.
+    size_bounds = py_to_c(size_bounds, c_type=AnyObjectPtr, type_name=RuntimeType(origin='Tuple', args=["usize", "usize"]))
+    bounds = py_to_c(bounds, c_type=AnyObjectPtr, type_name=RuntimeType(origin='Tuple', args=[T, T]))
+    T = py_to_c(T, c_type=ctypes.c_char_p)
+    
+    # Call library function.
+    function = lib.opendp_transformations__make_size_bounded_int_checked_sum
+    function.argtypes = [AnyObjectPtr, AnyObjectPtr, ctypes.c_char_p]
+    function.restype = FfiResult
+    
+    return c_to_py(unwrap(function(size_bounds, bounds, T), Transformation))
+
+
 def make_bounded_int_monotonic_sum(
     bounds: Tuple[Any, Any],
     T: RuntimeTypeDescriptor = None
@@ -2231,3 +2685,75 @@ def make_sized_bounded_variance(
     function.restype = FfiResult
     
     return c_to_py(unwrap(function(size, bounds, ddof, S), Transformation))
+
+
+def make_psd_projection(
+    dim: int,
+    T: RuntimeTypeDescriptor = "float"
+) -> Transformation:
+    """Make a postprocessing Transformation that projects a noisy symmetric matrix onto the nearest positive semi-definite matrix, by clamping negative eigenvalues to zero. 
+    The input and output are the upper triangle of the matrix (including the diagonal), flattened in row-major order.
+    
+    :param dim: Number of rows (and columns) in the matrix.
+    :type dim: int
+    :param T: Atomic type of the matrix entries.
+    :type T: :ref:`RuntimeTypeDescriptor`
+    :return: A psd_projection step.
+    :rtype: Transformation
+    :raises AssertionError: if an argument's type differs from the expected type
+    :raises UnknownTypeError: if a type-argument fails to parse
+    :raises OpenDPException: packaged error from the core OpenDP library
+    """
+    assert_features("contrib")
+    
+    # Standardize type arguments.
+    T = RuntimeType.parse(type_name=T)
+    
+    # Convert arguments to c types.
+    dim = py_to_c(dim, c_type=ctypes.c_uint)
+    T = py_to_c(T, c_type=ctypes.c_char_p)
+    
+    # Call library function.
+    function = lib.opendp_transformations__make_psd_projection
+    function.argtypes = [ctypes.c_uint, ctypes.c_char_p]
+    function.restype = FfiResult
+    
+    return c_to_py(unwrap(function(dim, T), Transformation))
+
+
+def make_regression_solver(
+    dim: int,
+    regularization,
+    T: RuntimeTypeDescriptor = None
+) -> Transformation:
+    """Make a postprocessing Transformation that solves the regularized normal equations, (X^T X + λI) β = X^T y, for the linear regression coefficients β. 
+    The input is the upper triangle of X^T X (including the diagonal) flattened in row-major order, followed by X^T y. 
+    The noisy X^T X is first projected onto the positive semi-definite cone.
+    
+    :param dim: Number of features.
+    :type dim: int
+    :param regularization: The ridge penalty added to the diagonal of X^T X. Must be positive.
+    :param T: Atomic type of the statistics.
+    :type T: :ref:`RuntimeTypeDescriptor`
+    :return: A regression_solver step.
+    :rtype: Transformation
+    :raises AssertionError: if an argument's type differs from the expected type
+    :raises UnknownTypeError: if a type-argument fails to parse
+    :raises OpenDPException: packaged error from the core OpenDP library
+    """
+    assert_features("contrib")
+    
+    # Standardize type arguments.
+    T = RuntimeType.parse_or_infer(type_name=T, public_example=regularization)
+    
+    # Convert arguments to c types.
+    dim = py_to_c(dim, c_type=ctypes.c_uint)
+    regularization = py_to_c(regularization, c_type=ctypes.c_void_p, type_name=T)
+    T = py_to_c(T, c_type=ctypes.c_char_p)
+    
+    # Call library function.
+    function = lib.opendp_transformations__make_regression_solver
+    function.argtypes = [ctypes.c_uint, ctypes.c_void_p, ctypes.c_char_p]
+    function.restype = FfiResult
+    
+    return c_to_py(unwrap(function(dim, regularization, T), Transformation))
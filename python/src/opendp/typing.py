@@ -1,7 +1,7 @@
 import sys
 import typing
 from collections.abc import Hashable
-from typing import Union, Any, Type, List
+from typing import Union, Any, Type, List, Callable
 
 from opendp.mod import UnknownTypeException, Measurement, Transformation
 from opendp._lib import ATOM_EQUIVALENCE_CLASSES
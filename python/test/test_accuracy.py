@@ -42,3 +42,53 @@ def test_accuracy_to_gaussian_scale():
     check_accuracy_to_gaussian_scale(accuracy=1., alpha=0.05)
     check_accuracy_to_gaussian_scale(accuracy=2., alpha=0.05)
     check_accuracy_to_gaussian_scale(accuracy=1.2, alpha=0.1)
+
+
+def test_discrete_scale_to_accuracy():
+    assert discrete_laplacian_scale_to_accuracy(scale=0., alpha=0.05) == 0.
+    scale = accuracy_to_discrete_laplacian_scale(accuracy=25., alpha=0.05)
+    assert discrete_laplacian_scale_to_accuracy(scale=scale, alpha=0.05) <= 25.
+
+    scale = accuracy_to_discrete_gaussian_scale(accuracy=25., alpha=0.05)
+    assert discrete_gaussian_scale_to_accuracy(scale=scale, alpha=0.05) <= 25.
+
+
+def test_measurement_accuracy():
+    from opendp.mod import enable_features
+    from opendp.measurements import make_base_laplace
+    from opendp.transformations import make_count
+    enable_features("contrib", "floating-point")
+
+    meas = make_count(TIA=int, TO=float) >> make_base_laplace(scale=2.)
+    assert abs(meas.accuracy(0.05) - laplacian_scale_to_accuracy(2., 0.05)) < 1e-12
+
+
+def test_simulate_utility():
+    from opendp.mod import enable_features
+    from opendp.measurements import make_base_laplace
+    from opendp.transformations import make_count
+    enable_features("contrib", "floating-point")
+
+    candidates = {scale: make_count(TIA=int, TO=float) >> make_base_laplace(scale=scale) for scale in [1., 10.]}
+    summaries = {
+        scale: simulate_utility(meas, [1, 2, 3], lambda release: release - 3., quantiles=[0.5, 0.95])
+        for scale, meas in candidates.items()
+    }
+    trials, bias, rmse, quantiles = summaries[1.]
+    assert trials == 1000
+    assert rmse < summaries[10.][2]
+    # quantiles are reported in the order requested
+    assert [q for q, _ in quantiles] == [0.5, 0.95]
+    assert quantiles[0][1] < quantiles[1][1]
+
+
+def test_compare_utility():
+    from opendp.mod import enable_features
+    from opendp.measurements import make_base_laplace
+    from opendp.transformations import make_count
+    enable_features("contrib", "floating-point")
+
+    candidates = [make_count(TIA=int, TO=float) >> make_base_laplace(scale=scale) for scale in [1., 10.]]
+    summaries = compare_utility(candidates, [1, 2, 3], lambda release: release - 3., quantiles=[0.5])
+    assert len(summaries) == 2
+    assert summaries[0][2] < summaries[1][2]
@@ -52,3 +52,15 @@ def test_type_inference():
     def mean_chainer_b(b):
         return make_sized_bounded_mean(1000, (-b, b))
     assert 499.999 < binary_search_param(mean_chainer_b, 2, 1.) < 500.
+
+
+def test_uninferrable_distances():
+    import pytest
+    # the chain can't be built anywhere in the bounds, so the distance types are unknown
+    with pytest.raises(ValueError):
+        binary_search_param(make_base_laplace, d_in=1., d_out=1., bounds=(-2., -1.))
+
+    def untyped(_param):
+        raise TypeError("untyped")
+    with pytest.raises(TypeError):
+        binary_search_param(untyped, d_in=1., d_out=1.)
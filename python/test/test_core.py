@@ -39,6 +39,48 @@ def test_chain():
     print("evaluate chain:", chain(data))
 
 
+def test_checked_invoke():
+    from opendp.mod import OpenDPException, disable_features
+    from opendp.transformations import make_sized_bounded_sum
+    from opendp.core import transformation_invoke
+    query = make_sized_bounded_sum(size=3, bounds=(0, 10))
+    # out-of-bounds data is only rejected when checked
+    assert query([1, 2, 30]) == 33
+    with pytest.raises(OpenDPException):
+        transformation_invoke(query, [1, 2, 30], check_domain=True)
+
+    enable_features("checked-invoke")
+    try:
+        assert query([1, 2, 3]) == 6
+        with pytest.raises(OpenDPException):
+            query([1, 2])
+    finally:
+        disable_features("checked-invoke")
+
+def test_entropy_source():
+    from opendp.mod import OpenDPException
+    from opendp.core import set_entropy_source, reset_entropy_source
+    from opendp.measurements import make_randomized_response_bool
+    meas = make_randomized_response_bool(0.75)
+
+    requested = []
+    def zeros(n):
+        requested.append(n)
+        return [0] * n
+
+    set_entropy_source(zeros)
+    try:
+        assert len({meas(True) for _ in range(10)}) == 1
+        assert requested
+
+        # sources must return exactly as many bytes as requested
+        set_entropy_source(lambda n: [0] * (n + 1))
+        with pytest.raises(OpenDPException):
+            meas(True)
+    finally:
+        reset_entropy_source()
+    meas(True)
+
 def test_bisect():
     from opendp.mod import binary_search
 
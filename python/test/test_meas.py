@@ -36,6 +36,13 @@ def test_base_laplace():
     assert meas.check(1., .096)
 
 
+def test_base_laplace_fixed_iteration():
+    from opendp.measurements import make_base_laplace_fixed_iteration
+    meas = make_base_laplace_fixed_iteration(scale=10.5, k=-10)
+    print("fixed-iteration base laplace:", meas(100.))
+    assert meas.check(1., .096)
+
+
 def test_base_vector_laplace():
     from opendp.measurements import make_base_laplace
     meas = make_base_laplace(scale=10.5, D="VectorDomain<AllDomain<f64>>")
@@ -113,6 +120,14 @@ def test_base_vector_discrete_laplace_cks20():
     assert not meas.check(1, 0.49999)
 
 
+def test_base_discrete_laplace_cks20_fixed_iteration():
+    from opendp.measurements import make_base_discrete_laplace_cks20_fixed_iteration
+    meas = make_base_discrete_laplace_cks20_fixed_iteration(scale=2., D="VectorDomain<AllDomain<i32>>")
+    print("fixed-iteration vector base_dl:", meas([100, 10, 12]))
+    assert meas.check(1, 0.5)
+    assert not meas.check(1, 0.49999)
+
+
 def test_base_discrete_laplace_linear():
     from opendp.measurements import make_base_discrete_laplace_linear
     meas = make_base_discrete_laplace_linear(scale=2., bounds=(1, 10))
@@ -173,3 +188,29 @@ def test_randomized_response_bool():
     import math
     assert meas.check(1, math.log(3.))
     assert not meas.check(1, math.log(2.999))
+
+
+def test_sized_bounded_winsorized_mean():
+    from opendp.measurements import make_sized_bounded_winsorized_mean
+    meas = make_sized_bounded_winsorized_mean(
+        size=10, bounds=(0., 100.), bin_edges=[float(v) for v in range(0, 101, 10)],
+        alpha=0.1, quantile_scale=1., scale=1.)
+    print("winsorized mean:", meas([1., 2., 3., 4., 5., 6., 7., 8., 9., 100.]))
+    assert meas.check(2, 2. + 10. + 1e-6)
+
+
+def test_mwem():
+    from opendp.measurements import make_mwem, make_synthetic_dataset
+    categories = ["a", "b", "c", "d"]
+    meas = make_mwem(
+        size=8, categories=categories,
+        queries=[[0], [1], [2], [3], [0, 1]],
+        rounds=5, selection_scale=1., scale=1.)
+    data = ["a", "a", "a", "a", "a", "a", "b", "c"]
+    distribution = meas(data)
+    print("mwem distribution:", distribution)
+    assert abs(sum(distribution) - 8.) < 1e-8
+    assert meas.check(2, 15. + 1e-6)
+
+    synthetic = meas >> make_synthetic_dataset(categories)
+    assert len(synthetic(data)) == 8
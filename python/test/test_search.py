@@ -0,0 +1,52 @@
+from opendp.search import binary_search
+
+
+def test_binary_search():
+    assert binary_search(lambda x: x >= 5., T=float) == 5.
+    assert binary_search(lambda x: x <= 5., T=float) == 5.
+    assert binary_search(lambda x: x > 5, T=int) == 6
+    assert binary_search(lambda x: x < 5, T=int, bounds=(0, 100)) == 4
+
+
+def test_binary_search_scale():
+    from opendp.mod import enable_features
+    from opendp.measurements import make_base_laplace
+    from opendp.transformations import make_sized_bounded_mean
+    enable_features("contrib", "floating-point")
+
+    pre = make_sized_bounded_mean(size=10, bounds=(0., 1.))
+    # the predicate raises for negative scales, which are considered not to pass
+    scale = binary_search(lambda s: (pre >> make_base_laplace(scale=s)).check(2, 1.), T=float)
+    assert (pre >> make_base_laplace(scale=scale)).check(2, 1.)
+    assert not (pre >> make_base_laplace(scale=scale * 0.99)).check(2, 1.)
+
+
+def test_binary_search_param():
+    from opendp.mod import binary_search_param, enable_features
+    from opendp.measurements import make_base_laplace, make_base_discrete_laplace
+    from opendp.transformations import make_sized_bounded_mean, make_sized_bounded_sum
+    enable_features("contrib", "floating-point")
+
+    assert binary_search_param(make_base_laplace, d_in=0.1, d_out=1.) == 0.1
+
+    # the symmetric distance is loaded as the input distance type of the chain
+    def make_sum(b):
+        return make_sized_bounded_sum(10_000, (-b, b)) >> make_base_discrete_laplace(100.)
+    b = binary_search_param(make_sum, d_in=2, d_out=1., bounds=(0, 10_000))
+    assert make_sum(b).check(2, 1.) and not make_sum(b + 1).check(2, 1.)
+
+    def make_mean(data_size):
+        return make_sized_bounded_mean(data_size, (0., 500_000.)) >> make_base_laplace(1000. / 3.)
+    size = binary_search_param(make_mean, d_in=2, d_out=1., bounds=(1, 1_000_000))
+    assert make_mean(size).check(2, 1.) and not make_mean(size - 1).check(2, 1.)
+
+
+def test_binary_search_chain():
+    from opendp.search import binary_search_chain
+    from opendp.mod import Measurement, enable_features
+    from opendp.measurements import make_base_laplace
+    enable_features("contrib", "floating-point")
+
+    chain = binary_search_chain(make_base_laplace, 0.1, 1., T=float)
+    assert isinstance(chain, Measurement)
+    assert chain.check(0.1, 1.) and not chain.check(0.11, 1.)
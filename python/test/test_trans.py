@@ -1,3 +1,4 @@
+import pytest
 from opendp.transformations import make_subset_by, make_quantiles_from_counts
 from opendp.typing import *
 from opendp.mod import enable_features
@@ -215,6 +216,29 @@ def test_sized_bounded_sum():
     assert query.check(1, 10. + 1e-5)
 
 
+def test_size_bounded_sum():
+    from opendp.transformations import make_clamp, make_truncate, \
+        make_size_bounded_float_checked_sum, make_size_bounded_int_checked_sum
+    query = make_clamp(bounds=(0., 10.)) >> \
+        make_truncate(max_size=100, bounds=(0., 10.)) >> \
+        make_size_bounded_float_checked_sum(size_bounds=(0, 100), bounds=(0., 10.))
+    assert query(FLOAT_DATA) == 45.
+    assert query.check(1, 20. + 1e-11)
+
+    query = make_size_bounded_int_checked_sum(size_bounds=(0, 100), bounds=(0, 10))
+    assert query(INT_DATA) == 45
+    assert query.check(1, 10)
+
+
+def test_size_bounded_mean():
+    from opendp.transformations import make_clamp, make_truncate, make_size_bounded_mean
+    query = make_clamp(bounds=(0., 10.)) >> \
+        make_truncate(max_size=100, bounds=(0., 10.)) >> \
+        make_size_bounded_mean(size_bounds=(0, 100), bounds=(0., 10.))
+    assert query(FLOAT_DATA) == 5.
+    assert query([]) == 5.
+    assert query.check(1, 20. + 1e-11)
+
 def test_bounded_variance():
     from opendp.transformations import make_sized_bounded_variance
     query = make_sized_bounded_variance(size=9, bounds=(0., 10.))
@@ -277,6 +301,17 @@ def test_count_by_categories_str():
     assert query.check(1, 1)
 
 
+def test_contingency_tables():
+    from opendp.transformations import make_split_dataframe, make_contingency_tables
+    query = make_split_dataframe(separator=",", col_names=["a", "b"]) >> make_contingency_tables(
+        keys=["a", "b"],
+        categories=[["x", "y"], ["0", "1"]],
+        marginals=[["a"], ["a", "b"]])
+    assert query("x,0\ny,1\nx,1\nz,1") == [2, 1, 1, 1, 0, 1]
+    assert query.check(1, 2)
+    assert not query.check(1, 1)
+
+
 def test_indexing():
     from opendp.transformations import make_find, make_impute_constant, make_find_bin, make_index
 
@@ -370,3 +405,76 @@ def test_lipschitz_b_ary_tree():
     print(meas_quantiles(data))
 
     assert meas_cdf.map(1) == 4.
+
+
+def test_psd_projection():
+    from opendp.transformations import make_psd_projection
+    projector = make_psd_projection(dim=2)
+    assert all(abs(v - 1.5) < 1e-9 for v in projector([1., 2., 1.]))
+
+
+def test_regression_solver():
+    from opendp.transformations import make_regression_solver
+    solver = make_regression_solver(dim=1, regularization=1e-9)
+    # X^T X = 2, X^T y = 4
+    assert abs(solver([2., 4.])[0] - 2.) < 1e-6
+
+
+def test_filter_map():
+    from opendp.transformations import make_filter_map
+    trans = make_filter_map(lambda x: x * 2., TIA=float, TOA=float)
+    assert trans([1., 2.]) == [2., 4.]
+    assert trans.check(1, 1)
+
+    trans = make_filter_map(lambda x: str(x), TIA=int, TOA=str)
+    assert trans([1, 2]) == ["1", "2"]
+
+    # rows on which the function fails are dropped
+    trans = make_filter_map(lambda x: 1 / x, TIA=float, TOA=float)
+    assert trans([0., 2.]) == [0.5]
+
+    # the output type cannot be inferred from a callable
+    with pytest.raises(TypeError):
+        make_filter_map(lambda x: x, TIA=float)
+
+
+def test_callback_release():
+    from opendp.transformations import make_filter
+    from opendp._convert import _CALLBACKS
+    filter_ = make_filter(lambda x: x > 1, T=int)
+    count = len(_CALLBACKS)
+    del filter_
+    assert len(_CALLBACKS) == count - 1
+
+
+def test_filter():
+    from opendp.transformations import make_filter, make_cast_default
+    filter_ = make_filter(lambda x: x > 1, T=int)
+    assert filter_([1, 2, 3]) == [2, 3]
+    assert filter_.check(1, 1)
+
+    # callbacks outlive the transformations they are chained into
+    chain = make_cast_default(TIA=str, TOA=int) >> make_filter(lambda x: x % 2 == 0, T=int)
+    assert chain(["1", "2", "4"]) == [2, 4]
+
+
+def test_sized_bounded_trimmed_mean():
+    from opendp.transformations import make_sized_bounded_trimmed_mean
+    trans = make_sized_bounded_trimmed_mean(size=4, bounds=(0., 10.), trim=0.25)
+    assert trans([10., 1., 2., 0.]) == 1.5
+    assert trans.check(2, 5. + 1e-6)
+
+
+def test_histogram():
+    from opendp.transformations import make_histogram, make_uniform_histogram, make_quantiles_from_counts
+    edges = [0., 10., 20., 30.]
+    histogram = make_histogram(edges, TA=float)
+    counts = histogram([-1., 5., 15., 15., 25., 40.])
+    assert counts == [1, 1, 2, 1, 1]
+    assert histogram.check(1, 1)
+
+    quantiles = make_quantiles_from_counts(edges, alphas=[0., 1.], TA=float, F=float)
+    assert quantiles([float(c) for c in counts]) == [0., 30.]
+
+    uniform = make_uniform_histogram((0., 30.), bins=3, MO=L2Distance[float], TC=float)
+    assert uniform([5., 15., 15., 25.]) == [0., 1., 2., 1., 0.]
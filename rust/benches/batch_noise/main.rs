@@ -0,0 +1,60 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use opendp::{
+    domains::{AllDomain, VectorDomain},
+    measurements::{
+        make_base_discrete_gaussian, make_base_discrete_laplace_cks20, make_base_gaussian,
+        make_base_laplace,
+    },
+    measures::ZeroConcentratedDivergence,
+};
+
+// compares releasing a vector in one batch, against releasing each element separately
+
+// execute bench via:
+//     cargo bench --bench batch_noise --features untrusted
+
+const SIZE: usize = 1000;
+
+pub fn collect(c: &mut Criterion) {
+    let floats = vec![0.; SIZE];
+    let ints = vec![0; SIZE];
+
+    c.bench_function("laplace batched", |b| {
+        let meas = make_base_laplace::<VectorDomain<AllDomain<f64>>>(1., None).unwrap();
+        b.iter(|| meas.invoke(&floats).unwrap())
+    });
+    c.bench_function("laplace elementwise", |b| {
+        let meas = make_base_laplace::<AllDomain<f64>>(1., None).unwrap();
+        b.iter(|| floats.iter().map(|v| meas.invoke(v).unwrap()).collect::<Vec<_>>())
+    });
+
+    c.bench_function("gaussian batched", |b| {
+        let meas = make_base_gaussian::<VectorDomain<AllDomain<f64>>, ZeroConcentratedDivergence<_>>(1., None).unwrap();
+        b.iter(|| meas.invoke(&floats).unwrap())
+    });
+    c.bench_function("gaussian elementwise", |b| {
+        let meas = make_base_gaussian::<AllDomain<f64>, ZeroConcentratedDivergence<_>>(1., None).unwrap();
+        b.iter(|| floats.iter().map(|v| meas.invoke(v).unwrap()).collect::<Vec<_>>())
+    });
+
+    c.bench_function("discrete laplace batched", |b| {
+        let meas = make_base_discrete_laplace_cks20::<VectorDomain<AllDomain<i32>>, _>(20.).unwrap();
+        b.iter(|| meas.invoke(&ints).unwrap())
+    });
+    c.bench_function("discrete laplace elementwise", |b| {
+        let meas = make_base_discrete_laplace_cks20::<AllDomain<i32>, _>(20.).unwrap();
+        b.iter(|| ints.iter().map(|v| meas.invoke(v).unwrap()).collect::<Vec<_>>())
+    });
+
+    c.bench_function("discrete gaussian batched", |b| {
+        let meas = make_base_discrete_gaussian::<VectorDomain<AllDomain<i32>>, ZeroConcentratedDivergence<f64>>(20.).unwrap();
+        b.iter(|| meas.invoke(&ints).unwrap())
+    });
+    c.bench_function("discrete gaussian elementwise", |b| {
+        let meas = make_base_discrete_gaussian::<AllDomain<i32>, ZeroConcentratedDivergence<f64>>(20.).unwrap();
+        b.iter(|| ints.iter().map(|v| meas.invoke(v).unwrap()).collect::<Vec<_>>())
+    });
+}
+
+criterion_group!(benches, collect);
+criterion_main!(benches);
@@ -112,7 +112,7 @@ fn main() {
     // only build the bindings if you're in dev mode
     if env::var("CARGO_PKG_VERSION").unwrap().as_str() != "0.0.0+development" { return }
 
-    let module_names = vec!["combinators", "measurements", "transformations", "data", "core", "accuracy"];
+    let module_names = ["combinators", "measurements", "transformations", "data", "core", "accuracy", "search"];
 
     let get_bootstrap_path = |val: &str|
         Path::new("src").join(val).join("bootstrap.json");
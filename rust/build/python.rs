@@ -46,8 +46,8 @@ fn generate_module(
         .collect::<Vec<String>>()
         .join("\n");
 
-    // the comb module needs access to core functions for type introspection on measurements/transformations
-    let comb_import = if module_name == "combinators" {"from opendp.core import *\n"} else {""};
+    // the comb and accuracy modules need access to core functions for type introspection on measurements/transformations
+    let comb_import = if module_name == "combinators" || module_name == "accuracy" {"from opendp.core import *\n"} else {""};
 
     format!(r#"# Auto-generated. Do not edit.
 from opendp._convert import *
@@ -284,6 +284,8 @@ fn generate_public_example(func: &Function, type_arg: &Argument) -> Option<Strin
 
     // code generation
     args.iter()
+        // a callback is not an example of the type it returns
+        .filter(|arg| arg.c_type.as_deref() != Some("CallbackFn"))
         .filter_map(|arg| match &arg.rust_type {
             Some(RuntimeType::Name(name)) => (name == type_name).then(|| arg.name()),
             Some(RuntimeType::Raise { origin, args }) =>
@@ -44,3 +44,7 @@ build_extern_accuracy!(scale, opendp_accuracy__laplacian_scale_to_accuracy, lapl
 build_extern_accuracy!(accuracy, opendp_accuracy__accuracy_to_laplacian_scale, accuracy_to_laplacian_scale);
 build_extern_accuracy!(scale, opendp_accuracy__gaussian_scale_to_accuracy, gaussian_scale_to_accuracy);
 build_extern_accuracy!(accuracy, opendp_accuracy__accuracy_to_gaussian_scale, accuracy_to_gaussian_scale);
+build_extern_accuracy!(scale, opendp_accuracy__discrete_laplacian_scale_to_accuracy, discrete_laplacian_scale_to_accuracy);
+build_extern_accuracy!(accuracy, opendp_accuracy__accuracy_to_discrete_laplacian_scale, accuracy_to_discrete_laplacian_scale);
+build_extern_accuracy!(scale, opendp_accuracy__discrete_gaussian_scale_to_accuracy, discrete_gaussian_scale_to_accuracy);
+build_extern_accuracy!(accuracy, opendp_accuracy__accuracy_to_discrete_gaussian_scale, accuracy_to_discrete_gaussian_scale);
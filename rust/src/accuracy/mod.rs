@@ -1,10 +1,13 @@
 #[cfg(feature="ffi")]
 mod ffi;
 
-use std::f64::consts::SQRT_2;
+mod simulate;
+pub use simulate::*;
 
-use num::{Float, One, Zero};
-use statrs::function::erf::erf_inv;
+use std::f64::consts::{PI, SQRT_2};
+
+use num::{Float, One, ToPrimitive, Zero};
+use statrs::function::erf::{erf_inv, erfc_inv};
 
 use crate::error::Fallible;
 use crate::traits::InfCast;
@@ -56,6 +59,462 @@ pub fn accuracy_to_gaussian_scale<T>(accuracy: T, alpha: T) -> Fallible<T>
     T::inf_cast(accuracy / SQRT_2 / erf_inv(1. - alpha))
 }
 
+pub fn discrete_laplacian_scale_to_accuracy<T>(scale: T, alpha: T) -> Fallible<T>
+    where f64: InfCast<T>, T: InfCast<f64> {
+    let scale = f64::inf_cast(scale)?;
+    let alpha = f64::inf_cast(alpha)?;
+    if scale.is_sign_negative() {
+        return fallible!(InvalidDistance, "scale may not be negative")
+    }
+    if alpha <= 0. || 1. < alpha {
+        return fallible!(InvalidDistance, "alpha ({:?}) must be in (0, 1]", alpha)
+    }
+    T::inf_cast(discrete_laplace_accuracy(scale, alpha))
+}
+
+pub fn accuracy_to_discrete_laplacian_scale<T>(accuracy: T, alpha: T) -> Fallible<T>
+    where f64: InfCast<T>, T: InfCast<f64> {
+    let accuracy = f64::inf_cast(accuracy)?;
+    let alpha = f64::inf_cast(alpha)?;
+    if accuracy.is_sign_negative() {
+        return fallible!(InvalidDistance, "accuracy may not be negative")
+    }
+    if alpha <= 0. || 1. <= alpha {
+        return fallible!(InvalidDistance, "alpha ({:?}) must be in (0, 1)", alpha)
+    }
+    // noise is integral, so only the integer part of the accuracy matters
+    let accuracy = accuracy.floor();
+    T::inf_cast(bisect_scale(|scale| discrete_laplace_tail(scale, accuracy) <= alpha))
+}
+
+pub fn discrete_gaussian_scale_to_accuracy<T>(scale: T, alpha: T) -> Fallible<T>
+    where f64: InfCast<T>, T: InfCast<f64> {
+    let scale = f64::inf_cast(scale)?;
+    let alpha = f64::inf_cast(alpha)?;
+    if scale.is_sign_negative() {
+        return fallible!(InvalidDistance, "scale may not be negative")
+    }
+    if alpha <= 0. || 1. < alpha {
+        return fallible!(InvalidDistance, "alpha ({:?}) must be in (0, 1]", alpha)
+    }
+    T::inf_cast(discrete_gaussian_accuracy(scale, alpha))
+}
+
+pub fn accuracy_to_discrete_gaussian_scale<T>(accuracy: T, alpha: T) -> Fallible<T>
+    where f64: InfCast<T>, T: InfCast<f64> {
+    let accuracy = f64::inf_cast(accuracy)?;
+    let alpha = f64::inf_cast(alpha)?;
+    if accuracy.is_sign_negative() {
+        return fallible!(InvalidDistance, "accuracy may not be negative")
+    }
+    if alpha <= 0. || 1. <= alpha {
+        return fallible!(InvalidDistance, "alpha ({:?}) must be in (0, 1)", alpha)
+    }
+    T::inf_cast(bisect_scale(|scale| discrete_gaussian_accuracy(scale, alpha) <= accuracy))
+}
+
+/// Estimate the proportion of a category from the `frequency` with which it was reported
+/// by randomized response with `categories` categories and probability `prob` of answering truthfully.
+///
+/// The estimate is unbiased, but may fall outside of [0, 1].
+pub fn debias_randomized_response<T>(frequency: T, prob: T, categories: usize) -> Fallible<T>
+    where f64: InfCast<T>, T: InfCast<f64> {
+    let frequency = f64::inf_cast(frequency)?;
+    let (prob, lie_prob) = randomized_response_probs(f64::inf_cast(prob)?, categories)?;
+    T::inf_cast((frequency - lie_prob) / (prob - lie_prob))
+}
+
+/// The accuracy of [`debias_randomized_response`], when estimating a proportion from `size` responses,
+/// at a statistical significance level of `alpha`.
+///
+/// Follows from Hoeffding's inequality on the frequency of the category among the responses.
+pub fn randomized_response_to_accuracy<T>(prob: T, categories: usize, size: usize, alpha: T) -> Fallible<T>
+    where f64: InfCast<T>, T: InfCast<f64> {
+    let (prob, lie_prob) = randomized_response_probs(f64::inf_cast(prob)?, categories)?;
+    let alpha = f64::inf_cast(alpha)?;
+    if alpha <= 0. || 1. < alpha {
+        return fallible!(InvalidDistance, "alpha ({:?}) must be in (0, 1]", alpha)
+    }
+    if size == 0 {
+        return fallible!(InvalidDistance, "size must be positive")
+    }
+    let frequency_accuracy = ((2. / alpha).ln() / (2. * size as f64)).sqrt();
+    T::inf_cast(frequency_accuracy / (prob - lie_prob))
+}
+
+// probabilities of reporting the true category, and of reporting each false category
+fn randomized_response_probs(prob: f64, categories: usize) -> Fallible<(f64, f64)> {
+    if categories < 2 {
+        return fallible!(InvalidDistance, "there must be at least two categories")
+    }
+    let lie_prob = (1. - prob) / (categories - 1) as f64;
+    if !(lie_prob < prob && prob <= 1.) {
+        return fallible!(InvalidDistance, "prob must be in (1/categories, 1]")
+    }
+    Ok((prob, lie_prob))
+}
+
+/// The smallest count at which a key is released by `make_base_ptr` with probability at least 1 - `alpha`.
+///
+/// Assumes the default discretization of `make_base_ptr`, which is too fine to affect the bound.
+///
+/// `make_base_ptr` records no noise distribution, as released counts are conditioned on passing the threshold.
+/// For keys with at least this count, an accuracy at level `beta` for laplace noise of the same `scale`
+/// holds for their released counts at level `beta / (1 - alpha)`.
+pub fn ptr_threshold_to_release_count<T>(scale: T, threshold: T, alpha: T) -> Fallible<T>
+    where f64: InfCast<T>, T: InfCast<f64> {
+    let scale = f64::inf_cast(scale)?;
+    let threshold = f64::inf_cast(threshold)?;
+    let alpha = f64::inf_cast(alpha)?;
+    if scale.is_sign_negative() {
+        return fallible!(InvalidDistance, "scale may not be negative")
+    }
+    if alpha <= 0. || 1. < alpha {
+        return fallible!(InvalidDistance, "alpha ({:?}) must be in (0, 1]", alpha)
+    }
+    // a key whose noisy count falls below the threshold is suppressed,
+    // and P(noise < -t) = exp(-t / scale) / 2
+    T::inf_cast(threshold + (scale * (0.5 / alpha).ln()).max(0.))
+}
+
+/// The factor by which `make_consistent_b_ary_tree` scales the variance of the noise on each node,
+/// when computing each leaf of a tree with `leaf_count` leaves.
+///
+/// The factor is exact when `leaf_count` is a power of `branching_factor`,
+/// and an upper bound otherwise, as the missing leaves are known to be zero.
+///
+/// # Citations
+/// * HRMS09, Boosting the Accuracy of Differentially Private Histograms Through Consistency
+///   Section 4.1: https://arxiv.org/pdf/0904.0942.pdf
+pub fn consistent_b_ary_tree_variance(leaf_count: usize, branching_factor: usize) -> Fallible<f64> {
+    let layers = b_ary_tree_layers(leaf_count, branching_factor)?;
+    let b = branching_factor as f64;
+
+    // a node in layer m covers b^(layers - m) leaves, and its subtree has (b^(layers - m + 1) - 1) / (b - 1) nodes.
+    // The leaf estimate sums one term per layer, from the root (m = 1) down to the leaf (m = layers).
+    let mut factor = 0.;
+    let mut prev_inv_leaves = 0.;
+    for m in 1..=layers {
+        let leaves = b.powi((layers - m) as i32);
+        let nodes = (b * leaves - 1.) / (b - 1.);
+        factor += (leaves.recip() - prev_inv_leaves) / nodes;
+        prev_inv_leaves = leaves.recip();
+    }
+    Ok(factor)
+}
+
+/// The noise on each leaf released by `make_consistent_b_ary_tree`,
+/// when each node of a tree from `make_b_ary_tree` with `leaf_count` leaves was perturbed independently by `noise`.
+pub fn consistent_b_ary_tree_noise(noise: &NoiseDistribution, leaf_count: usize, branching_factor: usize) -> Fallible<NoiseDistribution> {
+    let factor = consistent_b_ary_tree_variance(leaf_count, branching_factor)?;
+    let layers = b_ary_tree_layers(leaf_count, branching_factor)?;
+    let num_nodes = ((branching_factor as f64).powi(layers as i32) - 1.) / (branching_factor as f64 - 1.);
+    // Each leaf is a linear combination of the nodes, whose coefficients have a sum of squares of at most `factor`.
+    // By Cauchy-Schwarz, the absolute coefficients sum to at most sqrt(factor * num_nodes),
+    // which bounds the growth of any bias in the nodes.
+    Ok(tree_noise(noise, factor, (factor * num_nodes).sqrt()))
+}
+
+fn tree_noise(noise: &NoiseDistribution, factor: f64, bias_factor: f64) -> NoiseDistribution {
+    use NoiseDistribution::*;
+    let biased = |bias: f64, noise: NoiseDistribution|
+        if bias > 0. { Biased { bias: bias * bias_factor, noise: Box::new(noise) } } else { noise };
+
+    match noise {
+        // a linear combination of independent gaussians is gaussian
+        Gaussian { scale, granularity } if *granularity == 0. => Gaussian { scale: scale * factor.sqrt(), granularity: 0. },
+        // each node is sub-gaussian, and off by at most granularity / 2 from rounding
+        Gaussian { scale, granularity } => biased(granularity / 2., SubGaussian { scale: scale * factor.sqrt() }),
+        DiscreteGaussian { scale } | SubGaussian { scale } => SubGaussian { scale: scale * factor.sqrt() },
+        Scaled { factor: c, noise } => tree_noise(noise, factor, bias_factor).scale_by(*c),
+        Biased { bias, noise } => biased(*bias, tree_noise(noise, factor, bias_factor)),
+        Laplace { granularity, .. } => biased(granularity / 2., Variance { variance: noise.variance() * factor }),
+        DiscreteLaplace { .. } | Variance { .. } => Variance { variance: noise.variance() * factor },
+    }
+}
+
+// the number of layers in a b-ary tree with room for `leaf_count` leaves
+fn b_ary_tree_layers(leaf_count: usize, branching_factor: usize) -> Fallible<usize> {
+    if leaf_count == 0 {
+        return fallible!(InvalidDistance, "leaf_count must be at least 1")
+    }
+    if branching_factor < 2 {
+        return fallible!(InvalidDistance, "branching_factor must be at least two")
+    }
+    let (mut layers, mut width) = (1, 1usize);
+    while width < leaf_count {
+        width = width.checked_mul(branching_factor)
+            .ok_or_else(|| err!(InvalidDistance, "tree is too large"))?;
+        layers += 1;
+    }
+    Ok(layers)
+}
+
+/// The distribution of the noise in a release, from which its accuracy may be derived.
+///
+/// When a release is a vector or map, the noise is that of each element.
+#[derive(Clone, Debug, PartialEq)]
+pub enum NoiseDistribution {
+    /// Laplace noise on the lattice of multiples of `granularity`, as added by `make_base_laplace`.
+    /// The input is rounded to the nearest multiple of `granularity` before adding noise.
+    /// The noise is continuous when `granularity` is zero.
+    Laplace { scale: f64, granularity: f64 },
+    /// Gaussian noise on the lattice of multiples of `granularity`, as added by `make_base_gaussian`.
+    /// The input is rounded to the nearest multiple of `granularity` before adding noise.
+    /// The noise is continuous when `granularity` is zero.
+    Gaussian { scale: f64, granularity: f64 },
+    /// Discrete laplace noise on the integers.
+    DiscreteLaplace { scale: f64 },
+    /// Discrete gaussian noise on the integers.
+    DiscreteGaussian { scale: f64 },
+    /// Noise with mean zero, whose moment generating function is bounded by that of gaussian(`scale`) noise.
+    /// Then P(|noise| > t) <= 2 exp(-t^2 / (2 `scale`^2)).
+    SubGaussian { scale: f64 },
+    /// Noise with mean zero and a variance of at most `variance`.
+    Variance { variance: f64 },
+    /// Noise from `noise`, multiplied by `factor`.
+    Scaled { factor: f64, noise: Box<NoiseDistribution> },
+    /// Noise from `noise`, plus a fixed error of magnitude at most `bias`.
+    Biased { bias: f64, noise: Box<NoiseDistribution> },
+}
+
+impl NoiseDistribution {
+    /// The noise of `make_base_laplace` with discretization `k`.
+    pub fn laplace<T: ToPrimitive>(scale: T, k: i32) -> Fallible<Self> {
+        Ok(NoiseDistribution::Laplace { scale: to_f64(scale)?, granularity: pow2(k) })
+    }
+
+    /// The noise of `make_base_gaussian` with discretization `k`.
+    pub fn gaussian<T: ToPrimitive>(scale: T, k: i32) -> Fallible<Self> {
+        Ok(NoiseDistribution::Gaussian { scale: to_f64(scale)?, granularity: pow2(k) })
+    }
+
+    pub fn discrete_laplace<T: ToPrimitive>(scale: T) -> Fallible<Self> {
+        Ok(NoiseDistribution::DiscreteLaplace { scale: to_f64(scale)? })
+    }
+
+    pub fn discrete_gaussian<T: ToPrimitive>(scale: T) -> Fallible<Self> {
+        Ok(NoiseDistribution::DiscreteGaussian { scale: to_f64(scale)? })
+    }
+
+    /// The smallest accuracy such that the magnitude of the noise exceeds it with probability at most `alpha`.
+    ///
+    /// When a bound is used in place of the exact tail probability,
+    /// the accuracy is conservative, and the noise exceeds it with probability less than `alpha`.
+    pub fn accuracy(&self, alpha: f64) -> Fallible<f64> {
+        if !(0. < alpha && alpha <= 1.) {
+            return fallible!(InvalidDistance, "alpha ({:?}) must be in (0, 1]", alpha)
+        }
+        use NoiseDistribution::*;
+        Ok(match self {
+            Laplace { scale, granularity } => lattice_laplace_accuracy(*scale, *granularity, alpha),
+            Gaussian { scale, granularity } => lattice_gaussian_accuracy(*scale, *granularity, alpha),
+            DiscreteLaplace { scale } => discrete_laplace_accuracy(*scale, alpha),
+            DiscreteGaussian { scale } => discrete_gaussian_accuracy(*scale, alpha),
+            SubGaussian { scale } => scale * (2. * (2. / alpha).ln()).sqrt(),
+            // Chebyshev's inequality
+            Variance { variance } => (variance / alpha).sqrt(),
+            Scaled { factor, noise } => factor.abs() * noise.accuracy(alpha)?,
+            Biased { bias, noise } => bias + noise.accuracy(alpha)?,
+        })
+    }
+
+    /// An interval about `release` that contains the value it estimates with probability at least 1 - `alpha`.
+    pub fn confidence_interval(&self, release: f64, alpha: f64) -> Fallible<(f64, f64)> {
+        let accuracy = self.accuracy(alpha)?;
+        Ok((release - accuracy, release + accuracy))
+    }
+
+    /// The noise after multiplying the release by `factor`.
+    pub fn scale_by(self, factor: f64) -> Self {
+        use NoiseDistribution::*;
+        let magnitude = factor.abs();
+        match self {
+            Laplace { scale, granularity } => Laplace { scale: scale * magnitude, granularity: granularity * magnitude },
+            Gaussian { scale, granularity } => Gaussian { scale: scale * magnitude, granularity: granularity * magnitude },
+            SubGaussian { scale } => SubGaussian { scale: scale * magnitude },
+            Variance { variance } => Variance { variance: variance * factor * factor },
+            Scaled { factor: inner, noise } => Scaled { factor: inner * factor, noise },
+            Biased { bias, noise } => Biased { bias: bias * magnitude, noise: Box::new(noise.scale_by(factor)) },
+            noise => Scaled { factor, noise: Box::new(noise) },
+        }
+    }
+
+    // an upper bound on the variance of the noise, ignoring bias
+    fn variance(&self) -> f64 {
+        use NoiseDistribution::*;
+        match self {
+            // the variance of laplace noise on a lattice is at most that of continuous laplace noise
+            Laplace { scale, .. } => 2. * scale * scale,
+            // CKS20 Corollary 9: the variance of discrete gaussian noise is less than scale^2
+            Gaussian { scale, .. } | DiscreteGaussian { scale } | SubGaussian { scale } => scale * scale,
+            DiscreteLaplace { scale } => discrete_laplace_variance(*scale),
+            Variance { variance } => *variance,
+            Scaled { factor, noise } => factor * factor * noise.variance(),
+            Biased { noise, .. } => noise.variance(),
+        }
+    }
+}
+
+fn to_f64<T: ToPrimitive>(value: T) -> Fallible<f64> {
+    value.to_f64().ok_or_else(|| err!(FailedCast, "failed to cast to f64"))
+}
+
+fn pow2(k: i32) -> f64 {
+    (k as f64).exp2()
+}
+
+// P(|Z| > a) for Z ~ discrete laplace(scale), where a is a non-negative integer
+fn discrete_laplace_tail(scale: f64, a: f64) -> f64 {
+    if scale == 0. {
+        return 0.
+    }
+    2. * (-(a + 1.) / scale).exp() / (1. + (-scale.recip()).exp())
+}
+
+// the smallest integer a such that P(|Z| > a) <= alpha for Z ~ discrete laplace(scale)
+fn discrete_laplace_accuracy(scale: f64, alpha: f64) -> f64 {
+    if scale == 0. {
+        return 0.
+    }
+    // solve 2 q^(a + 1) / (1 + q) = alpha for a, where q = exp(-1 / scale)
+    let q = (-scale.recip()).exp();
+    let mut a = (scale * (2. / (alpha * (1. + q))).ln() - 1.).ceil().max(0.);
+
+    // correct for float error in the closed form, while integers are still exactly representable
+    if a < 2f64.powi(52) {
+        while discrete_laplace_tail(scale, a) > alpha {
+            a += 1.;
+        }
+        while a > 0. && discrete_laplace_tail(scale, a - 1.) <= alpha {
+            a -= 1.;
+        }
+    }
+    a
+}
+
+fn discrete_laplace_variance(scale: f64) -> f64 {
+    if scale == 0. {
+        return 0.
+    }
+    // 2q / (1 - q)^2, where q = exp(-1 / scale)
+    let q = (-scale.recip()).exp();
+    2. * q / (-scale.recip()).exp_m1().powi(2)
+}
+
+// above this scale, discrete gaussian tails are bounded by gaussian integrals instead of summed
+const DISCRETE_GAUSSIAN_EXACT_SCALE: f64 = 4096.;
+
+// an upper bound on the sum of exp(-z^2 / (2 scale^2)) over integers z > a, where a is a non-negative integer,
+// with a relative error of at most 2^-50
+fn gaussian_kernel_tail(scale: f64, a: f64) -> f64 {
+    let two_var = 2. * scale * scale;
+    let mut sum = 0.;
+    let mut z = a + 1.;
+    loop {
+        let term = (-z * z / two_var).exp();
+        // successive terms from z onwards shrink by at least this ratio
+        let ratio = (-(2. * z + 1.) / two_var).exp();
+        let remainder = term / (1. - ratio);
+        if remainder <= sum * 2f64.powi(-50) {
+            return sum + remainder
+        }
+        sum += term;
+        z += 1.;
+    }
+}
+
+// the smallest integer a such that P(|Z| > a) <= alpha for Z ~ discrete gaussian(scale)
+fn discrete_gaussian_accuracy(scale: f64, alpha: f64) -> f64 {
+    if scale == 0. {
+        return 0.
+    }
+    if scale > DISCRETE_GAUSSIAN_EXACT_SCALE {
+        // The tail sum beyond a is at most the integral of the kernel beyond a,
+        // and the normalizing sum is at least the integral of the kernel, minus one.
+        // Then P(|Z| > a) <= erfc(a / (scale sqrt(2))) / (1 - 1 / (scale sqrt(2 pi))).
+        return (scale * SQRT_2 * erfc_inv(alpha * (1. - (scale * (2. * PI).sqrt()).recip()))).ceil()
+    }
+
+    let normalizer = 1. + 2. * gaussian_kernel_tail(scale, 0.);
+    let tail = |a: f64| 2. * gaussian_kernel_tail(scale, a) / normalizer;
+    if tail(0.) <= alpha {
+        return 0.
+    }
+
+    // binary search, maintaining tail(lower) > alpha >= tail(upper)
+    let mut lower = 0.;
+    let mut upper = scale.ceil();
+    while tail(upper) > alpha {
+        lower = upper;
+        upper *= 2.;
+    }
+    while upper - lower > 1. {
+        let mid = ((lower + upper) / 2.).floor();
+        if tail(mid) <= alpha {
+            upper = mid;
+        } else {
+            lower = mid;
+        }
+    }
+    upper
+}
+
+// accuracy of laplace noise on the lattice of multiples of granularity, after rounding the input to the lattice
+fn lattice_laplace_accuracy(scale: f64, granularity: f64, alpha: f64) -> f64 {
+    if scale == 0. {
+        return granularity / 2.
+    }
+    // the noise is granularity * Z for Z ~ discrete laplace(scale / granularity), so with q = exp(-granularity / scale),
+    //    P(|noise| > a) = 2 q^(floor(a / granularity) + 1) / (1 + q) <= 2 exp(-a / scale) / (1 + q)
+    let q = (-granularity / scale).exp();
+    (scale * (2. / (alpha * (1. + q))).ln()).max(0.) + granularity / 2.
+}
+
+// accuracy of gaussian noise on the lattice of multiples of granularity, after rounding the input to the lattice
+fn lattice_gaussian_accuracy(scale: f64, granularity: f64, alpha: f64) -> f64 {
+    if scale == 0. {
+        return granularity / 2.
+    }
+    if granularity == 0. {
+        return scale * SQRT_2 * erf_inv(1. - alpha)
+    }
+    // the noise is granularity * Z for Z ~ discrete gaussian(scale / granularity)
+    let noise_accuracy = if scale / granularity <= DISCRETE_GAUSSIAN_EXACT_SCALE {
+        granularity * discrete_gaussian_accuracy(scale / granularity, alpha)
+    } else {
+        // the bound from discrete_gaussian_accuracy, in units of the scale,
+        //    where rounding up to the lattice adds at most one granularity
+        scale * SQRT_2 * erfc_inv(alpha * (1. - granularity / (scale * (2. * PI).sqrt()))) + granularity
+    };
+    noise_accuracy + granularity / 2.
+}
+
+// the largest scale at which `is_accurate` holds, where `is_accurate` holds at zero and is monotone in the scale
+fn bisect_scale(is_accurate: impl Fn(f64) -> bool) -> f64 {
+    let mut lower = 0.;
+    let mut upper = 1.;
+    while is_accurate(upper) {
+        if upper > f64::MAX / 2. {
+            return upper
+        }
+        lower = upper;
+        upper *= 2.;
+    }
+    for _ in 0..64 {
+        let mid = lower + (upper - lower) / 2.;
+        if mid <= lower || upper <= mid {
+            break
+        }
+        if is_accurate(mid) {
+            lower = mid;
+        } else {
+            upper = mid;
+        }
+    }
+    lower
+}
+
 
 #[cfg(all(test, feature="untrusted"))]
 pub mod test {
@@ -264,6 +723,239 @@ pub mod test {
         Ok(())
     }
 
+    // P(|Z| > a) for Z ~ discrete laplace(scale), summed directly from the pmf
+    fn discrete_laplace_tail_sum(scale: f64, a: i64) -> f64 {
+        let q = (-1. / scale).exp();
+        let pmf = |z: i64| (1. - q) / (1. + q) * q.powi(z.abs() as i32);
+        1. - (-a..=a).map(pmf).sum::<f64>()
+    }
+
+    // P(|Z| > a) for Z ~ discrete gaussian(scale), summed directly from the pmf
+    fn discrete_gaussian_tail_sum(scale: f64, a: i64) -> f64 {
+        let kernel = |z: i64| (-(z * z) as f64 / (2. * scale * scale)).exp();
+        let normalizer = (-1000..=1000).map(kernel).sum::<f64>();
+        1. - (-a..=a).map(kernel).sum::<f64>() / normalizer
+    }
+
+    #[test]
+    fn test_discrete_scale_to_accuracy() -> Fallible<()> {
+        for &scale in &[0.5, 2., 10.] {
+            for &alpha in &[0.01, 0.05, 0.5] {
+                // the accuracy is the smallest integer at which the tail is at most alpha
+                let accuracy = discrete_laplacian_scale_to_accuracy(scale, alpha)? as i64;
+                assert!(discrete_laplace_tail_sum(scale, accuracy) <= alpha + 1e-12);
+                assert!(accuracy == 0 || discrete_laplace_tail_sum(scale, accuracy - 1) > alpha);
+
+                let accuracy = discrete_gaussian_scale_to_accuracy(scale, alpha)? as i64;
+                assert!(discrete_gaussian_tail_sum(scale, accuracy) <= alpha + 1e-12);
+                assert!(accuracy == 0 || discrete_gaussian_tail_sum(scale, accuracy - 1) > alpha);
+            }
+        }
+        assert_eq!(discrete_laplacian_scale_to_accuracy(0., 0.05)?, 0.);
+        assert!(discrete_gaussian_scale_to_accuracy(1., 0.)
+            .is_err());
+
+        // the bound for large scales is slightly looser than the exact tail sum
+        let exact = discrete_gaussian_scale_to_accuracy(DISCRETE_GAUSSIAN_EXACT_SCALE, 0.05)?;
+        let bound = discrete_gaussian_scale_to_accuracy(DISCRETE_GAUSSIAN_EXACT_SCALE + 1e-6, 0.05)?;
+        assert!(exact <= bound && bound <= exact + 2.);
+        Ok(())
+    }
+
+    #[test]
+    fn test_accuracy_to_discrete_scale() -> Fallible<()> {
+        for &accuracy in &[0., 3., 25.] {
+            for &alpha in &[0.01, 0.05, 0.5] {
+                // the scale is the largest at which the accuracy is met
+                let scale = accuracy_to_discrete_laplacian_scale(accuracy, alpha)?;
+                assert!(discrete_laplacian_scale_to_accuracy(scale, alpha)? <= accuracy);
+                assert!(discrete_laplacian_scale_to_accuracy(scale * 1.001, alpha)? > accuracy);
+
+                let scale = accuracy_to_discrete_gaussian_scale(accuracy, alpha)?;
+                assert!(discrete_gaussian_scale_to_accuracy(scale, alpha)? <= accuracy);
+                assert!(discrete_gaussian_scale_to_accuracy(scale * 1.001, alpha)? > accuracy);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_randomized_response_accuracy() -> Fallible<()> {
+        // 3 categories: the truth is reported with probability 0.8, and each lie with probability 0.1
+        assert!((debias_randomized_response(0.1, 0.8, 3)? - 0.).abs() < 1e-12);
+        assert!((debias_randomized_response(0.8, 0.8, 3)? - 1.).abs() < 1e-12);
+        assert!((debias_randomized_response(0.45, 0.8, 3)? - 0.5).abs() < 1e-12);
+
+        let accuracy = randomized_response_to_accuracy(0.8, 3, 1000, 0.05)?;
+        assert!((accuracy - (40f64.ln() / 2000.).sqrt() / 0.7).abs() < 1e-12);
+
+        assert!(randomized_response_to_accuracy(0.3, 3, 1000, 0.05).is_err());
+        assert!(randomized_response_to_accuracy(0.8, 1, 1000, 0.05).is_err());
+        assert!(randomized_response_to_accuracy(0.8, 3, 0, 0.05).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_empirical_randomized_response_accuracy() -> Fallible<()> {
+        use crate::measurements::make_randomized_response_bool;
+        let (prob, size, alpha) = (0.75, 1000, 0.05);
+        let meas = make_randomized_response_bool(prob, false)?;
+        let accuracy = randomized_response_to_accuracy(prob, 2, size, alpha)?;
+
+        // 30% of the data is true
+        let trials = 200;
+        let failures = (0..trials).filter(|_| {
+            let count = (0..size).filter(|i| meas.invoke(&(i % 10 < 3)).unwrap_test()).count();
+            let estimate = debias_randomized_response(count as f64 / size as f64, prob, 2).unwrap_test();
+            (estimate - 0.3).abs() > accuracy
+        }).count();
+        // Hoeffding's inequality is conservative
+        assert!(failures as f64 / trials as f64 <= alpha);
+        Ok(())
+    }
+
+    #[test]
+    fn test_ptr_threshold_to_release_count() -> Fallible<()> {
+        use std::collections::HashMap;
+        use crate::measurements::make_base_ptr;
+        let (scale, threshold, alpha) = (1., 10., 0.05);
+        let count = ptr_threshold_to_release_count(scale, threshold, alpha)?;
+        assert!((count - (threshold + 10f64.ln())).abs() < 1e-12);
+
+        let meas = make_base_ptr::<char, f64>(scale, threshold, None)?;
+        let data = vec![('a', count)].into_iter().collect::<HashMap<_, _>>();
+        let n = 10_000;
+        let released = (0..n).filter(|_| meas.invoke(&data).unwrap_test().contains_key(&'a')).count();
+        assert!((released as f64 / n as f64 - (1. - alpha)).abs() < 1e-2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_consistent_b_ary_tree_variance() -> Fallible<()> {
+        use crate::metrics::L1Distance;
+        use crate::transformations::{make_b_ary_tree, make_consistent_b_ary_tree};
+
+        assert!((consistent_b_ary_tree_variance(2, 2)? - 2. / 3.).abs() < 1e-12);
+        assert!(consistent_b_ary_tree_variance(0, 2).is_err());
+        assert!(consistent_b_ary_tree_variance(4, 1).is_err());
+
+        for branching_factor in 2..5 {
+            for leaf_count in 1..30 {
+                let factor = consistent_b_ary_tree_variance(leaf_count, branching_factor)?;
+                let num_nodes = make_b_ary_tree::<L1Distance<i32>, i32>(leaf_count, branching_factor)?
+                    .invoke(&vec![0; leaf_count])?.len();
+                let consistent = make_consistent_b_ary_tree::<f64, f64>(branching_factor)?;
+
+                // the postprocessor is linear, so the variance of each leaf is the sum of squares of its coefficients
+                let mut variances = vec![0.; leaf_count];
+                for j in 0..num_nodes {
+                    let mut unit = vec![0.; num_nodes];
+                    unit[j] = 1.;
+                    consistent.invoke(&unit)?.into_iter().zip(variances.iter_mut())
+                        .for_each(|(coef, var)| *var += coef * coef);
+                }
+
+                let is_complete = branching_factor.pow(log_b_floor(leaf_count, branching_factor)) == leaf_count;
+                variances.into_iter().for_each(|var| if is_complete {
+                    assert!((var - factor).abs() < 1e-9, "{} {} {} {}", leaf_count, branching_factor, var, factor)
+                } else {
+                    assert!(var <= factor + 1e-9, "{} {} {} {}", leaf_count, branching_factor, var, factor)
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn log_b_floor(x: usize, b: usize) -> u32 {
+        let mut pow = 0;
+        while b.pow(pow + 1) <= x { pow += 1; }
+        pow
+    }
+
+    #[test]
+    fn test_noise_distribution_accuracy() -> Fallible<()> {
+        // continuous noise matches the scalar conversions
+        let laplace = NoiseDistribution::Laplace { scale: 2., granularity: 0. };
+        assert!((laplace.accuracy(0.05)? - laplacian_scale_to_accuracy(2., 0.05)?).abs() < 1e-12);
+        let gaussian = NoiseDistribution::Gaussian { scale: 2., granularity: 0. };
+        assert!((gaussian.accuracy(0.05)? - gaussian_scale_to_accuracy(2., 0.05)?).abs() < 1e-12);
+
+        // the default discretization is negligible
+        let laplace = NoiseDistribution::laplace(2., -1074)?;
+        assert!((laplace.accuracy(0.05)? - laplacian_scale_to_accuracy(2., 0.05)?).abs() < 1e-12);
+        let gaussian = NoiseDistribution::gaussian(2., -1074)?;
+        assert!((gaussian.accuracy(0.05)? - gaussian_scale_to_accuracy(2., 0.05)?).abs() < 1e-12);
+
+        // a coarse discretization widens the interval
+        assert!(NoiseDistribution::laplace(2., 1)?.accuracy(0.05)? > laplacian_scale_to_accuracy(2., 0.05)?);
+
+        let scaled = NoiseDistribution::discrete_laplace(2.)?.scale_by(-3.);
+        let accuracy = 3. * discrete_laplacian_scale_to_accuracy(2., 0.05)?;
+        assert_eq!(scaled.accuracy(0.05)?, accuracy);
+        let biased = NoiseDistribution::Biased { bias: 1., noise: Box::new(scaled) };
+        assert_eq!(biased.confidence_interval(10., 0.05)?, (9. - accuracy, 11. + accuracy));
+
+        assert!(NoiseDistribution::Variance { variance: 1. }.accuracy(1.5).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_empirical_noise_accuracy() -> Fallible<()> {
+        let alpha = 0.05;
+        let n = 50_000;
+        macro_rules! empirical_alpha {($meas:expr, $arg:expr, $diff:expr) => {{
+            let meas = $meas;
+            let accuracy = meas.accuracy(alpha)?;
+            let empirical_alpha = (0..n)
+                .filter(|_| $diff(meas.invoke(&$arg).unwrap_test()) > accuracy)
+                .count() as f64 / n as f64;
+            println!("accuracy: {:?}, empirical alpha: {:?}", accuracy, empirical_alpha);
+            empirical_alpha
+        }}}
+        let empirical = empirical_alpha!(make_base_laplace::<AllDomain<f64>>(2., None)?, 0.3, |v: f64| (v - 0.3).abs());
+        assert!((empirical - alpha).abs() < 1e-2);
+        let empirical = empirical_alpha!(make_base_gaussian::<AllDomain<f64>, ZeroConcentratedDivergence<_>>(2., None)?, 0., |v: f64| v.abs());
+        assert!((empirical - alpha).abs() < 1e-2);
+
+        // the noise is integral, so the tail probability at the accuracy may fall well below alpha
+        let empirical = empirical_alpha!(make_base_discrete_laplace::<AllDomain<i32>, f64>(3.)?, 0, |v: i32| v.abs() as f64);
+        assert!(empirical < alpha + 1e-2);
+        let empirical = empirical_alpha!(make_base_discrete_gaussian::<AllDomain<i32>, ZeroConcentratedDivergence<f64>>(3.)?, 0, |v: i32| v.abs() as f64);
+        assert!(empirical < alpha + 1e-2);
+
+        // the accuracy on a coarse lattice is conservative
+        let empirical = empirical_alpha!(make_base_laplace::<AllDomain<f64>>(2., Some(1))?, 0.3, |v: f64| (v - 0.3).abs());
+        assert!(empirical < alpha + 1e-2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_measurement_accuracy_postprocessing() -> Fallible<()> {
+        use crate::combinators::{make_chain_mt, make_chain_tm};
+        use crate::core::Function;
+        use crate::metrics::AbsoluteDistance;
+        use crate::measurements::make_randomized_response_bool;
+        use crate::transformations::{make_count, make_lipschitz_float_mul, make_postprocess};
+
+        let laplace = make_base_laplace::<AllDomain<f64>>(2., None)?;
+        let accuracy = laplace.accuracy(0.05)?;
+
+        // the noise is unchanged by preprocessing
+        let count = make_chain_mt(&laplace, &make_count::<i32, f64>()?)?;
+        assert_eq!(count.accuracy(0.05)?, accuracy);
+
+        // scaling the release scales the noise, up to float rounding
+        let mul = make_lipschitz_float_mul::<AllDomain<f64>, AbsoluteDistance<f64>>(-3., (0., 10.))?;
+        let scaled = make_chain_tm(&mul, &laplace)?;
+        assert!((scaled.accuracy(0.05)? - 3. * accuracy).abs() < 1e-12);
+
+        // the accuracy of randomized response, and of arbitrary postprocessing, is not known
+        assert!(make_randomized_response_bool(0.75, false)?.accuracy(0.05).is_err());
+        let square = make_postprocess(AllDomain::new(), AllDomain::new(), Function::new(|v: &f64| v * v))?;
+        assert!(make_chain_tm(&square, &laplace)?.accuracy(0.05).is_err());
+        Ok(())
+    }
+
     #[test]
     pub fn test_roundtrip() -> Fallible<()> {
         let accuracy = 1.;
@@ -0,0 +1,137 @@
+use std::convert::TryFrom;
+use std::os::raw::c_uint;
+
+use crate::accuracy::{compare_utility, simulate_utility, UtilitySummary};
+use crate::core::{CallbackFn, FfiResult, wrap_callback};
+use crate::error::Fallible;
+use crate::ffi::any::{AnyMeasurement, AnyObject, Downcast};
+use crate::ffi::util::AnyMeasurementPtr;
+
+// a summary is passed as a list of the trials, bias, rmse, and the (quantile, value) pairs in the order requested
+fn summary_into_any(summary: UtilitySummary) -> Fallible<AnyObject> {
+    let trials = u32::try_from(summary.trials)
+        .map_err(|_| err!(FFI, "trials must fit in an unsigned int"))?;
+    let quantiles = summary.quantiles.into_iter()
+        .map(AnyObject::new)
+        .collect::<Vec<AnyObject>>();
+    Ok(AnyObject::new(vec![
+        AnyObject::new(trials),
+        AnyObject::new(summary.bias),
+        AnyObject::new(summary.rmse),
+        AnyObject::new(quantiles),
+    ]))
+}
+
+#[no_mangle]
+pub extern "C" fn opendp_accuracy__simulate_utility(
+    measurement: *const AnyMeasurement,
+    data: *const AnyObject,
+    loss: CallbackFn,
+    trials: c_uint,
+    quantiles: *const AnyObject,
+) -> FfiResult<*mut AnyObject> {
+    let measurement = try_as_ref!(measurement);
+    let data = try_as_ref!(data);
+    let loss = wrap_callback(loss);
+    let quantiles = try_!(try_as_ref!(quantiles).downcast_ref::<Vec<f64>>());
+
+    simulate_utility(
+        measurement, data,
+        |release: &AnyObject| loss(release)?.downcast::<f64>(),
+        trials as usize, quantiles
+    ).and_then(summary_into_any).into()
+}
+
+#[no_mangle]
+pub extern "C" fn opendp_accuracy__compare_utility(
+    measurements: *const AnyObject,
+    data: *const AnyObject,
+    loss: CallbackFn,
+    trials: c_uint,
+    quantiles: *const AnyObject,
+) -> FfiResult<*mut AnyObject> {
+    let meas_ptrs = try_!(try_as_ref!(measurements).downcast_ref::<Vec<AnyMeasurementPtr>>());
+    let measurements: Vec<&AnyMeasurement> =
+        try_!(meas_ptrs.iter().map(|ptr| Ok(try_as_ref!(*ptr))).collect());
+    let data = try_as_ref!(data);
+    let loss = wrap_callback(loss);
+    let quantiles = try_!(try_as_ref!(quantiles).downcast_ref::<Vec<f64>>());
+
+    let summaries = try_!(compare_utility(
+        measurements, |measurement| Ok((*measurement).clone()), data,
+        |release: &AnyObject| loss(release)?.downcast::<f64>(),
+        trials as usize, quantiles));
+
+    summaries.into_iter()
+        .map(|(_, summary)| summary_into_any(summary))
+        .collect::<Fallible<Vec<AnyObject>>>()
+        .map(AnyObject::new)
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domains::AllDomain;
+    use crate::ffi::any::IntoAnyMeasurementExt;
+    use crate::ffi::util;
+    use crate::measurements::make_base_laplace;
+    use crate::transformations::make_count;
+
+    use super::*;
+
+    extern "C" fn error(arg: *const AnyObject) -> *mut FfiResult<*mut AnyObject> {
+        let arg = match util::as_ref(arg) {
+            Some(arg) => arg,
+            // a null argument releases the callback
+            None => return std::ptr::null_mut()
+        };
+        let arg = arg.downcast_ref::<f64>().map(|v| AnyObject::new(v - 3.));
+        util::into_raw(FfiResult::from(arg))
+    }
+
+    fn make_count_laplace(scale: f64) -> Fallible<AnyMeasurement> {
+        Ok((make_count::<i32, f64>()? >> make_base_laplace::<AllDomain<f64>>(scale, None)?)?.into_any())
+    }
+
+    fn summary_from_any(summary: &AnyObject) -> Fallible<(u32, f64, Vec<(f64, f64)>)> {
+        let summary: &Vec<AnyObject> = summary.downcast_ref()?;
+        let quantiles = summary[3].downcast_ref::<Vec<AnyObject>>()?.iter()
+            .map(|pair| pair.downcast_ref::<(f64, f64)>().copied())
+            .collect::<Fallible<_>>()?;
+        Ok((*summary[0].downcast_ref::<u32>()?, *summary[2].downcast_ref::<f64>()?, quantiles))
+    }
+
+    #[test]
+    fn test_simulate_utility_ffi() -> Fallible<()> {
+        let measurement = util::into_raw(make_count_laplace(1.)?);
+        let data = AnyObject::new_raw(vec![1i32, 2, 3]);
+        let quantiles = AnyObject::new_raw(vec![0.95, 0.5]);
+
+        let res = opendp_accuracy__simulate_utility(measurement, data, error, 1000, quantiles);
+        let (trials, rmse, quantiles) = summary_from_any(&Fallible::from(res)?)?;
+        assert_eq!(trials, 1000);
+        assert!(rmse > 0.);
+        // quantiles are reported in the order requested
+        assert_eq!((quantiles[0].0, quantiles[1].0), (0.95, 0.5));
+        assert!(quantiles[1].1 < quantiles[0].1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_compare_utility_ffi() -> Fallible<()> {
+        let measurements = AnyObject::new_raw(vec![
+            util::into_raw(make_count_laplace(1.)?) as AnyMeasurementPtr,
+            util::into_raw(make_count_laplace(10.)?) as AnyMeasurementPtr,
+        ]);
+        let data = AnyObject::new_raw(vec![1i32, 2, 3]);
+        let quantiles = AnyObject::new_raw(vec![0.5]);
+
+        let res = opendp_accuracy__compare_utility(measurements, data, error, 1000, quantiles);
+        let summaries: Vec<AnyObject> = Fallible::from(res)?.downcast()?;
+        assert_eq!(summaries.len(), 2);
+        let (_, rmse1, _) = summary_from_any(&summaries[0])?;
+        let (_, rmse10, _) = summary_from_any(&summaries[1])?;
+        assert!(rmse1 < rmse10);
+        Ok(())
+    }
+}
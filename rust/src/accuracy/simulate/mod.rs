@@ -0,0 +1,171 @@
+#[cfg(feature="ffi")]
+mod ffi;
+
+use crate::core::{Domain, Measure, Measurement, Metric};
+use crate::error::Fallible;
+
+/// Summary statistics of the loss over many simulated releases.
+#[derive(Clone, Debug, PartialEq)]
+pub struct UtilitySummary {
+    /// The number of simulated releases.
+    pub trials: usize,
+    /// The mean of the loss.
+    pub bias: f64,
+    /// The root of the mean of the squared loss.
+    pub rmse: f64,
+    /// Pairs of each requested quantile and the corresponding quantile of the absolute loss.
+    pub quantiles: Vec<(f64, f64)>,
+}
+
+/// Estimate the utility of `measurement` on `data` by simulating `trials` releases.
+///
+/// The `loss` is computed on each release, and is typically the signed error of the release
+/// from its noiseless value, so that the bias is the mean error.
+///
+/// The releases are not accounted for in any privacy budget,
+/// so `data` must be public or synthetic, like a proxy for the sensitive dataset.
+///
+/// # Arguments
+/// * `measurement` - Measurement to simulate.
+/// * `data` - Public or synthetic dataset to invoke `measurement` on.
+/// * `loss` - Function to compute the loss of each release.
+/// * `trials` - Number of releases to simulate.
+/// * `quantiles` - Quantiles of the absolute loss to report, each within [0, 1].
+pub fn simulate_utility<DI, DO, MI, MO>(
+    measurement: &Measurement<DI, DO, MI, MO>,
+    data: &DI::Carrier,
+    loss: impl Fn(&DO::Carrier) -> Fallible<f64>,
+    trials: usize,
+    quantiles: &[f64],
+) -> Fallible<UtilitySummary>
+    where DI: Domain, DO: Domain, MI: Metric, MO: Measure {
+    if trials == 0 {
+        return fallible!(FailedFunction, "trials must be positive")
+    }
+    if let Some(q) = quantiles.iter().find(|q| !(0. ..=1.).contains(*q)) {
+        return fallible!(FailedFunction, "quantile ({:?}) must be within [0, 1]", q)
+    }
+
+    let losses = (0..trials)
+        .map(|_| loss(&measurement.invoke(data)?))
+        .collect::<Fallible<Vec<f64>>>()?;
+    if losses.iter().any(|v| v.is_nan()) {
+        return fallible!(FailedFunction, "loss may not be nan")
+    }
+
+    let n = trials as f64;
+    let bias = losses.iter().sum::<f64>() / n;
+    let rmse = (losses.iter().map(|v| v * v).sum::<f64>() / n).sqrt();
+
+    let mut abs_losses = losses.into_iter().map(f64::abs).collect::<Vec<f64>>();
+    abs_losses.sort_by(|l, r| l.partial_cmp(r).unwrap());
+    // the empirical quantile is the smallest absolute loss whose rank is at least q * trials
+    let quantiles = quantiles.iter()
+        .map(|&q| (q, abs_losses[((q * n).ceil() as usize).clamp(1, trials) - 1]))
+        .collect();
+
+    Ok(UtilitySummary { trials, bias, rmse, quantiles })
+}
+
+/// Compare the utility of candidate measurements, one for each of the `params`, side by side.
+///
+/// Each candidate is built by `constructor`, and simulated as in [`simulate_utility`].
+/// Returns the summary of each candidate, in the same order as `params`.
+pub fn compare_utility<P, DI, DO, MI, MO>(
+    params: Vec<P>,
+    constructor: impl Fn(&P) -> Fallible<Measurement<DI, DO, MI, MO>>,
+    data: &DI::Carrier,
+    loss: impl Fn(&DO::Carrier) -> Fallible<f64>,
+    trials: usize,
+    quantiles: &[f64],
+) -> Fallible<Vec<(P, UtilitySummary)>>
+    where DI: Domain, DO: Domain, MI: Metric, MO: Measure {
+    params.into_iter()
+        .map(|param| {
+            let summary = simulate_utility(&constructor(&param)?, data, &loss, trials, quantiles)?;
+            Ok((param, summary))
+        })
+        .collect()
+}
+
+
+#[cfg(all(test, feature="untrusted"))]
+mod test {
+    use super::*;
+    use crate::domains::{AllDomain, VectorDomain};
+    use crate::measurements::{make_base_discrete_laplace, make_base_laplace};
+    use crate::metrics::{L1Distance, SymmetricDistance};
+    use crate::transformations::{make_b_ary_tree, make_consistent_b_ary_tree, make_count, make_sized_bounded_mean};
+    use crate::accuracy::consistent_b_ary_tree_variance;
+
+    #[test]
+    fn test_simulate_utility() -> Fallible<()> {
+        let measurement = (make_count::<i32, f64>()? >> make_base_laplace::<AllDomain<f64>>(2., None)?)?;
+        let data = vec![0; 100];
+        let loss = |release: &f64| Ok(release - 100.);
+        let summary = simulate_utility(&measurement, &data, loss, 10_000, &[0.5, 0.95])?;
+
+        // the loss is laplace, with a median absolute value of scale * ln(2) and a standard deviation of sqrt(2) scale
+        assert_eq!(summary.trials, 10_000);
+        assert!(summary.bias.abs() < 0.15, "bias: {}", summary.bias);
+        assert!((summary.rmse - 2. * 2f64.sqrt()).abs() < 0.15, "rmse: {}", summary.rmse);
+        assert_eq!(summary.quantiles[0].0, 0.5);
+        assert!((summary.quantiles[0].1 - 2. * 2f64.ln()).abs() < 0.1, "median: {:?}", summary.quantiles[0]);
+        // matches the accuracy at alpha = 0.05
+        let accuracy = measurement.accuracy(0.05)?;
+        assert!((summary.quantiles[1].1 - accuracy).abs() < 0.4, "{:?} {}", summary.quantiles[1], accuracy);
+
+        assert!(simulate_utility(&measurement, &data, loss, 0, &[0.5]).is_err());
+        assert!(simulate_utility(&measurement, &data, loss, 10, &[1.5]).is_err());
+        assert!(simulate_utility(&measurement, &data, |_| fallible!(FailedFunction), 10, &[]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_compare_utility_bounds() -> Fallible<()> {
+        let data = (0..100).map(|v| (v % 10) as f64).collect::<Vec<f64>>();
+        let true_mean = 4.5;
+        let summaries = compare_utility(
+            vec![(0., 10.), (0., 100.), (-100., 100.)],
+            |bounds| {
+                let mean = make_sized_bounded_mean::<SymmetricDistance, f64>(100, *bounds)?;
+                // calibrate the noise to an epsilon of 1.
+                let scale = mean.map(&2)?;
+                mean >> make_base_laplace::<AllDomain<f64>>(scale, None)?
+            },
+            &data, |release| Ok(release - true_mean), 1000, &[0.5, 0.9])?;
+
+        assert_eq!(summaries.iter().map(|(b, _)| *b).collect::<Vec<_>>(), vec![(0., 10.), (0., 100.), (-100., 100.)]);
+        // wider bounds add more noise
+        assert!(summaries[0].1.rmse < summaries[1].1.rmse);
+        assert!(summaries[1].1.rmse < summaries[2].1.rmse);
+        assert!(summaries[0].1.quantiles[1].1 < summaries[2].1.quantiles[1].1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_compare_utility_branching_factors() -> Fallible<()> {
+        let leaf_count = 8;
+        let data = vec![10i64; leaf_count];
+        let scale = |b: usize| -> Fallible<f64> {
+            // each record contributes to one node per layer, so the noise is calibrated to an epsilon of 1.
+            make_b_ary_tree::<L1Distance<i64>, i64>(leaf_count, b)?.map(&1).map(|d| d as f64)
+        };
+        // the root mean squared error over the leaves
+        let loss = |release: &Vec<f64>| Ok((release.iter().map(|v| (v - 10.).powi(2)).sum::<f64>() / leaf_count as f64).sqrt());
+        let summaries = compare_utility(
+            vec![2, 8],
+            |&b| make_b_ary_tree::<L1Distance<i64>, i64>(leaf_count, b)?
+                >> make_base_discrete_laplace::<VectorDomain<AllDomain<i64>>, f64>(scale(b)?)?
+                >> make_consistent_b_ary_tree::<i64, f64>(b)?,
+            &data, loss, 1000, &[0.5])?;
+
+        // the error of each leaf matches the variance of the consistent tree
+        for (b, summary) in summaries {
+            let variance = 2. * scale(b)?.powi(2) * consistent_b_ary_tree_variance(leaf_count, b)?;
+            let ratio = summary.rmse.powi(2) / variance;
+            assert!(0.85 < ratio && ratio < 1.15, "b: {}, ratio: {}", b, ratio);
+        }
+        Ok(())
+    }
+}
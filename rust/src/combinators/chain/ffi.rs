@@ -40,7 +40,7 @@ mod tests {
         let measurement1 = util::into_raw(make_test_measurement::<i32>().into_any());
         let chain = Result::from(opendp_combinators__make_chain_mt(measurement1, transformation0))?;
         let arg = AnyObject::new_raw(999);
-        let res = core::opendp_core__measurement_invoke(&chain, arg);
+        let res = core::opendp_core__measurement_invoke(&chain, arg, util::from_bool(false));
         let res: i32 = Fallible::from(res)?.downcast()?;
         assert_eq!(res, 999);
 
@@ -57,7 +57,7 @@ mod tests {
         let transformation1 = util::into_raw(make_test_transformation::<i32>().into_any());
         let chain = Result::from(opendp_combinators__make_chain_tt(transformation1, transformation0))?;
         let arg = AnyObject::new_raw(999);
-        let res = core::opendp_core__transformation_invoke(&chain, arg);
+        let res = core::opendp_core__transformation_invoke(&chain, arg, util::from_bool(false));
         let res: i32 = Fallible::from(res)?.downcast()?;
         assert_eq!(res, 999);
 
@@ -74,7 +74,7 @@ mod tests {
         let transformation1 = util::into_raw(make_test_transformation::<i32>().into_any());
         let chain = Result::from(opendp_combinators__make_chain_tm(transformation1, measurement0))?;
         let arg = AnyObject::new_raw(999);
-        let res = core::opendp_core__measurement_invoke(&chain, arg);
+        let res = core::opendp_core__measurement_invoke(&chain, arg, util::from_bool(false));
         let res: i32 = Fallible::from(res)?.downcast()?;
         assert_eq!(res, 999);
 
@@ -3,7 +3,7 @@ pub mod ffi;
 
 use std::ops::Shr;
 
-use crate::core::{Domain, Function, Measure, Measurement, Metric, PrivacyMap, StabilityMap, Transformation};
+use crate::core::{Domain, Function, Measure, Measurement, Metric, NoiseMap, PrivacyMap, StabilityMap, Transformation};
 use crate::error::Fallible;
 use std::fmt::Debug;
 
@@ -37,14 +37,17 @@ pub fn make_chain_mt<DI, DX, DO, MI, MX, MO>(
         return fallible!(MetricMismatch, mismatch_message("metric", &transformation0.output_metric, &measurement1.input_metric))
     }
 
-    Ok(Measurement::new(
+    let mut chain = Measurement::new(
         transformation0.input_domain.clone(),
         measurement1.output_domain.clone(),
         Function::make_chain(&measurement1.function, &transformation0.function),
         transformation0.input_metric.clone(),
         measurement1.output_measure.clone(),
         PrivacyMap::make_chain(&measurement1.privacy_map, &transformation0.stability_map)
-    ))
+    );
+    // the noise is added after the transformation, so is unchanged
+    chain.set_noise(measurement1.noise().cloned());
+    Ok(chain)
 }
 
 pub fn make_chain_tt<DI, DX, DO, MI, MX, MO>(
@@ -64,14 +67,21 @@ pub fn make_chain_tt<DI, DX, DO, MI, MX, MO>(
         return fallible!(MetricMismatch, mismatch_message("metric", &transformation0.output_metric, &transformation1.input_metric))
     }
 
-    Ok(Transformation::new(
+    let noise_map = match (transformation1.noise_map(), transformation0.noise_map()) {
+        (Some(map1), Some(map0)) => Some(NoiseMap::make_chain(map1, map0)),
+        _ => None
+    };
+
+    let mut chain = Transformation::new(
         transformation0.input_domain.clone(),
         transformation1.output_domain.clone(),
         Function::make_chain(&transformation1.function, &transformation0.function),
         transformation0.input_metric.clone(),
         transformation1.output_metric.clone(),
         StabilityMap::make_chain(&transformation1.stability_map, &transformation0.stability_map)
-    ))
+    );
+    chain.set_noise_map(noise_map);
+    Ok(chain)
 }
 
 // chain a measurement with a transformation as postprocessing
@@ -99,14 +109,22 @@ where
         );
     }
 
-    Ok(Measurement::new(
+    // the accuracy is only known after postprocessing if the transformation describes its effect on the noise
+    let noise = match (measurement0.noise(), transformation1.noise_map()) {
+        (Some(noise), Some(noise_map)) => noise_map.eval(noise).ok(),
+        _ => None
+    };
+
+    let mut chain = Measurement::new(
         measurement0.input_domain.clone(),
         transformation1.output_domain.clone(),
         Function::make_chain(&transformation1.function, &measurement0.function),
         measurement0.input_metric.clone(),
         measurement0.output_measure.clone(),
         measurement0.privacy_map.clone(),
-    ))
+    );
+    chain.set_noise(noise);
+    Ok(chain)
 }
 
 
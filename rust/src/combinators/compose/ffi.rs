@@ -68,7 +68,7 @@ mod tests {
                 AnyObject::new_raw(measurements),
             ))?;
         let arg = AnyObject::new_raw(999);
-        let res = core::opendp_core__measurement_invoke(&basic_composition, arg);
+        let res = core::opendp_core__measurement_invoke(&basic_composition, arg, util::from_bool(false));
         let res: Vec<AnyObject> = Fallible::from(res)?.downcast()?;
         let res = (*res[0].downcast_ref::<i32>()?, *res[1].downcast_ref::<i32>()?);
         println!("{:?}", res);
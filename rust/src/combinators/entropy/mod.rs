@@ -0,0 +1,66 @@
+use std::sync::Arc;
+
+use crate::core::{Domain, Function, Measure, Measurement, Metric};
+use crate::error::Fallible;
+use crate::traits::samplers::{with_entropy_source, EntropySource};
+
+/// Make a Measurement that draws all of its randomness from `source` when invoked,
+/// instead of from the process-wide entropy source.
+///
+/// # Arguments
+/// * `measurement` - The measurement to wrap.
+/// * `source` - Entropy source used by every invocation of the measurement.
+pub fn make_entropy_source<DI, DO, MI, MO>(
+    measurement: &Measurement<DI, DO, MI, MO>,
+    source: Arc<dyn EntropySource>,
+) -> Fallible<Measurement<DI, DO, MI, MO>>
+    where DI: 'static + Domain,
+          DO: 'static + Domain,
+          MI: Metric,
+          MO: Measure {
+    let Measurement {
+        input_domain,
+        output_domain,
+        function,
+        input_metric,
+        output_measure,
+        privacy_map,
+        ..
+    } = measurement.clone();
+
+    let mut sourced = Measurement::new(
+        input_domain,
+        output_domain,
+        Function::new_fallible(move |arg: &DI::Carrier|
+            with_entropy_source(source.clone(), || function.eval(arg))),
+        input_metric,
+        output_measure,
+        privacy_map,
+    );
+    sourced.set_noise(measurement.noise().cloned());
+    Ok(sourced)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::measurements::make_randomized_response_bool;
+    use crate::traits::samplers::{ReplayEntropy, RecordingEntropy, SystemEntropy};
+
+    #[test]
+    fn test_make_entropy_source() -> Fallible<()> {
+        let recorder = Arc::new(RecordingEntropy::new(Arc::new(SystemEntropy)));
+        let measurement = make_randomized_response_bool::<f64>(0.75, false)?;
+
+        let recorded = make_entropy_source(&measurement, recorder.clone())?;
+        let releases = (0..20).map(|_| recorded.invoke(&true)).collect::<Fallible<Vec<bool>>>()?;
+
+        let replayed = make_entropy_source(&measurement, Arc::new(ReplayEntropy::new(recorder.recorded()?)))?;
+        let replays = (0..20).map(|_| replayed.invoke(&true)).collect::<Fallible<Vec<bool>>>()?;
+        assert_eq!(releases, replays);
+        // the replayed stream is exhausted
+        assert!(replayed.invoke(&true).is_err());
+
+        Ok(())
+    }
+}
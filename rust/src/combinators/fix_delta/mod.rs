@@ -50,9 +50,10 @@ where
         input_metric,
         output_measure,
         privacy_map,
+        ..
     } = measurement.clone();
 
-    Ok(Measurement::new(
+    let mut fixed = Measurement::new(
         input_domain,
         output_domain,
         function,
@@ -63,5 +64,7 @@ where
             let curve = privacy_map.eval(d_in)?;
             output_measure.fix_delta(&curve, &delta)
         }),
-    ))
+    );
+    fixed.set_noise(measurement.noise().cloned());
+    Ok(fixed)
 }
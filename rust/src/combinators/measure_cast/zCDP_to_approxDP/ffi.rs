@@ -7,43 +7,50 @@ pub extern "C" fn opendp_combinators__make_zCDP_to_approxDP(
     // run combinator on measurement
 
     fn monomorphize<Q: Float>(measurement: &AnyMeasurement) -> FfiResult<*mut AnyMeasurement> {
+        let noise = measurement.noise().cloned();
         let AnyMeasurement {
             input_domain,
             output_domain,
             function,
             input_metric,
             output_measure,
-            privacy_map
+            privacy_map,
+            ..
         } = measurement.clone();
 
-        let measurement = Measurement {
+        let mut measurement = Measurement::new(
             input_domain,
             output_domain,
             function,
             input_metric,
-            output_measure: try_!(output_measure.downcast::<ZeroConcentratedDivergence<Q>>()),
-            privacy_map: PrivacyMap::new_fallible(move |d_in: &AnyObject| privacy_map.eval(d_in)?.downcast::<Q>())
-        };
+            try_!(output_measure.downcast::<ZeroConcentratedDivergence<Q>>()),
+            PrivacyMap::new_fallible(move |d_in: &AnyObject| privacy_map.eval(d_in)?.downcast::<Q>()),
+        );
+        measurement.set_noise(noise);
 
         let measurement = try_!(make_zCDP_to_approxDP(measurement));
 
+        let noise = measurement.noise().cloned();
         let Measurement {
             input_domain,
             output_domain,
             function,
             input_metric,
             output_measure,
-            privacy_map
+            privacy_map,
+            ..
         } = measurement;
 
-        FfiResult::Ok(util::into_raw(AnyMeasurement {
+        let mut measurement = AnyMeasurement::new(
             input_domain,
             output_domain,
             function,
             input_metric,
-            output_measure: AnyMeasure::new(output_measure),
-            privacy_map: PrivacyMap::new_fallible(move |d_in: &AnyObject| privacy_map.eval(d_in).map(AnyObject::new))
-        }))
+            AnyMeasure::new(output_measure),
+            PrivacyMap::new_fallible(move |d_in: &AnyObject| privacy_map.eval(d_in).map(AnyObject::new)),
+        );
+        measurement.set_noise(noise);
+        FfiResult::Ok(util::into_raw(measurement))
     }
 
     let measurement = try_as_ref!(measurement);
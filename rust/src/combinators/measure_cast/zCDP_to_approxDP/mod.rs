@@ -21,6 +21,7 @@ where
     MI: 'static + Metric,
     QO: Float,
 {
+    let noise = meas.noise().cloned();
     let Measurement {
         input_domain,
         output_domain,
@@ -30,7 +31,7 @@ where
         ..
     } = meas;
 
-    Ok(Measurement::new(
+    let mut cast = Measurement::new(
         input_domain,
         output_domain,
         function,
@@ -43,5 +44,7 @@ where
             }
             Ok(SMDCurve::new(move |&delta: &QO| cdp_epsilon(rho, delta)))
         }),
-    ))
+    );
+    cast.set_noise(noise);
+    Ok(cast)
 }
@@ -24,6 +24,11 @@ pub mod fix_delta;
 #[cfg(feature="contrib")]
 pub use crate::combinators::fix_delta::*;
 
+#[cfg(feature="contrib")]
+pub mod entropy;
+#[cfg(feature="contrib")]
+pub use crate::combinators::entropy::*;
+
 
 
 #[cfg(test)]
@@ -0,0 +1,665 @@
+//! Statistical conformance tests for the samplers and noise mechanisms.
+//!
+//! Each check draws `n` samples, and runs a goodness-of-fit test against the exact distribution:
+//! Pearson's chi-square test for samplers on the integers,
+//! and the Kolmogorov-Smirnov test for samplers on the floats.
+//! A check returns a [`GoodnessOfFit`], whose p-value should be compared against a significance level.
+//!
+//! A correct sampler fails a check at significance level `alpha` with probability `alpha`,
+//! so a suite of checks should use a small `alpha`, or correct for multiple comparisons.
+//! Larger `n` detect smaller deviations from the exact distribution.
+//!
+//! Samples are drawn from the current entropy source,
+//! so the checks can be run against a custom source with [`with_entropy_source`](crate::traits::samplers::with_entropy_source).
+//!
+//! This module is only available with the `conformance` feature.
+//!
+//! # Example
+//! ```
+//! use opendp::conformance::check_discrete_laplace;
+//! let fit = check_discrete_laplace(2., false, 1_000).unwrap();
+//! assert!(fit.passes(1e-9));
+//! ```
+
+use std::ops::RangeInclusive;
+
+use statrs::distribution::{Binomial, ChiSquared, Discrete, Univariate};
+use statrs::function::erf::erfc;
+use statrs::function::gamma::ln_gamma;
+
+use crate::core::{Domain, Measure, Measurement, Metric};
+use crate::domains::AllDomain;
+use crate::error::Fallible;
+use crate::measurements::{
+    make_base_discrete_gaussian, make_base_discrete_gaussian_fixed_iteration, make_base_discrete_laplace,
+    make_base_discrete_laplace_cks20_fixed_iteration, make_base_gaussian, make_base_gaussian_fixed_iteration,
+    make_base_laplace, make_base_laplace_fixed_iteration, make_randomized_response_bool,
+};
+use crate::measures::ZeroConcentratedDivergence;
+use crate::traits::samplers::{
+    CastInternalInteger, CastInternalRational, DiscreteGaussianSampler, DiscreteGaussianZ2k,
+    DiscreteLaplaceSampler, DiscreteLaplaceZ2k, SampleBernoulli, SampleBernoulliExp, SampleDiscreteLaplaceLinear,
+    SampleGeometric, SampleStandardBernoulli, SampleUniform, SampleUniformInt, SampleUniformIntBelow, Shuffle,
+    sample_binomial, sample_discrete_exponential, sample_poisson,
+};
+
+/// The most categories a chi-square test may have before merging.
+const MAX_CATEGORIES: u64 = 1 << 22;
+
+/// The result of a goodness-of-fit test.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GoodnessOfFit {
+    /// The test statistic: Pearson's chi-square statistic, or the Kolmogorov-Smirnov distance.
+    pub statistic: f64,
+    /// The probability of a statistic at least as extreme, if the samples follow the reference distribution.
+    pub p_value: f64,
+}
+
+impl GoodnessOfFit {
+    /// Returns true if the reference distribution is not rejected at significance level `alpha`.
+    pub fn passes(&self, alpha: f64) -> bool {
+        self.p_value >= alpha
+    }
+}
+
+/// Pearson's chi-square test of integer `samples` against the probability mass function `pmf`.
+///
+/// Each integer in `support` is a category, and all integers outside of `support` make one more category.
+/// Adjacent categories are merged until each has an expected count of at least five.
+pub fn chi_square_test(samples: &[i64], pmf: impl Fn(i64) -> f64, support: RangeInclusive<i64>) -> Fallible<GoodnessOfFit> {
+    if samples.is_empty() {
+        return fallible!(FailedFunction, "samples must be non-empty")
+    }
+    let (lower, upper) = (*support.start(), *support.end());
+    if lower > upper {
+        return fallible!(FailedFunction, "support must be non-empty")
+    }
+    let width = (upper as i128 - lower as i128 + 1) as u64;
+    if width > MAX_CATEGORIES {
+        return fallible!(FailedFunction, "support may have at most {} integers", MAX_CATEGORIES)
+    }
+    let width = width as usize;
+    let n = samples.len() as f64;
+
+    // the last category holds the samples outside of the support
+    let mut observed = vec![0usize; width + 1];
+    samples.iter().for_each(|x| observed[if support.contains(x) { (x - lower) as usize } else { width }] += 1);
+
+    let mut expected = support.map(|x| pmf(x) * n).collect::<Vec<f64>>();
+    let outside = (n - expected.iter().sum::<f64>()).max(0.);
+    expected.push(outside);
+
+    // merge adjacent categories until each expects at least five samples
+    let mut bins: Vec<(f64, f64)> = Vec::new();
+    let mut pending = (0., 0.);
+    for (e, o) in expected[..width].iter().zip(&observed[..width]) {
+        pending = (pending.0 + e, pending.1 + *o as f64);
+        if pending.0 >= 5. {
+            bins.push(pending);
+            pending = (0., 0.);
+        }
+    }
+    // the remainder of the support joins the category outside of the support,
+    //    which becomes its own bin if large enough, or else joins the last bin
+    pending = (pending.0 + expected[width], pending.1 + observed[width] as f64);
+    match bins.last_mut() {
+        Some(last) if pending.0 < 5. => *last = (last.0 + pending.0, last.1 + pending.1),
+        _ => bins.push(pending)
+    }
+
+    if bins.len() < 2 {
+        return fallible!(FailedFunction, "too few samples for a chi-square test")
+    }
+
+    let statistic = bins.iter().map(|(e, o)| (o - e).powi(2) / e).sum::<f64>();
+    let dof = ChiSquared::new((bins.len() - 1) as f64)
+        .map_err(|e| err!(FailedFunction, "{:?}", e))?;
+    Ok(GoodnessOfFit { statistic, p_value: 1. - dof.cdf(statistic) })
+}
+
+/// One-sample Kolmogorov-Smirnov test of `samples` against the cumulative distribution function `cdf`.
+///
+/// `cdf` may be discontinuous, as it is for distributions on a lattice,
+/// in which case the p-value is conservative.
+pub fn ks_test(samples: &[f64], cdf: impl Fn(f64) -> f64) -> Fallible<GoodnessOfFit> {
+    if samples.is_empty() {
+        return fallible!(FailedFunction, "samples must be non-empty")
+    }
+    if samples.iter().any(|x| x.is_nan()) {
+        return fallible!(FailedFunction, "samples may not be nan")
+    }
+    let mut sorted = samples.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let n = sorted.len() as f64;
+
+    // the empirical cdf is a step function, so the distance is greatest
+    //    either at a sample, or just below a sample
+    let mut statistic = 0f64;
+    let mut i = 0;
+    while i < sorted.len() {
+        let x = sorted[i];
+        let below = i as f64 / n;
+        while i < sorted.len() && sorted[i] == x { i += 1 }
+        let at = i as f64 / n;
+        statistic = statistic
+            .max((below - cdf(next_below(x))).abs())
+            .max((at - cdf(x)).abs());
+    }
+
+    // Stephens' approximation to the finite-sample distribution
+    let sqrt_n = n.sqrt();
+    let p_value = kolmogorov_survival((sqrt_n + 0.12 + 0.11 / sqrt_n) * statistic);
+    Ok(GoodnessOfFit { statistic, p_value })
+}
+
+/// The largest float less than `x`.
+fn next_below(x: f64) -> f64 {
+    if x == f64::NEG_INFINITY {
+        x
+    } else if x == 0. {
+        -f64::from_bits(1)
+    } else if x > 0. {
+        f64::from_bits(x.to_bits() - 1)
+    } else {
+        f64::from_bits(x.to_bits() + 1)
+    }
+}
+
+/// P(K > `lambda`), where K follows the Kolmogorov distribution.
+fn kolmogorov_survival(lambda: f64) -> f64 {
+    use std::f64::consts::PI;
+    if lambda <= 0. {
+        return 1.
+    }
+    // each series converges quickly on its side of 1
+    let p = if lambda < 1. {
+        let terms = (1..=16).map(|k| (-((2 * k - 1) as f64 * PI / lambda).powi(2) / 8.).exp()).sum::<f64>();
+        1. - (2. * PI).sqrt() / lambda * terms
+    } else {
+        2. * (1..=16).map(|k| {
+            let sign = if k % 2 == 1 { 1. } else { -1. };
+            sign * (-2. * (k as f64 * lambda).powi(2)).exp()
+        }).sum::<f64>()
+    };
+    p.clamp(0., 1.)
+}
+
+/// The probability mass function of the geometric distribution on {0, 1, 2, ...},
+/// the number of failures before the first success, where each trial succeeds with probability `prob`.
+pub fn geometric_pmf(prob: f64) -> impl Fn(i64) -> f64 {
+    move |k| if k < 0 { 0. } else { (1. - prob).powf(k as f64) * prob }
+}
+
+/// The probability mass function of the Poisson distribution with rate `lambda`.
+pub fn poisson_pmf(lambda: f64) -> impl Fn(i64) -> f64 {
+    move |k| match k {
+        k if k < 0 => 0.,
+        0 => (-lambda).exp(),
+        k => (k as f64 * lambda.ln() - lambda - ln_gamma(k as f64 + 1.)).exp()
+    }
+}
+
+/// The probability mass function of the discrete laplace distribution, centered at `shift`.
+pub fn discrete_laplace_pmf(shift: i64, scale: f64) -> impl Fn(i64) -> f64 {
+    move |x| {
+        let distance = (x as f64 - shift as f64).abs();
+        if scale == 0. {
+            return if distance == 0. { 1. } else { 0. }
+        }
+        // (1 - q) / (1 + q) * q^|x - shift|, where q = exp(-1 / scale)
+        (1. / (2. * scale)).tanh() * (-distance / scale).exp()
+    }
+}
+
+/// The probability mass function of the discrete gaussian distribution, centered at `shift`.
+pub fn discrete_gaussian_pmf(shift: i64, scale: f64) -> impl Fn(i64) -> f64 {
+    let kernel = move |distance: f64| (-(distance / scale).powi(2) / 2.).exp();
+    // the normalizing constant is within a factor of 1 + 2exp(-2π²σ²) of sqrt(2π)σ,
+    //    which is exact in floating-point when σ ≥ 2
+    let normalizer = if scale >= 2. {
+        (2. * std::f64::consts::PI).sqrt() * scale
+    } else {
+        // terms beyond 40σ are smaller than exp(-800)
+        let bound = (40. * scale).ceil() as i64 + 1;
+        (-bound..=bound).map(|z| kernel(z as f64)).sum()
+    };
+    move |x| {
+        let distance = x as f64 - shift as f64;
+        if scale == 0. {
+            return if distance == 0. { 1. } else { 0. }
+        }
+        kernel(distance) / normalizer
+    }
+}
+
+/// The cumulative distribution function of the standard uniform distribution on [0, 1).
+pub fn standard_uniform_cdf(x: f64) -> f64 {
+    x.clamp(0., 1.)
+}
+
+/// The cumulative distribution function of the laplace distribution on the lattice of multiples of 2^`k`,
+/// as sampled by [`DiscreteLaplaceZ2k`]: `shift` is rounded to the lattice, and noise is discrete laplace.
+pub fn discrete_laplace_z2k_cdf(shift: f64, scale: f64, k: i32) -> impl Fn(f64) -> f64 {
+    let gran = pow2(k);
+    let center = round_to_lattice(shift, k);
+    move |v| {
+        // the distance from the center to the greatest lattice point no greater than v
+        let distance = floor_to_lattice(v, k) - center;
+        if scale == 0. {
+            return if distance >= 0. { 1. } else { 0. }
+        }
+        // P(Z ≤ z) for Z discrete laplace, where q = exp(-2^k / scale) and z = distance / 2^k
+        let tail = 1. + (-gran / scale).exp();
+        if distance >= 0. {
+            1. - (-(distance + gran) / scale).exp() / tail
+        } else {
+            (distance / scale).exp() / tail
+        }
+    }
+}
+
+/// The cumulative distribution function of the gaussian distribution on the lattice of multiples of 2^`k`,
+/// as sampled by [`DiscreteGaussianZ2k`]: `shift` is rounded to the lattice, and noise is discrete gaussian.
+///
+/// The cdf is the running sum of the normalized mass of each lattice point within 12 scales of the center,
+/// as the mass beyond is less than exp(-72).
+/// When `scale` is more than 2^16 lattice points, the normal cdf with a continuity correction is used instead,
+/// which differs from the running sum by less than 2^-38.
+pub fn discrete_gaussian_z2k_cdf(shift: f64, scale: f64, k: i32) -> impl Fn(f64) -> f64 {
+    let gran = pow2(k);
+    let center = round_to_lattice(shift, k);
+    // the scale in units of 2^k
+    let lattice_scale = scale / gran;
+
+    // cumulative masses of the lattice points in [-radius, radius] from the center
+    let cumulative = (scale > 0. && lattice_scale <= 65536.).then(|| {
+        let radius = (12. * lattice_scale).ceil() as i64;
+        let masses = (-radius..=radius)
+            .map(|z| (-(z as f64 / lattice_scale).powi(2) / 2.).exp())
+            .scan(0., |total, mass| { *total += mass; Some(*total) })
+            .collect::<Vec<f64>>();
+        let total = masses[masses.len() - 1];
+        (radius, masses.into_iter().map(|m| m / total).collect::<Vec<_>>())
+    });
+
+    move |v| {
+        let distance = floor_to_lattice(v, k) - center;
+        if scale == 0. {
+            return if distance >= 0. { 1. } else { 0. }
+        }
+        match &cumulative {
+            Some((radius, masses)) => {
+                let z = distance / gran;
+                if z < -*radius as f64 { 0. }
+                else if z >= *radius as f64 { 1. }
+                else { masses[(z as i64 + radius) as usize] }
+            },
+            None => erfc(-(distance + gran / 2.) / scale / std::f64::consts::SQRT_2) / 2.
+        }
+    }
+}
+
+/// The cumulative distribution function of the standard uniform distribution on [0, 1), rounded down to f32.
+pub fn standard_uniform_f32_cdf(x: f64) -> f64 {
+    if x < 0. { return 0. }
+    if x >= 1. { return 1. }
+    // the greatest f32 no greater than x
+    let mut lower = x as f32;
+    if lower as f64 > x {
+        lower = f32::from_bits(lower.to_bits() - 1)
+    }
+    // the samples no greater than x are those of uniforms below the next f32
+    f32::from_bits(lower.to_bits() + 1) as f64
+}
+
+/// 2^`k`, for `k` within the range of f64 exponents.
+fn pow2(k: i32) -> f64 {
+    let k = k.clamp(-1074, 1023);
+    if k >= -1022 {
+        f64::from_bits(((k + 1023) as u64) << 52)
+    } else {
+        f64::from_bits(1u64 << (k + 1074))
+    }
+}
+
+/// The greatest multiple of 2^`k` no greater than `v`.
+fn floor_to_lattice(v: f64, k: i32) -> f64 {
+    // floats this large are already multiples of 2^k
+    if !v.is_finite() || v.abs() >= pow2(k.saturating_add(53)) { return v }
+    (v / pow2(k)).floor() * pow2(k)
+}
+
+/// The nearest multiple of 2^`k` to `v`, with ties rounded away from zero.
+fn round_to_lattice(v: f64, k: i32) -> f64 {
+    if !v.is_finite() || v.abs() >= pow2(k.saturating_add(53)) { return v }
+    (v / pow2(k)).round() * pow2(k)
+}
+
+/// A support wide enough that the tails beyond it are negligible, capped to the categories a chi-square test may use.
+fn symmetric_support(shift: i64, width: f64) -> RangeInclusive<i64> {
+    let radius = width.ceil().min((MAX_CATEGORIES / 2 - 1) as f64) as i64;
+    shift.saturating_sub(radius)..=shift.saturating_add(radius)
+}
+
+fn draw<T>(n: usize, mut sample: impl FnMut() -> Fallible<T>) -> Fallible<Vec<T>> {
+    (0..n).map(|_| sample()).collect()
+}
+
+fn from_bools(samples: Vec<bool>) -> Vec<i64> {
+    samples.into_iter().map(i64::from).collect()
+}
+
+fn bernoulli_pmf(prob: f64) -> impl Fn(i64) -> f64 {
+    move |x| match x { 0 => 1. - prob, 1 => prob, _ => 0. }
+}
+
+/// Check [`SampleStandardBernoulli`] against Bernoulli(1/2).
+pub fn check_standard_bernoulli(n: usize) -> Fallible<GoodnessOfFit> {
+    let samples = draw(n, bool::sample_standard_bernoulli)?;
+    chi_square_test(&from_bools(samples), bernoulli_pmf(0.5), 0..=1)
+}
+
+/// Check [`SampleBernoulli`] against Bernoulli(`prob`).
+pub fn check_bernoulli(prob: f64, constant_time: bool, n: usize) -> Fallible<GoodnessOfFit> {
+    let samples = draw(n, || bool::sample_bernoulli(prob, constant_time))?;
+    chi_square_test(&from_bools(samples), bernoulli_pmf(prob), 0..=1)
+}
+
+/// Check [`SampleUniform`] against the standard uniform distribution.
+pub fn check_standard_uniform(constant_time: bool, n: usize) -> Fallible<GoodnessOfFit> {
+    let samples = draw(n, || f64::sample_standard_uniform(constant_time))?;
+    ks_test(&samples, standard_uniform_cdf)
+}
+
+/// Check [`SampleUniform`] for f32 against the standard uniform distribution.
+pub fn check_standard_uniform_f32(constant_time: bool, n: usize) -> Fallible<GoodnessOfFit> {
+    let samples = draw(n, || f32::sample_standard_uniform(constant_time).map(f64::from))?;
+    ks_test(&samples, standard_uniform_f32_cdf)
+}
+
+/// Check [`SampleUniformInt`] against the uniform distribution on the bytes {0, 1, ..., 255}.
+pub fn check_uniform_int(n: usize) -> Fallible<GoodnessOfFit> {
+    let samples = draw(n, || u8::sample_uniform_int().map(i64::from))?;
+    chi_square_test(&samples, |_| 1. / 256., 0..=255)
+}
+
+/// Check [`Shuffle`] against the uniform distribution on the permutations of `len` elements.
+pub fn check_shuffle(len: usize, n: usize) -> Fallible<GoodnessOfFit> {
+    let permutations = (1..=len as u64).try_fold(1u64, |acc, v| acc.checked_mul(v))
+        .filter(|count| *count <= MAX_CATEGORIES)
+        .ok_or_else(|| err!(FailedFunction, "len must have at most {} permutations", MAX_CATEGORIES))?;
+    let samples = draw(n, || {
+        let mut permutation = (0..len).collect::<Vec<_>>();
+        permutation.shuffle()?;
+        Ok(permutation_rank(&permutation))
+    })?;
+    chi_square_test(&samples, |_| 1. / permutations as f64, 0..=permutations as i64 - 1)
+}
+
+/// The index of `permutation` in the lexicographic order of the permutations of {0, 1, ..., len - 1}.
+fn permutation_rank(permutation: &[usize]) -> i64 {
+    // the Lehmer code, read as a number in the factorial base
+    (0..permutation.len()).fold(0, |rank, i| {
+        let smaller_after = permutation[i + 1..].iter().filter(|v| **v < permutation[i]).count();
+        rank * (permutation.len() - i) as i64 + smaller_after as i64
+    })
+}
+
+/// Check [`SampleUniformIntBelow`] against the uniform distribution on {0, 1, ..., `upper` - 1}.
+pub fn check_uniform_int_below(upper: u64, fixed_iteration: bool, n: usize) -> Fallible<GoodnessOfFit> {
+    if upper == 0 || upper > MAX_CATEGORIES {
+        return fallible!(FailedFunction, "upper must be within [1, {}]", MAX_CATEGORIES)
+    }
+    let samples = draw(n, || if fixed_iteration {
+        u64::sample_uniform_int_below_fixed_iteration(upper)
+    } else {
+        u64::sample_uniform_int_below(upper)
+    })?;
+    let samples = samples.into_iter().map(|v| v as i64).collect::<Vec<_>>();
+    chi_square_test(&samples, |_| 1. / upper as f64, 0..=upper as i64 - 1)
+}
+
+/// Check [`SampleGeometric`] against the geometric distribution with success probability `prob`.
+pub fn check_geometric(prob: f64, n: usize) -> Fallible<GoodnessOfFit> {
+    let samples = draw(n, || i64::sample_geometric(0, true, prob, None))?;
+    let max = (40. / prob).ceil().min((MAX_CATEGORIES - 1) as f64) as i64;
+    chi_square_test(&samples, geometric_pmf(prob), 0..=max)
+}
+
+/// Check [`SampleDiscreteLaplaceLinear`] against the discrete laplace distribution.
+pub fn check_discrete_laplace_linear(scale: f64, n: usize) -> Fallible<GoodnessOfFit> {
+    let samples = draw(n, || i64::sample_discrete_laplace_linear(0, scale, None))?;
+    chi_square_test(&samples, discrete_laplace_pmf(0, scale), symmetric_support(0, 40. * scale))
+}
+
+/// Check [`SampleBernoulliExp`] against Bernoulli(exp(-`x`)).
+pub fn check_bernoulli_exp(x: f64, n: usize) -> Fallible<GoodnessOfFit> {
+    let rational_x = x.into_rational()?;
+    let samples = draw(n, || bool::sample_bernoulli_exp(rational_x.clone()))?;
+    chi_square_test(&from_bools(samples), bernoulli_pmf((-x).exp()), 0..=1)
+}
+
+/// Check [`sample_poisson`] against the Poisson distribution with rate `lambda`.
+pub fn check_poisson(lambda: f64, n: usize) -> Fallible<GoodnessOfFit> {
+    let rational_lambda = lambda.into_rational()?;
+    let samples = draw(n, || sample_poisson(rational_lambda.clone()).map(i64::from_integer))?;
+    let max = (lambda + 40. * lambda.sqrt() + 40.).min((MAX_CATEGORIES - 1) as f64) as i64;
+    chi_square_test(&samples, poisson_pmf(lambda), 0..=max)
+}
+
+/// Check [`sample_binomial`] against the binomial distribution with `trials` trials of success probability `prob`.
+pub fn check_binomial(trials: u64, prob: f64, n: usize) -> Fallible<GoodnessOfFit> {
+    let rational_prob = prob.into_rational()?;
+    let samples = draw(n, || sample_binomial(trials, rational_prob.clone()).map(|v| v as i64))?;
+    let binomial = Binomial::new(prob, trials).map_err(|e| err!(FailedFunction, "{:?}", e))?;
+    let max = trials.min(MAX_CATEGORIES - 1) as i64;
+    chi_square_test(&samples, |k| binomial.pmf(k as u64), 0..=max)
+}
+
+/// Check [`sample_discrete_exponential`] against the discrete exponential distribution.
+pub fn check_discrete_exponential(scale: f64, n: usize) -> Fallible<GoodnessOfFit> {
+    let rational_scale = scale.into_rational()?;
+    let samples = draw(n, || sample_discrete_exponential(rational_scale.clone()).map(i64::from_integer))?;
+    let max = (40. * scale).ceil().min((MAX_CATEGORIES - 1) as f64) as i64;
+    chi_square_test(&samples, geometric_pmf(1. - (-1. / scale).exp()), 0..=max)
+}
+
+/// Check [`DiscreteLaplaceSampler`] against the discrete laplace distribution.
+pub fn check_discrete_laplace(scale: f64, fixed_iteration: bool, n: usize) -> Fallible<GoodnessOfFit> {
+    let sampler = DiscreteLaplaceSampler::new(scale.into_rational()?, fixed_iteration);
+    let samples = draw(n, || sampler.sample().map(i64::from_integer))?;
+    chi_square_test(&samples, discrete_laplace_pmf(0, scale), symmetric_support(0, 40. * scale))
+}
+
+/// Check [`DiscreteGaussianSampler`] against the discrete gaussian distribution.
+pub fn check_discrete_gaussian(scale: f64, fixed_iteration: bool, n: usize) -> Fallible<GoodnessOfFit> {
+    let sampler = DiscreteGaussianSampler::new(scale.into_rational()?, fixed_iteration);
+    let samples = draw(n, || sampler.sample().map(i64::from_integer))?;
+    chi_square_test(&samples, discrete_gaussian_pmf(0, scale), symmetric_support(0, 10. * scale))
+}
+
+/// Check [`DiscreteLaplaceZ2k`] against the laplace distribution on the lattice of multiples of 2^`k`.
+pub fn check_discrete_laplace_z2k(shift: f64, scale: f64, k: i32, fixed_iteration: bool, n: usize) -> Fallible<GoodnessOfFit> {
+    let sampler = DiscreteLaplaceZ2k::new(scale, k, fixed_iteration)?;
+    let samples = draw(n, || sampler.sample(shift))?;
+    ks_test(&samples, discrete_laplace_z2k_cdf(shift, scale, k))
+}
+
+/// Check [`DiscreteGaussianZ2k`] against the gaussian distribution on the lattice of multiples of 2^`k`.
+/// See [`discrete_gaussian_z2k_cdf`] for the reference distribution.
+pub fn check_discrete_gaussian_z2k(shift: f64, scale: f64, k: i32, fixed_iteration: bool, n: usize) -> Fallible<GoodnessOfFit> {
+    let sampler = DiscreteGaussianZ2k::new(scale, k, fixed_iteration)?;
+    let samples = draw(n, || sampler.sample(shift))?;
+    ks_test(&samples, discrete_gaussian_z2k_cdf(shift, scale, k))
+}
+
+/// Invoke `measurement` on `arg` `n` times.
+pub fn sample_measurement<DI: Domain, DO: Domain, MI: Metric, MO: Measure>(
+    measurement: &Measurement<DI, DO, MI, MO>, arg: &DI::Carrier, n: usize
+) -> Fallible<Vec<DO::Carrier>> {
+    draw(n, || measurement.invoke(arg))
+}
+
+/// Check the output distribution of [`make_base_laplace`], or [`make_base_laplace_fixed_iteration`], on `shift`.
+pub fn check_base_laplace(shift: f64, scale: f64, k: Option<i32>, fixed_iteration: bool, n: usize) -> Fallible<GoodnessOfFit> {
+    let measurement = if fixed_iteration {
+        make_base_laplace_fixed_iteration::<AllDomain<f64>>(scale, k)?
+    } else {
+        make_base_laplace::<AllDomain<f64>>(scale, k)?
+    };
+    let (k, _) = crate::measurements::laplace::get_discretization_consts::<f64>(k)?;
+    ks_test(&sample_measurement(&measurement, &shift, n)?, discrete_laplace_z2k_cdf(shift, scale, k))
+}
+
+/// Check the output distribution of [`make_base_gaussian`], or [`make_base_gaussian_fixed_iteration`], on `shift`.
+/// See [`discrete_gaussian_z2k_cdf`] for the reference distribution.
+pub fn check_base_gaussian(shift: f64, scale: f64, k: Option<i32>, fixed_iteration: bool, n: usize) -> Fallible<GoodnessOfFit> {
+    type MO = ZeroConcentratedDivergence<f64>;
+    let measurement = if fixed_iteration {
+        make_base_gaussian_fixed_iteration::<AllDomain<f64>, MO>(scale, k)?
+    } else {
+        make_base_gaussian::<AllDomain<f64>, MO>(scale, k)?
+    };
+    let (k, _) = crate::measurements::laplace::get_discretization_consts::<f64>(k)?;
+    ks_test(&sample_measurement(&measurement, &shift, n)?, discrete_gaussian_z2k_cdf(shift, scale, k))
+}
+
+/// Check the output distribution of [`make_base_discrete_laplace`],
+/// or [`make_base_discrete_laplace_cks20_fixed_iteration`], on `shift`.
+pub fn check_base_discrete_laplace(shift: i64, scale: f64, fixed_iteration: bool, n: usize) -> Fallible<GoodnessOfFit> {
+    let measurement = if fixed_iteration {
+        make_base_discrete_laplace_cks20_fixed_iteration::<AllDomain<i64>, f64>(scale)?
+    } else {
+        make_base_discrete_laplace::<AllDomain<i64>, f64>(scale)?
+    };
+    let samples = sample_measurement(&measurement, &shift, n)?;
+    chi_square_test(&samples, discrete_laplace_pmf(shift, scale), symmetric_support(shift, 40. * scale))
+}
+
+/// Check the output distribution of [`make_base_discrete_gaussian`],
+/// or [`make_base_discrete_gaussian_fixed_iteration`], on `shift`.
+pub fn check_base_discrete_gaussian(shift: i64, scale: f64, fixed_iteration: bool, n: usize) -> Fallible<GoodnessOfFit> {
+    type MO = ZeroConcentratedDivergence<f64>;
+    let measurement = if fixed_iteration {
+        make_base_discrete_gaussian_fixed_iteration::<AllDomain<i64>, MO>(scale)?
+    } else {
+        make_base_discrete_gaussian::<AllDomain<i64>, MO>(scale)?
+    };
+    let samples = sample_measurement(&measurement, &shift, n)?;
+    chi_square_test(&samples, discrete_gaussian_pmf(shift, scale), symmetric_support(shift, 10. * scale))
+}
+
+/// Check the output distribution of [`make_randomized_response_bool`] on `arg`.
+pub fn check_randomized_response_bool(arg: bool, prob: f64, constant_time: bool, n: usize) -> Fallible<GoodnessOfFit> {
+    let measurement = make_randomized_response_bool(prob, constant_time)?;
+    let samples = sample_measurement(&measurement, &arg, n)?;
+    chi_square_test(&from_bools(samples), bernoulli_pmf(if arg { prob } else { 1. - prob }), 0..=1)
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // a correct sampler fails a check with probability ALPHA
+    const ALPHA: f64 = 1e-6;
+
+    #[test]
+    fn test_chi_square_rejects() -> Fallible<()> {
+        // samples from the discrete laplace with the wrong scale
+        let samples = draw(2_000, || i64::sample_discrete_laplace_linear(0, 2., None))?;
+        let fit = chi_square_test(&samples, discrete_laplace_pmf(0, 1.), symmetric_support(0, 40.))?;
+        assert!(!fit.passes(ALPHA), "{:?}", fit);
+        Ok(())
+    }
+
+    #[test]
+    fn test_ks_rejects() -> Fallible<()> {
+        let samples = draw(2_000, || f64::sample_standard_uniform(false).map(|v| v * 0.9))?;
+        let fit = ks_test(&samples, standard_uniform_cdf)?;
+        assert!(!fit.passes(ALPHA), "{:?}", fit);
+        Ok(())
+    }
+
+    #[test]
+    fn test_kolmogorov_survival() {
+        // critical values of the Kolmogorov distribution
+        assert!((kolmogorov_survival(1.3581) - 0.05).abs() < 1e-4);
+        assert!((kolmogorov_survival(0.8276) - 0.5).abs() < 1e-3);
+        assert_eq!(kolmogorov_survival(0.), 1.);
+    }
+
+    #[test]
+    fn test_lattice_cdf() {
+        let cdf = discrete_laplace_z2k_cdf(0.3, 1., -1);
+        // the center rounds to 0.5, and the cdf is constant between lattice points
+        assert_eq!(cdf(0.5), cdf(0.9));
+        assert!(cdf(0.5) > cdf(next_below(0.5)));
+        assert!((cdf(f64::MAX) - 1.).abs() < 1e-12);
+        assert!(cdf(f64::MIN) < 1e-12);
+    }
+
+    #[test]
+    fn test_discrete_gaussian_z2k_cdf() {
+        // on the integers, the cdf is the running sum of the pmf
+        let cdf = discrete_gaussian_z2k_cdf(0., 1.5, 0);
+        let pmf = discrete_gaussian_pmf(0, 1.5);
+        let mut total = 0.;
+        for z in -20..=20 {
+            total += pmf(z);
+            assert!((cdf(z as f64) - total).abs() < 1e-12);
+        }
+        assert_eq!(cdf(f64::MAX), 1.);
+        assert_eq!(cdf(f64::MIN), 0.);
+    }
+
+    #[test]
+    fn test_permutation_rank() {
+        assert_eq!(permutation_rank(&[0, 1, 2]), 0);
+        assert_eq!(permutation_rank(&[0, 2, 1]), 1);
+        assert_eq!(permutation_rank(&[2, 1, 0]), 5);
+        assert_eq!(permutation_rank(&[]), 0);
+    }
+
+    #[test]
+    fn test_samplers() -> Fallible<()> {
+        assert!(check_standard_bernoulli(2_000)?.passes(ALPHA));
+        assert!(check_bernoulli(0.3, false, 2_000)?.passes(ALPHA));
+        assert!(check_bernoulli(0.3, true, 2_000)?.passes(ALPHA));
+        assert!(check_standard_uniform(false, 2_000)?.passes(ALPHA));
+        assert!(check_standard_uniform(true, 2_000)?.passes(ALPHA));
+        assert!(check_standard_uniform_f32(false, 2_000)?.passes(ALPHA));
+        assert!(check_standard_uniform_f32(true, 2_000)?.passes(ALPHA));
+        assert!(check_uniform_int(20_000)?.passes(ALPHA));
+        assert!(check_shuffle(4, 2_000)?.passes(ALPHA));
+        assert!(check_uniform_int_below(7, false, 2_000)?.passes(ALPHA));
+        assert!(check_uniform_int_below(7, true, 2_000)?.passes(ALPHA));
+        assert!(check_geometric(0.2, 2_000)?.passes(ALPHA));
+        assert!(check_discrete_laplace_linear(3., 2_000)?.passes(ALPHA));
+        assert!(check_bernoulli_exp(1.7, 2_000)?.passes(ALPHA));
+        assert!(check_poisson(0.4, 2_000)?.passes(ALPHA));
+        assert!(check_poisson(6.5, 2_000)?.passes(ALPHA));
+        assert!(check_binomial(12, 0.35, 2_000)?.passes(ALPHA));
+        assert!(check_discrete_exponential(2.5, 2_000)?.passes(ALPHA));
+        assert!(check_discrete_laplace(3., false, 2_000)?.passes(ALPHA));
+        assert!(check_discrete_laplace(3., true, 200)?.passes(ALPHA));
+        assert!(check_discrete_gaussian(0.7, false, 2_000)?.passes(ALPHA));
+        assert!(check_discrete_gaussian(5., false, 2_000)?.passes(ALPHA));
+        assert!(check_discrete_laplace_z2k(0.3, 2., -2, false, 2_000)?.passes(ALPHA));
+        assert!(check_discrete_gaussian_z2k(0.3, 2., -4, false, 2_000)?.passes(ALPHA));
+        Ok(())
+    }
+
+    #[test]
+    fn test_measurements() -> Fallible<()> {
+        assert!(check_base_laplace(1.3, 2., Some(-3), false, 2_000)?.passes(ALPHA));
+        assert!(check_base_laplace(1.3, 2., None, false, 2_000)?.passes(ALPHA));
+        assert!(check_base_gaussian(1.3, 2., Some(-5), false, 2_000)?.passes(ALPHA));
+        assert!(check_base_discrete_laplace(4, 2., false, 2_000)?.passes(ALPHA));
+        assert!(check_base_discrete_laplace(4, 20., false, 2_000)?.passes(ALPHA));
+        assert!(check_base_discrete_gaussian(-4, 2., false, 2_000)?.passes(ALPHA));
+        assert!(check_randomized_response_bool(true, 0.75, false, 2_000)?.passes(ALPHA));
+        assert!(check_randomized_response_bool(false, 0.75, true, 2_000)?.passes(ALPHA));
+        Ok(())
+    }
+}
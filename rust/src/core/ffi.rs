@@ -2,12 +2,14 @@ use std::{fmt, ptr};
 use std::ffi::{c_void, CStr};
 use std::fmt::{Debug, Formatter};
 use std::os::raw::c_char;
+use std::sync::Arc;
 
 use crate::{try_, try_as_ref};
 use crate::error::{Error, ErrorVariant, ExplainUnwrap, Fallible};
-use crate::ffi::any::{AnyMeasurement, AnyObject, AnyTransformation, IntoAnyMeasurementExt, IntoAnyTransformationExt};
+use crate::ffi::any::{AnyMeasurement, AnyObject, AnyTransformation, Downcast, IntoAnyMeasurementExt, IntoAnyTransformationExt};
 use crate::ffi::util::{self, c_bool};
 use crate::ffi::util::into_c_char_p;
+use crate::traits::samplers::{set_entropy_source, EntropySource};
 
 #[repr(C)]
 pub struct FfiSlice {
@@ -142,6 +144,41 @@ impl<T: IntoAnyTransformationExt> IntoAnyTransformationFfiResultExt for Fallible
     }
 }
 
+/// A function implemented in a bindings language, like a Python lambda.
+///
+/// The callback borrows its argument, and must return a result allocated by the library,
+/// via [`opendp_core___callback_ok`] or [`opendp_core___callback_err`].
+///
+/// Once the library no longer holds the callback, it is called one last time with a null argument,
+/// so that the bindings language may release it. The return value of this last call is ignored.
+pub type CallbackFn = extern "C" fn(*const AnyObject) -> *mut FfiResult<*mut AnyObject>;
+
+/// Releases a [`CallbackFn`] when dropped.
+struct CallbackGuard(CallbackFn);
+
+impl Drop for CallbackGuard {
+    fn drop(&mut self) {
+        let res = (self.0)(std::ptr::null());
+        if !res.is_null() {
+            let _ = util::into_owned(res);
+        }
+    }
+}
+
+/// Wraps a [`CallbackFn`] as a Rust closure over [`AnyObject`]s.
+///
+/// The callback is released once the closure, and any closure or function that holds it, is dropped.
+pub fn wrap_callback(callback: CallbackFn) -> impl Fn(&AnyObject) -> Fallible<AnyObject> + Send + Sync {
+    let guard = CallbackGuard(callback);
+    move |arg: &AnyObject| match util::into_owned((guard.0)(arg as *const AnyObject))? {
+        FfiResult::Ok(ok) => util::into_owned(ok),
+        FfiResult::Err(err) => {
+            let err = util::into_owned(err)?;
+            fallible!(FailedFunction, "callback failed: {}", err.message_str().unwrap_or_else(|| err.variant_str()))
+        }
+    }
+}
+
 #[cfg(test)]
 impl From<FfiError> for Error {
     fn from(val: FfiError) -> Self {
@@ -184,6 +221,46 @@ pub extern "C" fn opendp_core___error_free(this: *mut FfiError) -> bool {
     util::into_owned(this).is_ok()
 }
 
+#[no_mangle]
+pub extern "C" fn opendp_core___callback_ok(value: *mut AnyObject) -> *mut FfiResult<*mut AnyObject> {
+    util::into_raw(FfiResult::Ok(value))
+}
+
+#[no_mangle]
+pub extern "C" fn opendp_core___callback_err(message: *const c_char) -> *mut FfiResult<*mut AnyObject> {
+    let message = util::to_str(message).unwrap_or("callback failed");
+    util::into_raw(FfiResult::from(err!(FailedFunction, "{}", message)))
+}
+
+/// An [`EntropySource`] implemented in a bindings language.
+/// The callback is passed the number of bytes requested, and returns them as a `Vec<u8>`.
+struct CallbackEntropy<F: Fn(&AnyObject) -> Fallible<AnyObject>>(F);
+
+impl<F: Fn(&AnyObject) -> Fallible<AnyObject> + Send + Sync> EntropySource for CallbackEntropy<F> {
+    fn fill_bytes(&self, buffer: &mut [u8]) -> Fallible<()> {
+        let bytes = (self.0)(&AnyObject::new(buffer.len()))?.downcast::<Vec<u8>>()?;
+        if bytes.len() != buffer.len() {
+            return fallible!(FailedFunction, "entropy source returned {} bytes, but {} were requested", bytes.len(), buffer.len())
+        }
+        buffer.copy_from_slice(&bytes);
+        Ok(())
+    }
+    // each call crosses into the bindings language, which may not expect to be read ahead
+    fn max_block(&self) -> usize {
+        0
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn opendp_core__set_entropy_source(source: CallbackFn) -> FfiResult<*mut ()> {
+    set_entropy_source(Some(Arc::new(CallbackEntropy(wrap_callback(source))))).into()
+}
+
+#[no_mangle]
+pub extern "C" fn opendp_core__reset_entropy_source() -> FfiResult<*mut ()> {
+    set_entropy_source(None).into()
+}
+
 #[no_mangle]
 pub extern "C" fn opendp_core__transformation_map(
     transformation: *const AnyTransformation,
@@ -233,10 +310,24 @@ pub extern "C" fn opendp_core__measurement_check(
 }
 
 #[no_mangle]
-pub extern "C" fn opendp_core__measurement_invoke(this: *const AnyMeasurement, arg: *const AnyObject) -> FfiResult<*mut AnyObject> {
+pub extern "C" fn opendp_core__measurement_accuracy(
+    measurement: *const AnyMeasurement,
+    alpha: *const AnyObject,
+) -> FfiResult<*mut AnyObject> {
+    let measurement = try_as_ref!(measurement);
+    let alpha = try_!(try_as_ref!(alpha).downcast_ref::<f64>());
+    measurement.accuracy(*alpha).map(AnyObject::new).into()
+}
+
+#[no_mangle]
+pub extern "C" fn opendp_core__measurement_invoke(this: *const AnyMeasurement, arg: *const AnyObject, check_domain: c_bool) -> FfiResult<*mut AnyObject> {
     let this = try_as_ref!(this);
     let arg = try_as_ref!(arg);
-    this.invoke(arg).into()
+    if util::to_bool(check_domain) {
+        this.invoke_checked(arg)
+    } else {
+        this.invoke(arg)
+    }.into()
 }
 
 #[no_mangle]
@@ -245,10 +336,14 @@ pub extern "C" fn opendp_core___measurement_free(this: *mut AnyMeasurement) -> F
 }
 
 #[no_mangle]
-pub extern "C" fn opendp_core__transformation_invoke(this: *const AnyTransformation, arg: *const AnyObject) -> FfiResult<*mut AnyObject> {
+pub extern "C" fn opendp_core__transformation_invoke(this: *const AnyTransformation, arg: *const AnyObject, check_domain: c_bool) -> FfiResult<*mut AnyObject> {
     let this = try_as_ref!(this);
     let arg = try_as_ref!(arg);
-    this.invoke(arg).into()
+    if util::to_bool(check_domain) {
+        this.invoke_checked(arg)
+    } else {
+        this.invoke(arg)
+    }.into()
 }
 
 #[no_mangle]
@@ -369,7 +464,7 @@ mod tests {
     fn test_measurement_invoke() -> Fallible<()> {
         let measurement = util::into_raw(make_test_measurement::<i32>().into_any());
         let arg = AnyObject::new_raw(999);
-        let res = opendp_core__measurement_invoke(measurement, arg);
+        let res = opendp_core__measurement_invoke(measurement, arg, util::from_bool(false));
         let res: i32 = Fallible::from(res)?.downcast()?;
         assert_eq!(res, 999);
         Ok(())
@@ -379,7 +474,7 @@ mod tests {
     fn test_measurement_invoke_wrong_type() -> Fallible<()> {
         let measurement = util::into_raw(make_test_measurement::<i32>().into_any());
         let arg = AnyObject::new_raw(999.0);
-        let res = Fallible::from(opendp_core__measurement_invoke(measurement, arg));
+        let res = Fallible::from(opendp_core__measurement_invoke(measurement, arg, util::from_bool(false)));
         assert_eq!(res.err().unwrap_test().variant, ErrorVariant::FailedCast);
         Ok(())
     }
@@ -388,7 +483,7 @@ mod tests {
     fn test_transformation_invoke() -> Fallible<()> {
         let transformation = util::into_raw(make_test_transformation::<i32>().into_any());
         let arg = AnyObject::new_raw(999);
-        let res = opendp_core__transformation_invoke(transformation, arg);
+        let res = opendp_core__transformation_invoke(transformation, arg, util::from_bool(false));
         let res: i32 = Fallible::from(res)?.downcast()?;
         assert_eq!(res, 999);
         Ok(())
@@ -398,8 +493,37 @@ mod tests {
     fn test_transformation_invoke_wrong_type() -> Fallible<()> {
         let transformation = util::into_raw(make_test_transformation::<i32>().into_any());
         let arg = AnyObject::new_raw(999.0);
-        let res = Fallible::from(opendp_core__transformation_invoke(transformation, arg));
+        let res = Fallible::from(opendp_core__transformation_invoke(transformation, arg, util::from_bool(false)));
         assert_eq!(res.err().unwrap_test().variant, ErrorVariant::FailedCast);
         Ok(())
     }
+
+    #[test]
+    fn test_transformation_invoke_checked() -> Fallible<()> {
+        let transformation = util::into_raw(crate::transformations::make_sized_bounded_int_checked_sum(3, (0, 10))?.into_any());
+        let res = opendp_core__transformation_invoke(transformation, AnyObject::new_raw(vec![1, 2, 3]), util::from_bool(true));
+        assert_eq!(Fallible::from(res)?.downcast::<i32>()?, 6);
+
+        // out of bounds
+        let res = Fallible::from(opendp_core__transformation_invoke(transformation, AnyObject::new_raw(vec![1, 2, 30]), util::from_bool(true)));
+        assert_eq!(res.err().unwrap_test().variant, ErrorVariant::DomainMismatch);
+        // wrong size
+        let res = Fallible::from(opendp_core__transformation_invoke(transformation, AnyObject::new_raw(vec![1, 2]), util::from_bool(true)));
+        assert_eq!(res.err().unwrap_test().variant, ErrorVariant::DomainMismatch);
+        // unchecked
+        let res = opendp_core__transformation_invoke(transformation, AnyObject::new_raw(vec![1, 2, 30]), util::from_bool(false));
+        assert_eq!(Fallible::from(res)?.downcast::<i32>()?, 33);
+        Ok(())
+    }
+
+    #[test]
+    fn test_measurement_invoke_checked() -> Fallible<()> {
+        let domain = crate::domains::CategoricalDomain::new(vec!["a".to_string(), "b".to_string()])?;
+        let measurement = util::into_raw(crate::measurements::make_randomized_response(domain, 0.75, false)?.into_any());
+        let res = Fallible::from(opendp_core__measurement_invoke(measurement, AnyObject::new_raw("a".to_string()), util::from_bool(true)));
+        assert!(res.is_ok());
+        let res = Fallible::from(opendp_core__measurement_invoke(measurement, AnyObject::new_raw("c".to_string()), util::from_bool(true)));
+        assert_eq!(res.err().unwrap_test().variant, ErrorVariant::DomainMismatch);
+        Ok(())
+    }
 }
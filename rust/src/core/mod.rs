@@ -27,6 +27,7 @@ pub use ffi::*;
 
 use std::rc::Rc;
 
+use crate::accuracy::NoiseDistribution;
 use crate::metrics::IntDistance;
 use crate::error::*;
 use crate::traits::{DistanceConstant, InfCast, InfMul, TotalOrd};
@@ -171,6 +172,42 @@ impl<MI: 'static + Metric, MO: 'static + Metric> StabilityMap<MI, MO> {
     }
 }
 
+/// A map describing how a [`Transformation`] changes the noise of a release that it postprocesses.
+///
+/// A `NoiseMap` is implemented as a function that takes the [`NoiseDistribution`] of the input release,
+/// and returns the noise distribution of the output, relative to the transformation of the noiseless release.
+pub struct NoiseMap(pub Rc<dyn Fn(&NoiseDistribution) -> Fallible<NoiseDistribution>>);
+
+impl Clone for NoiseMap {
+    fn clone(&self) -> Self {
+        NoiseMap(self.0.clone())
+    }
+}
+
+impl NoiseMap {
+    pub fn new(map: impl Fn(&NoiseDistribution) -> NoiseDistribution + 'static) -> Self {
+        NoiseMap(Rc::new(move |noise: &NoiseDistribution| Ok(map(noise))))
+    }
+    pub fn new_fallible(map: impl Fn(&NoiseDistribution) -> Fallible<NoiseDistribution> + 'static) -> Self {
+        NoiseMap(Rc::new(map))
+    }
+    pub fn eval(&self, noise: &NoiseDistribution) -> Fallible<NoiseDistribution> {
+        (self.0)(noise)
+    }
+    pub fn make_chain(map1: &NoiseMap, map0: &NoiseMap) -> Self {
+        let map1 = map1.0.clone();
+        let map0 = map0.0.clone();
+        NoiseMap(Rc::new(move |noise: &NoiseDistribution| map1(&map0(noise)?)))
+    }
+}
+
+
+fn check_member<D: Domain>(domain: &D, arg: &D::Carrier) -> Fallible<()> {
+    if !domain.member(arg)? {
+        return fallible!(DomainMismatch, "argument is not a member of the input domain {:?}", domain)
+    }
+    Ok(())
+}
 
 /// A randomized mechanism with certain privacy characteristics.
 #[derive(Clone)]
@@ -181,6 +218,8 @@ pub struct Measurement<DI: Domain, DO: Domain, MI: Metric, MO: Measure> {
     pub input_metric: MI,
     pub output_measure: MO,
     pub privacy_map: PrivacyMap<MI, MO>,
+    #[doc(hidden)]
+    pub noise: Option<NoiseDistribution>,
 }
 
 impl<DI: Domain, DO: Domain, MI: Metric, MO: Measure> Measurement<DI, DO, MI, MO> {
@@ -199,13 +238,36 @@ impl<DI: Domain, DO: Domain, MI: Metric, MO: Measure> Measurement<DI, DO, MI, MO
             input_metric,
             output_measure,
             privacy_map,
+            noise: None,
         }
     }
 
+    /// Record the distribution of the noise in each release, from which the accuracy may be derived.
+    pub fn with_noise(mut self, noise: NoiseDistribution) -> Self {
+        self.noise = Some(noise);
+        self
+    }
+
+    /// The distribution of the noise in each release, when known.
+    pub fn noise(&self) -> Option<&NoiseDistribution> {
+        self.noise.as_ref()
+    }
+
+    /// Replace the recorded distribution of the noise in each release.
+    pub fn set_noise(&mut self, noise: Option<NoiseDistribution>) {
+        self.noise = noise;
+    }
+
     pub fn invoke(&self, arg: &DI::Carrier) -> Fallible<DO::Carrier> {
         self.function.eval(arg)
     }
 
+    /// Invoke the function on `arg`, after checking that `arg` is a member of the input domain.
+    pub fn invoke_checked(&self, arg: &DI::Carrier) -> Fallible<DO::Carrier> {
+        check_member(&self.input_domain, arg)?;
+        self.invoke(arg)
+    }
+
     pub fn map(&self, d_in: &MI::Distance) -> Fallible<MO::Distance> {
         self.privacy_map.eval(d_in)
     }
@@ -214,6 +276,14 @@ impl<DI: Domain, DO: Domain, MI: Metric, MO: Measure> Measurement<DI, DO, MI, MO
         where MO::Distance: TotalOrd {
         d_out.total_ge(&self.map(d_in)?)
     }
+
+    /// The smallest accuracy such that each release differs from its noiseless value by more than the accuracy
+    /// with probability at most `alpha`.
+    pub fn accuracy(&self, alpha: f64) -> Fallible<f64> {
+        self.noise.as_ref()
+            .ok_or_else(|| err!(FailedFunction, "the accuracy of this measurement is not known"))?
+            .accuracy(alpha)
+    }
 }
 
 /// A data transformation with certain stability characteristics.
@@ -225,6 +295,8 @@ pub struct Transformation<DI: Domain, DO: Domain, MI: Metric, MO: Metric> {
     pub input_metric: MI,
     pub output_metric: MO,
     pub stability_map: StabilityMap<MI, MO>,
+    #[doc(hidden)]
+    pub noise_map: Option<NoiseMap>,
 }
 
 impl<DI: Domain, DO: Domain, MI: Metric, MO: Metric> Transformation<DI, DO, MI, MO> {
@@ -243,13 +315,36 @@ impl<DI: Domain, DO: Domain, MI: Metric, MO: Metric> Transformation<DI, DO, MI,
             input_metric,
             output_metric,
             stability_map,
+            noise_map: None,
         }
     }
 
+    /// Record how the transformation changes the noise of a release that it postprocesses.
+    pub fn with_noise_map(mut self, noise_map: NoiseMap) -> Self {
+        self.noise_map = Some(noise_map);
+        self
+    }
+
+    /// How the transformation changes the noise of a release that it postprocesses, when known.
+    pub fn noise_map(&self) -> Option<&NoiseMap> {
+        self.noise_map.as_ref()
+    }
+
+    /// Replace the recorded effect of the transformation on the noise of a release that it postprocesses.
+    pub fn set_noise_map(&mut self, noise_map: Option<NoiseMap>) {
+        self.noise_map = noise_map;
+    }
+
     pub fn invoke(&self, arg: &DI::Carrier) -> Fallible<DO::Carrier> {
         self.function.eval(arg)
     }
 
+    /// Invoke the function on `arg`, after checking that `arg` is a member of the input domain.
+    pub fn invoke_checked(&self, arg: &DI::Carrier) -> Fallible<DO::Carrier> {
+        check_member(&self.input_domain, arg)?;
+        self.invoke(arg)
+    }
+
     pub fn map(&self, d_in: &MI::Distance) -> Fallible<MO::Distance> {
         self.stability_map.eval(d_in)
     }
@@ -282,4 +377,19 @@ mod tests {
         let ret = identity.invoke(&arg).unwrap_test();
         assert_eq!(ret, 99);
     }
+
+    #[test]
+    fn test_invoke_checked() -> Fallible<()> {
+        use crate::domains::BoundedDomain;
+        let domain = BoundedDomain::new_closed((0, 10))?;
+        let function = Function::new(|arg: &i32| *arg);
+        let identity = Transformation::new(
+            domain.clone(), domain, function,
+            L1Distance::<i32>::default(), L1Distance::<i32>::default(),
+            StabilityMap::new_from_constant(1));
+        assert_eq!(identity.invoke_checked(&5)?, 5);
+        assert_eq!(identity.invoke_checked(&11).unwrap_err().variant, crate::error::ErrorVariant::DomainMismatch);
+        assert_eq!(identity.invoke(&11)?, 11);
+        Ok(())
+    }
 }
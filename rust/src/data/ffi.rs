@@ -49,6 +49,15 @@ pub extern "C" fn opendp_data__slice_as_object(raw: *const FfiSlice, T: *const c
         let vec = slice.to_vec();
         Ok(AnyObject::new(vec))
     }
+    fn raw_to_vec_vec<T: 'static + Clone>(raw: &FfiSlice) -> Fallible<AnyObject> {
+        let slice = unsafe { slice::from_raw_parts(raw.ptr as *const *const AnyObject, raw.len) };
+        let vec = slice.iter()
+            .map(|obj| util::as_ref(*obj)
+                .ok_or_else(|| err!(FFI, "Attempted to follow a null pointer to create a vector"))?
+                .downcast_ref::<Vec<T>>().cloned())
+            .collect::<Fallible<Vec<Vec<T>>>>()?;
+        Ok(AnyObject::new(vec))
+    }
     fn raw_to_tuple<T0: 'static + Clone, T1: 'static + Clone>(raw: &FfiSlice) -> Fallible<AnyObject> {
         if raw.len != 2 {
             return fallible!(FFI, "The slice length must be two when creating a tuple from FfiSlice");
@@ -89,6 +98,8 @@ pub extern "C" fn opendp_data__slice_as_object(raw: *const FfiSlice, T: *const c
                 "String" => raw_to_vec_string(raw),
                 "AnyMeasurementPtr" => raw_to_vec::<AnyMeasurementPtr>(raw),
                 "AnyTransformationPtr" => raw_to_vec::<AnyTransformationPtr>(raw),
+                // bytes from entropy sources
+                "u8" => raw_to_vec::<u8>(raw),
                 _ => dispatch!(raw_to_vec, [(element, @primitives)], (raw)),
             }
         }
@@ -107,8 +118,19 @@ pub extern "C" fn opendp_data__slice_as_object(raw: *const FfiSlice, T: *const c
                 let K = try_!(Type::of_id(&args[0]));
                 let V = try_!(Type::of_id(&args[1]));
                 dispatch!(raw_to_hashmap, [(K, @hashable), (V, @primitives)], (raw))
+            } else if name == "Vec" {
+                // the only generic vectors are vectors of vectors
+                if args.len() != 1 { return err!(FFI, "Vecs should have 1 type argument").into(); }
+                let element = try_!(Type::of_id(&args[0]));
+                if let TypeContents::VEC(atom_id) = element.contents {
+                    let T = try_!(Type::of_id(&atom_id));
+                    dispatch!(raw_to_vec_vec, [(T, @primitives)], (raw))
+                } else { fallible!(FFI, "unrecognized vector element {:?}", element.descriptor) }
             } else { fallible!(FFI, "unrecognized generic {:?}", name) }
         }
+        // chains are loaded by pointer, and remain owned by the caller
+        TypeContents::PLAIN("AnyMeasurementPtr") => raw_to_plain::<AnyMeasurementPtr>(raw),
+        TypeContents::PLAIN("AnyTransformationPtr") => raw_to_plain::<AnyTransformationPtr>(raw),
         // This list is explicit because it allows us to avoid including u32 in the @primitives
         _ => dispatch!(raw_to_plain, [(T, [u8, u32, u64, u128, i8, i16, i32, i64, i128, usize, f32, f64, bool])], (raw))
     }.into()
@@ -206,6 +228,8 @@ pub extern "C" fn opendp_data__object_as_slice(obj: *const AnyObject) -> FfiResu
                 dispatch!(hashmap_to_raw, [(K, @hashable), (V, @primitives)], (obj))
             } else { fallible!(FFI, "unrecognized generic {:?}", name) }
         }
+        TypeContents::PLAIN("AnyMeasurementPtr") => plain_to_raw::<AnyMeasurementPtr>(obj),
+        TypeContents::PLAIN("AnyTransformationPtr") => plain_to_raw::<AnyTransformationPtr>(obj),
         // This list is explicit because it allows us to avoid including u32 in the @primitives
         _ => { dispatch!(plain_to_raw, [(obj.type_, [u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, usize, f32, f64, bool])], (obj)) }
     }.into()
@@ -372,6 +396,19 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_slice_as_object_vec_vec() -> Fallible<()> {
+        let data = [
+            util::into_raw(AnyObject::new(vec![1, 2])) as *const AnyObject,
+            util::into_raw(AnyObject::new(vec![3])) as *const AnyObject,
+        ];
+        let raw = util::into_raw(FfiSlice::new(data.as_ptr() as *mut c_void, data.len()));
+        let res = opendp_data__slice_as_object(raw, "Vec<Vec<i32>>".to_char_p());
+        let res: Vec<Vec<i32>> = Fallible::from(res)?.downcast()?;
+        assert_eq!(res, vec![vec![1, 2], vec![3]]);
+        Ok(())
+    }
+
     #[test]
     fn test_slice_as_object_tuple_numbers() -> Fallible<()> {
         let raw_ptr = util::into_raw((util::into_raw(999), util::into_raw(-999))) as *mut c_void;
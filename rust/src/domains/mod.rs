@@ -5,14 +5,14 @@
 //! type.
 
 use std::any::Any;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::hash::Hash;
 use std::marker::PhantomData;
 use std::ops::Bound;
 
 use crate::core::Domain;
 use crate::error::Fallible;
-use crate::traits::{CheckNull, TotalOrd};
+use crate::traits::{CheckNull, TotalOrd, Float};
 use std::fmt::{Debug, Formatter};
 
 /// // retrieves the type_name for a given type
@@ -147,6 +147,137 @@ impl<T: Clone + TotalOrd> Domain for BoundedDomain<T> {
 }
 
 
+/// A Domain that contains each value in a finite, ordered set of categories.
+///
+/// The position of a category in the set is its index, so two CategoricalDomains are only equal
+/// if they list the same categories in the same order.
+#[derive(Clone, PartialEq)]
+pub struct CategoricalDomain<T: Eq + Hash> {
+    categories: Vec<T>,
+    indexes: HashMap<T, usize>,
+}
+impl<T: Eq + Hash + Clone> CategoricalDomain<T> {
+    pub fn new(categories: Vec<T>) -> Fallible<Self> {
+        if categories.is_empty() {
+            return fallible!(MakeDomain, "there must be at least one category")
+        }
+        let indexes = categories.iter().cloned().enumerate()
+            .map(|(i, c)| (c, i)).collect::<HashMap<T, usize>>();
+        if indexes.len() != categories.len() {
+            return fallible!(MakeDomain, "categories must be distinct")
+        }
+        Ok(CategoricalDomain { categories, indexes })
+    }
+}
+impl<T: Eq + Hash> CategoricalDomain<T> {
+    pub fn categories(&self) -> &Vec<T> { &self.categories }
+    pub fn size(&self) -> usize { self.categories.len() }
+    /// Retrieve the index of `val` in the categories, if it is a member.
+    pub fn index_of(&self, val: &T) -> Option<usize> { self.indexes.get(val).cloned() }
+}
+impl<T: Eq + Hash> Debug for CategoricalDomain<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "CategoricalDomain({}, size={})", type_name!(T), self.categories.len())
+    }
+}
+impl<T: Eq + Hash + Clone> Domain for CategoricalDomain<T> {
+    type Carrier = T;
+    fn member(&self, val: &Self::Carrier) -> Fallible<bool> {
+        Ok(self.indexes.contains_key(val))
+    }
+}
+
+/// A finite set of categories that constructors over categorical data may be built from.
+///
+/// When the categories are a [`CategoricalDomain`], the domain is carried by the transformation,
+/// so that chaining checks that the categories agree.
+/// Otherwise, values and their indexes are drawn from an [`AllDomain`].
+pub trait Categories {
+    type Atom;
+    /// The domain of a value that may be one of the categories.
+    type AtomDomain: Domain<Carrier=Self::Atom>;
+    /// The domain of the index of a category.
+    type IndexDomain: Domain<Carrier=usize>;
+    /// Split into the categories, in order, and the domains of each value and each index.
+    fn into_parts(self) -> Fallible<(Vec<Self::Atom>, Self::AtomDomain, Self::IndexDomain)>;
+}
+impl<T: CheckNull> Categories for Vec<T> {
+    type Atom = T;
+    type AtomDomain = AllDomain<T>;
+    type IndexDomain = AllDomain<usize>;
+    fn into_parts(self) -> Fallible<(Vec<T>, AllDomain<T>, AllDomain<usize>)> {
+        Ok((self, AllDomain::new(), AllDomain::new()))
+    }
+}
+impl<T: CheckNull> Categories for HashSet<T> {
+    type Atom = T;
+    type AtomDomain = AllDomain<T>;
+    type IndexDomain = AllDomain<usize>;
+    fn into_parts(self) -> Fallible<(Vec<T>, AllDomain<T>, AllDomain<usize>)> {
+        Ok((self.into_iter().collect(), AllDomain::new(), AllDomain::new()))
+    }
+}
+impl<T: Eq + Hash + Clone> Categories for CategoricalDomain<T> {
+    type Atom = T;
+    type AtomDomain = CategoricalDomain<T>;
+    type IndexDomain = CategoricalDomain<usize>;
+    fn into_parts(self) -> Fallible<(Vec<T>, CategoricalDomain<T>, CategoricalDomain<usize>)> {
+        let index_domain = CategoricalDomain::new((0..self.size()).collect())?;
+        Ok((self.categories.clone(), self, index_domain))
+    }
+}
+
+/// A Domain that contains vectors whose Lp norm is bounded above by `norm`.
+///
+/// Only the L1 and L2 norms are supported.
+#[derive(Clone, PartialEq)]
+pub struct LpBoundedDomain<const P: usize, T> {
+    pub norm: T,
+}
+impl<const P: usize, T: Float> LpBoundedDomain<P, T> {
+    pub fn new(norm: T) -> Fallible<Self> {
+        if P != 1 && P != 2 {
+            return fallible!(MakeDomain, "only L1 and L2 norms are supported")
+        }
+        if norm.is_sign_negative() || !norm.is_finite() {
+            return fallible!(MakeDomain, "norm must be non-negative and finite")
+        }
+        Ok(LpBoundedDomain { norm })
+    }
+}
+impl<const P: usize, T> Debug for LpBoundedDomain<P, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "L{}BoundedDomain({})", P, type_name!(T))
+    }
+}
+impl<const P: usize, T: Float> Domain for LpBoundedDomain<P, T> {
+    type Carrier = Vec<T>;
+    fn member(&self, val: &Self::Carrier) -> Fallible<bool> {
+        if val.iter().any(CheckNull::is_null) {
+            return Ok(false)
+        }
+        Ok(match lp_norm::<P, T>(val) {
+            Ok(norm) => norm <= self.norm,
+            Err(_) => false
+        })
+    }
+}
+
+pub type L1BoundedDomain<T> = LpBoundedDomain<1, T>;
+pub type L2BoundedDomain<T> = LpBoundedDomain<2, T>;
+
+/// Computes the Lp norm of `x`, rounding towards infinity.
+pub(crate) fn lp_norm<const P: usize, T: Float>(x: &[T]) -> Fallible<T> {
+    match P {
+        1 => x.iter().try_fold(T::zero(), |acc, v| acc.inf_add(&v.alerting_abs()?)),
+        2 => x.iter()
+            .try_fold(T::zero(), |acc, v| acc.inf_add(&v.inf_mul(v)?))?
+            .inf_sqrt(),
+        _ => fallible!(FailedFunction, "only L1 and L2 norms are supported")
+    }
+}
+
+
 /// A Domain that contains pairs of values.
 #[derive(Clone, PartialEq, Debug)]
 pub struct PairDomain<D0: Domain, D1: Domain>(pub D0, pub D1);
@@ -162,6 +293,169 @@ impl<D0: Domain, D1: Domain> Domain for PairDomain<D0, D1> {
     }
 }
 
+/// A Domain that contains histograms: pairs of bin edges and counts.
+///
+/// There is one more count than edges, for the underflow and overflow bins.
+/// Mechanisms that map over a histogram only map over the counts, so the edges stay attached.
+pub struct HistogramDomain<TA, TC> {
+    _marker: PhantomData<(TA, TC)>,
+}
+impl<TA, TC> Debug for HistogramDomain<TA, TC> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "HistogramDomain({}, {})", type_name!(TA), type_name!(TC))
+    }
+}
+impl<TA, TC> Default for HistogramDomain<TA, TC> {
+    fn default() -> Self { Self::new() }
+}
+impl<TA, TC> HistogramDomain<TA, TC> {
+    pub fn new() -> Self {
+        HistogramDomain { _marker: PhantomData }
+    }
+}
+// Auto-deriving Clone would put the same trait bound on TA and TC, so we implement it manually.
+impl<TA, TC> Clone for HistogramDomain<TA, TC> {
+    fn clone(&self) -> Self { Self::new() }
+}
+// Auto-deriving PartialEq would put the same trait bound on TA and TC, so we implement it manually.
+impl<TA, TC> PartialEq for HistogramDomain<TA, TC> {
+    fn eq(&self, _other: &Self) -> bool { true }
+}
+impl<TA: CheckNull, TC: CheckNull> Domain for HistogramDomain<TA, TC> {
+    type Carrier = (Vec<TA>, Vec<TC>);
+    fn member(&self, val: &Self::Carrier) -> Fallible<bool> {
+        let (edges, counts) = val;
+        Ok(edges.len() + 1 == counts.len()
+            && !edges.iter().any(CheckNull::is_null)
+            && !counts.iter().any(CheckNull::is_null))
+    }
+}
+
+/// A Domain that contains fixed-arity tuples (records), where each field has its own element domain.
+///
+/// `DS` is a tuple of domains, like `(BoundedDomain<u8>, BoundedDomain<f64>, CategoricalDomain<String>)`.
+/// Members are tuples of the carriers, where each field is a member of the respective domain.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ProductDomain<DS>(pub DS);
+impl<DS> ProductDomain<DS> where Self: Domain {
+    pub fn new(field_domains: DS) -> Self {
+        ProductDomain(field_domains)
+    }
+}
+
+/// Access to the field at position `I` of a [`ProductDomain`].
+pub trait ProductField<const I: usize>: Domain {
+    type FieldDomain: Domain;
+    /// Retrieve the domain of the field.
+    fn field_domain(&self) -> &Self::FieldDomain;
+    /// Retrieve the field from a member of the product domain.
+    fn field(val: &Self::Carrier) -> &<Self::FieldDomain as Domain>::Carrier;
+}
+
+/// A [`ProductDomain`] whose fields are all vectors, which can be zipped into a vector of records.
+pub trait ProductZip: Domain {
+    type RowDomain: Domain;
+    /// Retrieve the domain of each zipped record.
+    fn row_domain(&self) -> Self::RowDomain;
+    /// Zip the columns into a vector of records. Fails if the columns differ in length.
+    fn zip(val: &Self::Carrier) -> Fallible<Vec<<Self::RowDomain as Domain>::Carrier>>;
+}
+
+macro_rules! impl_product_domain {
+    ($($i:tt $D:ident),+) => {
+        impl<$($D: Domain),+> Domain for ProductDomain<($($D,)+)> {
+            type Carrier = ($($D::Carrier,)+);
+            fn member(&self, val: &Self::Carrier) -> Fallible<bool> {
+                $(if !(self.0).$i.member(&val.$i)? { return Ok(false) })+
+                Ok(true)
+            }
+        }
+        impl_product_domain!(@fields ($($D),+); $($i $D),+);
+
+        impl<$($D: Domain),+> ProductZip for ProductDomain<($(VectorDomain<$D>,)+)>
+            where $($D::Carrier: Clone),+ {
+            type RowDomain = ProductDomain<($($D,)+)>;
+            fn row_domain(&self) -> Self::RowDomain {
+                ProductDomain(($((self.0).$i.element_domain.clone(),)+))
+            }
+            fn zip(val: &Self::Carrier) -> Fallible<Vec<<Self::RowDomain as Domain>::Carrier>> {
+                let len = val.0.len();
+                if [$(val.$i.len()),+].iter().any(|l| *l != len) {
+                    return fallible!(FailedFunction, "columns must share the same length");
+                }
+                Ok((0..len).map(|r| ($(val.$i[r].clone(),)+)).collect())
+            }
+        }
+    };
+    (@fields $all:tt; $($i:tt $D:ident),+) => {
+        $(impl_product_domain!(@field $all $i $D);)+
+    };
+    (@field ($($all:ident),+) $i:tt $D:ident) => {
+        impl<$($all: Domain),+> ProductField<$i> for ProductDomain<($($all,)+)> {
+            type FieldDomain = $D;
+            fn field_domain(&self) -> &$D { &(self.0).$i }
+            fn field(val: &Self::Carrier) -> &$D::Carrier { &val.$i }
+        }
+    };
+}
+impl_product_domain!(0 D0);
+impl_product_domain!(0 D0, 1 D1);
+impl_product_domain!(0 D0, 1 D1, 2 D2);
+impl_product_domain!(0 D0, 1 D1, 2 D2, 3 D3);
+impl_product_domain!(0 D0, 1 D1, 2 D2, 3 D3, 4 D4);
+impl_product_domain!(0 D0, 1 D1, 2 D2, 3 D3, 4 D4, 5 D5);
+impl_product_domain!(0 D0, 1 D1, 2 D2, 3 D3, 4 D4, 5 D5, 6 D6);
+impl_product_domain!(0 D0, 1 D1, 2 D2, 3 D3, 4 D4, 5 D5, 6 D6, 7 D7);
+
+/// A Domain that contains undirected graphs, represented as lists of edges.
+///
+/// Members have no self-loops and no repeated edges, where `(a, b)` and `(b, a)` denote the same edge.
+/// If `max_degree` is set, no node may be incident to more than `max_degree` edges.
+#[derive(Clone, PartialEq)]
+pub struct GraphDomain<T> {
+    pub max_degree: Option<usize>,
+    _marker: PhantomData<T>
+}
+impl<T> GraphDomain<T> {
+    pub fn new() -> Self {
+        GraphDomain { max_degree: None, _marker: PhantomData }
+    }
+    pub fn new_degree_bounded(max_degree: usize) -> Self {
+        GraphDomain { max_degree: Some(max_degree), _marker: PhantomData }
+    }
+}
+impl<T> Default for GraphDomain<T> {
+    fn default() -> Self { Self::new() }
+}
+impl<T> Debug for GraphDomain<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match self.max_degree {
+            Some(max_degree) => write!(f, "GraphDomain({}, max_degree={})", type_name!(T), max_degree),
+            None => write!(f, "GraphDomain({})", type_name!(T))
+        }
+    }
+}
+impl<T: Eq + Hash + Clone> Domain for GraphDomain<T> {
+    type Carrier = Vec<(T, T)>;
+    fn member(&self, val: &Self::Carrier) -> Fallible<bool> {
+        let mut edges = HashSet::with_capacity(val.len());
+        let mut degrees = HashMap::<&T, usize>::new();
+        for (a, b) in val {
+            if a == b || edges.contains(&(b, a)) || !edges.insert((a, b)) {
+                return Ok(false)
+            }
+            for node in [a, b] {
+                let degree = degrees.entry(node).or_insert(0);
+                *degree += 1;
+                if self.max_degree.map(|max| *degree > max).unwrap_or(false) {
+                    return Ok(false)
+                }
+            }
+        }
+        Ok(true)
+    }
+}
+
 
 /// A Domain that contains maps of (homogeneous) values.
 #[derive(Clone, PartialEq, Debug)]
@@ -196,10 +490,15 @@ impl<DK: Domain, DV: Domain> Domain for MapDomain<DK, DV> where DK::Carrier: Eq
 #[derive(Clone, PartialEq)]
 pub struct VectorDomain<D: Domain> {
     pub element_domain: D,
+    /// Inclusive lower and upper bounds on the number of elements, if known.
+    pub size_bounds: Option<(usize, usize)>,
 }
 impl<D: Domain> Debug for VectorDomain<D> {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
-        write!(f, "VectorDomain({:?})", self.element_domain)
+        match self.size_bounds {
+            Some((lower, upper)) => write!(f, "VectorDomain({:?}, size_bounds=({}, {}))", self.element_domain, lower, upper),
+            None => write!(f, "VectorDomain({:?})", self.element_domain)
+        }
     }
 }
 impl<D: Domain + Default> Default for VectorDomain<D> {
@@ -207,7 +506,14 @@ impl<D: Domain + Default> Default for VectorDomain<D> {
 }
 impl<D: Domain> VectorDomain<D> {
     pub fn new(element_domain: D) -> Self {
-        VectorDomain { element_domain }
+        VectorDomain { element_domain, size_bounds: None }
+    }
+    /// Construct a VectorDomain whose members have between `size_bounds.0` and `size_bounds.1` elements, inclusive.
+    pub fn new_size_bounded(element_domain: D, size_bounds: (usize, usize)) -> Fallible<Self> {
+        if size_bounds.0 > size_bounds.1 {
+            return fallible!(MakeDomain, "lower size bound may not be greater than upper size bound")
+        }
+        Ok(VectorDomain { element_domain, size_bounds: Some(size_bounds) })
     }
 }
 impl<T: CheckNull> VectorDomain<AllDomain<T>> {
@@ -218,6 +524,9 @@ impl<T: CheckNull> VectorDomain<AllDomain<T>> {
 impl<D: Domain> Domain for VectorDomain<D> {
     type Carrier = Vec<D::Carrier>;
     fn member(&self, val: &Self::Carrier) -> Fallible<bool> {
+        if let Some((lower, upper)) = self.size_bounds {
+            if val.len() < lower || val.len() > upper {return Ok(false)}
+        }
         for e in val {
             if !self.element_domain.member(e)? {return Ok(false)}
         }
@@ -241,13 +550,25 @@ impl<D: Domain> Debug for SizedDomain<D> {
         write!(f, "SizedDomain({:?}, size={})", self.element_domain, self.size)
     }
 }
-impl<D: Domain> Domain for SizedDomain<D> {
+impl<D: Domain> Domain for SizedDomain<D> where D::Carrier: CollectionSize {
     type Carrier = D::Carrier;
     fn member(&self, val: &Self::Carrier) -> Fallible<bool> {
+        if val.size() != self.size {return Ok(false)}
         self.element_domain.member(val)
     }
 }
 
+/// A carrier type whose number of elements may be checked against a [`SizedDomain`].
+pub trait CollectionSize {
+    fn size(&self) -> usize;
+}
+impl<T> CollectionSize for Vec<T> {
+    fn size(&self) -> usize { self.len() }
+}
+impl<K, V> CollectionSize for HashMap<K, V> {
+    fn size(&self) -> usize { self.len() }
+}
+
 /// A domain with a built-in representation of nullity, that may take on null values at runtime
 #[derive(Clone, PartialEq)]
 pub struct InherentNullDomain<D: Domain>
@@ -347,14 +347,17 @@ impl<DI: 'static + Domain, DO: 'static + Domain, MI: 'static + Metric, MO: 'stat
           MI::Distance: 'static,
           MO::Distance: 'static {
     fn into_any(self) -> AnyMeasurement {
-        AnyMeasurement::new(
+        let noise = self.noise().cloned();
+        let mut any = AnyMeasurement::new(
             AnyDomain::new(self.input_domain),
             AnyDomain::new(self.output_domain),
             self.function.into_any(),
             AnyMetric::new(self.input_metric),
             AnyMeasure::new(self.output_measure),
             self.privacy_map.into_any(),
-        )
+        );
+        any.set_noise(noise);
+        any
     }
 }
 
@@ -367,14 +370,17 @@ pub trait IntoAnyMeasurementOutExt {
 impl<DO: 'static + Domain> IntoAnyMeasurementOutExt for Measurement<AnyDomain, DO, AnyMetric, AnyMeasure>
     where DO::Carrier: 'static {
     fn into_any_out(self) -> AnyMeasurement {
-        AnyMeasurement::new(
+        let noise = self.noise().cloned();
+        let mut any = AnyMeasurement::new(
             self.input_domain,
             AnyDomain::new(self.output_domain),
             self.function.into_any_out(),
             self.input_metric,
             self.output_measure,
             self.privacy_map,
-        )
+        );
+        any.set_noise(noise);
+        any
     }
 }
 
@@ -394,14 +400,17 @@ impl<DI: 'static + Domain, DO: 'static + Domain, MI: 'static + Metric, MO: 'stat
           MI::Distance: 'static,
           MO::Distance: 'static {
     fn into_any(self) -> AnyTransformation {
-        AnyTransformation::new(
+        let noise_map = self.noise_map().cloned();
+        let mut any = AnyTransformation::new(
             AnyDomain::new(self.input_domain),
             AnyDomain::new(self.output_domain),
             self.function.into_any(),
             AnyMetric::new(self.input_metric),
             AnyMetric::new(self.output_metric),
             self.stability_map.into_any(),
-        )
+        );
+        any.set_noise_map(noise_map);
+        any
     }
 }
 
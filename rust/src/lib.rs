@@ -45,12 +45,16 @@
 //!     use opendp::transformations::{make_split_lines, make_cast_default, make_clamp, make_bounded_sum};
 //!     use opendp::combinators::{make_chain_tt, make_chain_mt};
 //!     use opendp::measurements::make_base_laplace;
+//!     use opendp::search::binary_search_param;
 //! 
 //!     let data = "56\n15\n97\n56\n6\n17\n2\n19\n16\n50".to_owned();
 //!     let bounds = (0.0, 100.0);
 //!     let epsilon = 1.0;
-//!     // remove some epsilon to account for floating-point error
-//!     let sigma = (bounds.1 - bounds.0) / (epsilon - 0.0001);
+//!     // find the smallest noise scale for which the sum is (1, epsilon)-close,
+//!     // accounting for floating-point error
+//!     let sigma = binary_search_param(
+//!         |s| make_clamp(bounds)? >> make_bounded_sum(bounds)? >> make_base_laplace(s, None)?,
+//!         &1, &epsilon, None)?;
 //!
 //!     // Construct a Transformation to load the numbers.
 //!     let split_lines = make_split_lines()?;
@@ -166,7 +170,6 @@ macro_rules! enclose {
 #[cfg(feature="ffi")]
 #[macro_use]
 mod ffi;
-#[cfg(feature="ffi")]
 #[macro_use]
 extern crate lazy_static;
 
@@ -184,6 +187,9 @@ pub mod traits;
 pub mod transformations;
 pub mod combinators;
 pub mod accuracy;
+pub mod search;
+#[cfg(feature="conformance")]
+pub mod conformance;
 pub mod domains;
 pub mod metrics;
 pub mod measures;
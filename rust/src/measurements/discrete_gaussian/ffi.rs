@@ -1,15 +1,16 @@
 use std::convert::TryFrom;
 use std::os::raw::{c_char, c_void};
 
-use az::SaturatingCast;
-use rug::{Integer, Rational};
-
 use crate::core::{FfiResult, IntoAnyMeasurementFfiResultExt};
 use crate::domains::{AllDomain, VectorDomain};
 use crate::ffi::any::AnyMeasurement;
 use crate::ffi::util::Type;
-use crate::measurements::{make_base_discrete_gaussian, DiscreteGaussianDomain, DiscreteGaussianMeasure};
+use crate::measurements::{
+    make_base_discrete_gaussian, make_base_discrete_gaussian_fixed_iteration, DiscreteGaussianDomain,
+    DiscreteGaussianMeasure,
+};
 use crate::measures::ZeroConcentratedDivergence;
+use crate::traits::samplers::{CastInternalInteger, CastInternalRational};
 use crate::traits::{Float, CheckNull};
 
 #[no_mangle]
@@ -18,29 +19,47 @@ pub extern "C" fn opendp_measurements__make_base_discrete_gaussian(
     D: *const c_char,
     MO: *const c_char,
 ) -> FfiResult<*mut AnyMeasurement> {
-    fn monomorphize<T, Q>(scale: *const c_void, D: Type, MO: Type) -> FfiResult<*mut AnyMeasurement>
-    where
-        T: 'static + Clone + CheckNull,
-        Integer: From<T> + SaturatingCast<T>,
+    base_discrete_gaussian(scale, D, MO, false)
+}
+
+#[no_mangle]
+pub extern "C" fn opendp_measurements__make_base_discrete_gaussian_fixed_iteration(
+    scale: *const c_void,
+    D: *const c_char,
+    MO: *const c_char,
+) -> FfiResult<*mut AnyMeasurement> {
+    base_discrete_gaussian(scale, D, MO, true)
+}
 
-        Q: Float,
-        Rational: TryFrom<Q>,
+fn base_discrete_gaussian(
+    scale: *const c_void,
+    D: *const c_char,
+    MO: *const c_char,
+    fixed_iteration: bool,
+) -> FfiResult<*mut AnyMeasurement> {
+    fn monomorphize<T, Q>(scale: *const c_void, D: Type, MO: Type, fixed_iteration: bool) -> FfiResult<*mut AnyMeasurement>
+    where
+        T: 'static + Clone + CheckNull + CastInternalInteger,
+        Q: Float + CastInternalRational,
     {
-        fn monomorphize2<D, MO>(scale: MO::Atom) -> FfiResult<*mut AnyMeasurement>
+        fn monomorphize2<D, MO>(scale: MO::Atom, fixed_iteration: bool) -> FfiResult<*mut AnyMeasurement>
         where
             D: 'static + DiscreteGaussianDomain<MO::Atom>,
-            Integer: From<D::Atom> + SaturatingCast<D::Atom>,
-
+            D::Atom: CastInternalInteger,
             MO: 'static + DiscreteGaussianMeasure<D>,
-            Rational: TryFrom<MO::Atom>,
+            MO::Atom: CastInternalRational,
         {
-            make_base_discrete_gaussian::<D, MO>(scale).into_any()
+            if fixed_iteration {
+                make_base_discrete_gaussian_fixed_iteration::<D, MO>(scale).into_any()
+            } else {
+                make_base_discrete_gaussian::<D, MO>(scale).into_any()
+            }
         }
         let scale = *try_as_ref!(scale as *const Q);
         dispatch!(monomorphize2, [
             (D, [VectorDomain<AllDomain<T>>, AllDomain<T>]),
             (MO, [ZeroConcentratedDivergence<Q>])
-        ], (scale))
+        ], (scale, fixed_iteration))
     }
     let D = try_!(Type::try_from(D));
     let MO = try_!(Type::try_from(MO));
@@ -49,7 +68,7 @@ pub extern "C" fn opendp_measurements__make_base_discrete_gaussian(
     dispatch!(monomorphize, [
         (T, @integers),
         (Q, @floats)
-    ], (scale, D, MO))
+    ], (scale, D, MO, fixed_iteration))
 }
 
 #[cfg(test)]
@@ -70,7 +89,21 @@ mod tests {
             "ZeroConcentratedDivergence<f64>".to_char_p(),
         ))?;
         let arg = AnyObject::new_raw(99);
-        let res = core::opendp_core__measurement_invoke(&measurement, arg);
+        let res = core::opendp_core__measurement_invoke(&measurement, arg, util::from_bool(false));
+        let res: i32 = Fallible::from(res)?.downcast()?;
+        assert_eq!(res, 99);
+        Ok(())
+    }
+
+    #[test]
+    fn test_make_base_discrete_gaussian_fixed_iteration() -> Fallible<()> {
+        let measurement = Result::from(opendp_measurements__make_base_discrete_gaussian_fixed_iteration(
+            util::into_raw(0.0) as *const c_void,
+            "AllDomain<i32>".to_char_p(),
+            "ZeroConcentratedDivergence<f64>".to_char_p(),
+        ))?;
+        let arg = AnyObject::new_raw(99);
+        let res = core::opendp_core__measurement_invoke(&measurement, arg, util::from_bool(false));
         let res: i32 = Fallible::from(res)?.downcast()?;
         assert_eq!(res, 99);
         Ok(())
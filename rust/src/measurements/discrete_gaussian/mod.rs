@@ -1,16 +1,17 @@
-use std::convert::TryFrom;
-
-use az::{SaturatingAs, SaturatingCast};
-use num::{traits::Pow, Float as _, Zero};
+use num::{Float as _, Zero};
+#[cfg(feature = "use-mpfr")]
+use num::traits::Pow;
+#[cfg(feature = "use-mpfr")]
 use rug::{Integer, Rational};
 
 use crate::{
+    accuracy::NoiseDistribution,
     core::{Measure, Measurement, PrivacyMap, SensitivityMetric},
     domains::{AllDomain, VectorDomain},
     error::Fallible,
     measures::ZeroConcentratedDivergence,
     metrics::{AbsoluteDistance, L2Distance},
-    traits::{samplers::sample_discrete_gaussian, CheckNull, Float},
+    traits::{samplers::{CastInternalInteger, CastInternalRational, DiscreteGaussianSampler}, CheckNull, Float},
 };
 
 #[cfg(feature = "ffi")]
@@ -39,8 +40,7 @@ where
 impl<DI, Q> DiscreteGaussianMeasure<DI> for ZeroConcentratedDivergence<Q>
 where
     DI: DiscreteGaussianDomain<Q>,
-    Q: Float,
-    Rational: TryFrom<Q>,
+    Q: Float + CastInternalRational,
 {
     type Atom = Q;
 
@@ -69,16 +69,51 @@ pub fn make_base_discrete_gaussian<D, MO>(
 ) -> Fallible<Measurement<D, D, D::InputMetric, MO>>
 where
     D: DiscreteGaussianDomain<MO::Atom>,
-    Integer: From<D::Atom> + SaturatingCast<D::Atom>,
+    D::Atom: CastInternalInteger,
 
     MO: DiscreteGaussianMeasure<D>,
-    Rational: TryFrom<MO::Atom>,
+    MO::Atom: CastInternalRational,
+{
+    base_discrete_gaussian(scale, false)
+}
+
+/// Make a Measurement that adds noise from the discrete_gaussian(`scale`) distribution,
+/// where the number of loop iterations and random draws made by the noise sampler does not depend on the noise.
+///
+/// Each release fails, or deviates from the distribution of [`make_base_discrete_gaussian`],
+/// with probability less than 2^-36 per element.
+/// The big-integer arithmetic, the rounding of the noise and the bookkeeping of each loop still take variable time,
+/// so this only removes the dominant timing channel, and does not run in constant time.
+/// Sampling is much slower than in [`make_base_discrete_gaussian`].
+pub fn make_base_discrete_gaussian_fixed_iteration<D, MO>(
+    scale: MO::Atom,
+) -> Fallible<Measurement<D, D, D::InputMetric, MO>>
+where
+    D: DiscreteGaussianDomain<MO::Atom>,
+    D::Atom: CastInternalInteger,
+
+    MO: DiscreteGaussianMeasure<D>,
+    MO::Atom: CastInternalRational,
+{
+    base_discrete_gaussian(scale, true)
+}
+
+fn base_discrete_gaussian<D, MO>(
+    scale: MO::Atom,
+    fixed_iteration: bool,
+) -> Fallible<Measurement<D, D, D::InputMetric, MO>>
+where
+    D: DiscreteGaussianDomain<MO::Atom>,
+    D::Atom: CastInternalInteger,
+
+    MO: DiscreteGaussianMeasure<D>,
+    MO::Atom: CastInternalRational,
 {
     if scale.is_sign_negative() {
         return fallible!(MakeMeasurement, "scale must not be negative");
     }
-    let scale_rational =
-        Rational::try_from(scale).map_err(|_| err!(MakeMeasurement, "scale must be finite"))?;
+    let scale_rational = scale.into_rational()
+        .map_err(|_| err!(MakeMeasurement, "scale must be finite"))?;
 
     Ok(Measurement::new(
         D::default(),
@@ -86,22 +121,25 @@ where
         if scale.is_zero() {
             D::new_map_function(move |arg: &D::Atom| Ok(arg.clone()))
         } else {
-            D::new_map_function(move |arg: &D::Atom| {
-                // exact conversion to bignum int
-                let arg = Integer::from(arg.clone());
-                // exact sampling of noise
-                let noise = sample_discrete_gaussian(scale_rational.clone())?;
-                // exact addition, and then postprocess by casting to D::Atom
-                //     clamp to the data type's bounds if out of range
-                Ok((arg + noise).saturating_as())
-            })
+            D::new_batch_map_function(
+                move || Ok(DiscreteGaussianSampler::new(scale_rational.clone(), fixed_iteration)),
+                |sampler, arg: &D::Atom| {
+                    // exact conversion to bignum int
+                    let arg = arg.clone().into_integer();
+                    // exact sampling of noise
+                    let noise = sampler.sample()?;
+                    // exact addition, and then postprocess by casting to D::Atom
+                    //     clamp to the data type's bounds if out of range
+                    Ok(D::Atom::from_integer(arg + noise))
+                })
         },
         D::InputMetric::default(),
         MO::default(),
         MO::new_forward_map(scale)?,
-    ))
+    ).with_noise(NoiseDistribution::discrete_gaussian(scale)?))
 }
 
+#[cfg(feature = "use-mpfr")]
 pub fn make_base_discrete_gaussian_rug<D>(
     scale: Rational,
 ) -> Fallible<Measurement<D, D, D::InputMetric, ZeroConcentratedDivergence<Rational>>>
@@ -111,25 +149,24 @@ where
     if scale <= 0 {
         return fallible!(MakeMeasurement, "scale must be positive");
     }
+    let noise = NoiseDistribution::DiscreteGaussian { scale: scale.to_f64() };
 
     Ok(Measurement::new(
         D::default(),
         D::default(),
-        D::new_map_function(enclose!(scale, move |arg: &Integer| {
-            sample_discrete_gaussian(scale.clone()).map(|n| arg + n)
-        })),
+        D::new_batch_map_function(
+            enclose!(scale, move || Ok(DiscreteGaussianSampler::new(scale.clone(), false))),
+            |sampler, arg: &Integer| sampler.sample().map(|n| arg + n)),
         D::InputMetric::default(),
         ZeroConcentratedDivergence::default(),
         PrivacyMap::new(move |d_in: &Rational| (d_in.clone() / &scale).pow(2) / 2),
-    ))
+    ).with_noise(noise))
 }
 
 #[cfg(test)]
 mod test {
-    use num::{One, Zero};
-
     use super::*;
-    use crate::{domains::AllDomain, error::ExplainUnwrap};
+    use crate::domains::AllDomain;
 
     // there is a distributional test in the accuracy module
 
@@ -151,7 +188,19 @@ mod test {
     }
 
     #[test]
+    fn test_make_base_discrete_gaussian_fixed_iteration() -> Fallible<()> {
+        let meas = make_base_discrete_gaussian_fixed_iteration::<AllDomain<_>, ZeroConcentratedDivergence<_>>(3f64)?;
+        println!("{:?}", meas.invoke(&0)?);
+        assert!(meas.check(&1., &0.0556)?);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "use-mpfr")]
     fn test_make_base_discrete_gaussian_rug() -> Fallible<()> {
+        use std::convert::TryFrom;
+        use num::One;
+        use crate::error::ExplainUnwrap;
         let _1e30 = Rational::try_from(1e30f64).unwrap_test();
         let meas = make_base_discrete_gaussian_rug::<AllDomain<_>>(_1e30.clone())?;
         println!("{:?}", meas.invoke(&Integer::zero())?);
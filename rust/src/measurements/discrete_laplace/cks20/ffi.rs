@@ -1,15 +1,13 @@
 use std::convert::TryFrom;
 use std::os::raw::{c_char, c_void};
 
-use az::SaturatingCast;
-
 use crate::core::FfiResult;
 use crate::ffi::any::AnyMeasurement;
 use crate::{
     domains::{AllDomain, VectorDomain},
     ffi::util::Type,
-    measurements::{make_base_discrete_laplace_cks20, DiscreteLaplaceDomain},
-    traits::InfCast,
+    measurements::{make_base_discrete_laplace_cks20, make_base_discrete_laplace_cks20_fixed_iteration, DiscreteLaplaceDomain},
+    traits::{samplers::{CastInternalInteger, CastInternalRational}, InfCast},
 };
 use crate::core::IntoAnyMeasurementFfiResultExt;
 
@@ -20,28 +18,46 @@ pub extern "C" fn opendp_measurements__make_base_discrete_laplace_cks20(
     D: *const c_char,
     QO: *const c_char,
 ) -> FfiResult<*mut AnyMeasurement> {
-    fn monomorphize<T, QO>(scale: *const c_void, D: Type, QO: Type) -> FfiResult<*mut AnyMeasurement>
+    base_discrete_laplace_cks20(scale, D, QO, false)
+}
+
+#[no_mangle]
+pub extern "C" fn opendp_measurements__make_base_discrete_laplace_cks20_fixed_iteration(
+    scale: *const c_void,
+    D: *const c_char,
+    QO: *const c_char,
+) -> FfiResult<*mut AnyMeasurement> {
+    base_discrete_laplace_cks20(scale, D, QO, true)
+}
+
+fn base_discrete_laplace_cks20(
+    scale: *const c_void,
+    D: *const c_char,
+    QO: *const c_char,
+    fixed_iteration: bool,
+) -> FfiResult<*mut AnyMeasurement> {
+    fn monomorphize<T, QO>(scale: *const c_void, D: Type, QO: Type, fixed_iteration: bool) -> FfiResult<*mut AnyMeasurement>
     where
-        T: crate::traits::Integer,
-        QO: crate::traits::Float + InfCast<T>,
-        rug::Rational: TryFrom<QO>,
-        rug::Integer: From<T> + SaturatingCast<T>,
+        T: crate::traits::Integer + CastInternalInteger,
+        QO: crate::traits::Float + InfCast<T> + CastInternalRational,
     {
-        fn monomorphize2<D, QO>(scale: QO) -> FfiResult<*mut AnyMeasurement>
+        fn monomorphize2<D, QO>(scale: QO, fixed_iteration: bool) -> FfiResult<*mut AnyMeasurement>
         where
             D: 'static + DiscreteLaplaceDomain,
-            D::Atom: crate::traits::Integer,
-            QO: crate::traits::Float + InfCast<D::Atom>,
-            rug::Rational: TryFrom<QO>,
-            rug::Integer: From<D::Atom> + SaturatingCast<D::Atom>,
+            D::Atom: crate::traits::Integer + CastInternalInteger,
+            QO: crate::traits::Float + InfCast<D::Atom> + CastInternalRational,
         {
-            make_base_discrete_laplace_cks20::<D, QO>(scale).into_any()
+            if fixed_iteration {
+                make_base_discrete_laplace_cks20_fixed_iteration::<D, QO>(scale).into_any()
+            } else {
+                make_base_discrete_laplace_cks20::<D, QO>(scale).into_any()
+            }
         }
         let scale = *try_as_ref!(scale as *const QO);
         dispatch!(monomorphize2, [
             (D, [AllDomain<T>, VectorDomain<AllDomain<T>>]),
             (QO, [QO])
-        ], (scale))
+        ], (scale, fixed_iteration))
     }
     let D = try_!(Type::try_from(D));
     let T = try_!(D.get_atom());
@@ -49,7 +65,7 @@ pub extern "C" fn opendp_measurements__make_base_discrete_laplace_cks20(
     dispatch!(monomorphize, [
         (T, @integers),
         (QO, @floats)
-    ], (scale, D, QO))
+    ], (scale, D, QO, fixed_iteration))
 }
 
 
@@ -72,21 +88,21 @@ mod tests {
             "f64".to_char_p(),
         ))?;
         let arg = AnyObject::new_raw(99);
-        let res = core::opendp_core__measurement_invoke(&measurement, arg);
+        let res = core::opendp_core__measurement_invoke(&measurement, arg, util::from_bool(false));
         let res: i32 = Fallible::from(res)?.downcast()?;
         assert_eq!(res, 99);
         Ok(())
     }
 
     #[test]
-    fn test_constant_time_make_base_discrete_laplace_cks20_ffi() -> Fallible<()> {
-        let measurement = Result::from(opendp_measurements__make_base_discrete_laplace_cks20(
+    fn test_fixed_iteration_make_base_discrete_laplace_cks20_ffi() -> Fallible<()> {
+        let measurement = Result::from(opendp_measurements__make_base_discrete_laplace_cks20_fixed_iteration(
             util::into_raw(0.0) as *const c_void,
             "AllDomain<i32>".to_char_p(),
             "f64".to_char_p(),
         ))?;
         let arg = AnyObject::new_raw(99);
-        let res = core::opendp_core__measurement_invoke(&measurement, arg);
+        let res = core::opendp_core__measurement_invoke(&measurement, arg, util::from_bool(false));
         let res: i32 = Fallible::from(res)?.downcast()?;
         assert_eq!(res, 99);
         Ok(())
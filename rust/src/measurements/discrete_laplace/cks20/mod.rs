@@ -1,13 +1,12 @@
-use std::convert::TryFrom;
-
-use az::{SaturatingAs, SaturatingCast};
+#[cfg(feature = "use-mpfr")]
 use rug::{Complete, Integer, Rational};
 
 use crate::{
+    accuracy::NoiseDistribution,
     core::{Measurement, PrivacyMap},
     error::Fallible,
     measures::MaxDivergence,
-    traits::{samplers::sample_discrete_laplace, InfCast},
+    traits::{samplers::{CastInternalInteger, CastInternalRational, DiscreteLaplaceSampler}, InfCast},
 };
 
 use super::DiscreteLaplaceDomain;
@@ -20,16 +19,45 @@ pub fn make_base_discrete_laplace_cks20<D, QO>(
 ) -> Fallible<Measurement<D, D, D::InputMetric, MaxDivergence<QO>>>
 where
     D: DiscreteLaplaceDomain,
-    D::Atom: crate::traits::Integer,
-    QO: crate::traits::Float + InfCast<D::Atom>,
-    Rational: TryFrom<QO>,
-    Integer: From<D::Atom> + SaturatingCast<D::Atom>,
+    D::Atom: crate::traits::Integer + CastInternalInteger,
+    QO: crate::traits::Float + InfCast<D::Atom> + CastInternalRational,
+{
+    base_discrete_laplace_cks20(scale, false)
+}
+
+/// Make a Measurement that adds noise from the discrete_laplace(`scale`) distribution,
+/// where the number of loop iterations and random draws made by the noise sampler does not depend on the noise.
+///
+/// Each release fails, or deviates from the distribution of [`make_base_discrete_laplace_cks20`],
+/// with probability less than 2^-43 per element.
+/// The big-integer arithmetic, the rounding of the noise and the bookkeeping of each loop still take variable time,
+/// so this only removes the dominant timing channel, and does not run in constant time.
+/// Sampling is much slower than in [`make_base_discrete_laplace_cks20`].
+pub fn make_base_discrete_laplace_cks20_fixed_iteration<D, QO>(
+    scale: QO,
+) -> Fallible<Measurement<D, D, D::InputMetric, MaxDivergence<QO>>>
+where
+    D: DiscreteLaplaceDomain,
+    D::Atom: crate::traits::Integer + CastInternalInteger,
+    QO: crate::traits::Float + InfCast<D::Atom> + CastInternalRational,
+{
+    base_discrete_laplace_cks20(scale, true)
+}
+
+fn base_discrete_laplace_cks20<D, QO>(
+    scale: QO,
+    fixed_iteration: bool,
+) -> Fallible<Measurement<D, D, D::InputMetric, MaxDivergence<QO>>>
+where
+    D: DiscreteLaplaceDomain,
+    D::Atom: crate::traits::Integer + CastInternalInteger,
+    QO: crate::traits::Float + InfCast<D::Atom> + CastInternalRational,
 {
     if scale.is_sign_negative() {
         return fallible!(MakeMeasurement, "scale must not be negative");
     }
-    let scale_rational =
-        Rational::try_from(scale).map_err(|_| err!(MakeMeasurement, "scale must be finite"))?;
+    let scale_rational = scale.into_rational()
+        .map_err(|_| err!(MakeMeasurement, "scale must be finite"))?;
 
     Ok(Measurement::new(
         D::default(),
@@ -37,11 +65,13 @@ where
         if scale.is_zero() {
             D::new_map_function(move |arg: &D::Atom| Ok(*arg))
         } else {
-            D::new_map_function(move |arg: &D::Atom| {
-                let arg = Integer::from(*arg);
-                let noise = sample_discrete_laplace(scale_rational.clone())?;
-                Ok((arg + noise).saturating_as())
-            })
+            D::new_batch_map_function(
+                move || Ok(DiscreteLaplaceSampler::new(scale_rational.clone(), fixed_iteration)),
+                |sampler, arg: &D::Atom| {
+                    let arg = D::Atom::into_integer(*arg);
+                    let noise = sampler.sample()?;
+                    Ok(D::Atom::from_integer(arg + noise))
+                })
         },
         D::InputMetric::default(),
         MaxDivergence::default(),
@@ -59,10 +89,10 @@ where
             // d_in / scale
             d_in.inf_div(&scale)
         }),
-    ))
+    ).with_noise(NoiseDistribution::discrete_laplace(scale)?))
 }
 
-
+#[cfg(feature = "use-mpfr")]
 pub fn make_base_discrete_laplace_cks20_rug<D>(
     scale: Rational,
 ) -> Fallible<Measurement<D, D, D::InputMetric, MaxDivergence<Rational>>>
@@ -72,25 +102,24 @@ where
     if scale <= 0 {
         return fallible!(MakeMeasurement, "scale must be positive");
     }
+    let noise = NoiseDistribution::DiscreteLaplace { scale: scale.to_f64() };
 
     Ok(Measurement::new(
         D::default(),
         D::default(),
-        D::new_map_function(enclose!(scale, move |arg: &Integer| {
-            sample_discrete_laplace(scale.clone()).map(|n| arg + n)
-        })),
+        D::new_batch_map_function(
+            enclose!(scale, move || Ok(DiscreteLaplaceSampler::new(scale.clone(), false))),
+            |sampler, arg: &Integer| sampler.sample().map(|n| arg + n)),
         D::InputMetric::default(),
         MaxDivergence::default(),
         PrivacyMap::new(move |d_in: &Integer| (d_in / &scale).complete()),
-    ))
+    ).with_noise(noise))
 }
 
 #[cfg(test)]
 mod test {
-    use num::{One, Zero};
-
     use super::*;
-    use crate::{domains::AllDomain, error::ExplainUnwrap};
+    use crate::domains::AllDomain;
 
     // there is a distributional test in the accuracy module
 
@@ -112,7 +141,22 @@ mod test {
     }
 
     #[test]
+    fn test_make_base_discrete_laplace_cks20_fixed_iteration() -> Fallible<()> {
+        let meas = make_base_discrete_laplace_cks20_fixed_iteration::<AllDomain<_>, _>(1e30f64)?;
+        println!("{:?}", meas.invoke(&0)?);
+        assert!(meas.check(&1, &1e30f64)?);
+
+        let meas = make_base_discrete_laplace_cks20_fixed_iteration::<AllDomain<_>, _>(0.)?;
+        assert_eq!(meas.invoke(&0)?, 0);
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "use-mpfr")]
     fn test_make_base_discrete_laplace_cks20_rug() -> Fallible<()> {
+        use std::convert::TryFrom;
+        use num::{One, Zero};
+        use crate::error::ExplainUnwrap;
         let _1e30 = Rational::try_from(1e30f64).unwrap_test();
         let meas = make_base_discrete_laplace_cks20_rug::<AllDomain<_>>(_1e30.clone())?;
         println!("{:?}", meas.invoke(&Integer::zero())?);
@@ -6,7 +6,7 @@ use crate::domains::{AllDomain, VectorDomain};
 use crate::ffi::any::AnyMeasurement;
 use crate::ffi::util::Type;
 use crate::measurements::{make_base_discrete_laplace, DiscreteLaplaceDomain};
-use crate::traits::samplers::SampleDiscreteLaplaceLinear;
+use crate::traits::samplers::{CastInternalInteger, CastInternalRational, SampleDiscreteLaplaceLinear};
 use crate::traits::{Float, InfCast, Integer};
 use crate::{err, try_, try_as_ref};
 
@@ -17,41 +17,16 @@ pub extern "C" fn opendp_measurements__make_base_discrete_laplace(
     QO: *const c_char,
 ) -> FfiResult<*mut AnyMeasurement> {
 
-    #[cfg(feature="use-mpfr")]
     fn monomorphize<T, QO>(scale: *const c_void, D: Type, QO: Type) -> FfiResult<*mut AnyMeasurement>
     where
-        T: Integer + SampleDiscreteLaplaceLinear<QO>,
-        QO: Float + InfCast<T> + InfCast<T>,
-        rug::Rational: TryFrom<QO>,
-        rug::Integer: From<T> + az::SaturatingCast<T>,
+        T: Integer + SampleDiscreteLaplaceLinear<QO> + CastInternalInteger,
+        QO: Float + InfCast<T> + CastInternalRational,
     {
         fn monomorphize2<D, QO>(scale: QO) -> FfiResult<*mut AnyMeasurement>
         where
             D: 'static + DiscreteLaplaceDomain,
-            D::Atom: Integer + SampleDiscreteLaplaceLinear<QO>,
-            QO: Float + InfCast<D::Atom> + InfCast<D::Atom>,
-            rug::Rational: TryFrom<QO>,
-            rug::Integer: From<D::Atom> + az::SaturatingCast<D::Atom>,
-        {
-            make_base_discrete_laplace::<D, QO>(scale).into_any()
-        }
-        let scale = *try_as_ref!(scale as *const QO);
-        dispatch!(monomorphize2, [
-            (D, [AllDomain<T>, VectorDomain<AllDomain<T>>]),
-            (QO, [QO])
-        ], (scale))
-    }
-    #[cfg(not(feature="use-mpfr"))]
-    fn monomorphize<T, QO>(scale: *const c_void, D: Type, QO: Type) -> FfiResult<*mut AnyMeasurement>
-    where
-        T: Integer + SampleDiscreteLaplaceLinear<QO>,
-        QO: Float + InfCast<T>,
-    {
-        fn monomorphize2<D, QO>(scale: QO) -> FfiResult<*mut AnyMeasurement>
-        where
-            D: 'static + DiscreteLaplaceDomain,
-            D::Atom: Integer + SampleDiscreteLaplaceLinear<QO>,
-            QO: Float + InfCast<D::Atom>,
+            D::Atom: Integer + SampleDiscreteLaplaceLinear<QO> + CastInternalInteger,
+            QO: Float + InfCast<D::Atom> + CastInternalRational,
         {
             make_base_discrete_laplace::<D, QO>(scale).into_any()
         }
@@ -89,7 +64,7 @@ mod tests {
             "f64".to_char_p(),
         ))?;
         let arg = AnyObject::new_raw(99);
-        let res = core::opendp_core__measurement_invoke(&measurement, arg);
+        let res = core::opendp_core__measurement_invoke(&measurement, arg, util::from_bool(false));
         let res: i32 = Fallible::from(res)?.downcast()?;
         assert_eq!(res, 99);
         Ok(())
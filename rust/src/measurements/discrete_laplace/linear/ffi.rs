@@ -95,7 +95,7 @@ mod tests {
             "f64".to_char_p(),
         ))?;
         let arg = AnyObject::new_raw(99);
-        let res = core::opendp_core__measurement_invoke(&measurement, arg);
+        let res = core::opendp_core__measurement_invoke(&measurement, arg, util::from_bool(false));
         let res: i32 = Fallible::from(res)?.downcast()?;
         assert_eq!(res, 99);
         Ok(())
@@ -110,7 +110,7 @@ mod tests {
             "f64".to_char_p(),
         ))?;
         let arg = AnyObject::new_raw(99);
-        let res = core::opendp_core__measurement_invoke(&measurement, arg);
+        let res = core::opendp_core__measurement_invoke(&measurement, arg, util::from_bool(false));
         let res: i32 = Fallible::from(res)?.downcast()?;
         assert_eq!(res, 99);
         Ok(())
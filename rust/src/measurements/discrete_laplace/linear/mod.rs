@@ -1,6 +1,7 @@
 #[cfg(feature = "ffi")]
 mod ffi;
 
+use crate::accuracy::NoiseDistribution;
 use crate::core::{Measurement, PrivacyMap};
 use crate::error::*;
 use crate::measures::MaxDivergence;
@@ -29,10 +30,10 @@ where
         return fallible!(MakeMeasurement, "lower may not be greater than upper");
     }
 
-    Ok(Measurement::new(
+    let measurement = Measurement::new(
         D::default(),
         D::default(),
-        D::new_map_function(move |v: &D::Atom| {
+        D::new_batch_map_function(|| Ok(()), move |_, v: &D::Atom| {
             D::Atom::sample_discrete_laplace_linear(*v, scale, bounds)
         }),
         D::InputMetric::default(),
@@ -51,7 +52,13 @@ where
             // d_in / scale
             d_in.inf_div(&scale)
         }),
-    ))
+    );
+
+    // when bounded, the noise is conditioned on the release falling within the bounds
+    Ok(match bounds {
+        Some(_) => measurement,
+        None => measurement.with_noise(NoiseDistribution::discrete_laplace(scale)?)
+    })
 }
 
 #[deprecated(
@@ -1,22 +1,17 @@
 use crate::{
     core::{Domain, Function, Measurement, SensitivityMetric},
-    domains::{AllDomain, VectorDomain},
+    domains::{AllDomain, HistogramDomain, VectorDomain},
     error::Fallible,
     measures::MaxDivergence,
     metrics::{AbsoluteDistance, L1Distance},
-    traits::samplers::SampleDiscreteLaplaceLinear,
+    traits::samplers::{with_buffered_entropy, CastInternalInteger, CastInternalRational, SampleDiscreteLaplaceLinear},
     traits::{CheckNull, Float, InfCast, Integer},
 };
 
-#[cfg(feature = "use-mpfr")]
-use az::SaturatingCast;
-
 #[cfg(feature = "ffi")]
 mod ffi;
 
-#[cfg(feature = "use-mpfr")]
 mod cks20;
-#[cfg(feature = "use-mpfr")]
 pub use cks20::*;
 
 mod linear;
@@ -34,6 +29,20 @@ pub trait MappableDomain: Domain {
     ) -> Function<Self, Self> {
         Function::new_fallible(move |arg: &Self::Carrier| Self::map_over(arg, &func))
     }
+
+    /// Like `new_map_function`, for functions that draw noise.
+    ///
+    /// On each invocation, `prepare` is evaluated once, and its output is shared by every atom,
+    /// while entropy is drawn in blocks via [`with_buffered_entropy`].
+    fn new_batch_map_function<S>(
+        prepare: impl Fn() -> Fallible<S> + 'static,
+        func: impl Fn(&S, &Self::Atom) -> Fallible<Self::Atom> + 'static,
+    ) -> Function<Self, Self> {
+        Function::new_fallible(move |arg: &Self::Carrier| with_buffered_entropy(|| {
+            let state = prepare()?;
+            Self::map_over(arg, &|v| func(&state, v))
+        }))
+    }
 }
 
 impl<T: Clone + CheckNull> MappableDomain for AllDomain<T> {
@@ -54,6 +63,17 @@ impl<D: MappableDomain> MappableDomain for VectorDomain<D> {
         arg.iter().map(|v| D::map_over(v, func)).collect()
     }
 }
+impl<TA: Clone + CheckNull, TC: Clone + CheckNull> MappableDomain for HistogramDomain<TA, TC> {
+    type Atom = TC;
+    fn map_over(
+        arg: &Self::Carrier,
+        func: &impl Fn(&Self::Atom) -> Fallible<Self::Atom>,
+    ) -> Fallible<Self::Carrier> {
+        // the edges are public, so only the counts are mapped
+        let (edges, counts) = arg;
+        Ok((edges.clone(), counts.iter().map(func).collect::<Fallible<_>>()?))
+    }
+}
 
 pub trait DiscreteLaplaceDomain: MappableDomain + Default {
     type InputMetric: SensitivityMetric<Distance = Self::Atom> + Default;
@@ -64,17 +84,17 @@ impl<T: Clone + CheckNull> DiscreteLaplaceDomain for AllDomain<T> {
 impl<T: Clone + CheckNull> DiscreteLaplaceDomain for VectorDomain<AllDomain<T>> {
     type InputMetric = L1Distance<T>;
 }
+impl<TA: Clone + CheckNull, TC: Clone + CheckNull> DiscreteLaplaceDomain for HistogramDomain<TA, TC> {
+    type InputMetric = L1Distance<TC>;
+}
 
-#[cfg(feature = "use-mpfr")]
 pub fn make_base_discrete_laplace<D, QO>(
     scale: QO,
 ) -> Fallible<Measurement<D, D, D::InputMetric, MaxDivergence<QO>>>
 where
     D: DiscreteLaplaceDomain,
-    D::Atom: Integer + SampleDiscreteLaplaceLinear<QO>,
-    QO: Float + InfCast<D::Atom> + InfCast<D::Atom>,
-    rug::Rational: std::convert::TryFrom<QO>,
-    rug::Integer: From<D::Atom> + SaturatingCast<D::Atom>,
+    D::Atom: Integer + SampleDiscreteLaplaceLinear<QO> + CastInternalInteger,
+    QO: Float + InfCast<D::Atom> + CastInternalRational,
 {
     // benchmarking results at different levels of σ
     // src in /rust/benches/discrete_laplace/main.rs
@@ -109,18 +129,6 @@ where
     }
 }
 
-#[cfg(not(feature = "use-mpfr"))]
-pub fn make_base_discrete_laplace<D, QO>(
-    scale: QO,
-) -> Fallible<Measurement<D, D, D::InputMetric, MaxDivergence<QO>>>
-where
-    D: DiscreteLaplaceDomain,
-    D::Atom: Integer + SampleDiscreteLaplaceLinear<QO>,
-    QO: Float + InfCast<D::Atom>,
-{
-    make_base_discrete_laplace_linear(scale, None)
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
@@ -5,11 +5,10 @@ use crate::core::{FfiResult, IntoAnyMeasurementFfiResultExt};
 use crate::domains::{AllDomain, VectorDomain};
 use crate::ffi::any::AnyMeasurement;
 use crate::ffi::util::Type;
-use crate::measurements::{make_base_gaussian, GaussianDomain, GaussianMeasure};
+use crate::measurements::{make_base_gaussian, make_base_gaussian_fixed_iteration, GaussianDomain, GaussianMeasure};
 use crate::measures::ZeroConcentratedDivergence;
 use crate::traits::samplers::{CastInternalRational, SampleDiscreteGaussianZ2k};
 use crate::traits::{ExactIntCast, Float, FloatBits};
-use crate::{err, try_, try_as_ref};
 
 #[no_mangle]
 pub extern "C" fn opendp_measurements__make_base_gaussian(
@@ -17,33 +16,57 @@ pub extern "C" fn opendp_measurements__make_base_gaussian(
     k: c_long,
     D: *const c_char,
     MO: *const c_char,
+) -> FfiResult<*mut AnyMeasurement> {
+    base_gaussian(scale, k, D, MO, false)
+}
+
+#[no_mangle]
+pub extern "C" fn opendp_measurements__make_base_gaussian_fixed_iteration(
+    scale: *const c_void,
+    k: c_long,
+    D: *const c_char,
+    MO: *const c_char,
+) -> FfiResult<*mut AnyMeasurement> {
+    base_gaussian(scale, k, D, MO, true)
+}
+
+fn base_gaussian(
+    scale: *const c_void,
+    k: c_long,
+    D: *const c_char,
+    MO: *const c_char,
+    fixed_iteration: bool,
 ) -> FfiResult<*mut AnyMeasurement> {
     fn monomorphize1<T>(
         scale: *const c_void,
         k: i32,
         D: Type,
         MO: Type,
+        fixed_iteration: bool,
     ) -> FfiResult<*mut AnyMeasurement>
     where
         T: Float + CastInternalRational + SampleDiscreteGaussianZ2k,
         i32: ExactIntCast<T::Bits>,
-        rug::Rational: TryFrom<T>,
     {
         let scale = *try_as_ref!(scale as *const T);
-        fn monomorphize2<D, MO>(scale: D::Atom, k: i32) -> FfiResult<*mut AnyMeasurement>
+        fn monomorphize2<D, MO>(scale: D::Atom, k: i32, fixed_iteration: bool) -> FfiResult<*mut AnyMeasurement>
         where
             D: 'static + GaussianDomain,
             D::Atom: Float + SampleDiscreteGaussianZ2k,
             MO: 'static + GaussianMeasure<D>,
             i32: ExactIntCast<<D::Atom as FloatBits>::Bits>,
         {
-            make_base_gaussian::<D, MO>(scale, Some(k)).into_any()
+            if fixed_iteration {
+                make_base_gaussian_fixed_iteration::<D, MO>(scale, Some(k)).into_any()
+            } else {
+                make_base_gaussian::<D, MO>(scale, Some(k)).into_any()
+            }
         }
 
         dispatch!(monomorphize2, [
             (D, [AllDomain<T>, VectorDomain<AllDomain<T>>]),
             (MO, [ZeroConcentratedDivergence<T>])
-        ], (scale, k))
+        ], (scale, k, fixed_iteration))
     }
     let k = k as i32;
     let D = try_!(Type::try_from(D));
@@ -51,7 +74,7 @@ pub extern "C" fn opendp_measurements__make_base_gaussian(
     let T = try_!(D.get_atom());
     dispatch!(monomorphize1, [
         (T, @floats)
-    ], (scale, k, D, MO))
+    ], (scale, k, D, MO, fixed_iteration))
 }
 
 #[cfg(test)]
@@ -73,7 +96,7 @@ mod tests {
             "ZeroConcentratedDivergence<f64>".to_char_p(),
         ))?;
         let arg = AnyObject::new_raw(vec![1.0, 2.0, 3.0]);
-        let res = core::opendp_core__measurement_invoke(&measurement, arg);
+        let res = core::opendp_core__measurement_invoke(&measurement, arg, util::from_bool(false));
         let res: Vec<f64> = Fallible::from(res)?.downcast()?;
         assert_eq!(res, vec![1.0, 2.0, 3.0]);
         Ok(())
@@ -88,7 +111,22 @@ mod tests {
             "ZeroConcentratedDivergence<f64>".to_char_p(),
         ))?;
         let arg = AnyObject::new_raw(1.0);
-        let res = core::opendp_core__measurement_invoke(&measurement, arg);
+        let res = core::opendp_core__measurement_invoke(&measurement, arg, util::from_bool(false));
+        let res: f64 = Fallible::from(res)?.downcast()?;
+        assert_eq!(res, 1.0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_make_base_gaussian_fixed_iteration() -> Fallible<()> {
+        let measurement = Result::from(opendp_measurements__make_base_gaussian_fixed_iteration(
+            util::into_raw(0.0) as *const c_void,
+            -1078,
+            "AllDomain<f64>".to_char_p(),
+            "ZeroConcentratedDivergence<f64>".to_char_p(),
+        ))?;
+        let arg = AnyObject::new_raw(1.0);
+        let res = core::opendp_core__measurement_invoke(&measurement, arg, util::from_bool(false));
         let res: f64 = Fallible::from(res)?.downcast()?;
         assert_eq!(res, 1.0);
         Ok(())
@@ -1,12 +1,13 @@
 use num::Float as _;
 
 use crate::{
+    accuracy::NoiseDistribution,
     core::{Measure, Measurement, PrivacyMap, SensitivityMetric},
-    domains::{AllDomain, VectorDomain},
+    domains::{AllDomain, HistogramDomain, VectorDomain},
     error::Fallible,
     measures::ZeroConcentratedDivergence,
     metrics::{AbsoluteDistance, L2Distance},
-    traits::{samplers::SampleDiscreteGaussianZ2k, Float, FloatBits, ExactIntCast, CheckNull},
+    traits::{samplers::{DiscreteGaussianZ2k, SampleDiscreteGaussianZ2k}, Float, FloatBits, ExactIntCast, CheckNull},
 };
 
 use super::{get_discretization_consts, MappableDomain};
@@ -23,6 +24,9 @@ impl<T: Clone + CheckNull> GaussianDomain for AllDomain<T> {
 impl<T: Clone + CheckNull> GaussianDomain for VectorDomain<AllDomain<T>> {
     type InputMetric = L2Distance<T>;
 }
+impl<TA: Clone + CheckNull, TC: Clone + CheckNull> GaussianDomain for HistogramDomain<TA, TC> {
+    type InputMetric = L2Distance<TC>;
+}
 
 pub trait GaussianMeasure<DI: GaussianDomain>: Measure + Default {
     fn new_forward_map(scale: DI::Atom, relaxation: DI::Atom) -> PrivacyMap<DI::InputMetric, Self>;
@@ -53,6 +57,34 @@ where
 }
 
 pub fn make_base_gaussian<D, MO>(scale: D::Atom, k: Option<i32>) -> Fallible<Measurement<D, D, D::InputMetric, MO>>
+where
+    D: GaussianDomain,
+    D::Atom: Float + SampleDiscreteGaussianZ2k,
+    MO: GaussianMeasure<D>,
+    i32: ExactIntCast<<D::Atom as FloatBits>::Bits>
+{
+    base_gaussian(scale, k, false)
+}
+
+/// Make a Measurement that adds noise from the gaussian(`scale`) distribution,
+/// where the number of loop iterations and random draws made by the noise sampler does not depend on the noise.
+///
+/// Each release fails, or deviates from the distribution of [`make_base_gaussian`],
+/// with probability less than 2^-36 per element.
+/// The big-integer arithmetic, the rounding of the noise and the bookkeeping of each loop still take variable time,
+/// so this only removes the dominant timing channel, and does not run in constant time.
+/// Sampling is much slower than in [`make_base_gaussian`], especially when `scale` is large relative to 2^`k`.
+pub fn make_base_gaussian_fixed_iteration<D, MO>(scale: D::Atom, k: Option<i32>) -> Fallible<Measurement<D, D, D::InputMetric, MO>>
+where
+    D: GaussianDomain,
+    D::Atom: Float + SampleDiscreteGaussianZ2k,
+    MO: GaussianMeasure<D>,
+    i32: ExactIntCast<<D::Atom as FloatBits>::Bits>
+{
+    base_gaussian(scale, k, true)
+}
+
+fn base_gaussian<D, MO>(scale: D::Atom, k: Option<i32>, fixed_iteration: bool) -> Fallible<Measurement<D, D, D::InputMetric, MO>>
 where
     D: GaussianDomain,
     D::Atom: Float + SampleDiscreteGaussianZ2k,
@@ -68,11 +100,13 @@ where
     Ok(Measurement::new(
         D::default(),
         D::default(),
-        D::new_map_function(move |arg: &D::Atom| D::Atom::sample_discrete_gaussian_Z2k(*arg, scale, k)),
+        D::new_batch_map_function(
+            move || DiscreteGaussianZ2k::new(scale, k, fixed_iteration),
+            |sampler, arg: &D::Atom| sampler.sample(*arg)),
         D::InputMetric::default(),
         MO::default(),
         MO::new_forward_map(scale, relaxation),
-    ))
+    ).with_noise(NoiseDistribution::gaussian(scale, k)?))
 }
 
 #[cfg(test)]
@@ -100,4 +134,14 @@ mod tests {
         assert!(measurement.map(&0.1)? <= 0.0050000001);
         Ok(())
     }
+
+    #[test]
+    fn test_make_gaussian_fixed_iteration() -> Fallible<()> {
+        let measurement =
+            make_base_gaussian_fixed_iteration::<AllDomain<_>, ZeroConcentratedDivergence<_>>(1.0f64, Some(-4))?;
+        let _ret = measurement.invoke(&0.0)?;
+
+        assert!(measurement.map(&0.1)? <= 0.01321);
+        Ok(())
+    }
 }
@@ -5,7 +5,7 @@ use crate::core::{FfiResult, IntoAnyMeasurementFfiResultExt};
 use crate::domains::{AllDomain, VectorDomain};
 use crate::ffi::any::AnyMeasurement;
 use crate::ffi::util::Type;
-use crate::measurements::{make_base_laplace, LaplaceDomain};
+use crate::measurements::{make_base_laplace, make_base_laplace_fixed_iteration, LaplaceDomain};
 use crate::traits::samplers::SampleDiscreteLaplaceZ2k;
 use crate::traits::{ExactIntCast, FloatBits, Float};
 use crate::{err, try_, try_as_ref};
@@ -16,20 +16,42 @@ pub extern "C" fn opendp_measurements__make_base_laplace(
     k: c_long,
     D: *const c_char,
 ) -> FfiResult<*mut AnyMeasurement> {
-    fn monomorphize<D>(scale: *const c_void, k: i32) -> FfiResult<*mut AnyMeasurement>
+    base_laplace(scale, k, D, false)
+}
+
+#[no_mangle]
+pub extern "C" fn opendp_measurements__make_base_laplace_fixed_iteration(
+    scale: *const c_void,
+    k: c_long,
+    D: *const c_char,
+) -> FfiResult<*mut AnyMeasurement> {
+    base_laplace(scale, k, D, true)
+}
+
+fn base_laplace(
+    scale: *const c_void,
+    k: c_long,
+    D: *const c_char,
+    fixed_iteration: bool,
+) -> FfiResult<*mut AnyMeasurement> {
+    fn monomorphize<D>(scale: *const c_void, k: i32, fixed_iteration: bool) -> FfiResult<*mut AnyMeasurement>
     where
     D: 'static + LaplaceDomain,
     D::Atom: Float + SampleDiscreteLaplaceZ2k,
     i32: ExactIntCast<<D::Atom as FloatBits>::Bits>,
     {
         let scale = *try_as_ref!(scale as *const D::Atom);
-        make_base_laplace::<D>(scale, Some(k)).into_any()
+        if fixed_iteration {
+            make_base_laplace_fixed_iteration::<D>(scale, Some(k)).into_any()
+        } else {
+            make_base_laplace::<D>(scale, Some(k)).into_any()
+        }
     }
     let k = k as i32;
     let D = try_!(Type::try_from(D));
     dispatch!(monomorphize, [
         (D, [AllDomain<f64>, AllDomain<f32>, VectorDomain<AllDomain<f64>>, VectorDomain<AllDomain<f32>>])
-    ], (scale, k))
+    ], (scale, k, fixed_iteration))
 }
 
 #[cfg(test)]
@@ -50,7 +72,7 @@ mod tests {
             "AllDomain<f64>".to_char_p(),
         ))?;
         let arg = AnyObject::new_raw(1.0);
-        let res = core::opendp_core__measurement_invoke(&measurement, arg);
+        let res = core::opendp_core__measurement_invoke(&measurement, arg, util::from_bool(false));
         let res: f64 = Fallible::from(res)?.downcast()?;
         assert_eq!(res, 1.0);
         Ok(())
@@ -64,9 +86,23 @@ mod tests {
             "VectorDomain<AllDomain<f64>>".to_char_p(),
         ))?;
         let arg = AnyObject::new_raw(vec![1.0, 2.0, 3.0]);
-        let res = core::opendp_core__measurement_invoke(&measurement, arg);
+        let res = core::opendp_core__measurement_invoke(&measurement, arg, util::from_bool(false));
         let res: Vec<f64> = Fallible::from(res)?.downcast()?;
         assert_eq!(res, vec![1.0, 2.0, 3.0]);
         Ok(())
     }
+
+    #[test]
+    fn test_make_base_laplace_fixed_iteration() -> Fallible<()> {
+        let measurement = Result::from(opendp_measurements__make_base_laplace_fixed_iteration(
+            util::into_raw(0.0) as *const c_void,
+            -1078,
+            "AllDomain<f64>".to_char_p(),
+        ))?;
+        let arg = AnyObject::new_raw(1.0);
+        let res = core::opendp_core__measurement_invoke(&measurement, arg, util::from_bool(false));
+        let res: f64 = Fallible::from(res)?.downcast()?;
+        assert_eq!(res, 1.0);
+        Ok(())
+    }
 }
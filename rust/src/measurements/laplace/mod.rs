@@ -3,12 +3,13 @@ mod ffi;
 
 use num::{Zero, Float as _};
 
+use crate::accuracy::NoiseDistribution;
 use crate::core::{Measurement, PrivacyMap, SensitivityMetric};
 use crate::measures::MaxDivergence;
 use crate::metrics::{L1Distance, AbsoluteDistance};
-use crate::domains::{AllDomain, VectorDomain};
+use crate::domains::{AllDomain, HistogramDomain, VectorDomain};
 use crate::error::*;
-use crate::traits::samplers::SampleDiscreteLaplaceZ2k;
+use crate::traits::samplers::{DiscreteLaplaceZ2k, SampleDiscreteLaplaceZ2k};
 use crate::traits::{InfDiv, Float, InfAdd, ExactIntCast, FloatBits, CheckNull};
 
 use super::MappableDomain;
@@ -21,8 +22,33 @@ impl<T: Clone + CheckNull> LaplaceDomain for AllDomain<T> {
 impl<T: Clone + CheckNull> LaplaceDomain for VectorDomain<AllDomain<T>> {
     type InputMetric = L1Distance<T>;
 }
+impl<TA: Clone + CheckNull, TC: Clone + CheckNull> LaplaceDomain for HistogramDomain<TA, TC> {
+    type InputMetric = L1Distance<TC>;
+}
 
 pub fn make_base_laplace<D>(scale: D::Atom, k: Option<i32>) -> Fallible<Measurement<D, D, D::InputMetric, MaxDivergence<D::Atom>>>
+    where D: LaplaceDomain,
+          D::Atom: Float + SampleDiscreteLaplaceZ2k,
+          i32: ExactIntCast<<D::Atom as FloatBits>::Bits> {
+    base_laplace(scale, k, false)
+}
+
+/// Make a Measurement that adds noise from the laplace(`scale`) distribution,
+/// where the number of loop iterations and random draws made by the noise sampler does not depend on the noise.
+///
+/// Each release fails, or deviates from the distribution of [`make_base_laplace`],
+/// with probability less than 2^-43 per element.
+/// The big-integer arithmetic, the rounding of the noise and the bookkeeping of each loop still take variable time,
+/// so this only removes the dominant timing channel, and does not run in constant time.
+/// Sampling is much slower than in [`make_base_laplace`], especially when `scale` is large relative to 2^`k`.
+pub fn make_base_laplace_fixed_iteration<D>(scale: D::Atom, k: Option<i32>) -> Fallible<Measurement<D, D, D::InputMetric, MaxDivergence<D::Atom>>>
+    where D: LaplaceDomain,
+          D::Atom: Float + SampleDiscreteLaplaceZ2k,
+          i32: ExactIntCast<<D::Atom as FloatBits>::Bits> {
+    base_laplace(scale, k, true)
+}
+
+fn base_laplace<D>(scale: D::Atom, k: Option<i32>, fixed_iteration: bool) -> Fallible<Measurement<D, D, D::InputMetric, MaxDivergence<D::Atom>>>
     where D: LaplaceDomain,
           D::Atom: Float + SampleDiscreteLaplaceZ2k,
           i32: ExactIntCast<<D::Atom as FloatBits>::Bits> {
@@ -35,7 +61,9 @@ pub fn make_base_laplace<D>(scale: D::Atom, k: Option<i32>) -> Fallible<Measurem
     Ok(Measurement::new(
         D::default(),
         D::default(),
-        D::new_map_function(move |arg: &D::Atom| D::Atom::sample_discrete_laplace_Z2k(*arg, scale, k)),
+        D::new_batch_map_function(
+            move || DiscreteLaplaceZ2k::new(scale, k, fixed_iteration),
+            |sampler, arg: &D::Atom| sampler.sample(*arg)),
         D::InputMetric::default(),
         MaxDivergence::default(),
         PrivacyMap::new_fallible(
@@ -53,7 +81,7 @@ pub fn make_base_laplace<D>(scale: D::Atom, k: Option<i32>) -> Fallible<Measurem
                 // d_in / scale
                 d_in.inf_div(&scale)
             })
-    ))
+    ).with_noise(NoiseDistribution::laplace(scale, k)?))
 }
 
 // proof should show that the return is always a valid (k, relaxation) pairing
@@ -117,5 +145,14 @@ mod tests {
         assert!(measurement.check(&1., &1.)?);
         Ok(())
     }
+
+    #[test]
+    fn test_make_laplace_fixed_iteration() -> Fallible<()> {
+        let measurement = make_base_laplace_fixed_iteration::<VectorDomain<_>>(1.0, Some(-10))?;
+        let _ret = measurement.invoke(&vec![1.0, 2.0])?;
+
+        assert!(measurement.check(&1., &1.001)?);
+        Ok(())
+    }
 }
 
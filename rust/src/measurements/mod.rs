@@ -2,9 +2,9 @@
 //!
 //! The different [`Measurement`] implementations in this module are accessed by calling the appropriate constructor function.
 //! Constructors are named in the form `make_xxx()`, where `xxx` indicates what the resulting `Measurement` does.
-#[cfg(all(feature="use-mpfr", feature="contrib"))]
+#[cfg(feature="contrib")]
 pub mod discrete_gaussian;
-#[cfg(all(feature="use-mpfr", feature="contrib"))]
+#[cfg(feature="contrib")]
 pub use crate::measurements::discrete_gaussian::*;
 
 #[cfg(feature="contrib")]
@@ -17,9 +17,9 @@ pub mod laplace;
 #[cfg(all(feature="floating-point", feature="contrib"))]
 pub use crate::measurements::laplace::*;
 
-#[cfg(all(feature="floating-point", feature="contrib", feature="use-mpfr"))]
+#[cfg(all(feature="floating-point", feature="contrib"))]
 pub mod gaussian;
-#[cfg(all(feature="floating-point", feature="contrib", feature="use-mpfr"))]
+#[cfg(all(feature="floating-point", feature="contrib"))]
 pub use crate::measurements::gaussian::*;
 
 #[cfg(all(feature="floating-point", feature="contrib"))]
@@ -36,3 +36,13 @@ pub use crate::measurements::randomized_response::*;
 pub mod alp;
 #[cfg(all(feature="use-mpfr", feature="floating-point", feature="contrib"))]
 pub use crate::measurements::alp::*;
+
+#[cfg(all(feature="floating-point", feature="contrib"))]
+pub mod winsorized_mean;
+#[cfg(all(feature="floating-point", feature="contrib"))]
+pub use crate::measurements::winsorized_mean::*;
+
+#[cfg(all(feature="floating-point", feature="contrib"))]
+pub mod mwem;
+#[cfg(all(feature="floating-point", feature="contrib"))]
+pub use crate::measurements::mwem::*;
@@ -0,0 +1,117 @@
+use std::convert::TryFrom;
+use std::os::raw::{c_char, c_uint, c_void};
+
+use crate::core::{FfiResult, IntoAnyMeasurementFfiResultExt, IntoAnyTransformationFfiResultExt};
+use crate::ffi::any::{AnyMeasurement, AnyObject, AnyTransformation, Downcast};
+use crate::ffi::util::Type;
+use crate::measurements::{MWEMMeasure, make_mwem, make_synthetic_dataset};
+use crate::measures::{MaxDivergence, ZeroConcentratedDivergence};
+use crate::metrics::IntDistance;
+use crate::traits::samplers::{SampleDiscreteGaussianZ2k, SampleDiscreteLaplaceZ2k, SampleExponentialMechanism};
+use crate::traits::{ExactIntCast, Float, FloatBits, Hashable, InfCast, RoundCast};
+
+#[no_mangle]
+pub extern "C" fn opendp_measurements__make_mwem(
+    size: c_uint,
+    categories: *const AnyObject,
+    queries: *const AnyObject,
+    rounds: c_uint,
+    selection_scale: *const c_void,
+    scale: *const c_void,
+    TA: *const c_char,
+    MO: *const c_char,
+) -> FfiResult<*mut AnyMeasurement> {
+    #[allow(clippy::too_many_arguments)]
+    fn monomorphize<T>(
+        size: usize,
+        categories: *const AnyObject,
+        queries: *const AnyObject,
+        rounds: usize,
+        selection_scale: *const c_void,
+        scale: *const c_void,
+        TA: Type,
+        MO: Type,
+    ) -> FfiResult<*mut AnyMeasurement>
+        where T: 'static + Float + SampleExponentialMechanism + SampleDiscreteLaplaceZ2k + SampleDiscreteGaussianZ2k + InfCast<IntDistance>,
+              i32: ExactIntCast<<T as FloatBits>::Bits> {
+        fn monomorphize2<TA, MO>(
+            size: usize,
+            categories: *const AnyObject,
+            queries: *const AnyObject,
+            rounds: usize,
+            selection_scale: *const c_void,
+            scale: *const c_void,
+        ) -> FfiResult<*mut AnyMeasurement>
+            where TA: Hashable,
+                  MO: 'static + MWEMMeasure {
+            let categories = try_!(try_as_ref!(categories).downcast_ref::<Vec<TA>>()).clone();
+            let queries = try_!(try_as_ref!(queries).downcast_ref::<Vec<Vec<usize>>>()).clone();
+            let selection_scale = *try_as_ref!(selection_scale as *const MO::Atom);
+            let scale = *try_as_ref!(scale as *const MO::Atom);
+            make_mwem::<TA, MO>(size, categories, queries, rounds, selection_scale, scale).into_any()
+        }
+        dispatch!(monomorphize2, [
+            (TA, @hashable),
+            (MO, [MaxDivergence<T>, ZeroConcentratedDivergence<T>])
+        ], (size, categories, queries, rounds, selection_scale, scale))
+    }
+    let size = size as usize;
+    let rounds = rounds as usize;
+    let TA = try_!(Type::try_from(TA));
+    let MO = try_!(Type::try_from(MO));
+    let T = try_!(MO.get_atom());
+    dispatch!(monomorphize, [
+        (T, @floats)
+    ], (size, categories, queries, rounds, selection_scale, scale, TA, MO))
+}
+
+#[no_mangle]
+pub extern "C" fn opendp_measurements__make_synthetic_dataset(
+    categories: *const AnyObject,
+    TA: *const c_char,
+    T: *const c_char,
+) -> FfiResult<*mut AnyTransformation> {
+    fn monomorphize<TA, T>(categories: *const AnyObject) -> FfiResult<*mut AnyTransformation>
+        where TA: Hashable,
+              T: Float,
+              usize: RoundCast<T> {
+        let categories = try_!(try_as_ref!(categories).downcast_ref::<Vec<TA>>()).clone();
+        make_synthetic_dataset::<TA, T>(categories).into_any()
+    }
+    let TA = try_!(Type::try_from(TA));
+    let T = try_!(Type::try_from(T));
+    dispatch!(monomorphize, [
+        (TA, @hashable),
+        (T, @floats)
+    ], (categories))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core;
+    use crate::error::Fallible;
+    use crate::ffi::any::{AnyObject, Downcast};
+    use crate::ffi::util;
+    use crate::ffi::util::ToCharP;
+
+    use super::*;
+
+    #[test]
+    fn test_make_mwem() -> Fallible<()> {
+        let measurement = Result::from(opendp_measurements__make_mwem(
+            3 as c_uint,
+            util::into_raw(AnyObject::new(vec!["a".to_string(), "b".to_string()])),
+            util::into_raw(AnyObject::new(vec![vec![0usize], vec![1usize]])),
+            2 as c_uint,
+            util::into_raw(1.) as *const c_void,
+            util::into_raw(1.) as *const c_void,
+            "String".to_char_p(),
+            "MaxDivergence<f64>".to_char_p(),
+        ))?;
+        let arg = AnyObject::new_raw(vec!["a".to_string(), "a".to_string(), "b".to_string()]);
+        let res = core::opendp_core__measurement_invoke(&measurement, arg, util::from_bool(false));
+        let res: Vec<f64> = Fallible::from(res)?.downcast()?;
+        assert_eq!(res.len(), 2);
+        Ok(())
+    }
+}
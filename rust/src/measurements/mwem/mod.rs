@@ -0,0 +1,262 @@
+#[cfg(feature="ffi")]
+mod ffi;
+
+use std::collections::{HashMap, HashSet};
+
+use num::{Float as _, One, Zero};
+
+use crate::combinators::BasicCompositionMeasure;
+use crate::core::{Function, Measurement, PrivacyMap, Transformation};
+use crate::domains::{AllDomain, SizedDomain, VectorDomain};
+use crate::error::*;
+use crate::measures::MaxDivergence;
+use crate::metrics::{AbsoluteDistance, AgnosticMetric, IntDistance, SymmetricDistance};
+use crate::traits::{ExactIntCast, Float, FloatBits, Hashable, InfCast, InfDiv, InfMul, RoundCast};
+use crate::traits::samplers::{SampleDiscreteLaplaceZ2k, SampleExponentialMechanism};
+use crate::transformations::make_postprocess;
+
+use super::make_base_laplace;
+
+/// A measure under which MWEM can account for its selection and measurement steps.
+pub trait MWEMMeasure: BasicCompositionMeasure<Distance = Self::Atom> {
+    type Atom: Float + SampleExponentialMechanism + InfCast<IntDistance>;
+    /// Make the mechanism used to release the answer to each selected query.
+    fn make_noise(scale: Self::Atom) -> Fallible<Measurement<AllDomain<Self::Atom>, AllDomain<Self::Atom>, AbsoluteDistance<Self::Atom>, Self>>;
+    /// Convert the privacy loss of an `epsilon`-DP exponential mechanism into this measure.
+    fn selection_loss(epsilon: Self::Atom) -> Fallible<Self::Atom>;
+}
+
+impl<T> MWEMMeasure for MaxDivergence<T>
+    where T: 'static + Float + SampleExponentialMechanism + SampleDiscreteLaplaceZ2k + InfCast<IntDistance>,
+          i32: ExactIntCast<<T as FloatBits>::Bits> {
+    type Atom = T;
+    fn make_noise(scale: T) -> Fallible<Measurement<AllDomain<T>, AllDomain<T>, AbsoluteDistance<T>, Self>> {
+        make_base_laplace(scale, None)
+    }
+    fn selection_loss(epsilon: T) -> Fallible<T> {
+        Ok(epsilon)
+    }
+}
+
+impl<T> MWEMMeasure for crate::measures::ZeroConcentratedDivergence<T>
+    where T: 'static + Float + SampleExponentialMechanism + crate::traits::samplers::SampleDiscreteGaussianZ2k + InfCast<IntDistance>,
+          i32: ExactIntCast<<T as FloatBits>::Bits> {
+    type Atom = T;
+    fn make_noise(scale: T) -> Fallible<Measurement<AllDomain<T>, AllDomain<T>, AbsoluteDistance<T>, Self>> {
+        super::make_base_gaussian(scale, None)
+    }
+    fn selection_loss(epsilon: T) -> Fallible<T> {
+        // an epsilon-DP exponential mechanism satisfies epsilon^2/8-zCDP (Cesar and Rogers, 2021)
+        epsilon.inf_mul(&epsilon)?.inf_div(&T::exact_int_cast(8)?)
+    }
+}
+
+/// Make a Measurement that releases a synthetic distribution over `categories` with
+/// the Multiplicative Weights Exponential Mechanism (MWEM).
+///
+/// Each query in the workload `queries` counts the records whose category index is in the query.
+/// MWEM starts from a uniform distribution, and in each of `rounds` rounds:
+/// 1. selects the query worst answered by the synthetic distribution, with an exponential mechanism with noise scale `selection_scale`.
+///    The error of each query is the distance from its count to its synthetic answer, rounded to a whole number of records,
+///    and the query is sampled exactly from the exponential mechanism over the errors
+/// 2. releases a noisy answer to the selected query, with the noise mechanism of `MO` with noise scale `scale`
+/// 3. updates the synthetic distribution towards the noisy answer with a multiplicative weights update
+///
+/// The released distribution is the average of the synthetic distributions in each round, and sums to `size`.
+/// Records outside of `categories` are not counted by any query.
+/// The privacy loss is the composition of the privacy losses of both steps in each round.
+///
+/// # Arguments
+/// * `size` - Number of records in input data.
+/// * `categories` - The set of categories the data may take on.
+/// * `queries` - Each query is a set of distinct indices into `categories`.
+/// * `rounds` - Number of queries to select and measure.
+/// * `selection_scale` - Noise scale for the exponential mechanism.
+/// * `scale` - Noise scale for the query answers.
+pub fn make_mwem<TA, MO>(
+    size: usize,
+    categories: Vec<TA>,
+    queries: Vec<Vec<usize>>,
+    rounds: usize,
+    selection_scale: MO::Atom,
+    scale: MO::Atom,
+) -> Fallible<Measurement<SizedDomain<VectorDomain<AllDomain<TA>>>, VectorDomain<AllDomain<MO::Atom>>, SymmetricDistance, MO>>
+    where TA: Hashable,
+          MO: 'static + MWEMMeasure {
+    if size == 0 {
+        return fallible!(MakeMeasurement, "size must be positive")
+    }
+    let mut uniques = HashSet::new();
+    if categories.is_empty() || categories.iter().any(move |x| !uniques.insert(x)) {
+        return fallible!(MakeMeasurement, "categories must be non-empty and distinct")
+    }
+    if queries.is_empty() {
+        return fallible!(MakeMeasurement, "there must be at least one query")
+    }
+    if queries.iter().flatten().any(|i| *i >= categories.len()) {
+        return fallible!(MakeMeasurement, "query indices must be less than the number of categories")
+    }
+    // a repeated index would count a record more than once, so the answer could move by more than one per change
+    if queries.iter().any(|query| {
+        let mut uniques = HashSet::new();
+        query.iter().any(move |i| !uniques.insert(i))
+    }) {
+        return fallible!(MakeMeasurement, "query indices must be distinct")
+    }
+    if rounds == 0 {
+        return fallible!(MakeMeasurement, "there must be at least one round")
+    }
+    if selection_scale <= MO::Atom::zero() || selection_scale.is_nan() {
+        return fallible!(MakeMeasurement, "selection scale must be positive")
+    }
+    let noise = MO::make_noise(scale)?;
+
+    let index = categories.iter().enumerate()
+        .map(|(i, c)| (c.clone(), i)).collect::<HashMap<TA, usize>>();
+    let n = MO::Atom::exact_int_cast(size)?;
+    let num_categories = categories.len();
+    let num_cats = MO::Atom::exact_int_cast(num_categories)?;
+    let num_rounds = MO::Atom::exact_int_cast(rounds)?;
+    let _2 = MO::Atom::exact_int_cast(2)?;
+
+    let function = noise.function.clone();
+    let privacy_map = noise.privacy_map.clone();
+    let output_measure = noise.output_measure.clone();
+
+    Ok(Measurement::new(
+        SizedDomain::new(VectorDomain::new_all(), size),
+        VectorDomain::new_all(),
+        Function::new_fallible(move |arg: &Vec<TA>| {
+            let mut counts = vec![MO::Atom::zero(); num_categories];
+            arg.iter().filter_map(|v| index.get(v))
+                .for_each(|i| counts[*i] += MO::Atom::one());
+
+            let answer = |hist: &Vec<MO::Atom>, query: &Vec<usize>| query.iter()
+                .fold(MO::Atom::zero(), |sum, i| sum + hist[*i]);
+            let exact = queries.iter().map(|q| answer(&counts, q)).collect::<Vec<_>>();
+
+            let mut synthetic = vec![n / num_cats; counts.len()];
+            let mut average = vec![MO::Atom::zero(); counts.len()];
+
+            for _ in 0..rounds {
+                // 1. select the query with the largest error, via the exponential mechanism.
+                //    The synthetic answers are rounded, so that each error is a whole number, computed exactly
+                let scores = queries.iter().zip(exact.iter())
+                    .map(|(q, e)| (answer(&synthetic, q).round() - *e).abs())
+                    .collect::<Vec<_>>();
+                let selected = MO::Atom::sample_exponential_mechanism(&scores, selection_scale)?;
+                let query = &queries[selected];
+
+                // 2. measure the selected query
+                let measured = function.eval(&exact[selected])?;
+
+                // 3. multiplicative weights update towards the measurement
+                let step = (measured - answer(&synthetic, query)) / (_2 * n);
+                query.iter().for_each(|i| synthetic[*i] *= step.exp());
+                let total = synthetic.iter().fold(MO::Atom::zero(), |sum, v| sum + *v);
+                if total > MO::Atom::zero() && total.is_finite() {
+                    synthetic.iter_mut().for_each(|v| *v = *v * n / total);
+                }
+
+                average.iter_mut().zip(synthetic.iter())
+                    .for_each(|(a, s)| *a += *s / num_rounds);
+            }
+            Ok(average)
+        }),
+        SymmetricDistance,
+        output_measure.clone(),
+        PrivacyMap::new_fallible(move |d_in: &IntDistance| {
+            // with a fixed dataset size, d_in / 2 records are changed, and each change moves every count by at most one
+            let d_changes = MO::Atom::inf_cast(d_in / 2)?;
+
+            // the error scores have the same sensitivity as the counts,
+            // so the exponential mechanism is (2 * d_changes / selection_scale)-DP
+            let selection = MO::selection_loss(_2.inf_mul(&d_changes)?.inf_div(&selection_scale)?)?;
+            let measurement = privacy_map.eval(&d_changes)?;
+
+            output_measure.compose((0..rounds)
+                .flat_map(|_| vec![selection, measurement])
+                .collect())
+        }),
+    ))
+}
+
+/// Make a postprocessor that expands a synthetic distribution over `categories` into a synthetic dataset.
+///
+/// Each weight is rounded to a whole number of records, such that the total number of records
+/// is the rounded sum of the weights. Remaining records go to the weights with the largest fractional parts.
+pub fn make_synthetic_dataset<TA, T>(
+    categories: Vec<TA>,
+) -> Fallible<Transformation<VectorDomain<AllDomain<T>>, VectorDomain<AllDomain<TA>>, AgnosticMetric, AgnosticMetric>>
+    where TA: Hashable,
+          T: Float,
+          usize: RoundCast<T> {
+    make_postprocess(
+        VectorDomain::new_all(),
+        VectorDomain::new_all(),
+        Function::new_fallible(move |weights: &Vec<T>| {
+            if weights.len() != categories.len() {
+                return fallible!(FailedFunction, "there must be one weight for each category")
+            }
+            let weights = weights.iter()
+                .map(|w| if w.is_finite() && *w > T::zero() { *w } else { T::zero() })
+                .collect::<Vec<T>>();
+            let total = weights.iter().fold(T::zero(), |sum, w| sum + *w);
+            let total = usize::round_cast(total.round())?;
+
+            let mut counts = weights.iter()
+                .map(|w| usize::round_cast(w.floor()))
+                .collect::<Fallible<Vec<usize>>>()?;
+            let remaining = total.saturating_sub(counts.iter().sum());
+
+            // distribute the remaining records by largest fractional part
+            let mut order = (0..weights.len()).collect::<Vec<usize>>();
+            order.sort_by(|&l, &r| (weights[r] - weights[r].floor())
+                .partial_cmp(&(weights[l] - weights[l].floor()))
+                .unwrap_or(std::cmp::Ordering::Equal));
+            order.into_iter().take(remaining).for_each(|i| counts[i] += 1);
+
+            Ok(categories.iter().zip(counts)
+                .flat_map(|(c, n)| std::iter::repeat(c.clone()).take(n))
+                .collect())
+        }))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_make_mwem() -> Fallible<()> {
+        let categories = vec!["a", "b", "c", "d"];
+        let queries = vec![vec![0], vec![1], vec![2], vec![3], vec![0, 1]];
+        let meas = make_mwem::<_, MaxDivergence<f64>>(
+            8, categories.clone(), queries, 10, 1., 1.)?;
+
+        let data = vec!["a", "a", "a", "a", "a", "a", "b", "c"];
+        let distribution = meas.invoke(&data)?;
+        assert_eq!(distribution.len(), 4);
+        assert!((distribution.iter().sum::<f64>() - 8.).abs() < 1e-8);
+
+        // 10 rounds of (2 / 1 + 1 / 1), plus the discretization relaxation of the laplace mechanism
+        assert!(meas.check(&2, &30.001)?);
+        assert!(!meas.check(&2, &29.999)?);
+
+        let synthetic = (meas >> make_synthetic_dataset(categories.clone())?)?;
+        assert_eq!(synthetic.invoke(&data)?.len(), 8);
+
+        // a repeated index would count the same records twice
+        assert!(make_mwem::<_, MaxDivergence<f64>>(8, categories, vec![vec![0, 0]], 10, 1., 1.).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_make_synthetic_dataset() -> Fallible<()> {
+        let trans = make_synthetic_dataset(vec!["a", "b", "c"])?;
+        assert_eq!(trans.invoke(&vec![1.4, 1.3, 0.3])?, vec!["a", "a", "b"]);
+        assert_eq!(trans.invoke(&vec![2.2, -1., 0.7])?, vec!["a", "a", "c"]);
+        assert!(trans.invoke(&vec![1.]).is_err());
+        Ok(())
+    }
+}
@@ -18,6 +18,8 @@ use super::get_discretization_consts;
 // propose-test-release count grouped by unknown categories,
 // IMPORTANT: Assumes that dataset distance is bounded above by d_in.
 //  This assumption holds for count queries in L1-space.
+// No noise distribution is recorded, as released counts are conditioned on passing the threshold,
+//  so the accuracy of each count depends on the unknown probability that its key is released.
 pub fn make_base_ptr<TK, TV>(
     scale: TV, threshold: TV, k: Option<i32>
 ) -> Fallible<Measurement<MapDomain<AllDomain<TK>, AllDomain<TV>>, MapDomain<AllDomain<TK>, AllDomain<TV>>, L1Distance<TV>, SmoothedMaxDivergence<TV>>>
@@ -85,6 +87,10 @@ mod tests {
 
         let epsilon_p = measurement.map(&max_influence)?.epsilon(&delta)?;
         assert_eq!(epsilon_p, epsilon);
+
+        // thresholding changes the distribution of the noise on released counts
+        assert!(measurement.noise().is_none());
+        assert!(measurement.accuracy(0.05).is_err());
         Ok(())
     }
 }
\ No newline at end of file
@@ -48,7 +48,7 @@ pub extern "C" fn opendp_measurements__make_randomized_response(
               Q: Float {
         let categories = try_!(try_as_ref!(categories).downcast_ref::<Vec<T>>()).clone();
         let prob = *try_as_ref!(prob as *const Q);
-        make_randomized_response::<T, Q>(
+        make_randomized_response::<HashSet<T>, Q>(
             HashSet::from_iter(categories.into_iter()),
             prob, constant_time).into_any()
     }
@@ -59,4 +59,4 @@ pub extern "C" fn opendp_measurements__make_randomized_response(
         (T, @hashable),
         (Q, @floats)
     ], (categories, prob, constant_time))
-}
\ No newline at end of file
+}
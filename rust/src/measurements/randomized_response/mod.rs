@@ -4,7 +4,7 @@ mod ffi;
 use std::collections::HashSet;
 
 use crate::core::{Function, Measurement, PrivacyMap};
-use crate::domains::AllDomain;
+use crate::domains::{AllDomain, Categories};
 use crate::error::Fallible;
 use crate::measures::MaxDivergence;
 use crate::metrics::DiscreteDistance;
@@ -58,16 +58,26 @@ pub fn make_randomized_response_bool<Q>(
     ))
 }
 
-pub fn make_randomized_response<T, Q>(
-    categories: HashSet<T>,
+/// Make a Measurement that implements randomized response on a value that may be one of `categories`.
+///
+/// `categories` may be a `HashSet`, or a [`CategoricalDomain`](crate::domains::CategoricalDomain) that is carried by the measurement,
+/// so the release may be passed on to constructors that expect the same categories.
+/// Values that are not a category are always released as a lie.
+pub fn make_randomized_response<C, Q>(
+    categories: C,
     prob: Q,
     constant_time: bool,
-) -> Fallible<Measurement<AllDomain<T>, AllDomain<T>, DiscreteDistance, MaxDivergence<Q>>>
-    where T: Hashable,
+) -> Fallible<Measurement<C::AtomDomain, C::AtomDomain, DiscreteDistance, MaxDivergence<Q>>>
+    where C: Categories,
+          C::Atom: Hashable,
           bool: SampleBernoulli<Q>,
           Q: Float {
+    let (categories, domain, _) = categories.into_parts()?;
 
-    let categories = categories.into_iter().collect::<Vec<_>>();
+    let mut uniques = HashSet::new();
+    if categories.iter().any(move |x| !uniques.insert(x)) {
+        return fallible!(MakeTransformation, "categories must be distinct")
+    }
     if categories.len() < 2 {
         return fallible!(
             MakeTransformation,
@@ -94,17 +104,20 @@ pub fn make_randomized_response<T, Q>(
         .inf_ln()?;
 
     Ok(Measurement::new(
-        AllDomain::new(),
-        AllDomain::new(),
-        Function::new_fallible(move |truth: &T| {
+        domain.clone(),
+        domain,
+        Function::new_fallible(move |truth: &C::Atom| {
             // find index of truth in category set, or None
             let index = categories.iter().position(|cat| cat == truth);
 
             // randomly sample a lie from among the categories with equal probability
             // if truth in categories, sample among n - 1 categories
-            let mut sample = usize::sample_uniform_int_below(
-                categories.len() - if index.is_some() { 1 } else { 0 },
-            )?;
+            let num_lies = categories.len() - if index.is_some() { 1 } else { 0 };
+            let mut sample = if constant_time {
+                usize::sample_uniform_int_below_fixed_iteration(num_lies)
+            } else {
+                usize::sample_uniform_int_below(num_lies)
+            }?;
             // shift the sample by one if index is greater or equal to the index of truth
             if let Some(i) = index {
                 if sample >= i {
@@ -135,6 +148,9 @@ mod test {
     use super::*;
     use std::iter::FromIterator;
     use num::Float as _;
+    use crate::core::Domain;
+    use crate::domains::CategoricalDomain;
+    use crate::traits::InfLn;
 
     #[test]
     fn test_bool() -> Fallible<()> {
@@ -184,4 +200,19 @@ mod test {
         .is_err());
         Ok(())
     }
+    #[test]
+    fn test_categorical() -> Fallible<()> {
+        let domain = CategoricalDomain::new(vec!["a", "b", "c"])?;
+        let ran_res = make_randomized_response(domain.clone(), 0.75, false)?;
+        assert_eq!(ran_res.input_domain, domain);
+        assert!(domain.member(&ran_res.invoke(&"b")?)?);
+        // values that are not a category are released as one of the categories
+        assert!(domain.member(&ran_res.invoke(&"d")?)?);
+        // (.75 * 2 / .25) = 6
+        assert!(ran_res.check(&1, &6.0.inf_ln()?)?);
+        assert!(!ran_res.check(&1, &5.99999.ln())?);
+        assert!(make_randomized_response(CategoricalDomain::new(vec!["a"])?, 0.75, false).is_err());
+        assert!(make_randomized_response(vec!["a", "a"], 0.75, false).is_err());
+        Ok(())
+    }
 }
@@ -0,0 +1,76 @@
+use std::convert::TryFrom;
+use std::os::raw::{c_char, c_uint, c_void};
+
+use crate::core::{FfiResult, IntoAnyMeasurementFfiResultExt};
+use crate::domains::AllDomain;
+use crate::ffi::any::{AnyMeasurement, AnyObject, Downcast};
+use crate::ffi::util::Type;
+use crate::measurements::make_sized_bounded_winsorized_mean;
+use crate::metrics::{AbsoluteDistance, SymmetricDistance};
+use crate::traits::samplers::SampleDiscreteLaplaceZ2k;
+use crate::traits::{ExactIntCast, Float, FloatBits, RoundCast};
+use crate::transformations::{LipschitzMulFloatDomain, LipschitzMulFloatMetric, MakeSizedBoundedSum};
+
+#[no_mangle]
+pub extern "C" fn opendp_measurements__make_sized_bounded_winsorized_mean(
+    size: c_uint,
+    bounds: *const AnyObject,
+    bin_edges: *const AnyObject,
+    alpha: *const c_void,
+    quantile_scale: *const c_void,
+    scale: *const c_void,
+    T: *const c_char,
+) -> FfiResult<*mut AnyMeasurement> {
+    fn monomorphize<T>(
+        size: usize,
+        bounds: *const AnyObject,
+        bin_edges: *const AnyObject,
+        alpha: *const c_void,
+        quantile_scale: *const c_void,
+        scale: *const c_void,
+    ) -> FfiResult<*mut AnyMeasurement>
+        where T: 'static + Float + SampleDiscreteLaplaceZ2k + MakeSizedBoundedSum<SymmetricDistance> + RoundCast<T>,
+              i32: ExactIntCast<<T as FloatBits>::Bits>,
+              AllDomain<T>: LipschitzMulFloatDomain<Atom = T, Carrier = T>,
+              AbsoluteDistance<T>: LipschitzMulFloatMetric<Distance = T> {
+        let bounds = *try_!(try_as_ref!(bounds).downcast_ref::<(T, T)>());
+        let bin_edges = try_!(try_as_ref!(bin_edges).downcast_ref::<Vec<T>>()).clone();
+        let alpha = *try_as_ref!(alpha as *const T);
+        let quantile_scale = *try_as_ref!(quantile_scale as *const T);
+        let scale = *try_as_ref!(scale as *const T);
+        make_sized_bounded_winsorized_mean::<T>(size, bounds, bin_edges, alpha, quantile_scale, scale).into_any()
+    }
+    let size = size as usize;
+    let T = try_!(Type::try_from(T));
+    dispatch!(monomorphize, [
+        (T, @floats)
+    ], (size, bounds, bin_edges, alpha, quantile_scale, scale))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core;
+    use crate::error::Fallible;
+    use crate::ffi::any::{AnyObject, Downcast};
+    use crate::ffi::util;
+    use crate::ffi::util::ToCharP;
+
+    use super::*;
+
+    #[test]
+    fn test_make_sized_bounded_winsorized_mean() -> Fallible<()> {
+        let measurement = Result::from(opendp_measurements__make_sized_bounded_winsorized_mean(
+            3 as c_uint,
+            util::into_raw(AnyObject::new((0., 10.))),
+            util::into_raw(AnyObject::new(vec![0., 5., 10.])),
+            util::into_raw(0.1) as *const c_void,
+            util::into_raw(1.) as *const c_void,
+            util::into_raw(1.) as *const c_void,
+            "f64".to_char_p(),
+        ))?;
+        let arg = AnyObject::new_raw(vec![1., 2., 3.]);
+        let res = core::opendp_core__measurement_invoke(&measurement, arg, util::from_bool(false));
+        let _res: f64 = Fallible::from(res)?.downcast()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,146 @@
+#[cfg(feature="ffi")]
+mod ffi;
+
+use crate::core::{Function, Measurement, PrivacyMap, StabilityMap, Transformation};
+use crate::domains::{AllDomain, BoundedDomain, SizedDomain, VectorDomain};
+use crate::error::*;
+use crate::measures::MaxDivergence;
+use crate::metrics::{AbsoluteDistance, IntDistance, L1Distance, SymmetricDistance};
+use crate::traits::{ExactIntCast, Float, FloatBits, RoundCast};
+use crate::traits::samplers::SampleDiscreteLaplaceZ2k;
+use crate::transformations::{
+    make_quantiles_from_counts, make_sized_bounded_mean, Interpolation,
+    LipschitzMulFloatDomain, LipschitzMulFloatMetric, MakeSizedBoundedSum,
+};
+
+use super::make_base_laplace;
+
+/// Make a Measurement that releases the mean of bounded data, after clipping each record to
+/// the `alpha` and `1 - alpha` quantiles of the data.
+///
+/// The quantiles are first estimated privately from a histogram over `bin_edges` with noise scale `quantile_scale`.
+/// The clipped mean is then released with noise scale `scale`.
+/// Since the clipped data still lies within `bounds`, the noise for the mean is calibrated to `bounds`,
+/// and the privacy loss is the sum of the privacy losses of the two releases.
+///
+/// # Arguments
+/// * `size` - Number of records in input data.
+/// * `bounds` - Tuple of inclusive lower and upper bounds of the input data.
+/// * `bin_edges` - Strictly increasing edges of the histogram used to estimate the quantiles.
+/// * `alpha` - Fraction of records to clip in each tail. Must be in [0, 0.5].
+/// * `quantile_scale` - Noise scale for the histogram counts.
+/// * `scale` - Noise scale for the clipped mean.
+pub fn make_sized_bounded_winsorized_mean<T>(
+    size: usize,
+    bounds: (T, T),
+    bin_edges: Vec<T>,
+    alpha: T,
+    quantile_scale: T,
+    scale: T,
+) -> Fallible<Measurement<SizedDomain<VectorDomain<BoundedDomain<T>>>, AllDomain<T>, SymmetricDistance, MaxDivergence<T>>>
+    where T: 'static + Float + SampleDiscreteLaplaceZ2k + MakeSizedBoundedSum<SymmetricDistance> + RoundCast<T>,
+          i32: ExactIntCast<<T as FloatBits>::Bits>,
+          AllDomain<T>: LipschitzMulFloatDomain<Atom = T, Carrier = T>,
+          AbsoluteDistance<T>: LipschitzMulFloatMetric<Distance = T> {
+    if bin_edges.len() < 2 {
+        return fallible!(MakeMeasurement, "there must be at least two bin edges");
+    }
+    if !(T::zero()..=T::exact_int_cast(2)?.recip()).contains(&alpha) {
+        return fallible!(MakeMeasurement, "alpha must be in [0, 0.5]");
+    }
+    let input_domain = SizedDomain::new(VectorDomain::new(BoundedDomain::new_closed(bounds)?), size);
+
+    // 1. release noisy counts of the number of records in each bin, from which the quantiles are estimated
+    let noisy_counts = make_sized_bin_counts(input_domain.clone(), bin_edges.clone())?
+        >> make_base_laplace::<VectorDomain<AllDomain<T>>>(quantile_scale, None)?;
+    let noisy_counts = noisy_counts?;
+    let quantiles = make_quantiles_from_counts(
+        bin_edges, vec![alpha, T::one() - alpha], Interpolation::Linear)?;
+
+    // 2. release the mean of the clipped data. The clipped data is a member of the same input domain
+    let clipped_mean = (make_sized_bounded_mean::<SymmetricDistance, T>(size, bounds)?
+        >> make_base_laplace::<AllDomain<T>>(scale, None)?)?;
+
+    let quantile_map = noisy_counts.privacy_map.clone();
+    let mean_map = clipped_mean.privacy_map.clone();
+
+    Ok(Measurement::new(
+        input_domain,
+        AllDomain::new(),
+        Function::new_fallible(move |arg: &Vec<T>| {
+            // negative noisy counts are postprocessed to zero
+            let counts = noisy_counts.invoke(arg)?.into_iter()
+                .map(|c| c.total_max(T::zero()))
+                .collect::<Fallible<Vec<T>>>()?;
+            let estimates = quantiles.invoke(&counts)?;
+
+            // fall back to the bounds if the quantiles could not be estimated
+            let clip = |v: T, default: T| if v.is_nan() { Ok(default) } else { v.total_clamp(bounds.0, bounds.1) };
+            let lower = clip(estimates[0], bounds.0)?;
+            let upper = clip(estimates[1], bounds.1)?.total_max(lower)?;
+
+            let clipped = arg.iter()
+                .map(|v| v.total_clamp(lower, upper))
+                .collect::<Fallible<Vec<T>>>()?;
+            clipped_mean.invoke(&clipped)
+        }),
+        SymmetricDistance,
+        MaxDivergence::default(),
+        PrivacyMap::new_fallible(move |d_in: &IntDistance| {
+            // Clipping to the released quantiles is a 1-stable row-by-row transformation into the input domain,
+            //     so the privacy loss is bounded by basic composition of the two releases.
+            quantile_map.eval(d_in)?.inf_add(&mean_map.eval(d_in)?)
+        }),
+    ))
+}
+
+/// Make a Transformation that counts the number of records in each bin.
+/// Records outside the edges are counted in the nearest bin.
+fn make_sized_bin_counts<T: 'static + Float>(
+    input_domain: SizedDomain<VectorDomain<BoundedDomain<T>>>,
+    bin_edges: Vec<T>,
+) -> Fallible<Transformation<SizedDomain<VectorDomain<BoundedDomain<T>>>, VectorDomain<AllDomain<T>>, SymmetricDistance, L1Distance<T>>> {
+    if bin_edges.windows(2).any(|w| w[0] >= w[1]) {
+        return fallible!(MakeTransformation, "bin_edges must be increasing");
+    }
+    let num_bins = bin_edges.len() - 1;
+    Ok(Transformation::new(
+        input_domain,
+        VectorDomain::new_all(),
+        Function::new(move |arg: &Vec<T>| {
+            let mut counts = vec![T::zero(); num_bins];
+            arg.iter().for_each(|v| {
+                let idx = bin_edges.partition_point(|e| e <= v).saturating_sub(1).min(num_bins - 1);
+                counts[idx] += T::one();
+            });
+            counts
+        }),
+        SymmetricDistance,
+        L1Distance::default(),
+        // each added or removed record changes one count by one
+        StabilityMap::new_fallible(|d_in: &IntDistance| T::inf_cast(*d_in)),
+    ))
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_make_sized_bounded_winsorized_mean() -> Fallible<()> {
+        let bin_edges = (0..=20).map(|v| v as f64 * 5.).collect();
+        let meas = make_sized_bounded_winsorized_mean(
+            100, (0., 100.), bin_edges, 0.1, 1e-6, 1e-6)?;
+
+        // the outliers at 100 are clipped to the 90th percentile
+        let mut data: Vec<f64> = (0..90).map(|_| 10.).collect();
+        data.extend((0..10).map(|_| 100.));
+        let ret = meas.invoke(&data)?;
+        assert!(ret < 20., "winsorized mean was {}", ret);
+
+        // (d_in / quantile_scale) + (d_in / 2 * (U - L) / n / scale)
+        assert!(meas.check(&2, &(2. / 1e-6 + 100. / 100. / 1e-6 + 1.))?);
+        Ok(())
+    }
+}
@@ -73,6 +73,46 @@ impl Metric for ChangeOneDistance {
 
 impl DatasetMetric for ChangeOneDistance {}
 
+/// Distance between graphs, counted as the number of edges added or removed.
+#[derive(Clone)]
+pub struct EdgeDistance;
+
+impl Default for EdgeDistance {
+    fn default() -> Self { EdgeDistance }
+}
+
+impl PartialEq for EdgeDistance {
+    fn eq(&self, _other: &Self) -> bool { true }
+}
+impl Debug for EdgeDistance {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "EdgeDistance()")
+    }
+}
+impl Metric for EdgeDistance {
+    type Distance = IntDistance;
+}
+
+/// Distance between graphs, counted as the number of nodes added or removed, along with all of their edges.
+#[derive(Clone)]
+pub struct NodeDistance;
+
+impl Default for NodeDistance {
+    fn default() -> Self { NodeDistance }
+}
+
+impl PartialEq for NodeDistance {
+    fn eq(&self, _other: &Self) -> bool { true }
+}
+impl Debug for NodeDistance {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        write!(f, "NodeDistance()")
+    }
+}
+impl Metric for NodeDistance {
+    type Distance = IntDistance;
+}
+
 #[derive(Clone)]
 pub struct HammingDistance;
 
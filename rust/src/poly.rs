@@ -58,14 +58,17 @@ impl<DI, DO, MI, MO> Measurement<DI, DO, MI, MO>
     /// Converts this Measurement into one with polymorphic output. This is useful for composition
     /// of heterogeneous Measurements.
     pub fn into_poly(self) -> Measurement<DI, PolyDomain, MI, MO> {
-        Measurement::new(
+        let noise = self.noise().cloned();
+        let mut poly = Measurement::new(
             self.input_domain,
             PolyDomain::new(),
             self.function.into_poly(),
             self.input_metric,
             self.output_measure,
             self.privacy_map,
-        )
+        );
+        poly.set_noise(noise);
+        poly
     }
 }
 
@@ -79,14 +82,17 @@ impl<DI, DO, MI, MO> Transformation<DI, DO, MI, MO>
     /// Converts this Transformation into one with polymorphic output. It's not clear if we'll need this,
     /// but it's provided for symmetry with Measurement.
     pub fn into_poly(self) -> Transformation<DI, PolyDomain, MI, MO> {
-        Transformation::new(
+        let noise_map = self.noise_map().cloned();
+        let mut poly = Transformation::new(
             self.input_domain,
             PolyDomain::new(),
             self.function.into_poly(),
             self.input_metric,
             self.output_metric,
             self.stability_map,
-        )
+        );
+        poly.set_noise_map(noise_map);
+        poly
     }
 }
 
@@ -0,0 +1,205 @@
+use std::convert::TryFrom;
+use std::os::raw::c_char;
+
+use crate::core::{CallbackFn, FfiResult, wrap_callback};
+use crate::error::Fallible;
+use crate::ffi::any::{AnyMeasurement, AnyObject, AnyTransformation, Downcast};
+use crate::ffi::util::{self, AnyMeasurementPtr, AnyTransformationPtr, Type};
+use crate::search::{binary_search, binary_search_chain, binary_search_param, CheckRelation, SearchParam};
+
+/// A computation chain built by a callback, which may be either a measurement or a transformation.
+enum AnyChain {
+    Measurement(AnyMeasurement),
+    Transformation(AnyTransformation),
+}
+
+impl AnyChain {
+    /// The callback keeps ownership of the chain it returns, so the chain is cloned out of the pointer.
+    fn from_ptr(chain: &AnyObject) -> Fallible<Self> {
+        if let Ok(ptr) = chain.downcast_ref::<AnyMeasurementPtr>() {
+            util::as_ref(*ptr).cloned().map(AnyChain::Measurement)
+        } else if let Ok(ptr) = chain.downcast_ref::<AnyTransformationPtr>() {
+            util::as_ref(*ptr).cloned().map(AnyChain::Transformation)
+        } else {
+            return fallible!(FFI, "expected a Measurement or Transformation, found {}", chain.type_.descriptor)
+        }.ok_or_else(|| err!(FFI, "null pointer: chain"))
+    }
+
+    /// Ownership of the chain is transferred to the caller.
+    fn into_ptr(self) -> AnyObject {
+        match self {
+            AnyChain::Measurement(chain) => AnyObject::new(util::into_raw(chain) as AnyMeasurementPtr),
+            AnyChain::Transformation(chain) => AnyObject::new(util::into_raw(chain) as AnyTransformationPtr),
+        }
+    }
+}
+
+impl CheckRelation for AnyChain {
+    type InputDistance = AnyObject;
+    type OutputDistance = AnyObject;
+    fn check(&self, d_in: &AnyObject, d_out: &AnyObject) -> Fallible<bool> {
+        match self {
+            AnyChain::Measurement(chain) => chain.check(d_in, d_out),
+            AnyChain::Transformation(chain) => chain.check(d_in, d_out),
+        }
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn opendp_search__binary_search(
+    predicate: CallbackFn,
+    bounds: *const AnyObject,
+    T: *const c_char,
+) -> FfiResult<*mut AnyObject> {
+    let T = try_!(Type::try_from(T));
+
+    fn monomorphize<T: SearchParam>(predicate: CallbackFn, bounds: *const AnyObject) -> FfiResult<*mut AnyObject> {
+        let predicate = wrap_callback(predicate);
+        let bounds = if let Some(bounds) = util::as_ref(bounds) {
+            Some(*try_!(bounds.downcast_ref::<(T, T)>()))
+        } else {
+            None
+        };
+        binary_search(|v: T| predicate(&AnyObject::new(v))?.downcast::<bool>(), bounds)
+            .map(AnyObject::new).into()
+    }
+    dispatch!(monomorphize, [(T, [u32, u64, i32, i64, usize, f32, f64])], (predicate, bounds))
+}
+
+#[no_mangle]
+pub extern "C" fn opendp_search__binary_search_param(
+    make_chain: CallbackFn,
+    d_in: *const AnyObject,
+    d_out: *const AnyObject,
+    bounds: *const AnyObject,
+    T: *const c_char,
+) -> FfiResult<*mut AnyObject> {
+    let d_in = try_as_ref!(d_in);
+    let d_out = try_as_ref!(d_out);
+    let T = try_!(Type::try_from(T));
+
+    fn monomorphize<T: SearchParam>(
+        make_chain: CallbackFn, d_in: &AnyObject, d_out: &AnyObject, bounds: *const AnyObject,
+    ) -> FfiResult<*mut AnyObject> {
+        let make_chain = wrap_callback(make_chain);
+        let bounds = if let Some(bounds) = util::as_ref(bounds) {
+            Some(*try_!(bounds.downcast_ref::<(T, T)>()))
+        } else {
+            None
+        };
+        binary_search_param(|v: T| AnyChain::from_ptr(&make_chain(&AnyObject::new(v))?), d_in, d_out, bounds)
+            .map(AnyObject::new).into()
+    }
+    dispatch!(monomorphize, [(T, [u32, u64, i32, i64, usize, f32, f64])], (make_chain, d_in, d_out, bounds))
+}
+
+#[no_mangle]
+pub extern "C" fn opendp_search__binary_search_chain(
+    make_chain: CallbackFn,
+    d_in: *const AnyObject,
+    d_out: *const AnyObject,
+    bounds: *const AnyObject,
+    T: *const c_char,
+) -> FfiResult<*mut AnyObject> {
+    let d_in = try_as_ref!(d_in);
+    let d_out = try_as_ref!(d_out);
+    let T = try_!(Type::try_from(T));
+
+    fn monomorphize<T: SearchParam>(
+        make_chain: CallbackFn, d_in: &AnyObject, d_out: &AnyObject, bounds: *const AnyObject,
+    ) -> FfiResult<*mut AnyObject> {
+        let make_chain = wrap_callback(make_chain);
+        let bounds = if let Some(bounds) = util::as_ref(bounds) {
+            Some(*try_!(bounds.downcast_ref::<(T, T)>()))
+        } else {
+            None
+        };
+        binary_search_chain(|v: T| AnyChain::from_ptr(&make_chain(&AnyObject::new(v))?), d_in, d_out, bounds)
+            .map(AnyChain::into_ptr).into()
+    }
+    dispatch!(monomorphize, [(T, [u32, u64, i32, i64, usize, f32, f64])], (make_chain, d_in, d_out, bounds))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ptr::null;
+
+    use crate::ffi::util::ToCharP;
+
+    use super::*;
+
+    extern "C" fn at_least_five(arg: *const AnyObject) -> *mut FfiResult<*mut AnyObject> {
+        let arg = match util::as_ref(arg) {
+            Some(arg) => arg,
+            // a null argument releases the callback
+            None => return std::ptr::null_mut()
+        };
+        let arg = arg.downcast_ref::<i32>().map(|v| AnyObject::new(*v >= 5));
+        util::into_raw(FfiResult::from(arg))
+    }
+
+    #[test]
+    fn test_binary_search() -> Fallible<()> {
+        let res = opendp_search__binary_search(at_least_five, null(), "i32".to_char_p());
+        let res: i32 = Fallible::from(res)?.downcast()?;
+        assert_eq!(res, 5);
+
+        let bounds = AnyObject::new_raw((0i32, 100i32));
+        let res = opendp_search__binary_search(at_least_five, bounds, "i32".to_char_p());
+        let res: i32 = Fallible::from(res)?.downcast()?;
+        assert_eq!(res, 5);
+
+        // the callback fails when the type doesn't match, so the search has no decision boundary
+        let res = opendp_search__binary_search(at_least_five, null(), "f64".to_char_p());
+        assert!(Fallible::from(res).is_err());
+        Ok(())
+    }
+
+    #[cfg(feature = "contrib")]
+    extern "C" fn make_laplace(arg: *const AnyObject) -> *mut FfiResult<*mut AnyObject> {
+        use crate::domains::AllDomain;
+        use crate::ffi::any::IntoAnyMeasurementExt;
+        use crate::measurements::make_base_laplace;
+
+        let arg = match util::as_ref(arg) {
+            Some(arg) => arg,
+            // a null argument releases the callback
+            None => return std::ptr::null_mut()
+        };
+        // the callback keeps ownership of the chains it returns, so they are leaked here
+        let chain = arg.downcast_ref::<f64>()
+            .and_then(|scale| make_base_laplace::<AllDomain<f64>>(*scale, None))
+            .map(|meas| AnyObject::new(util::into_raw(meas.into_any()) as AnyMeasurementPtr));
+        util::into_raw(FfiResult::from(chain))
+    }
+
+    #[test]
+    #[cfg(feature = "contrib")]
+    fn test_binary_search_param() -> Fallible<()> {
+        let d_in = AnyObject::new_raw(0.1);
+        let d_out = AnyObject::new_raw(1.);
+        let res = opendp_search__binary_search_param(make_laplace, d_in, d_out, null(), "f64".to_char_p());
+        let res: f64 = Fallible::from(res)?.downcast()?;
+        assert_eq!(res, 0.1);
+
+        // the callback fails when the type doesn't match, so the search has no decision boundary
+        let res = opendp_search__binary_search_param(make_laplace, d_in, d_out, null(), "i32".to_char_p());
+        assert!(Fallible::from(res).is_err());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "contrib")]
+    fn test_binary_search_chain() -> Fallible<()> {
+        let d_in = AnyObject::new_raw(0.1);
+        let d_out = AnyObject::new_raw(1.);
+        let bounds = AnyObject::new_raw((0., 10.));
+        let res = opendp_search__binary_search_chain(make_laplace, d_in, d_out, bounds, "f64".to_char_p());
+        let chain: AnyMeasurementPtr = Fallible::from(res)?.downcast()?;
+        // ownership of the chain is transferred to the caller
+        let chain = util::into_owned(chain as *mut AnyMeasurement)?;
+        assert!(chain.check(&AnyObject::new(0.1), &AnyObject::new(1.))?);
+        assert!(!chain.check(&AnyObject::new(0.11), &AnyObject::new(1.))?);
+        Ok(())
+    }
+}
@@ -0,0 +1,322 @@
+//! Search for the tightest parameter of a computation chain, subject to a privacy guarantee.
+//!
+//! The search is over one numeric argument to a constructor, like a noise scale, a threshold or a dataset size.
+//! The tightest parameter is the nearest value to the decision boundary for which the chain is (`d_in`, `d_out`)-close.
+
+#[cfg(feature="ffi")]
+mod ffi;
+
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt::Debug;
+
+use crate::core::{Domain, Measure, Measurement, Metric, Transformation};
+use crate::error::Fallible;
+use crate::traits::TotalOrd;
+
+/// A computation chain whose relation may be checked at a pair of distances.
+pub trait CheckRelation {
+    type InputDistance;
+    type OutputDistance;
+    fn check(&self, d_in: &Self::InputDistance, d_out: &Self::OutputDistance) -> Fallible<bool>;
+}
+
+impl<DI: Domain, DO: Domain, MI: Metric, MO: Measure> CheckRelation for Measurement<DI, DO, MI, MO>
+    where MO::Distance: TotalOrd {
+    type InputDistance = MI::Distance;
+    type OutputDistance = MO::Distance;
+    fn check(&self, d_in: &MI::Distance, d_out: &MO::Distance) -> Fallible<bool> {
+        Measurement::check(self, d_in, d_out)
+    }
+}
+
+impl<DI: Domain, DO: Domain, MI: Metric, MO: Metric> CheckRelation for Transformation<DI, DO, MI, MO>
+    where MO::Distance: TotalOrd {
+    type InputDistance = MI::Distance;
+    type OutputDistance = MO::Distance;
+    fn check(&self, d_in: &MI::Distance, d_out: &MO::Distance) -> Fallible<bool> {
+        Transformation::check(self, d_in, d_out)
+    }
+}
+
+/// A numeric type that may be searched over.
+pub trait SearchParam: 'static + Copy + PartialOrd + Debug {
+    /// The value to start an exponential search from.
+    fn center() -> Self;
+    /// A value between `lower` and `upper`, equal to one of them when there are no values strictly between.
+    fn midpoint(lower: Self, upper: Self) -> Self;
+    /// The edges of increasingly large bands, moving away from `center` in the direction of `positive`.
+    fn bands(center: Self, positive: bool) -> Vec<Self>;
+}
+
+macro_rules! impl_search_param_int {
+    ($($ty:ty),+) => ($(impl SearchParam for $ty {
+        fn center() -> Self { 0 }
+        fn midpoint(lower: Self, upper: Self) -> Self {
+            // avoid overflow
+            lower / 2 + upper / 2 + (lower % 2 + upper % 2) / 2
+        }
+        fn bands(center: Self, positive: bool) -> Vec<Self> {
+            // searching bands of [(k - 1) * 2^16, k * 2^16].
+            // center + 1 is included because zero is prone to error
+            let sign = if positive { 1i128 } else { -1 };
+            let offsets = (1..=8).map(|k| sign * (k << 16));
+            std::iter::once(1).chain(offsets)
+                .map(|offset| <$ty>::try_from(center as i128 + offset))
+                .take_while(Result::is_ok)
+                .map(Result::unwrap)
+                .collect()
+        }
+    })+)
+}
+impl_search_param_int!(u32, u64, i32, i64, usize);
+
+macro_rules! impl_search_param_float {
+    ($($ty:ty),+) => ($(impl SearchParam for $ty {
+        fn center() -> Self { 0. }
+        fn midpoint(lower: Self, upper: Self) -> Self {
+            // avoid overflow
+            lower / 2. + upper / 2.
+        }
+        fn bands(center: Self, positive: bool) -> Vec<Self> {
+            // searching bands of [2^((k - 1)^2), 2^(k^2)].
+            // the last band ends at 2^49, as larger numbers are unlikely to be needed, so only check k in [0, 8).
+            let sign = if positive { 1. } else { -1. };
+            (0..8i32).map(|k| center + sign * (2. as $ty).powi(k * k)).collect()
+        }
+    })+)
+}
+impl_search_param_float!(f32, f64);
+
+/// Find the nearest passing value to the decision boundary of `predicate` within `bounds`.
+///
+/// The `predicate` must be monotonic. Values at which it fails are considered not to pass,
+/// so the search may extend over invalid values.
+/// If the lower bound passes, the largest passing value is returned, otherwise the smallest.
+///
+/// # Arguments
+/// * `predicate` - A monotonic function from a number to a boolean.
+/// * `bounds` - Lower and upper bounds on the decision boundary. If `None`, the bounds are found by [`exponential_bounds_search`].
+pub fn binary_search<T: SearchParam>(
+    predicate: impl Fn(T) -> Fallible<bool>,
+    bounds: Option<(T, T)>,
+) -> Fallible<T> {
+    let (lower, upper) = match bounds {
+        Some(bounds) => bounds,
+        None => exponential_bounds_search(&predicate)?
+    };
+    let (mut lower, mut upper) = match lower.partial_cmp(&upper) {
+        Some(Ordering::Greater) => (upper, lower),
+        Some(_) => (lower, upper),
+        None => return fallible!(FailedFunction, "bounds must be comparable")
+    };
+
+    let passes = |v: T| predicate(v).unwrap_or(false);
+    let maximize = passes(lower); // if the lower bound passes, we should maximize
+    let minimize = passes(upper); // if the upper bound passes, we should minimize
+    if maximize == minimize {
+        return fallible!(FailedFunction, "the decision boundary of the predicate is outside the bounds")
+    }
+
+    loop {
+        let mid = T::midpoint(lower, upper);
+        // stop when no values remain strictly between the bounds
+        if !(lower < mid && mid < upper) {
+            break
+        }
+        if passes(mid) == minimize {
+            upper = mid;
+        } else {
+            lower = mid;
+        }
+    }
+
+    // one bound always passes, the other does not. Return the passing bound
+    Ok(if minimize { upper } else { lower })
+}
+
+/// Find bounds on the decision boundary of `predicate` with an exponential search,
+/// in bands moving away from zero, first in the positive direction and then in the negative direction.
+///
+/// As in [`binary_search`], values at which `predicate` fails are considered not to pass.
+pub fn exponential_bounds_search<T: SearchParam>(
+    predicate: impl Fn(T) -> Fallible<bool>,
+) -> Fallible<(T, T)> {
+    let passes = |v: T| predicate(v).unwrap_or(false);
+    let center = T::center();
+    let at_center = passes(center);
+
+    // identify which band the decision boundary lies in, starting from `center` in the direction of `positive`
+    let signed_band_search = |positive: bool| {
+        let mut prev = center;
+        for edge in T::bands(center, positive) {
+            // looking for a change in sign that indicates the decision boundary is within this band
+            if passes(edge) != at_center {
+                return Some(if positive { (prev, edge) } else { (edge, prev) })
+            }
+            prev = edge;
+        }
+        None
+    };
+
+    if let Some(bounds) = signed_band_search(true).or_else(|| signed_band_search(false)) {
+        return Ok(bounds)
+    }
+    match predicate(center) {
+        Err(e) => fallible!(FailedFunction, "unable to infer bounds. Error at center: {}", e.message.clone().unwrap_or_else(|| e.variant.to_string())),
+        Ok(_) => fallible!(FailedFunction, "unable to infer bounds")
+    }
+}
+
+/// Find the tightest argument to `make_chain` for which the chain is (`d_in`, `d_out`)-close.
+///
+/// Arguments for which `make_chain` or the check fails are considered not to pass,
+/// so the search may extend over invalid arguments, like negative noise scales.
+///
+/// # Arguments
+/// * `make_chain` - A function from the argument to a Measurement or Transformation.
+/// * `d_in` - Desired input distance of the computation chain.
+/// * `d_out` - Desired output distance of the computation chain.
+/// * `bounds` - Lower and upper bounds on the argument. If `None`, the bounds are found by [`exponential_bounds_search`].
+///
+/// # Example
+/// ```
+/// # #[cfg(feature = "contrib")] {
+/// use opendp::domains::AllDomain;
+/// use opendp::measurements::make_base_laplace;
+/// use opendp::search::binary_search_param;
+///
+/// // the smallest noise scale that satisfies 1.0-epsilon-DP with a sensitivity of 0.1
+/// let scale = binary_search_param(|s| make_base_laplace::<AllDomain<f64>>(s, None), &0.1, &1.0, None).unwrap();
+/// assert!(make_base_laplace::<AllDomain<f64>>(scale, None).unwrap().check(&0.1, &1.0).unwrap());
+/// # }
+/// ```
+pub fn binary_search_param<T, C>(
+    make_chain: impl Fn(T) -> Fallible<C>,
+    d_in: &C::InputDistance,
+    d_out: &C::OutputDistance,
+    bounds: Option<(T, T)>,
+) -> Fallible<T>
+    where T: SearchParam, C: CheckRelation {
+    binary_search(|param| make_chain(param)?.check(d_in, d_out), bounds)
+}
+
+/// Find the computation chain built by `make_chain` with the tightest argument for which it is (`d_in`, `d_out`)-close.
+///
+/// See [`binary_search_param`] to retrieve the argument instead of the chain.
+pub fn binary_search_chain<T, C>(
+    make_chain: impl Fn(T) -> Fallible<C>,
+    d_in: &C::InputDistance,
+    d_out: &C::OutputDistance,
+    bounds: Option<(T, T)>,
+) -> Fallible<C>
+    where T: SearchParam, C: CheckRelation {
+    make_chain(binary_search_param(&make_chain, d_in, d_out, bounds)?)
+}
+
+
+#[cfg(all(test, feature="contrib"))]
+mod test {
+    use super::*;
+    use crate::combinators::{make_fix_delta, make_population_amplification};
+    use crate::domains::AllDomain;
+    use crate::measurements::{make_base_discrete_laplace, make_base_laplace, make_base_ptr};
+    use crate::metrics::{L2Distance, SymmetricDistance};
+    use crate::transformations::{make_bounded_sum, make_count_by_categories, make_sized_bounded_mean, make_sized_bounded_sum};
+
+    #[test]
+    fn test_binary_search() -> Fallible<()> {
+        assert_eq!(binary_search(|x: f64| Ok(x >= 5.), None)?, 5.);
+        assert_eq!(binary_search(|x: f64| Ok(x <= 5.), None)?, 5.);
+        assert_eq!(binary_search(|x: f64| Ok(x >= -5.), None)?, -5.);
+        assert_eq!(binary_search(|x: i32| Ok(x > 5), None)?, 6);
+        assert_eq!(binary_search(|x: i32| Ok(x < 5), None)?, 4);
+        assert_eq!(binary_search(|x: i64| Ok(x > -500_000), None)?, -499_999);
+        assert_eq!(binary_search(|x: u32| Ok(x >= 100_000), None)?, 100_000);
+        assert_eq!(binary_search(|x: usize| Ok(x > 5), Some((10, 0)))?, 6);
+
+        // the decision boundary must be within the bounds
+        assert!(binary_search(|x: f64| Ok(x >= 5.), Some((6., 10.))).is_err());
+        assert!(binary_search(|x: f64| Ok(x >= 5.), Some((f64::NAN, 10.))).is_err());
+        // constant predicates have no decision boundary
+        assert!(binary_search(|_: f64| Ok(true), None).is_err());
+        let error = binary_search(|_: u64| fallible!(FailedFunction, "always"), None).unwrap_err();
+        assert_eq!(error.message, Some("unable to infer bounds. Error at center: always".to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_binary_search_fallible() -> Fallible<()> {
+        // fails below 10, and passes from 20
+        let predicate = |x: i64| if x < 10 { fallible!(FailedFunction, "too small") } else { Ok(x >= 20) };
+        assert_eq!(exponential_bounds_search(predicate)?, (1, 1 << 16));
+        assert_eq!(binary_search(predicate, None)?, 20);
+
+        // fails below 10, and passes everywhere else, so the failure edge is the decision boundary
+        assert_eq!(binary_search(|x: f64| if x < 10. { fallible!(FailedFunction) } else { Ok(true) }, None)?, 10.);
+
+        // the failure edge is found in the negative direction
+        assert_eq!(binary_search(|x: i32| if x > -1000 { fallible!(FailedFunction) } else { Ok(x <= -2000) }, None)?, -2000);
+        Ok(())
+    }
+
+    #[test]
+    fn test_binary_search_scale() -> Fallible<()> {
+        // noise scale of the laplace mechanism
+        let scale = binary_search_param(|s| make_base_laplace::<AllDomain<f64>>(s, None), &0.1, &1., None)?;
+        assert!((scale - 0.1).abs() < 1e-6, "{}", scale);
+        assert!(make_base_laplace::<AllDomain<f64>>(scale, None)?.check(&0.1, &1.)?);
+
+        // noise scale of a mean
+        let make_mean = |s| make_sized_bounded_mean::<SymmetricDistance, f64>(10, (0., 1.))?
+            >> make_base_laplace(s, None)?;
+        let chain = binary_search_chain(make_mean, &2, &1., None)?;
+        assert!(chain.check(&2, &1.)?);
+
+        // widest clamping bounds for a sum with fixed noise
+        let make_sum = |b: i64| make_sized_bounded_sum::<SymmetricDistance, i64>(10_000, (-b, b))?
+            >> make_base_discrete_laplace::<AllDomain<i64>, f64>(100.)?;
+        let b = binary_search_param(make_sum, &2, &1., Some((0, 10_000)))?;
+        assert_eq!(b, 50);
+        assert!(make_sum(b)?.check(&2, &1.)?);
+        assert!(!make_sum(b + 1)?.check(&2, &1.)?);
+
+        // widest bounds for a sum with a sensitivity of at most 100, from a transformation
+        let make_bounded = |b: f64| make_bounded_sum::<SymmetricDistance, f64>((-b, b));
+        let b = binary_search_param(make_bounded, &1, &100., None)?;
+        assert!(49.9 < b && b < 50., "{}", b);
+        assert!(make_bounded(b)?.check(&1, &100.)?);
+
+        // sensitivity of a histogram
+        let histogram = make_count_by_categories::<L2Distance<i32>, Vec<String>, i32>(vec!["a".to_string()], true)?;
+        assert_eq!(binary_search(|d_out: i32| histogram.check(&3, &d_out), Some((0, 100)))?, 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_binary_search_ptr_threshold() -> Fallible<()> {
+        let (scale, d_in, delta) = (1., 1., 1e-6);
+        let make_ptr = |threshold| make_fix_delta(&make_base_ptr::<String, f64>(scale, threshold, None)?, delta);
+        // the map fails for thresholds that are too small
+        let threshold = binary_search_param(make_ptr, &d_in, &(2., delta), None)?;
+        assert!(make_ptr(threshold)?.check(&d_in, &(2., delta))?);
+        assert!(make_ptr(threshold - 1e-6)?.check(&d_in, &(2., delta)).map_or(true, |pass| !pass));
+        Ok(())
+    }
+
+    #[test]
+    fn test_binary_search_population_amplification() -> Fallible<()> {
+        let meas = (make_sized_bounded_mean::<SymmetricDistance, f64>(10, (0., 10.))?
+            >> make_base_laplace(0.5, None)?)?;
+        let amplified_epsilon = 0.1;
+        assert!(!meas.check(&2, &amplified_epsilon)?);
+
+        // smallest population that amplifies the mean to the desired epsilon.
+        // populations smaller than the sample fail
+        let population_size = binary_search_param(
+            |n| make_population_amplification(&meas, n), &2, &amplified_epsilon, None)?;
+        assert!(make_population_amplification(&meas, population_size)?.check(&2, &amplified_epsilon)?);
+        assert!(!make_population_amplification(&meas, population_size - 1)?.check(&2, &amplified_epsilon)?);
+        Ok(())
+    }
+}
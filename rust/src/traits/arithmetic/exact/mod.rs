@@ -0,0 +1,467 @@
+//! Exact arithmetic over big rationals, for directed rounding without MPFR.
+//!
+//! Field operations and square roots are computed exactly.
+//! Transcendental functions are enclosed between two rationals by series summed in fixed point,
+//! where the rounding error of each operation and the truncated tail are bounded,
+//! carried to `PRECISION` bits, so that rounding the bounds outward is always sound.
+
+use std::cmp::Ordering;
+
+use num::traits::Pow;
+use num::{BigInt, BigRational, Integer, One, Signed, ToPrimitive, Zero};
+
+use crate::error::ExplainUnwrap;
+
+/// Bits of working precision in the enclosures of transcendental functions.
+const PRECISION: u64 = 160;
+
+/// Direction to round an exact value onto a float.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub(crate) enum Round {
+    /// towards positive infinity
+    Up,
+    /// towards negative infinity
+    Down,
+    /// to the nearest float, ties to even
+    Nearest,
+}
+
+/// An interval `(lower, upper)` that contains the exact result.
+pub(crate) type Enclosure = (BigRational, BigRational);
+
+/// Conversions between floats and big rationals.
+pub(crate) trait RoundRational: Sized {
+    /// Round the rational `v` onto Self in the direction of `round`.
+    /// Values beyond the range of Self saturate to infinity, or to the greatest finite value when rounding towards zero.
+    fn from_rational_round(v: &BigRational, round: Round) -> Self;
+    /// The exact value of `self`, if finite.
+    fn exact_rational(self) -> Option<BigRational>;
+}
+
+macro_rules! impl_round_rational {
+    ($($ty:ty),+) => ($(impl RoundRational for $ty {
+        fn from_rational_round(v: &BigRational, round: Round) -> Self {
+            if v.is_zero() {
+                return 0.0;
+            }
+            let negative = v.is_negative();
+            // whether the magnitude is rounded away from zero
+            let away = match round {
+                Round::Up => Some(!negative),
+                Round::Down => Some(negative),
+                Round::Nearest => None,
+            };
+            let (numer, denom) = (v.numer().abs(), v.denom().abs());
+            let exp = floor_log2(&numer, &denom);
+
+            let magnitude = if exp >= <$ty>::MAX_EXP as i64 {
+                if away == Some(false) { <$ty>::MAX } else { <$ty>::INFINITY }
+            } else {
+                // exponent of the least significant bit of the mantissa, accounting for subnormals
+                let lsb = (exp - (<$ty>::MANTISSA_DIGITS as i64 - 1))
+                    .max((<$ty>::MIN_EXP - <$ty>::MANTISSA_DIGITS as i32) as i64);
+                let (numer, denom) = if lsb >= 0 {
+                    (numer, denom << lsb as usize)
+                } else {
+                    (numer << (-lsb) as usize, denom)
+                };
+                let (mut mantissa, rem) = numer.div_rem(&denom);
+                let carry = match away {
+                    Some(away) => away && !rem.is_zero(),
+                    None => match (rem << 1usize).cmp(&denom) {
+                        Ordering::Greater => true,
+                        Ordering::Equal => mantissa.is_odd(),
+                        Ordering::Less => false,
+                    },
+                };
+                if carry {
+                    mantissa += 1;
+                }
+                let mut magnitude = mantissa.to_u64()
+                    .unwrap_assert("the mantissa has at most MANTISSA_DIGITS + 1 bits") as $ty;
+                // scale in steps small enough that every intermediate value is exact
+                let mut lsb = lsb;
+                while lsb != 0 {
+                    let step = lsb.clamp(-60, 60);
+                    magnitude *= <$ty>::powi(2.0, step as i32);
+                    lsb -= step;
+                }
+                magnitude
+            };
+            if negative { -magnitude } else { magnitude }
+        }
+        fn exact_rational(self) -> Option<BigRational> {
+            BigRational::from_float(self)
+        }
+    })+)
+}
+impl_round_rational!(f32, f64);
+
+/// floor(log2(numer / denom)), for positive `numer` and `denom`.
+fn floor_log2(numer: &BigInt, denom: &BigInt) -> i64 {
+    let exp = numer.bits() as i64 - denom.bits() as i64;
+    // numer / denom lies in (2^(exp - 1), 2^(exp + 1))
+    let below = if exp >= 0 {
+        numer < &(denom << exp as usize)
+    } else {
+        &(numer << (-exp) as usize) < denom
+    };
+    if below { exp - 1 } else { exp }
+}
+
+/// exactly 2^exp
+fn pow2(exp: i64) -> BigRational {
+    if exp >= 0 {
+        BigRational::from_integer(BigInt::one() << exp as usize)
+    } else {
+        BigRational::new_raw(BigInt::one(), BigInt::one() << (-exp) as usize)
+    }
+}
+
+fn integer(v: i64) -> BigRational {
+    BigRational::from_integer(BigInt::from(v))
+}
+
+/// Round `v` to `PRECISION` significant bits, towards positive infinity if `up`, else towards negative infinity.
+fn round_precision(v: BigRational, up: bool) -> BigRational {
+    if v.is_zero() {
+        return v;
+    }
+    let lsb = floor_log2(&v.numer().abs(), v.denom()) - PRECISION as i64;
+    let scaled = v * pow2(-lsb);
+    let mantissa = if up { scaled.ceil() } else { scaled.floor() };
+    mantissa * pow2(lsb)
+}
+
+/// Round the bounds of an enclosure outward to `PRECISION` significant bits.
+fn widen(lower: BigRational, upper: BigRational) -> Enclosure {
+    (round_precision(lower, false), round_precision(upper, true))
+}
+
+/// floor(x * 2^w), the fixed-point approximation of `x` with `w` fractional bits.
+fn to_fixed(x: &BigRational, w: u64) -> BigInt {
+    (x.numer() << w as usize).div_floor(x.denom())
+}
+
+/// Enclosure of `value * 2^-w`, where `value` is off by at most `error` units in the last place.
+fn from_fixed(value: BigInt, error: u64, w: u64) -> Enclosure {
+    let denom = BigInt::one() << w as usize;
+    (BigRational::new(&value - error, denom.clone()), BigRational::new(value + error, denom))
+}
+
+/// Enclosure of atanh(t), for |t| <= 1/3.
+fn atanh(t: &BigRational) -> Enclosure {
+    if t.is_zero() {
+        return (BigRational::zero(), BigRational::zero());
+    }
+    // carry PRECISION bits past the leading bit of t, so that precision is relative to the result
+    let w = (PRECISION as i64 + 16 - floor_log2(&t.numer().abs(), t.denom())) as u64;
+    let (numer2, denom2) = (t.numer() * t.numer(), t.denom() * t.denom());
+
+    // sum the odd powers t^(2k + 1) / (2k + 1), truncating each operation towards zero
+    let mut power = to_fixed(t, w);
+    let (mut sum, mut k) = (power.clone(), 1u64);
+    loop {
+        power = power * &numer2 / &denom2;
+        if power.is_zero() {
+            break;
+        }
+        sum += &power / (2 * k + 1);
+        k += 1;
+    }
+    // since t^2 <= 1/9, each power is off by at most 9/8 ulps, and each term by at most 3 ulps.
+    // Once a power truncates to zero, the tail is also at most 3 ulps
+    from_fixed(sum, 3 * k + 3, w)
+}
+
+/// Enclosure of ln(2).
+fn ln2() -> Enclosure {
+    // ln(2) = 2 atanh(1/3)
+    let (lower, upper) = atanh(&BigRational::new(BigInt::one(), BigInt::from(3)));
+    (lower * integer(2), upper * integer(2))
+}
+
+/// Split positive `x` into `(exp, mantissa)`, where `x = mantissa * 2^exp` and mantissa is in [2/3, 4/3).
+fn split_exponent(x: &BigRational) -> (i64, BigRational) {
+    let mut exp = floor_log2(x.numer(), x.denom());
+    let mut mantissa = x * pow2(-exp);
+    if mantissa >= BigRational::new(BigInt::from(4), BigInt::from(3)) {
+        mantissa /= integer(2);
+        exp += 1;
+    }
+    (exp, mantissa)
+}
+
+/// Enclosure of ln(mantissa), for mantissa in [2/3, 4/3).
+fn ln_mantissa(mantissa: &BigRational) -> Enclosure {
+    // ln(m) = 2 atanh((m - 1) / (m + 1)), where |(m - 1) / (m + 1)| <= 1/5
+    let one = BigRational::one();
+    let (lower, upper) = atanh(&((mantissa - &one) / (mantissa + &one)));
+    (lower * integer(2), upper * integer(2))
+}
+
+pub(crate) fn ln(x: &BigRational) -> Option<Enclosure> {
+    if !x.is_positive() {
+        return None;
+    }
+    let (exp, mantissa) = split_exponent(x);
+    let (lower, upper) = ln_mantissa(&mantissa);
+    if exp == 0 {
+        return Some(widen(lower, upper));
+    }
+    // ln(x) = ln(m) + exp * ln(2)
+    let (ln2_lower, ln2_upper) = ln2();
+    let exp = integer(exp);
+    let (offset_lower, offset_upper) = if exp.is_positive() {
+        (&exp * ln2_lower, exp * ln2_upper)
+    } else {
+        (&exp * ln2_upper, exp * ln2_lower)
+    };
+    Some(widen(lower + offset_lower, upper + offset_upper))
+}
+
+pub(crate) fn log2(x: &BigRational) -> Option<Enclosure> {
+    if !x.is_positive() {
+        return None;
+    }
+    let (exp, mantissa) = split_exponent(x);
+    if mantissa.is_one() {
+        // powers of two are exact
+        return Some((integer(exp), integer(exp)));
+    }
+    // log2(x) = ln(m) / ln(2) + exp
+    let (lower, upper) = ln_mantissa(&mantissa);
+    let (ln2_lower, ln2_upper) = ln2();
+    let quotients = [&lower / &ln2_lower, &lower / &ln2_upper, &upper / &ln2_lower, &upper / &ln2_upper];
+    let min = quotients.iter().min().unwrap_assert("quotients is non-empty").clone();
+    let max = quotients.iter().max().unwrap_assert("quotients is non-empty").clone();
+    let exp = integer(exp);
+    Some(widen(min + &exp, max + exp))
+}
+
+pub(crate) fn ln_1p(x: &BigRational) -> Option<Enclosure> {
+    let one = BigRational::one();
+    if x <= &-&one {
+        return None;
+    }
+    if x.abs() * integer(2) > one {
+        return ln(&(x + one));
+    }
+    // ln(1 + x) = 2 atanh(x / (2 + x)), where |x / (2 + x)| <= 1/3
+    let (lower, upper) = atanh(&(x / (x + integer(2))));
+    Some(widen(lower * integer(2), upper * integer(2)))
+}
+
+pub(crate) fn exp(x: &BigRational) -> Option<Enclosure> {
+    // exp(x) saturates every float type beyond these bounds
+    if x > &integer(710) {
+        return Some((pow2(1024), pow2(1024)));
+    }
+    if x < &integer(-800) {
+        return Some((BigRational::zero(), pow2(-1100)));
+    }
+
+    if x.is_zero() {
+        return Some((BigRational::one(), BigRational::one()));
+    }
+
+    // exp(x) = exp(x / 2^s)^(2^s), where |x / 2^s| <= 1/2
+    let s = (floor_log2(&x.numer().abs(), x.denom()) + 2).max(0);
+    let (lower, upper) = exp_series(&(x * pow2(-s)), 0);
+    let (mut lower, mut upper) = widen(BigRational::one() + lower, BigRational::one() + upper);
+    for _ in 0..s {
+        lower = round_precision(&lower * &lower, false);
+        upper = round_precision(&upper * &upper, true);
+    }
+    Some((lower, upper))
+}
+
+/// Enclosure of exp(r) - 1, for |r| <= 1/2, with `w` more fractional bits than `PRECISION`.
+fn exp_series(r: &BigRational, w: u64) -> Enclosure {
+    let w = PRECISION + 16 + w;
+    let (numer, denom) = (r.numer(), r.denom());
+
+    // sum the powers r^i / i!, truncating each operation towards zero
+    let mut term = to_fixed(r, w);
+    let (mut sum, mut i) = (term.clone(), 2u64);
+    loop {
+        term = term * numer / (denom * i);
+        if term.is_zero() {
+            break;
+        }
+        sum += &term;
+        i += 1;
+    }
+    // since |r| <= 1/2, each term is off by at most 2 ulps.
+    // Once a term truncates to zero, the tail is at most 6 ulps
+    from_fixed(sum, 2 * i + 6, w)
+}
+
+pub(crate) fn exp_m1(x: &BigRational) -> Option<Enclosure> {
+    let one = BigRational::one();
+    if x.abs() * integer(2) >= one {
+        let (lower, upper) = exp(x)?;
+        return Some((lower - &one, upper - one));
+    }
+    if x.is_zero() {
+        return Some((BigRational::zero(), BigRational::zero()));
+    }
+    // sum the series directly, with precision relative to x, to keep relative precision near zero
+    let w = -floor_log2(&x.numer().abs(), x.denom()) as u64;
+    let (lower, upper) = exp_series(x, w);
+    Some(widen(lower, upper))
+}
+
+pub(crate) fn sqrt(x: &BigRational) -> Option<Enclosure> {
+    if x.is_negative() {
+        return None;
+    }
+    // sqrt(n / d) = sqrt(n * d) / d, scaled so that the integer root carries at least PRECISION bits
+    let radicand = x.numer() * x.denom();
+    let shift = (PRECISION.saturating_sub(radicand.bits() / 2) + 1) as usize;
+    let radicand = radicand << (2 * shift);
+    let root = radicand.sqrt();
+    let denom = x.denom() << shift;
+    let lower = BigRational::new(root.clone(), denom.clone());
+    if &root * &root == radicand {
+        return Some((lower.clone(), lower));
+    }
+    Some((lower, BigRational::new(root + 1, denom)))
+}
+
+pub(crate) fn add(x: &BigRational, y: &BigRational) -> Option<Enclosure> {
+    let v = x + y;
+    Some((v.clone(), v))
+}
+
+pub(crate) fn sub(x: &BigRational, y: &BigRational) -> Option<Enclosure> {
+    let v = x - y;
+    Some((v.clone(), v))
+}
+
+pub(crate) fn mul(x: &BigRational, y: &BigRational) -> Option<Enclosure> {
+    let v = x * y;
+    Some((v.clone(), v))
+}
+
+pub(crate) fn div(x: &BigRational, y: &BigRational) -> Option<Enclosure> {
+    if y.is_zero() {
+        return None;
+    }
+    let v = x / y;
+    Some((v.clone(), v))
+}
+
+pub(crate) fn pow(x: &BigRational, y: &BigRational) -> Option<Enclosure> {
+    if y.is_zero() {
+        return Some((BigRational::one(), BigRational::one()));
+    }
+    if x.is_zero() {
+        return y.is_positive().then(|| (BigRational::zero(), BigRational::zero()));
+    }
+    // integer powers are exact, so long as the result is of moderate size
+    if let Some(power) = y.is_integer().then(|| y.to_integer().to_i32()).flatten() {
+        let bits = x.numer().bits().max(x.denom().bits());
+        if u64::from(power.unsigned_abs()) * bits <= 1 << 16 {
+            let v = Pow::pow(x, power);
+            return Some((v.clone(), v));
+        }
+    }
+    // negative bases only have real powers at integer exponents
+    if x.is_negative() && !y.is_integer() {
+        return None;
+    }
+
+    // |x|^y = exp(y * ln(|x|))
+    let (lower, upper) = ln(&x.abs())?;
+    let (lower, upper) = if y.is_positive() {
+        (y * lower, y * upper)
+    } else {
+        (y * upper, y * lower)
+    };
+    let (lower, upper) = widen(lower, upper);
+    let (lower, upper) = (exp(&lower)?.0, exp(&upper)?.1);
+
+    if x.is_negative() && y.to_integer().is_odd() {
+        Some((-upper, -lower))
+    } else {
+        Some((lower, upper))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn rational(v: f64) -> BigRational {
+        v.exact_rational().unwrap_test()
+    }
+
+    fn bounds(enclosure: Option<Enclosure>) -> (f64, f64) {
+        let (lower, upper) = enclosure.unwrap_test();
+        (f64::from_rational_round(&lower, Round::Down), f64::from_rational_round(&upper, Round::Up))
+    }
+
+    #[test]
+    fn test_round_rational() {
+        let third = BigRational::new(BigInt::one(), BigInt::from(3));
+        let (down, up) = (f64::from_rational_round(&third, Round::Down), f64::from_rational_round(&third, Round::Up));
+        assert_eq!(f64::from_rational_round(&third, Round::Nearest), 1. / 3.);
+        assert!(down < up);
+        assert_eq!(f64::from_bits(down.to_bits() + 1), up);
+        assert_eq!(f64::from_rational_round(&-&third, Round::Up), -down);
+
+        // exact values round to themselves
+        for v in [1.5, -0.1, f64::MAX, f64::MIN_POSITIVE, f64::from_bits(1), 1e-310] {
+            for round in [Round::Up, Round::Down, Round::Nearest] {
+                assert_eq!(f64::from_rational_round(&rational(v), round), v);
+            }
+        }
+        assert_eq!(f32::from_rational_round(&rational(0.1), Round::Nearest), 0.1f32);
+
+        // subnormals
+        let tiny = pow2(-1080);
+        assert_eq!(f64::from_rational_round(&tiny, Round::Up), f64::from_bits(1));
+        assert_eq!(f64::from_rational_round(&tiny, Round::Down), 0.);
+        assert_eq!(f32::from_rational_round(&pow2(-149), Round::Nearest), f32::from_bits(1));
+
+        // overflow
+        let huge = rational(f64::MAX) * integer(2);
+        assert_eq!(f64::from_rational_round(&huge, Round::Up), f64::INFINITY);
+        assert_eq!(f64::from_rational_round(&huge, Round::Down), f64::MAX);
+        assert_eq!(f64::from_rational_round(&-huge, Round::Up), f64::MIN);
+        assert_eq!(f32::from_rational_round(&rational(f64::MAX), Round::Nearest), f32::INFINITY);
+    }
+
+    #[test]
+    fn test_transcendental_enclosures() {
+        let check = |(lower, upper): (f64, f64), expected: f64| {
+            assert!(lower <= expected && expected <= upper, "{} <= {} <= {}", lower, expected, upper);
+            // enclosures are at most one ulp wide
+            assert!((upper.to_bits() as i64 - lower.to_bits() as i64).abs() <= 1, "{} {}", lower, upper);
+        };
+        check(bounds(ln(&integer(2))), std::f64::consts::LN_2);
+        check(bounds(ln(&rational(10.))), std::f64::consts::LN_10);
+        check(bounds(exp(&integer(1))), std::f64::consts::E);
+        check(bounds(exp(&integer(-1))), 1. / std::f64::consts::E);
+        check(bounds(sqrt(&integer(2))), std::f64::consts::SQRT_2);
+        check(bounds(log2(&integer(10))), std::f64::consts::LOG2_10);
+        check(bounds(ln_1p(&rational(1e-20))), 1e-20);
+        check(bounds(exp_m1(&rational(-1e-20))), -1e-20);
+        check(bounds(pow(&integer(2), &rational(0.5))), std::f64::consts::SQRT_2);
+
+        // exact cases
+        assert_eq!(bounds(log2(&integer(1024))), (10., 10.));
+        assert_eq!(bounds(sqrt(&rational(2.25))), (1.5, 1.5));
+        assert_eq!(bounds(pow(&rational(-1.5), &integer(3))), (-3.375, -3.375));
+        assert_eq!(bounds(pow(&integer(2), &integer(-1074))), (f64::from_bits(1), f64::from_bits(1)));
+        assert_eq!(bounds(exp(&integer(0))), (1., 1.));
+
+        // saturation and domain errors
+        assert_eq!(bounds(exp(&integer(1000))), (f64::MAX, f64::INFINITY));
+        assert_eq!(bounds(exp(&integer(-1000))), (0., f64::from_bits(1)));
+        assert!(ln(&integer(0)).is_none());
+        assert!(sqrt(&integer(-1)).is_none());
+        assert!(pow(&integer(-2), &rational(0.5)).is_none());
+    }
+}
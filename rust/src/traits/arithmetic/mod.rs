@@ -6,6 +6,12 @@ use crate::error::Fallible;
 #[cfg(feature="use-mpfr")]
 use crate::traits::CastInternalReal;
 
+// without MPFR, directed rounding is computed over big rationals
+#[cfg(not(feature = "use-mpfr"))]
+pub(crate) mod exact;
+#[cfg(not(feature = "use-mpfr"))]
+use exact::{Round, RoundRational};
+
 /// Computes the absolute value and returns an error if overflowing.
 pub trait AlertingAbs: Sized {
     fn alerting_abs(&self) -> Fallible<Self>;
@@ -303,7 +309,7 @@ impl_alerting_float!(f32, f64);
 
 // TRAIT InfSqrt, InfLn, InfExp (univariate)
 macro_rules! impl_float_inf_uni {
-    ($($ty:ty),+; $name:ident, $method_inf:ident, $method_neg_inf:ident, $op:ident, $fallback:ident) => {
+    ($($ty:ty),+; $name:ident, $method_inf:ident, $method_neg_inf:ident, $op:ident, $exact:ident) => {
         $(
         #[cfg(feature="use-mpfr")]
         impl $name for $ty {
@@ -331,15 +337,17 @@ macro_rules! impl_float_inf_uni {
         #[cfg(not(feature="use-mpfr"))]
         impl $name for $ty {
             fn $method_inf(self) -> Fallible<Self> {
-                let this = self.$fallback();
-                this.is_finite().then(|| this).ok_or_else(|| err!(
+                let this = self.exact_rational().and_then(|v| exact::$exact(&v))
+                    .map(|(_, upper)| Self::from_rational_round(&upper, Round::Up));
+                this.filter(|v| v.is_finite()).ok_or_else(|| err!(
                     FailedFunction,
                     concat!("({}).", stringify!($method_inf), "() is not finite. Consider tightening your parameters."),
                     self))
             }
             fn $method_neg_inf(self) -> Fallible<Self> {
-                let this = self.$fallback();
-                this.is_finite().then(|| this).ok_or_else(|| err!(
+                let this = self.exact_rational().and_then(|v| exact::$exact(&v))
+                    .map(|(lower, _)| Self::from_rational_round(&lower, Round::Down));
+                this.filter(|v| v.is_finite()).ok_or_else(|| err!(
                     FailedFunction,
                     concat!("({}).", stringify!($method_neg_inf), "() is not finite. Consider tightening your parameters."),
                     self))
@@ -389,7 +397,7 @@ macro_rules! impl_int_inf {
 impl_int_inf!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
 
 macro_rules! impl_float_inf_bi {
-    ($($ty:ty),+; $name:ident, $method_inf:ident, $method_neg_inf:ident, $op:ident, $fallback:ident) => {
+    ($($ty:ty),+; $name:ident, $method_inf:ident, $method_neg_inf:ident, $op:ident, $exact:ident) => {
         $(
         #[cfg(feature="use-mpfr")]
         impl $name for $ty {
@@ -417,15 +425,19 @@ macro_rules! impl_float_inf_bi {
         #[cfg(not(feature="use-mpfr"))]
         impl $name for $ty {
             fn $method_inf(&self, other: &Self) -> Fallible<Self> {
-                let this = self.$fallback(other)?;
-                this.is_finite().then(|| this).ok_or_else(|| err!(
+                let this = self.exact_rational().zip(other.exact_rational())
+                    .and_then(|(x, y)| exact::$exact(&x, &y))
+                    .map(|(_, upper)| Self::from_rational_round(&upper, Round::Up));
+                this.filter(|v| v.is_finite()).ok_or_else(|| err!(
                     FailedFunction,
                     concat!("({}).", stringify!($method_inf), "({}) is not finite. Consider tightening your parameters."),
                     self, other))
             }
             fn $method_neg_inf(&self, other: &Self) -> Fallible<Self> {
-                let this = self.$fallback(other)?;
-                this.is_finite().then(|| this).ok_or_else(|| err!(
+                let this = self.exact_rational().zip(other.exact_rational())
+                    .and_then(|(x, y)| exact::$exact(&x, &y))
+                    .map(|(lower, _)| Self::from_rational_round(&lower, Round::Down));
+                this.filter(|v| v.is_finite()).ok_or_else(|| err!(
                     FailedFunction,
                     concat!("({}).", stringify!($method_neg_inf), "({}) is not finite. Consider tightening your parameters."),
                     self, other))
@@ -433,8 +445,8 @@ macro_rules! impl_float_inf_bi {
         })+
     }
 }
-impl_float_inf_bi!(f64, f32; InfAdd, inf_add, neg_inf_add, add_assign_round, alerting_add);
-impl_float_inf_bi!(f64, f32; InfSub, inf_sub, neg_inf_sub, sub_assign_round, alerting_sub);
-impl_float_inf_bi!(f64, f32; InfMul, inf_mul, neg_inf_mul, mul_assign_round, alerting_mul);
-impl_float_inf_bi!(f64, f32; InfDiv, inf_div, neg_inf_div, div_assign_round, alerting_div);
-impl_float_inf_bi!(f64, f32; InfPow, inf_pow, neg_inf_pow, pow_assign_round, alerting_pow);
+impl_float_inf_bi!(f64, f32; InfAdd, inf_add, neg_inf_add, add_assign_round, add);
+impl_float_inf_bi!(f64, f32; InfSub, inf_sub, neg_inf_sub, sub_assign_round, sub);
+impl_float_inf_bi!(f64, f32; InfMul, inf_mul, neg_inf_mul, mul_assign_round, mul);
+impl_float_inf_bi!(f64, f32; InfDiv, inf_div, neg_inf_div, div_assign_round, div);
+impl_float_inf_bi!(f64, f32; InfPow, inf_pow, neg_inf_pow, pow_assign_round, pow);
@@ -191,7 +191,9 @@ macro_rules! impl_inf_cast_int_float {
         #[cfg(not(feature="use-mpfr"))]
         impl InfCast<$int> for $float {
             fn inf_cast(v_int: $int) -> Fallible<Self> {
-                <$float>::round_cast(v_int)
+                use crate::traits::arithmetic::exact::{Round, RoundRational};
+                let rational = num::BigRational::from_integer(num::BigInt::from(v_int));
+                Ok(<$float>::from_rational_round(&rational, Round::Up))
             }
         }
     )
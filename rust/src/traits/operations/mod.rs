@@ -89,6 +89,7 @@ macro_rules! impl_check_null_for_non_nullable {
     }
 }
 impl_check_null_for_non_nullable!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, bool, String, &str, char, usize, isize);
+impl_check_null_for_non_nullable!(chrono::NaiveDate, chrono::NaiveDateTime);
 impl<T: CheckNull> CheckNull for Option<T> {
     #[inline]
     fn is_null(&self) -> bool {
@@ -126,6 +127,7 @@ macro_rules! impl_total_ord_for_ord {
     })*}
 }
 impl_total_ord_for_ord!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_total_ord_for_ord!(chrono::NaiveDate, chrono::NaiveDateTime);
 
 #[cfg(feature="use-mpfr")]
 impl_total_ord_for_ord!(rug::Rational, rug::Integer);
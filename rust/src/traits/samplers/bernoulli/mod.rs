@@ -7,7 +7,6 @@ use crate::{
 
 use super::{fill_bytes, sample_geometric_buffer};
 
-#[cfg(feature = "use-mpfr")]
 use super::SampleUniformIntBelow;
 
 pub trait SampleStandardBernoulli: Sized {
@@ -145,14 +144,24 @@ where
 #[cfg(feature = "use-mpfr")]
 impl SampleBernoulli<rug::Rational> for bool {
     fn sample_bernoulli(prob: rug::Rational, constant_time: bool) -> Fallible<bool> {
-        if constant_time {
-            return fallible!(
-                FailedFunction,
-                "constant-time uniform sampling of rationals is not implemented"
-            );
-        }
         let (numer, denom) = prob.into_numer_denom();
-        rug::Integer::sample_uniform_int_below(denom).map(|s| s < numer)
+        if constant_time {
+            rug::Integer::sample_uniform_int_below_fixed_iteration(denom)
+        } else {
+            rug::Integer::sample_uniform_int_below(denom)
+        }.map(|s| s < numer)
+    }
+}
+
+#[cfg(not(feature = "use-mpfr"))]
+impl SampleBernoulli<num::BigRational> for bool {
+    fn sample_bernoulli(prob: num::BigRational, constant_time: bool) -> Fallible<bool> {
+        let denom = prob.denom().clone();
+        if constant_time {
+            num::BigInt::sample_uniform_int_below_fixed_iteration(denom)
+        } else {
+            num::BigInt::sample_uniform_int_below(denom)
+        }.map(|s| &s < prob.numer())
     }
 }
 
@@ -48,7 +48,10 @@ use crate::error::Fallible;
 
 use num::{Zero, One};
 
-use super::{SampleUniformIntBelow, SampleStandardBernoulli};
+use super::{
+    SampleUniformIntBelow, SampleStandardBernoulli, FIXED_EXP1_TRIALS, FIXED_EXP_WHOLE_TRIALS,
+    FIXED_GAUSSIAN_PROPOSALS, FIXED_GEOMETRIC_PROPOSALS, FIXED_GEOMETRIC_TRIALS,
+};
 
 
 // sample from a Bernoulli(exp(-x)) distribution
@@ -66,7 +69,7 @@ fn sample_bernoulli_exp1(x: Rational) -> Fallible<bool> {
 
 // sample from a Bernoulli(exp(-x)) distribution
 // assumes x is a rational number >=0
-fn sample_bernoulli_exp(mut x: Rational) -> Fallible<bool> {
+pub(super) fn sample_bernoulli_exp(mut x: Rational) -> Fallible<bool> {
     // Sample floor(x) independent Bernoulli(exp(-1))
     // If all are 1, return Bernoulli(exp(-(x-floor(x))))
     while x > 1 {
@@ -94,7 +97,7 @@ fn sample_geometric_exp_slow(x: Rational) -> Fallible<Integer> {
 
 // sample from a geometric(1-exp(-x)) distribution
 // assumes x >= 0 rational
-fn sample_geometric_exp_fast(x: Rational) -> Fallible<Integer> {
+pub(super) fn sample_geometric_exp_fast(x: Rational) -> Fallible<Integer> {
     if x.is_zero() {
         return Ok(0.into());
     }
@@ -108,38 +111,201 @@ fn sample_geometric_exp_fast(x: Rational) -> Fallible<Integer> {
     Ok((v2 * denom + u) / numer)
 }
 
-pub fn sample_discrete_laplace(scale: Rational) -> Fallible<Integer> {
-    if scale.is_zero() {
-        return Ok(0.into())
+// The fixed-iteration samplers below run every loop for a fixed number of iterations,
+// and take a fixed number of uniform draws, regardless of the outcome.
+// Only these counts are fixed: the big-number arithmetic and the branches on the outcome take variable time.
+// Once the outcome of a loop is known, the remaining iterations are dummies.
+// Each loop fails if it runs out of iterations. See the bounds in the parent module.
+
+fn ran_out(name: &str) -> crate::error::Error {
+    err!(FailedFunction, "fixed-iteration {} sampler ran out of iterations", name)
+}
+
+// sample from a Bernoulli(exp(-x)) distribution with a fixed number of iterations
+// assumes x is a rational number in [0,1]
+fn sample_bernoulli_exp1_fixed(x: &Rational) -> Fallible<bool> {
+    let mut outcome = None;
+    for k in 1..=FIXED_EXP1_TRIALS {
+        let success = bool::sample_bernoulli(x.clone() / Integer::from(k), true)?;
+        if !success && outcome.is_none() {
+            outcome = Some(k % 2 == 1);
+        }
     }
-    let inv_scale = scale.recip();
-    
-    loop {
-        let positive = bool::sample_standard_bernoulli()?;
-        let magnitude = sample_geometric_exp_fast(inv_scale.clone())?;
-        if positive || !magnitude.is_zero() {
-            return Ok(if positive {
-                magnitude
-            } else {
-                -magnitude
-            })
+    outcome.ok_or_else(|| ran_out("bernoulli"))
+}
+
+// sample from a Bernoulli(exp(-x)) distribution with a fixed number of iterations
+// assumes x is a rational number >=0
+fn sample_bernoulli_exp_fixed(x: Rational) -> Fallible<bool> {
+    // Sample a fixed number of independent Bernoulli(exp(-1)), and keep the first floor(x)
+    // If x exceeds the number of trials, exp(-x) is rounded down to zero
+    let (fract, whole) = x.fract_floor(Integer::new());
+    let one = Rational::one();
+    let mut outcome = whole <= FIXED_EXP_WHOLE_TRIALS;
+    for i in 0..FIXED_EXP_WHOLE_TRIALS {
+        let success = sample_bernoulli_exp1_fixed(&one)?;
+        if whole > i {
+            outcome &= success;
         }
     }
+    Ok(sample_bernoulli_exp1_fixed(&fract)? && outcome)
 }
 
+// sample from a geometric(1-exp(-1)) distribution with a fixed number of iterations
+fn sample_geometric_exp_slow_fixed() -> Fallible<Integer> {
+    let one = Rational::one();
+    let mut outcome = None;
+    for k in 0..FIXED_GEOMETRIC_TRIALS {
+        let success = sample_bernoulli_exp1_fixed(&one)?;
+        if !success && outcome.is_none() {
+            outcome = Some(Integer::from(k));
+        }
+    }
+    outcome.ok_or_else(|| ran_out("geometric"))
+}
 
-pub fn sample_discrete_gaussian(scale: Rational) -> Fallible<Integer> {
-    if scale.is_zero() {
-        return Ok(0.into())
+// sample from a geometric(1-exp(-x)) distribution with a fixed number of iterations
+// assumes x >= 0 rational
+fn sample_geometric_exp_fast_fixed(x: Rational) -> Fallible<Integer> {
+    if x.is_zero() {
+        return Ok(0.into());
     }
-    let t = scale.clone().floor() + 1i8;
-    let sigma2 = scale.square();
-    loop {
-        let candidate = sample_discrete_laplace(t.clone())?;
-        let x = candidate.clone().abs() - sigma2.clone() / &t;
-        let bias = x.square() / (2 * sigma2.clone());
-        if sample_bernoulli_exp(bias)? {
-            return Ok(candidate);
+
+    let (numer, denom) = x.into_numer_denom();
+    let mut u = None;
+    for _ in 0..FIXED_GEOMETRIC_PROPOSALS {
+        // u / denom < 1, so each proposal is accepted with probability at least exp(-1)
+        let proposal = Integer::sample_uniform_int_below_fixed_iteration(denom.clone())?;
+        let accept = sample_bernoulli_exp1_fixed(&Rational::from((proposal.clone(), denom.clone())))?;
+        if accept && u.is_none() {
+            u = Some(proposal);
         }
     }
+    let u = u.ok_or_else(|| ran_out("geometric"))?;
+    let v2 = sample_geometric_exp_slow_fixed()?;
+    Ok((v2 * denom + u) / numer)
+}
+
+/// Draws from the discrete Laplace distribution with a fixed scale.
+///
+/// The reciprocal of the scale is computed once, and shared by every draw.
+///
+/// When `fixed_iteration` is set, every draw takes the same number of loop iterations and uniform draws,
+/// so that neither reveals the noise.
+/// The arithmetic on big integers and rationals, and the branches on whether a loop has finished, still take variable time.
+/// A fixed-iteration draw fails, or deviates from the discrete Laplace distribution,
+/// with probability less than 2^-43, and is much slower.
+pub struct DiscreteLaplaceSampler {
+    // None when the scale is zero
+    inv_scale: Option<Rational>,
+    fixed_iteration: bool,
+}
+
+impl DiscreteLaplaceSampler {
+    pub fn new(scale: Rational, fixed_iteration: bool) -> Self {
+        DiscreteLaplaceSampler { inv_scale: (!scale.is_zero()).then(|| scale.recip()), fixed_iteration }
+    }
+
+    pub fn sample(&self) -> Fallible<Integer> {
+        let inv_scale = match &self.inv_scale {
+            Some(inv_scale) => inv_scale,
+            None => return Ok(0.into())
+        };
+
+        if self.fixed_iteration {
+            // the difference of two geometric variables is discrete laplace, without a rejection step
+            return Ok(sample_geometric_exp_fast_fixed(inv_scale.clone())? - sample_geometric_exp_fast_fixed(inv_scale.clone())?)
+        }
+
+        loop {
+            let positive = bool::sample_standard_bernoulli()?;
+            let magnitude = sample_geometric_exp_fast(inv_scale.clone())?;
+            if positive || !magnitude.is_zero() {
+                return Ok(if positive {
+                    magnitude
+                } else {
+                    -magnitude
+                })
+            }
+        }
+    }
+}
+
+pub fn sample_discrete_laplace(scale: Rational) -> Fallible<Integer> {
+    DiscreteLaplaceSampler::new(scale, false).sample()
+}
+
+/// Draws from the discrete Gaussian distribution with a fixed scale.
+///
+/// The proposal distribution and the constants of the rejection step are computed once, and shared by every draw.
+///
+/// When `fixed_iteration` is set, every draw takes the same number of loop iterations and uniform draws,
+/// so that neither reveals the noise.
+/// The arithmetic on big integers and rationals, and the branches on whether a loop has finished, still take variable time.
+/// A fixed-iteration draw fails, or deviates from the discrete Gaussian distribution,
+/// with probability less than 2^-36, and is much slower.
+pub struct DiscreteGaussianSampler {
+    // None when the scale is zero
+    constants: Option<GaussianConstants>,
+    fixed_iteration: bool,
+}
+
+struct GaussianConstants {
+    // proposals are drawn from the discrete Laplace with scale t = floor(scale) + 1
+    laplace: DiscreteLaplaceSampler,
+    // a proposal c is accepted with probability exp(-(|c| - offset)^2 / bias_denom)
+    offset: Rational,
+    bias_denom: Rational,
+}
+
+impl DiscreteGaussianSampler {
+    pub fn new(scale: Rational, fixed_iteration: bool) -> Self {
+        if scale.is_zero() {
+            return DiscreteGaussianSampler { constants: None, fixed_iteration }
+        }
+        let t = scale.clone().floor() + 1i8;
+        let sigma2 = scale.square();
+        DiscreteGaussianSampler {
+            constants: Some(GaussianConstants {
+                offset: sigma2.clone() / &t,
+                bias_denom: 2 * sigma2,
+                laplace: DiscreteLaplaceSampler::new(t, fixed_iteration),
+            }),
+            fixed_iteration,
+        }
+    }
+
+    pub fn sample(&self) -> Fallible<Integer> {
+        let GaussianConstants { laplace, offset, bias_denom } = match &self.constants {
+            Some(constants) => constants,
+            None => return Ok(0.into())
+        };
+
+        if self.fixed_iteration {
+            // each proposal is accepted with probability at least 1/4
+            let mut outcome = None;
+            for _ in 0..FIXED_GAUSSIAN_PROPOSALS {
+                let candidate = laplace.sample()?;
+                let x = candidate.clone().abs() - offset.clone();
+                let accept = sample_bernoulli_exp_fixed(x.square() / bias_denom)?;
+                if accept && outcome.is_none() {
+                    outcome = Some(candidate);
+                }
+            }
+            return outcome.ok_or_else(|| ran_out("gaussian"))
+        }
+
+        loop {
+            let candidate = laplace.sample()?;
+            let x = candidate.clone().abs() - offset.clone();
+            let bias = x.square() / bias_denom;
+            if sample_bernoulli_exp(bias)? {
+                return Ok(candidate);
+            }
+        }
+    }
+}
+
+pub fn sample_discrete_gaussian(scale: Rational) -> Fallible<Integer> {
+    DiscreteGaussianSampler::new(scale, false).sample()
 }
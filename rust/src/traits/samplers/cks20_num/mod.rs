@@ -0,0 +1,387 @@
+// Copyright (c) 2022 President and Fellows of Harvard College
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included in all
+// copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+//
+// This file incorporates work covered by the following copyright and
+// permission notice:
+//
+//   Copyright 2020 Thomas Steinke
+//
+//   Licensed under the Apache License, Version 2.0 (the "License");
+//   you may not use this file except in compliance with the License.
+//   You may obtain a copy of the License at
+//
+//       http://www.apache.org/licenses/LICENSE-2.0
+//
+//   Unless required by applicable law or agreed to in writing, software
+//   distributed under the License is distributed on an "AS IS" BASIS,
+//   WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//   See the License for the specific language governing permissions and
+//   limitations under the License.
+
+// Algorithm from:
+//     Clément Canonne, Gautam Kamath, Thomas Steinke. The Discrete Gaussian for Differential Privacy. 2020.
+//     https://arxiv.org/abs/2004.00010
+//
+// This file is derived from the following implementation by Thomas Steinke:
+//     https://github.com/IBM/discrete-gaussian-differential-privacy/blob/cb190d2a990a78eff6e21159203bc888e095f01b/discretegauss.py
+//
+// This is a pure-Rust port of the MPFR-backed implementation in `cks20`,
+// over the big integers and rationals from `num`.
+
+use num::{BigInt, BigRational, Integer, One, Signed, Zero};
+use crate::error::Fallible;
+
+use super::{
+    SampleUniformIntBelow, SampleStandardBernoulli, FIXED_EXP1_TRIALS, FIXED_EXP_WHOLE_TRIALS,
+    FIXED_GAUSSIAN_PROPOSALS, FIXED_GEOMETRIC_PROPOSALS, FIXED_GEOMETRIC_TRIALS,
+};
+
+
+// The loops below hold x as an unreduced numerator and denominator,
+// as normalizing a BigRational computes a gcd on every operation.
+
+// sample from a Bernoulli(numer / denom) distribution
+pub(super) fn sample_bernoulli_ratio(numer: &BigInt, denom: &BigInt) -> Fallible<bool> {
+    Ok(&BigInt::sample_uniform_int_below(denom.clone())? < numer)
+}
+
+// sample from a Bernoulli(exp(-x)) distribution, where x = numer / denom
+// assumes x is a rational number in [0,1]
+fn sample_bernoulli_exp1(numer: &BigInt, denom: &BigInt) -> Fallible<bool> {
+    let mut k = BigInt::one();
+    loop {
+        if sample_bernoulli_ratio(numer, &(denom * &k))? {
+            k += 1;
+        } else {
+            return Ok(k.is_odd());
+        }
+    }
+}
+
+// sample from a Bernoulli(exp(-x)) distribution, where x = numer / denom
+// assumes x is a rational number >=0
+pub(super) fn sample_bernoulli_exp(mut numer: BigInt, denom: &BigInt) -> Fallible<bool> {
+    // Sample floor(x) independent Bernoulli(exp(-1))
+    // If all are 1, return Bernoulli(exp(-(x-floor(x))))
+    let one = BigInt::one();
+    while &numer > denom {
+        if sample_bernoulli_exp1(&one, &one)? {
+            numer -= denom;
+        } else {
+            return Ok(false);
+        }
+    }
+    sample_bernoulli_exp1(&numer, denom)
+}
+
+// sample from a geometric(1-exp(-1)) distribution
+fn sample_geometric_exp_slow() -> Fallible<BigInt> {
+    let (one, mut k) = (BigInt::one(), BigInt::zero());
+    loop {
+        if sample_bernoulli_exp1(&one, &one)? {
+            k += 1;
+        } else {
+            return Ok(k);
+        }
+    }
+}
+
+// sample from a geometric(1-exp(-x)) distribution
+// assumes x >= 0 rational
+pub(super) fn sample_geometric_exp_fast(x: &BigRational) -> Fallible<BigInt> {
+    if x.is_zero() {
+        return Ok(BigInt::zero());
+    }
+
+    let (numer, denom) = (x.numer(), x.denom());
+    let mut u = BigInt::sample_uniform_int_below(denom.clone())?;
+    while !sample_bernoulli_exp(u.clone(), denom)? {
+        u = BigInt::sample_uniform_int_below(denom.clone())?;
+    }
+    let v2 = sample_geometric_exp_slow()?;
+    Ok((v2 * denom + u) / numer)
+}
+
+// The fixed-iteration samplers below run every loop for a fixed number of iterations,
+// and take a fixed number of uniform draws, regardless of the outcome.
+// Only these counts are fixed: the big-number arithmetic and the branches on the outcome take variable time.
+// Once the outcome of a loop is known, the remaining iterations are dummies.
+// Each loop fails if it runs out of iterations. See the bounds in the parent module.
+
+fn ran_out(name: &str) -> crate::error::Error {
+    err!(FailedFunction, "fixed-iteration {} sampler ran out of iterations", name)
+}
+
+// sample from a Bernoulli(numer / denom) distribution with a fixed number of iterations
+fn sample_bernoulli_ratio_fixed(numer: &BigInt, denom: &BigInt) -> Fallible<bool> {
+    Ok(&BigInt::sample_uniform_int_below_fixed_iteration(denom.clone())? < numer)
+}
+
+// sample from a Bernoulli(exp(-x)) distribution with a fixed number of iterations, where x = numer / denom
+// assumes x is a rational number in [0,1]
+fn sample_bernoulli_exp1_fixed(numer: &BigInt, denom: &BigInt) -> Fallible<bool> {
+    let mut outcome = None;
+    for k in 1..=FIXED_EXP1_TRIALS {
+        let success = sample_bernoulli_ratio_fixed(numer, &(denom * k))?;
+        if !success && outcome.is_none() {
+            outcome = Some(k % 2 == 1);
+        }
+    }
+    outcome.ok_or_else(|| ran_out("bernoulli"))
+}
+
+// sample from a Bernoulli(exp(-x)) distribution with a fixed number of iterations, where x = numer / denom
+// assumes x is a rational number >=0
+fn sample_bernoulli_exp_fixed(numer: &BigInt, denom: &BigInt) -> Fallible<bool> {
+    // Sample a fixed number of independent Bernoulli(exp(-1)), and keep the first floor(x)
+    // If x exceeds the number of trials, exp(-x) is rounded down to zero
+    let (whole, fract) = numer.div_mod_floor(denom);
+    let one = BigInt::one();
+    let mut outcome = whole <= BigInt::from(FIXED_EXP_WHOLE_TRIALS);
+    for i in 0..FIXED_EXP_WHOLE_TRIALS {
+        let success = sample_bernoulli_exp1_fixed(&one, &one)?;
+        if BigInt::from(i) < whole {
+            outcome &= success;
+        }
+    }
+    Ok(sample_bernoulli_exp1_fixed(&fract, denom)? && outcome)
+}
+
+// sample from a geometric(1-exp(-1)) distribution with a fixed number of iterations
+fn sample_geometric_exp_slow_fixed() -> Fallible<BigInt> {
+    let one = BigInt::one();
+    let mut outcome = None;
+    for k in 0..FIXED_GEOMETRIC_TRIALS {
+        let success = sample_bernoulli_exp1_fixed(&one, &one)?;
+        if !success && outcome.is_none() {
+            outcome = Some(BigInt::from(k));
+        }
+    }
+    outcome.ok_or_else(|| ran_out("geometric"))
+}
+
+// sample from a geometric(1-exp(-x)) distribution with a fixed number of iterations
+// assumes x >= 0 rational
+fn sample_geometric_exp_fast_fixed(x: &BigRational) -> Fallible<BigInt> {
+    if x.is_zero() {
+        return Ok(BigInt::zero());
+    }
+
+    let (numer, denom) = (x.numer(), x.denom());
+    let mut u = None;
+    for _ in 0..FIXED_GEOMETRIC_PROPOSALS {
+        // u / denom < 1, so each proposal is accepted with probability at least exp(-1)
+        let proposal = BigInt::sample_uniform_int_below_fixed_iteration(denom.clone())?;
+        let accept = sample_bernoulli_exp1_fixed(&proposal, denom)?;
+        if accept && u.is_none() {
+            u = Some(proposal);
+        }
+    }
+    let u = u.ok_or_else(|| ran_out("geometric"))?;
+    let v2 = sample_geometric_exp_slow_fixed()?;
+    Ok((v2 * denom + u) / numer)
+}
+
+/// Draws from the discrete Laplace distribution with a fixed scale.
+///
+/// The reciprocal of the scale is computed once, and shared by every draw.
+///
+/// When `fixed_iteration` is set, every draw takes the same number of loop iterations and uniform draws,
+/// so that neither reveals the noise.
+/// The arithmetic on big integers and rationals, and the branches on whether a loop has finished, still take variable time.
+/// A fixed-iteration draw fails, or deviates from the discrete Laplace distribution,
+/// with probability less than 2^-43, and is much slower.
+pub struct DiscreteLaplaceSampler {
+    // None when the scale is zero
+    inv_scale: Option<BigRational>,
+    fixed_iteration: bool,
+}
+
+impl DiscreteLaplaceSampler {
+    pub fn new(scale: BigRational, fixed_iteration: bool) -> Self {
+        DiscreteLaplaceSampler { inv_scale: (!scale.is_zero()).then(|| scale.recip()), fixed_iteration }
+    }
+
+    pub fn sample(&self) -> Fallible<BigInt> {
+        let inv_scale = match &self.inv_scale {
+            Some(inv_scale) => inv_scale,
+            None => return Ok(BigInt::zero())
+        };
+
+        if self.fixed_iteration {
+            // the difference of two geometric variables is discrete laplace, without a rejection step
+            return Ok(sample_geometric_exp_fast_fixed(inv_scale)? - sample_geometric_exp_fast_fixed(inv_scale)?)
+        }
+
+        loop {
+            let positive = bool::sample_standard_bernoulli()?;
+            let magnitude = sample_geometric_exp_fast(inv_scale)?;
+            if positive || !magnitude.is_zero() {
+                return Ok(if positive {
+                    magnitude
+                } else {
+                    -magnitude
+                })
+            }
+        }
+    }
+}
+
+pub fn sample_discrete_laplace(scale: BigRational) -> Fallible<BigInt> {
+    DiscreteLaplaceSampler::new(scale, false).sample()
+}
+
+/// Draws from the discrete Gaussian distribution with a fixed scale.
+///
+/// The proposal distribution and the constants of the rejection step are computed once, and shared by every draw.
+///
+/// When `fixed_iteration` is set, every draw takes the same number of loop iterations and uniform draws,
+/// so that neither reveals the noise.
+/// The arithmetic on big integers and rationals, and the branches on whether a loop has finished, still take variable time.
+/// A fixed-iteration draw fails, or deviates from the discrete Gaussian distribution,
+/// with probability less than 2^-36, and is much slower.
+pub struct DiscreteGaussianSampler {
+    // None when the scale is zero
+    constants: Option<GaussianConstants>,
+    fixed_iteration: bool,
+}
+
+struct GaussianConstants {
+    // proposals are drawn from the discrete Laplace with scale t = floor(scale) + 1
+    laplace: DiscreteLaplaceSampler,
+    // where scale^2 = a / b, a proposal c is accepted with probability exp(-bias), where
+    //     bias = (|c| - scale^2 / t)^2 / (2 scale^2) = (|c| b t - a)^2 / (2 a b t^2)
+    a: BigInt,
+    bt: BigInt,
+    bias_denom: BigInt,
+}
+
+impl DiscreteGaussianSampler {
+    pub fn new(scale: BigRational, fixed_iteration: bool) -> Self {
+        if scale.is_zero() {
+            return DiscreteGaussianSampler { constants: None, fixed_iteration }
+        }
+        let t = scale.floor().to_integer() + BigInt::one();
+        let (a, b) = (scale.numer() * scale.numer(), scale.denom() * scale.denom());
+        let bt = &b * &t;
+        let bias_denom = BigInt::from(2) * &a * &bt * &t;
+        DiscreteGaussianSampler {
+            constants: Some(GaussianConstants {
+                laplace: DiscreteLaplaceSampler::new(BigRational::from_integer(t), fixed_iteration),
+                a, bt, bias_denom,
+            }),
+            fixed_iteration,
+        }
+    }
+
+    pub fn sample(&self) -> Fallible<BigInt> {
+        let GaussianConstants { laplace, a, bt, bias_denom } = match &self.constants {
+            Some(constants) => constants,
+            None => return Ok(BigInt::zero())
+        };
+
+        if self.fixed_iteration {
+            // each proposal is accepted with probability at least 1/4
+            let mut outcome = None;
+            for _ in 0..FIXED_GAUSSIAN_PROPOSALS {
+                let candidate = laplace.sample()?;
+                let x = candidate.abs() * bt - a;
+                let accept = sample_bernoulli_exp_fixed(&(&x * &x), bias_denom)?;
+                if accept && outcome.is_none() {
+                    outcome = Some(candidate);
+                }
+            }
+            return outcome.ok_or_else(|| ran_out("gaussian"))
+        }
+
+        loop {
+            let candidate = laplace.sample()?;
+            let x = candidate.abs() * bt - a;
+            if sample_bernoulli_exp(&x * &x, bias_denom)? {
+                return Ok(candidate);
+            }
+        }
+    }
+}
+
+pub fn sample_discrete_gaussian(scale: BigRational) -> Fallible<BigInt> {
+    DiscreteGaussianSampler::new(scale, false).sample()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::traits::samplers::test_utils::*;
+
+    #[test]
+    fn test_sample_bernoulli_exp() {
+        // P(Bernoulli(exp(-3/2)) = 1) = exp(-3/2)
+        let sampler = || if sample_bernoulli_exp(BigInt::from(3), &BigInt::from(2)).unwrap() { 1. } else { 0. };
+        assert!(test_proportion_parameters(sampler, (-1.5f64).exp(), 0.00001, 0.01));
+    }
+
+    #[test]
+    fn test_sample_discrete_laplace() -> Fallible<()> {
+        assert_eq!(sample_discrete_laplace(BigRational::zero())?, BigInt::zero());
+        // P(|X| = 0) = (1 - exp(-1/scale)) / (1 + exp(-1/scale))
+        let scale = BigRational::from_integer(BigInt::from(2));
+        let p_zero = (1. - (-0.5f64).exp()) / (1. + (-0.5f64).exp());
+        let sampler = || if sample_discrete_laplace(scale.clone()).unwrap().is_zero() { 1. } else { 0. };
+        assert!(test_proportion_parameters(sampler, p_zero, 0.00001, 0.01));
+        Ok(())
+    }
+
+    #[test]
+    fn test_sample_discrete_gaussian() -> Fallible<()> {
+        assert_eq!(sample_discrete_gaussian(BigRational::zero())?, BigInt::zero());
+        // for scale = 1, P(X = 0) = 1 / sum_k exp(-k^2 / 2)
+        let normalizer: f64 = (-20..=20).map(|k: i32| (-(k * k) as f64 / 2.).exp()).sum();
+        let scale = BigRational::one();
+        let sampler = || if sample_discrete_gaussian(scale.clone()).unwrap().is_zero() { 1. } else { 0. };
+        assert!(test_proportion_parameters(sampler, 1. / normalizer, 0.00001, 0.01));
+        Ok(())
+    }
+
+    #[test]
+    fn test_sample_discrete_laplace_fixed_iteration() -> Fallible<()> {
+        // P(|X| = 0) = (1 - exp(-1/scale)) / (1 + exp(-1/scale))
+        let laplace = DiscreteLaplaceSampler::new(BigRational::from_integer(BigInt::from(2)), true);
+        let p_zero = (1. - (-0.5f64).exp()) / (1. + (-0.5f64).exp());
+        let sampler = || if laplace.sample().unwrap().is_zero() { 1. } else { 0. };
+        assert!(test_proportion_parameters(sampler, p_zero, 0.00001, 0.05));
+        Ok(())
+    }
+
+    #[test]
+    fn test_sample_discrete_gaussian_fixed_iteration() -> Fallible<()> {
+        assert_eq!(DiscreteGaussianSampler::new(BigRational::zero(), true).sample()?, BigInt::zero());
+        let gaussian = DiscreteGaussianSampler::new(BigRational::one(), true);
+        (0..10).try_for_each(|_| gaussian.sample().map(|_| ()))
+    }
+
+    #[test]
+    fn test_sample_bernoulli_exp_fixed_iteration() {
+        // x = 40 exceeds the iteration bound, so exp(-x) is rounded down to zero
+        assert!(!sample_bernoulli_exp_fixed(&BigInt::from(40), &BigInt::one()).unwrap());
+        // P(Bernoulli(exp(-3/2)) = 1) = exp(-3/2)
+        let sampler = || if sample_bernoulli_exp_fixed(&BigInt::from(3), &BigInt::from(2)).unwrap() { 1. } else { 0. };
+        assert!(test_proportion_parameters(sampler, (-1.5f64).exp(), 0.00001, 0.05));
+    }
+}
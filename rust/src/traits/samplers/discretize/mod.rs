@@ -1,15 +1,15 @@
 use num::One;
 use std::convert::TryFrom;
+use std::marker::PhantomData;
 
 // stands for Big Integer, an integer with unlimited precision, from gmp
 use rug::{Integer, Rational};
 
-use crate::traits::samplers::sample_discrete_laplace;
 use crate::error::Fallible;
 
-use super::sample_discrete_gaussian;
+use super::{DiscreteGaussianSampler, DiscreteLaplaceSampler};
 
-pub trait SampleDiscreteLaplaceZ2k: Sized {
+pub trait SampleDiscreteLaplaceZ2k: CastInternalRational + Sized {
     #![allow(non_snake_case)]
     fn sample_discrete_laplace_Z2k(shift: Self, scale: Self, k: i32) -> Fallible<Self>;
 }
@@ -20,18 +20,43 @@ where
     T: CastInternalRational,
 {
     fn sample_discrete_laplace_Z2k(shift: Self, scale: Self, k: i32) -> Fallible<Self> {
+        DiscreteLaplaceZ2k::new(scale, k, false)?.sample(shift)
+    }
+}
+
+/// Draws from the discrete Laplace distribution on ℤ*2^k with a fixed scale, for any number of shifts.
+///
+/// The scale is converted once, and shared by every draw.
+/// When `fixed_iteration` is set, draws are made by the fixed-iteration mode of [`DiscreteLaplaceSampler`].
+/// Fixed-iteration draws are much slower when the scale is large relative to 2^k.
+pub struct DiscreteLaplaceZ2k<T> {
+    sampler: DiscreteLaplaceSampler,
+    k: i32,
+    _marker: PhantomData<fn(T) -> T>,
+}
+
+impl<T: CastInternalRational> DiscreteLaplaceZ2k<T> {
+    pub fn new(scale: T, k: i32, fixed_iteration: bool) -> Fallible<Self> {
+        Ok(DiscreteLaplaceZ2k {
+            sampler: DiscreteLaplaceSampler::new(scale.into_rational()? >> k, fixed_iteration),
+            k,
+            _marker: PhantomData,
+        })
+    }
+
+    pub fn sample(&self, shift: T) -> Fallible<T> {
         // integerize
-        let mut i = find_nearest_multiple_of_2k(shift.into_rational()?, k);
+        let mut i = find_nearest_multiple_of_2k(shift.into_rational()?, self.k);
 
         // sample from the discrete laplace on ℤ*2^k
-        i += sample_discrete_laplace(scale.into_rational()? >> k)?;
+        i += self.sampler.sample()?;
 
         // postprocess! int -> rational -> T
-        Ok(Self::from_rational(x_mul_2k(i, k)))
+        Ok(T::from_rational(x_mul_2k(i, self.k)))
     }
 }
 
-pub trait SampleDiscreteGaussianZ2k: Sized {
+pub trait SampleDiscreteGaussianZ2k: CastInternalRational + Sized {
     #![allow(non_snake_case)]
     fn sample_discrete_gaussian_Z2k(shift: Self, scale: Self, k: i32) -> Fallible<Self>;
 }
@@ -42,14 +67,39 @@ where
     T: CastInternalRational,
 {
     fn sample_discrete_gaussian_Z2k(shift: Self, scale: Self, k: i32) -> Fallible<Self> {
+        DiscreteGaussianZ2k::new(scale, k, false)?.sample(shift)
+    }
+}
+
+/// Draws from the discrete Gaussian distribution on ℤ*2^k with a fixed scale, for any number of shifts.
+///
+/// The scale is converted once, and shared by every draw.
+/// When `fixed_iteration` is set, draws are made by the fixed-iteration mode of [`DiscreteGaussianSampler`].
+/// Fixed-iteration draws are much slower when the scale is large relative to 2^k.
+pub struct DiscreteGaussianZ2k<T> {
+    sampler: DiscreteGaussianSampler,
+    k: i32,
+    _marker: PhantomData<fn(T) -> T>,
+}
+
+impl<T: CastInternalRational> DiscreteGaussianZ2k<T> {
+    pub fn new(scale: T, k: i32, fixed_iteration: bool) -> Fallible<Self> {
+        Ok(DiscreteGaussianZ2k {
+            sampler: DiscreteGaussianSampler::new(scale.into_rational()? >> k, fixed_iteration),
+            k,
+            _marker: PhantomData,
+        })
+    }
+
+    pub fn sample(&self, shift: T) -> Fallible<T> {
         // integerize
-        let mut i = find_nearest_multiple_of_2k(shift.into_rational()?, k);
+        let mut i = find_nearest_multiple_of_2k(shift.into_rational()?, self.k);
 
         // sample from the discrete gaussian on ℤ*2^k
-        i += sample_discrete_gaussian(scale.into_rational()? >> k)?;
+        i += self.sampler.sample()?;
 
         // postprocess! int -> rational -> T
-        Ok(Self::from_rational(x_mul_2k(i, k)))
+        Ok(T::from_rational(x_mul_2k(i, self.k)))
     }
 }
 
@@ -90,6 +140,25 @@ macro_rules! impl_cast_internal_rational {
 impl_cast_internal_rational!(f32, to_f32);
 impl_cast_internal_rational!(f64, to_f64);
 
+/// Conversions between integers and the big integers that exact samplers operate on.
+pub trait CastInternalInteger {
+    /// Convert `v` to Self, saturating at the bounds of Self.
+    fn from_integer(v: Integer) -> Self;
+    fn into_integer(self) -> Integer;
+}
+
+impl<T> CastInternalInteger for T
+where
+    Integer: From<T> + az::SaturatingCast<T>,
+{
+    fn from_integer(v: Integer) -> Self {
+        az::SaturatingCast::saturating_cast(v)
+    }
+    fn into_integer(self) -> Integer {
+        Integer::from(self)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -148,6 +217,27 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_prepared_z2k() -> Fallible<()> {
+        let laplace = DiscreteLaplaceZ2k::new(2f64, -2, false)?;
+        let gaussian = DiscreteGaussianZ2k::new(2f64, -2, false)?;
+        for shift in [0.1, 34.29, -100.23] {
+            assert_eq!(laplace.sample(shift)? % 0.25, 0.);
+            assert_eq!(gaussian.sample(shift)? % 0.25, 0.);
+        }
+        assert!(DiscreteLaplaceZ2k::new(f64::INFINITY, -2, false).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_fixed_iteration_z2k() -> Fallible<()> {
+        let laplace = DiscreteLaplaceZ2k::new(2f64, -2, true)?;
+        let gaussian = DiscreteGaussianZ2k::new(2f64, -2, true)?;
+        assert_eq!(laplace.sample(34.29)? % 0.25, 0.);
+        assert_eq!(gaussian.sample(-100.23)? % 0.25, 0.);
+        Ok(())
+    }
+
     #[test]
     fn test_rational_k() -> Fallible<()> {
         println!("{:?}", 1.5.into_rational()? << -2);
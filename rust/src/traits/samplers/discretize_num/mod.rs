@@ -0,0 +1,257 @@
+// This is a pure-Rust port of the MPFR-backed implementation in `discretize`,
+// over the big integers and rationals from `num`.
+
+use std::marker::PhantomData;
+
+use num::{BigInt, BigRational, NumCast, One, Signed};
+
+use crate::traits::arithmetic::exact::{Round, RoundRational};
+use crate::error::Fallible;
+
+use super::{DiscreteGaussianSampler, DiscreteLaplaceSampler};
+
+pub trait SampleDiscreteLaplaceZ2k: CastInternalRational + Sized {
+    #![allow(non_snake_case)]
+    fn sample_discrete_laplace_Z2k(shift: Self, scale: Self, k: i32) -> Fallible<Self>;
+}
+
+impl<T> SampleDiscreteLaplaceZ2k for T
+where
+    T: CastInternalRational,
+{
+    fn sample_discrete_laplace_Z2k(shift: Self, scale: Self, k: i32) -> Fallible<Self> {
+        DiscreteLaplaceZ2k::new(scale, k, false)?.sample(shift)
+    }
+}
+
+/// Draws from the discrete Laplace distribution on ℤ*2^k with a fixed scale, for any number of shifts.
+///
+/// The scale is converted once, and shared by every draw.
+/// When `fixed_iteration` is set, draws are made by the fixed-iteration mode of [`DiscreteLaplaceSampler`].
+/// Fixed-iteration draws are much slower when the scale is large relative to 2^k.
+pub struct DiscreteLaplaceZ2k<T> {
+    sampler: DiscreteLaplaceSampler,
+    k: i32,
+    _marker: PhantomData<fn(T) -> T>,
+}
+
+impl<T: CastInternalRational> DiscreteLaplaceZ2k<T> {
+    pub fn new(scale: T, k: i32, fixed_iteration: bool) -> Fallible<Self> {
+        Ok(DiscreteLaplaceZ2k {
+            sampler: DiscreteLaplaceSampler::new(x_mul_2k(scale.into_rational()?, -k), fixed_iteration),
+            k,
+            _marker: PhantomData,
+        })
+    }
+
+    pub fn sample(&self, shift: T) -> Fallible<T> {
+        // integerize
+        let mut i = find_nearest_multiple_of_2k(shift.into_rational()?, self.k);
+
+        // sample from the discrete laplace on ℤ*2^k
+        i += self.sampler.sample()?;
+
+        // postprocess! int -> rational -> T
+        Ok(T::from_rational(x_mul_2k(BigRational::from_integer(i), self.k)))
+    }
+}
+
+pub trait SampleDiscreteGaussianZ2k: CastInternalRational + Sized {
+    #![allow(non_snake_case)]
+    fn sample_discrete_gaussian_Z2k(shift: Self, scale: Self, k: i32) -> Fallible<Self>;
+}
+
+impl<T> SampleDiscreteGaussianZ2k for T
+where
+    T: CastInternalRational,
+{
+    fn sample_discrete_gaussian_Z2k(shift: Self, scale: Self, k: i32) -> Fallible<Self> {
+        DiscreteGaussianZ2k::new(scale, k, false)?.sample(shift)
+    }
+}
+
+/// Draws from the discrete Gaussian distribution on ℤ*2^k with a fixed scale, for any number of shifts.
+///
+/// The scale is converted once, and shared by every draw.
+/// When `fixed_iteration` is set, draws are made by the fixed-iteration mode of [`DiscreteGaussianSampler`].
+/// Fixed-iteration draws are much slower when the scale is large relative to 2^k.
+pub struct DiscreteGaussianZ2k<T> {
+    sampler: DiscreteGaussianSampler,
+    k: i32,
+    _marker: PhantomData<fn(T) -> T>,
+}
+
+impl<T: CastInternalRational> DiscreteGaussianZ2k<T> {
+    pub fn new(scale: T, k: i32, fixed_iteration: bool) -> Fallible<Self> {
+        Ok(DiscreteGaussianZ2k {
+            sampler: DiscreteGaussianSampler::new(x_mul_2k(scale.into_rational()?, -k), fixed_iteration),
+            k,
+            _marker: PhantomData,
+        })
+    }
+
+    pub fn sample(&self, shift: T) -> Fallible<T> {
+        // integerize
+        let mut i = find_nearest_multiple_of_2k(shift.into_rational()?, self.k);
+
+        // sample from the discrete gaussian on ℤ*2^k
+        i += self.sampler.sample()?;
+
+        // postprocess! int -> rational -> T
+        Ok(T::from_rational(x_mul_2k(BigRational::from_integer(i), self.k)))
+    }
+}
+
+
+// find index of nearest multiple of 2^k from x
+fn find_nearest_multiple_of_2k(x: BigRational, k: i32) -> BigInt {
+    // argmin_i |i * 2^k - x|, the index of nearest multiple of 2^k, with ties away from zero
+    x_mul_2k(x, -k).round().to_integer()
+}
+
+// exactly multiply x by 2^k
+fn x_mul_2k(x: BigRational, k: i32) -> BigRational {
+    let pow = BigInt::one() << k.unsigned_abs() as usize;
+    if k >= 0 { x * pow } else { x / pow }
+}
+
+
+pub trait CastInternalRational {
+    fn from_rational(v: BigRational) -> Self;
+    fn into_rational(self) -> Fallible<BigRational>;
+}
+
+macro_rules! impl_cast_internal_rational {
+    ($ty:ty) => {
+        impl CastInternalRational for $ty {
+            fn from_rational(v: BigRational) -> Self {
+                Self::from_rational_round(&v, Round::Nearest)
+            }
+            fn into_rational(self) -> Fallible<BigRational> {
+                self.exact_rational().ok_or_else(|| err!(FailedFunction, "shift must be finite"))
+            }
+        }
+    };
+}
+
+impl_cast_internal_rational!(f32);
+impl_cast_internal_rational!(f64);
+
+/// Conversions between integers and the big integers that exact samplers operate on.
+pub trait CastInternalInteger {
+    /// Convert `v` to Self, saturating at the bounds of Self.
+    fn from_integer(v: BigInt) -> Self;
+    fn into_integer(self) -> BigInt;
+}
+
+macro_rules! impl_cast_internal_integer {
+    ($($ty:ty),+) => ($(impl CastInternalInteger for $ty {
+        fn from_integer(v: BigInt) -> Self {
+            let saturated = if v.is_negative() { <$ty>::MIN } else { <$ty>::MAX };
+            <$ty as NumCast>::from(v).unwrap_or(saturated)
+        }
+        fn into_integer(self) -> BigInt {
+            BigInt::from(self)
+        }
+    })+)
+}
+impl_cast_internal_integer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[test]
+    fn test_sample_discrete_laplace() -> Fallible<()> {
+        let dgeo: f64 = f64::sample_discrete_laplace_Z2k(0f64, 1f64, 50)?;
+        assert_eq!(dgeo % 2f64.powi(50), 0.);
+        Ok(())
+    }
+
+    #[test]
+    fn test_sample_discrete_laplace_pos_k() -> Fallible<()> {
+        // check rounding of negative arguments
+        assert_eq!(f64::sample_discrete_laplace_Z2k(-4., 0f64, 2)?, -4.0);
+        assert_eq!(f64::sample_discrete_laplace_Z2k(-3., 0f64, 2)?, -4.0);
+        assert_eq!(f64::sample_discrete_laplace_Z2k(-2., 0f64, 2)?, -4.0);
+        assert_eq!(f64::sample_discrete_laplace_Z2k(-1., 0f64, 2)?, 0.0);
+        assert_eq!(f64::sample_discrete_laplace_Z2k(-3.6522343492937, 0f64, 2)?, -4.0);
+
+        assert_eq!(f64::sample_discrete_laplace_Z2k(0., 0f64, 2)?, 0.0);
+
+        // check rounding of positive arguments
+        assert_eq!(f64::sample_discrete_laplace_Z2k(1., 0f64, 2)?, 0.0);
+        assert_eq!(f64::sample_discrete_laplace_Z2k(2., 0f64, 2)?, 4.0);
+        assert_eq!(f64::sample_discrete_laplace_Z2k(3., 0f64, 2)?, 4.0);
+        assert_eq!(f64::sample_discrete_laplace_Z2k(4., 0f64, 2)?, 4.0);
+        assert_eq!(f64::sample_discrete_laplace_Z2k(3.6522343492937, 0f64, 2)?, 4.0);
+
+        // check that noise is applied in increments of 4
+        assert_eq!(f64::sample_discrete_laplace_Z2k(4., 23f64, 2)? % 4., 0.);
+        assert_eq!(f64::sample_discrete_laplace_Z2k(4., 2f64, 2)? % 4., 0.);
+        assert_eq!(f64::sample_discrete_laplace_Z2k(4., 456e3f64, 2)? % 4., 0.);
+        
+        Ok(())
+    }
+
+
+    #[test]
+    fn test_sample_discrete_laplace_neg_k() -> Fallible<()> {
+        assert_eq!(f64::sample_discrete_laplace_Z2k(-100.23, 0f64, -2)?, -100.25);
+        assert_eq!(f64::sample_discrete_laplace_Z2k(-34.29, 0f64, -2)?, -34.25);
+        assert_eq!(f64::sample_discrete_laplace_Z2k(-0.1, 0f64, -2)?, 0.0);
+        assert_eq!(f64::sample_discrete_laplace_Z2k(0., 0f64, -2)?, 0.0);
+        assert_eq!(f64::sample_discrete_laplace_Z2k(0.1, 0f64, -2)?, 0.0);
+        assert_eq!(f64::sample_discrete_laplace_Z2k(0.125, 0f64, -2)?, 0.25);
+        assert_eq!(f64::sample_discrete_laplace_Z2k(0.13, 0f64, -2)?, 0.25);
+
+        // check that noise is applied in increments of .25
+        assert_eq!(f64::sample_discrete_laplace_Z2k(2342.234532, 23f64, -2)? % 0.25, 0.);
+        assert_eq!(f64::sample_discrete_laplace_Z2k(2.8954, 2f64, -2)? % 0.25, 0.);
+        assert_eq!(f64::sample_discrete_laplace_Z2k(834.349, 456e3f64, -2)? % 0.25, 0.);
+        
+        Ok(())
+    }
+
+    #[test]
+    fn test_prepared_z2k() -> Fallible<()> {
+        let laplace = DiscreteLaplaceZ2k::new(2f64, -2, false)?;
+        let gaussian = DiscreteGaussianZ2k::new(2f64, -2, false)?;
+        for shift in [0.1, 34.29, -100.23] {
+            assert_eq!(laplace.sample(shift)? % 0.25, 0.);
+            assert_eq!(gaussian.sample(shift)? % 0.25, 0.);
+        }
+        assert!(DiscreteLaplaceZ2k::new(f64::INFINITY, -2, false).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_fixed_iteration_z2k() -> Fallible<()> {
+        let laplace = DiscreteLaplaceZ2k::new(2f64, -2, true)?;
+        let gaussian = DiscreteGaussianZ2k::new(2f64, -2, true)?;
+        assert_eq!(laplace.sample(34.29)? % 0.25, 0.);
+        assert_eq!(gaussian.sample(-100.23)? % 0.25, 0.);
+        Ok(())
+    }
+
+    #[test]
+    fn test_rational_k() -> Fallible<()> {
+        assert_eq!(x_mul_2k(1.5.into_rational()?, -2), 0.375.into_rational()?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_extreme_rational() -> Fallible<()> {
+        // rationals with greater magnitude than MAX saturate to infinity
+        let rat = f64::MAX.into_rational()?;
+        assert_eq!(f64::from_rational(rat * BigInt::from(2)), f64::INFINITY);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cast_internal_integer() {
+        assert_eq!(u8::from_integer(BigInt::from(300)), u8::MAX);
+        assert_eq!(i8::from_integer(BigInt::from(-300)), i8::MIN);
+        assert_eq!(i32::from_integer(7i32.into_integer()), 7);
+    }
+}
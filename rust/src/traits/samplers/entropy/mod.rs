@@ -0,0 +1,303 @@
+use std::cell::RefCell;
+use std::sync::{Arc, Mutex, RwLock};
+
+use crate::error::Fallible;
+
+/// A source of uniformly random bytes.
+///
+/// Every sampler in the library draws its randomness from [`fill_bytes`](super::fill_bytes),
+/// including [`GeneratorOpenDP`](super::GeneratorOpenDP), [`Shuffle`](super::Shuffle) and the CKS20 samplers.
+/// [`fill_bytes`](super::fill_bytes) reads from the source set by [`with_entropy_source`] on the current thread,
+/// then from the process-wide source set by [`set_entropy_source`],
+/// and otherwise from [`SystemEntropy`].
+///
+/// The privacy guarantees of every measurement rely on the bytes being uniformly random and unpredictable.
+pub trait EntropySource: Send + Sync {
+    /// Fill `buffer` with random bytes.
+    fn fill_bytes(&self, buffer: &mut [u8]) -> Fallible<()>;
+
+    /// The largest block that [`with_buffered_entropy`] may draw from this source ahead of time.
+    ///
+    /// Sources that must not be read ahead, like replayed streams and callbacks, return zero, and are never buffered.
+    fn max_block(&self) -> usize {
+        BufferedEntropy::DEFAULT_MAX_BLOCK
+    }
+}
+
+/// The entropy source chosen at compile time:
+/// OpenSSL if the `use-openssl` feature is enabled, and otherwise `rand::thread_rng`.
+pub struct SystemEntropy;
+
+impl EntropySource for SystemEntropy {
+    fn fill_bytes(&self, buffer: &mut [u8]) -> Fallible<()> {
+        super::system_fill_bytes(buffer)
+    }
+}
+
+/// An entropy source that replays a recorded byte stream, and fails once the stream is exhausted.
+pub struct ReplayEntropy {
+    bytes: Vec<u8>,
+    position: Mutex<usize>,
+}
+
+impl ReplayEntropy {
+    pub fn new(bytes: Vec<u8>) -> Self {
+        ReplayEntropy { bytes, position: Mutex::new(0) }
+    }
+}
+
+impl EntropySource for ReplayEntropy {
+    fn fill_bytes(&self, buffer: &mut [u8]) -> Fallible<()> {
+        let mut position = self.position.lock()
+            .map_err(|_| err!(FailedFunction, "replayed entropy is poisoned"))?;
+        let end = *position + buffer.len();
+        if end > self.bytes.len() {
+            return fallible!(FailedFunction, "replayed entropy is exhausted")
+        }
+        buffer.copy_from_slice(&self.bytes[*position..end]);
+        *position = end;
+        Ok(())
+    }
+    // reading ahead would exhaust a stream that holds exactly the bytes of the recorded release
+    fn max_block(&self) -> usize {
+        0
+    }
+}
+
+/// An entropy source that forwards bytes from another source, and keeps a copy of them.
+///
+/// The recorded bytes may be replayed with [`ReplayEntropy`] to reproduce a release in an audit.
+pub struct RecordingEntropy {
+    source: Arc<dyn EntropySource>,
+    record: Mutex<Vec<u8>>,
+}
+
+impl RecordingEntropy {
+    pub fn new(source: Arc<dyn EntropySource>) -> Self {
+        RecordingEntropy { source, record: Mutex::new(Vec::new()) }
+    }
+    /// Retrieve a copy of all bytes drawn so far.
+    pub fn recorded(&self) -> Fallible<Vec<u8>> {
+        self.record.lock().map(|record| record.clone())
+            .map_err(|_| err!(FailedFunction, "recorded entropy is poisoned"))
+    }
+}
+
+impl EntropySource for RecordingEntropy {
+    fn fill_bytes(&self, buffer: &mut [u8]) -> Fallible<()> {
+        self.source.fill_bytes(buffer)?;
+        self.record.lock()
+            .map_err(|_| err!(FailedFunction, "recorded entropy is poisoned"))?
+            .extend_from_slice(buffer);
+        Ok(())
+    }
+    fn max_block(&self) -> usize {
+        self.source.max_block()
+    }
+}
+
+/// An entropy source that draws from another source in blocks, and serves requests from the current block.
+///
+/// Bytes are served in the order they are drawn, and each byte is served at most once.
+/// Blocks start small and double in size up to `max_block`,
+/// so that short-lived buffers waste little entropy, while long runs of draws amortize the cost of each draw.
+/// When `max_block` is zero, requests are passed directly to the source.
+pub struct BufferedEntropy {
+    source: Arc<dyn EntropySource>,
+    max_block: usize,
+    block: Mutex<Block>,
+}
+
+struct Block {
+    bytes: Vec<u8>,
+    position: usize,
+}
+
+impl BufferedEntropy {
+    const MIN_BLOCK: usize = 64;
+    pub const DEFAULT_MAX_BLOCK: usize = 1 << 16;
+
+    pub fn new(source: Arc<dyn EntropySource>, max_block: usize) -> Self {
+        BufferedEntropy { source, max_block, block: Mutex::new(Block { bytes: Vec::new(), position: 0 }) }
+    }
+}
+
+impl EntropySource for BufferedEntropy {
+    fn fill_bytes(&self, buffer: &mut [u8]) -> Fallible<()> {
+        if self.max_block == 0 {
+            return self.source.fill_bytes(buffer)
+        }
+        let mut block = self.block.lock()
+            .map_err(|_| err!(FailedFunction, "buffered entropy is poisoned"))?;
+        let mut filled = 0;
+        while filled < buffer.len() {
+            if block.position == block.bytes.len() {
+                let size = (block.bytes.len() * 2).max(Self::MIN_BLOCK).min(self.max_block);
+                // discard the spent block before drawing, so that a failed draw is never served
+                block.bytes.clear();
+                block.position = 0;
+                let mut bytes = vec![0u8; size];
+                self.source.fill_bytes(&mut bytes)?;
+                block.bytes = bytes;
+            }
+            let count = (buffer.len() - filled).min(block.bytes.len() - block.position);
+            let position = block.position;
+            buffer[filled..filled + count].copy_from_slice(&block.bytes[position..position + count]);
+            block.position += count;
+            filled += count;
+        }
+        Ok(())
+    }
+    // the bytes are already drawn ahead, so nested buffers read directly from this one
+    fn max_block(&self) -> usize {
+        0
+    }
+}
+
+lazy_static! {
+    static ref GLOBAL_ENTROPY: RwLock<Option<Arc<dyn EntropySource>>> = RwLock::new(None);
+}
+
+thread_local! {
+    static SCOPED_ENTROPY: RefCell<Vec<Arc<dyn EntropySource>>> = RefCell::new(Vec::new());
+}
+
+/// Set the process-wide entropy source. Pass `None` to restore [`SystemEntropy`].
+pub fn set_entropy_source(source: Option<Arc<dyn EntropySource>>) -> Fallible<()> {
+    *GLOBAL_ENTROPY.write()
+        .map_err(|_| err!(FailedFunction, "entropy source is poisoned"))? = source;
+    Ok(())
+}
+
+/// Evaluate `f`, while all randomness drawn on the current thread comes from `source`.
+///
+/// Takes precedence over the process-wide source. Calls may be nested.
+pub fn with_entropy_source<R>(source: Arc<dyn EntropySource>, f: impl FnOnce() -> R) -> R {
+    // pops the source even if `f` panics
+    struct Scope;
+    impl Drop for Scope {
+        fn drop(&mut self) {
+            SCOPED_ENTROPY.with(|scoped| scoped.borrow_mut().pop());
+        }
+    }
+    SCOPED_ENTROPY.with(|scoped| scoped.borrow_mut().push(source));
+    let _scope = Scope;
+    f()
+}
+
+/// Evaluate `f`, while all randomness drawn on the current thread is buffered from the current source.
+///
+/// Used to draw many samples at once, like when releasing a vector.
+/// Blocks are at most [`EntropySource::max_block`] bytes, and sources that may not be read ahead are not buffered.
+/// See [`BufferedEntropy`].
+pub fn with_buffered_entropy<R>(f: impl FnOnce() -> Fallible<R>) -> Fallible<R> {
+    let source = current_entropy_source()?;
+    match source.max_block() {
+        0 => f(),
+        max_block => with_entropy_source(Arc::new(BufferedEntropy::new(source, max_block)), f)
+    }
+}
+
+/// The source that [`fill_bytes`](super::fill_bytes) currently reads from.
+fn current_entropy_source() -> Fallible<Arc<dyn EntropySource>> {
+    if let Some(source) = SCOPED_ENTROPY.with(|scoped| scoped.borrow().last().cloned()) {
+        return Ok(source)
+    }
+    let global = GLOBAL_ENTROPY.read()
+        .map_err(|_| err!(FailedFunction, "entropy source is poisoned"))?
+        .clone();
+    Ok(global.unwrap_or_else(|| Arc::new(SystemEntropy)))
+}
+
+pub(super) fn entropy_fill_bytes(buffer: &mut [u8]) -> Fallible<()> {
+    if let Some(source) = SCOPED_ENTROPY.with(|scoped| scoped.borrow().last().cloned()) {
+        return source.fill_bytes(buffer)
+    }
+    let global = GLOBAL_ENTROPY.read()
+        .map_err(|_| err!(FailedFunction, "entropy source is poisoned"))?
+        .clone();
+    match global {
+        Some(source) => source.fill_bytes(buffer),
+        None => super::system_fill_bytes(buffer)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::traits::samplers::{fill_bytes, Shuffle, SampleStandardBernoulli};
+
+    #[test]
+    fn test_replay_entropy() -> Fallible<()> {
+        let source = Arc::new(ReplayEntropy::new(vec![1, 2, 3]));
+        let mut buffer = [0u8; 2];
+        with_entropy_source(source, || fill_bytes(&mut buffer))?;
+        assert_eq!(buffer, [1, 2]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_exhausted_entropy() {
+        let source = Arc::new(ReplayEntropy::new(vec![0; 2]));
+        assert!(with_entropy_source(source, || vec![1, 2, 3, 4].shuffle()).is_err());
+    }
+
+    #[test]
+    fn test_record_and_replay() -> Fallible<()> {
+        let recorder = Arc::new(RecordingEntropy::new(Arc::new(SystemEntropy)));
+        let mut data = (0..100).collect::<Vec<i32>>();
+        with_entropy_source(recorder.clone(), || data.shuffle())?;
+
+        let mut replayed = (0..100).collect::<Vec<i32>>();
+        with_entropy_source(Arc::new(ReplayEntropy::new(recorder.recorded()?)),
+                            || replayed.shuffle())?;
+        assert_eq!(data, replayed);
+        Ok(())
+    }
+
+    /// A replayed stream that may be read ahead.
+    struct ReadAhead(ReplayEntropy);
+    impl EntropySource for ReadAhead {
+        fn fill_bytes(&self, buffer: &mut [u8]) -> Fallible<()> {
+            self.0.fill_bytes(buffer)
+        }
+    }
+
+    #[test]
+    fn test_buffered_entropy() -> Fallible<()> {
+        // buffering serves the same byte stream, in the same order
+        let bytes = (0..=255).cycle().take(1000).collect::<Vec<u8>>();
+        let source = Arc::new(ReadAhead(ReplayEntropy::new(bytes.clone())));
+        let buffered = with_entropy_source(source, || with_buffered_entropy(|| {
+            let mut buffer = Vec::new();
+            for size in [1, 7, 64, 200, 3] {
+                let mut chunk = vec![0u8; size];
+                fill_bytes(&mut chunk)?;
+                buffer.extend(chunk);
+            }
+            Ok(buffer)
+        }))?;
+        assert_eq!(buffered, &bytes[..buffered.len()]);
+
+        // a failed draw is not served
+        let source = Arc::new(ReadAhead(ReplayEntropy::new(vec![0; 10])));
+        assert!(with_entropy_source(source, || with_buffered_entropy(|| fill_bytes(&mut [0u8; 1]))).is_err());
+
+        // replayed streams are not read ahead, so a stream with exactly the bytes drawn is sufficient
+        let source = Arc::new(ReplayEntropy::new(vec![0; 10]));
+        with_entropy_source(source, || with_buffered_entropy(|| fill_bytes(&mut [0u8; 10])))?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_nested_entropy() -> Fallible<()> {
+        let zeros = Arc::new(ReplayEntropy::new(vec![0; 64]));
+        let ones = Arc::new(ReplayEntropy::new(vec![255; 64]));
+        let (outer, inner) = with_entropy_source(zeros, || -> Fallible<_> {
+            let inner = with_entropy_source(ones, bool::sample_standard_bernoulli)?;
+            Ok((bool::sample_standard_bernoulli()?, inner))
+        })?;
+        assert_ne!(outer, inner);
+        Ok(())
+    }
+}
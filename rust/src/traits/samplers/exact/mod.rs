@@ -0,0 +1,141 @@
+// Exact samplers for the Poisson, binomial and discrete exponential distributions, and the exponential mechanism,
+// built from the Bernoulli(exp(-x)) and geometric samplers in `cks20`.
+
+use rug::{Integer, Rational};
+
+use crate::error::Fallible;
+
+use super::{CastInternalRational, SampleBernoulli, SampleStandardBernoulli, SampleUniformIntBelow};
+use super::cks20::{sample_bernoulli_exp, sample_geometric_exp_fast};
+
+pub trait SampleBernoulliExp<T>: Sized {
+    /// Sample a single bit that is true with probability exp(-`x`).
+    ///
+    /// The sample is exact, as it only compares uniform integers against rationals.
+    ///
+    /// # Arguments
+    /// * `x` - A non-negative rational.
+    fn sample_bernoulli_exp(x: T) -> Fallible<Self>;
+}
+
+impl SampleBernoulliExp<Rational> for bool {
+    fn sample_bernoulli_exp(x: Rational) -> Fallible<bool> {
+        if x < 0 {
+            return fallible!(FailedFunction, "x must be non-negative")
+        }
+        sample_bernoulli_exp(x)
+    }
+}
+
+/// Sample from the Poisson distribution with rate `lambda`.
+///
+/// The rate is split into ceil(2 `lambda`) equal parts of at most 1/2,
+/// and each part is sampled by rejection from a geometric(1/2) proposal,
+/// so the time taken grows linearly in `lambda`.
+pub fn sample_poisson(lambda: Rational) -> Fallible<Integer> {
+    if lambda < 0 {
+        return fallible!(FailedFunction, "lambda must be non-negative")
+    }
+    let doubled = lambda * 2u32;
+    let (_, parts) = doubled.clone().fract_ceil(Integer::new());
+    if parts == 0 {
+        return Ok(Integer::new())
+    }
+
+    // each part has rate y / 2, where y = 2 lambda / parts is in (0, 1]
+    let y = doubled / &parts;
+    let mut total = Integer::new();
+    let mut i = Integer::new();
+    while i < parts {
+        total += sample_poisson_half(&y)?;
+        i += 1;
+    }
+    Ok(total)
+}
+
+// sample from a Poisson(y / 2) distribution
+// assumes y is a rational number in [0, 1]
+fn sample_poisson_half(y: &Rational) -> Fallible<Integer> {
+    loop {
+        // propose k with probability 2^-(k+1)
+        let mut k = Integer::new();
+        while bool::sample_standard_bernoulli()? {
+            k += 1;
+        }
+        // accept k with probability y^k / k!, which is proportional to the Poisson(y / 2) mass at k
+        // each proposal is accepted with probability exp(y / 2) / 2 >= 1/2
+        let mut j = Integer::from(1);
+        while j <= k && bool::sample_bernoulli(y.clone() / &j, false)? {
+            j += 1;
+        }
+        if j > k {
+            return Ok(k)
+        }
+    }
+}
+
+/// Sample from the binomial distribution:
+/// the number of successes in `trials` independent trials, each of which succeeds with probability `prob`.
+///
+/// Each trial is sampled separately, so the time taken grows linearly in `trials`.
+pub fn sample_binomial(trials: u64, prob: Rational) -> Fallible<u64> {
+    if prob < 0 || prob > 1 {
+        return fallible!(FailedFunction, "prob must be within [0, 1]")
+    }
+    let mut successes = 0;
+    for _ in 0..trials {
+        if bool::sample_bernoulli(prob.clone(), false)? {
+            successes += 1;
+        }
+    }
+    Ok(successes)
+}
+
+/// Sample from the discrete exponential distribution,
+/// where the probability of each non-negative integer k is proportional to exp(-k / `scale`).
+///
+/// This is the exponential distribution with the same scale, rounded down to an integer.
+pub fn sample_discrete_exponential(scale: Rational) -> Fallible<Integer> {
+    if scale < 0 {
+        return fallible!(FailedFunction, "scale must be non-negative")
+    }
+    if scale == 0 {
+        return Ok(Integer::new())
+    }
+    sample_geometric_exp_fast(scale.recip())
+}
+
+pub trait SampleExponentialMechanism: Sized {
+    /// Sample the index of one of `scores`, with probability proportional to exp(`score` / `scale`),
+    /// as in the exponential mechanism.
+    ///
+    /// Each index is proposed uniformly at random, and accepted with probability exp(-(max - `score`) / `scale`).
+    /// The scores and scale are converted exactly to rationals, so the sample is exact.
+    /// The expected number of proposals is at most the number of scores.
+    ///
+    /// # Arguments
+    /// * `scores` - Finite scores of each candidate.
+    /// * `scale` - A positive noise scale.
+    fn sample_exponential_mechanism(scores: &[Self], scale: Self) -> Fallible<usize>;
+}
+
+impl<T: Clone + CastInternalRational> SampleExponentialMechanism for T {
+    fn sample_exponential_mechanism(scores: &[Self], scale: Self) -> Fallible<usize> {
+        let scale = scale.into_rational().map_err(|_| err!(FailedFunction, "scale must be finite"))?;
+        if scale <= 0 {
+            return fallible!(FailedFunction, "scale must be positive")
+        }
+        let scores = scores.iter()
+            .map(|score| score.clone().into_rational().map_err(|_| err!(FailedFunction, "scores must be finite")))
+            .collect::<Fallible<Vec<Rational>>>()?;
+        let max = scores.iter().max()
+            .ok_or_else(|| err!(FailedFunction, "there must be at least one score"))?;
+
+        loop {
+            let index = usize::sample_uniform_int_below(scores.len())?;
+            if sample_bernoulli_exp(Rational::from(max - &scores[index]) / &scale)? {
+                return Ok(index)
+            }
+        }
+    }
+}
@@ -0,0 +1,211 @@
+// Exact samplers for the Poisson, binomial and discrete exponential distributions, and the exponential mechanism,
+// built from the Bernoulli(exp(-x)) and geometric samplers in `cks20_num`.
+//
+// This is a pure-Rust port of the MPFR-backed implementation in `exact`,
+// over the big integers and rationals from `num`.
+
+use num::{BigInt, BigRational, One, Signed, Zero};
+
+use crate::error::Fallible;
+
+use super::{CastInternalRational, SampleStandardBernoulli, SampleUniformIntBelow};
+use super::cks20_num::{sample_bernoulli_exp, sample_bernoulli_ratio, sample_geometric_exp_fast};
+
+pub trait SampleBernoulliExp<T>: Sized {
+    /// Sample a single bit that is true with probability exp(-`x`).
+    ///
+    /// The sample is exact, as it only compares uniform integers against rationals.
+    ///
+    /// # Arguments
+    /// * `x` - A non-negative rational.
+    fn sample_bernoulli_exp(x: T) -> Fallible<Self>;
+}
+
+impl SampleBernoulliExp<BigRational> for bool {
+    fn sample_bernoulli_exp(x: BigRational) -> Fallible<bool> {
+        if x.is_negative() {
+            return fallible!(FailedFunction, "x must be non-negative")
+        }
+        sample_bernoulli_exp(x.numer().clone(), x.denom())
+    }
+}
+
+/// Sample from the Poisson distribution with rate `lambda`.
+///
+/// The rate is split into ceil(2 `lambda`) equal parts of at most 1/2,
+/// and each part is sampled by rejection from a geometric(1/2) proposal,
+/// so the time taken grows linearly in `lambda`.
+pub fn sample_poisson(lambda: BigRational) -> Fallible<BigInt> {
+    if lambda.is_negative() {
+        return fallible!(FailedFunction, "lambda must be non-negative")
+    }
+    let doubled = lambda * BigInt::from(2);
+    let parts = doubled.ceil().to_integer();
+    if parts.is_zero() {
+        return Ok(BigInt::zero())
+    }
+
+    // each part has rate y / 2, where y = 2 lambda / parts is in (0, 1]
+    let y = doubled / &parts;
+    let mut total = BigInt::zero();
+    let mut i = BigInt::zero();
+    while i < parts {
+        total += sample_poisson_half(y.numer(), y.denom())?;
+        i += 1;
+    }
+    Ok(total)
+}
+
+// sample from a Poisson(y / 2) distribution, where y = numer / denom
+// assumes y is a rational number in [0, 1]
+fn sample_poisson_half(numer: &BigInt, denom: &BigInt) -> Fallible<BigInt> {
+    loop {
+        // propose k with probability 2^-(k+1)
+        let mut k = BigInt::zero();
+        while bool::sample_standard_bernoulli()? {
+            k += 1;
+        }
+        // accept k with probability y^k / k!, which is proportional to the Poisson(y / 2) mass at k
+        // each proposal is accepted with probability exp(y / 2) / 2 >= 1/2
+        let mut j = BigInt::one();
+        while j <= k && sample_bernoulli_ratio(numer, &(denom * &j))? {
+            j += 1;
+        }
+        if j > k {
+            return Ok(k)
+        }
+    }
+}
+
+/// Sample from the binomial distribution:
+/// the number of successes in `trials` independent trials, each of which succeeds with probability `prob`.
+///
+/// Each trial is sampled separately, so the time taken grows linearly in `trials`.
+pub fn sample_binomial(trials: u64, prob: BigRational) -> Fallible<u64> {
+    if prob.is_negative() || prob > BigRational::one() {
+        return fallible!(FailedFunction, "prob must be within [0, 1]")
+    }
+    let (numer, denom) = (prob.numer(), prob.denom());
+    let mut successes = 0;
+    for _ in 0..trials {
+        if sample_bernoulli_ratio(numer, denom)? {
+            successes += 1;
+        }
+    }
+    Ok(successes)
+}
+
+/// Sample from the discrete exponential distribution,
+/// where the probability of each non-negative integer k is proportional to exp(-k / `scale`).
+///
+/// This is the exponential distribution with the same scale, rounded down to an integer.
+pub fn sample_discrete_exponential(scale: BigRational) -> Fallible<BigInt> {
+    if scale.is_negative() {
+        return fallible!(FailedFunction, "scale must be non-negative")
+    }
+    if scale.is_zero() {
+        return Ok(BigInt::zero())
+    }
+    sample_geometric_exp_fast(&scale.recip())
+}
+
+pub trait SampleExponentialMechanism: Sized {
+    /// Sample the index of one of `scores`, with probability proportional to exp(`score` / `scale`),
+    /// as in the exponential mechanism.
+    ///
+    /// Each index is proposed uniformly at random, and accepted with probability exp(-(max - `score`) / `scale`).
+    /// The scores and scale are converted exactly to rationals, so the sample is exact.
+    /// The expected number of proposals is at most the number of scores.
+    ///
+    /// # Arguments
+    /// * `scores` - Finite scores of each candidate.
+    /// * `scale` - A positive noise scale.
+    fn sample_exponential_mechanism(scores: &[Self], scale: Self) -> Fallible<usize>;
+}
+
+impl<T: Clone + CastInternalRational> SampleExponentialMechanism for T {
+    fn sample_exponential_mechanism(scores: &[Self], scale: Self) -> Fallible<usize> {
+        let scale = scale.into_rational().map_err(|_| err!(FailedFunction, "scale must be finite"))?;
+        if !scale.is_positive() {
+            return fallible!(FailedFunction, "scale must be positive")
+        }
+        let scores = scores.iter()
+            .map(|score| score.clone().into_rational().map_err(|_| err!(FailedFunction, "scores must be finite")))
+            .collect::<Fallible<Vec<BigRational>>>()?;
+        let max = scores.iter().max()
+            .ok_or_else(|| err!(FailedFunction, "there must be at least one score"))?;
+
+        loop {
+            let index = usize::sample_uniform_int_below(scores.len())?;
+            if bool::sample_bernoulli_exp((max - &scores[index]) / &scale)? {
+                return Ok(index)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::traits::samplers::test_utils::*;
+
+    fn rational(numer: i64, denom: i64) -> BigRational {
+        BigRational::new(BigInt::from(numer), BigInt::from(denom))
+    }
+
+    #[test]
+    fn test_sample_bernoulli_exp() {
+        assert!(bool::sample_bernoulli_exp(rational(-1, 2)).is_err());
+        assert!(bool::sample_bernoulli_exp(BigRational::zero()).unwrap());
+        // P(Bernoulli(exp(-7/3)) = 1) = exp(-7/3)
+        let sampler = || if bool::sample_bernoulli_exp(rational(7, 3)).unwrap() { 1. } else { 0. };
+        assert!(test_proportion_parameters(sampler, (-7f64 / 3.).exp(), 0.00001, 0.01));
+    }
+
+    #[test]
+    fn test_sample_exponential_mechanism() -> Fallible<()> {
+        assert!(f64::sample_exponential_mechanism(&[], 1.).is_err());
+        assert!(f64::sample_exponential_mechanism(&[0., f64::NAN], 1.).is_err());
+        assert!(f64::sample_exponential_mechanism(&[0., 1.], 0.).is_err());
+        assert_eq!(f64::sample_exponential_mechanism(&[0., 1000., 0.], 1.)?, 1);
+        // P(X = 0) = exp(1 / 2) / (exp(1 / 2) + 2)
+        let sampler = || if f64::sample_exponential_mechanism(&[1., 0., 0.], 2.).unwrap() == 0 { 1. } else { 0. };
+        let e = 0.5f64.exp();
+        assert!(test_proportion_parameters(sampler, e / (e + 2.), 0.00001, 0.01));
+        Ok(())
+    }
+
+    #[test]
+    fn test_sample_poisson() -> Fallible<()> {
+        assert!(sample_poisson(rational(-1, 2)).is_err());
+        assert_eq!(sample_poisson(BigRational::zero())?, BigInt::zero());
+        // P(X = 0) = exp(-lambda)
+        [(rational(1, 3), 1f64 / 3.), (rational(5, 2), 2.5)].iter().for_each(|(lambda, lambda_f64)| {
+            let sampler = || if sample_poisson(lambda.clone()).unwrap().is_zero() { 1. } else { 0. };
+            assert!(test_proportion_parameters(sampler, (-lambda_f64).exp(), 0.00001, 0.01));
+        });
+        Ok(())
+    }
+
+    #[test]
+    fn test_sample_binomial() -> Fallible<()> {
+        assert!(sample_binomial(10, rational(3, 2)).is_err());
+        assert_eq!(sample_binomial(10, BigRational::one())?, 10);
+        assert_eq!(sample_binomial(10, BigRational::zero())?, 0);
+        // P(X = 3) = C(10, 3) 0.3^3 0.7^7
+        let p_three = 120. * 0.3f64.powi(3) * 0.7f64.powi(7);
+        let sampler = || if sample_binomial(10, rational(3, 10)).unwrap() == 3 { 1. } else { 0. };
+        assert!(test_proportion_parameters(sampler, p_three, 0.00001, 0.01));
+        Ok(())
+    }
+
+    #[test]
+    fn test_sample_discrete_exponential() -> Fallible<()> {
+        assert!(sample_discrete_exponential(rational(-1, 2)).is_err());
+        assert_eq!(sample_discrete_exponential(BigRational::zero())?, BigInt::zero());
+        // P(X = 0) = 1 - exp(-1/scale)
+        let sampler = || if sample_discrete_exponential(rational(2, 1)).unwrap().is_zero() { 1. } else { 0. };
+        assert!(test_proportion_parameters(sampler, 1. - (-0.5f64).exp(), 0.00001, 0.01));
+        Ok(())
+    }
+}
@@ -11,17 +11,43 @@ mod discretize;
 #[cfg(feature="use-mpfr")]
 pub use discretize::*;
 
+// without MPFR, the exact samplers are computed over big integers and rationals from `num`
+#[cfg(not(feature="use-mpfr"))]
+mod cks20_num;
+#[cfg(not(feature="use-mpfr"))]
+pub use cks20_num::*;
+
+#[cfg(not(feature="use-mpfr"))]
+mod discretize_num;
+#[cfg(not(feature="use-mpfr"))]
+pub use discretize_num::*;
+
+#[cfg(feature="use-mpfr")]
+mod exact;
+#[cfg(feature="use-mpfr")]
+pub use exact::*;
+
+#[cfg(not(feature="use-mpfr"))]
+mod exact_num;
+#[cfg(not(feature="use-mpfr"))]
+pub use exact_num::*;
+
 mod geometric;
 pub use geometric::*;
 
 mod uniform;
 pub use uniform::*;
 
-// these samplers are only accessible if the library is compiled in an explicitly unsafe way
-#[cfg(not(feature = "use-mpfr"))]
-mod vulnerable_fallbacks;
-#[cfg(not(feature = "use-mpfr"))]
-pub use vulnerable_fallbacks::*;
+mod entropy;
+pub use entropy::*;
+
+// seeded samplers are only accessible in debug builds with the `seeded-sampling` feature
+#[cfg(feature = "seeded-sampling")]
+mod seeded;
+#[cfg(feature = "seeded-sampling")]
+pub use seeded::*;
+#[cfg(all(feature = "seeded-sampling", not(debug_assertions)))]
+compile_error!("the seeded-sampling feature makes all releases reproducible, and may only be enabled in debug builds");
 
 
 use rand::RngCore;
@@ -31,11 +57,16 @@ use rug::rand::ThreadRandGen;
 
 
 use crate::error::Fallible;
-#[cfg(any(not(feature="use-mpfr"), not(feature="use-openssl")))]
+#[cfg(not(feature="use-openssl"))]
 use rand::Rng;
 
-#[cfg(feature="use-openssl")]
+/// Fill `buffer` with random bytes from the current [`EntropySource`].
 pub fn fill_bytes(buffer: &mut [u8]) -> Fallible<()> {
+    entropy_fill_bytes(buffer)
+}
+
+#[cfg(feature="use-openssl")]
+fn system_fill_bytes(buffer: &mut [u8]) -> Fallible<()> {
     use openssl::rand::rand_bytes;
     if let Err(e) = rand_bytes(buffer) {
         fallible!(FailedFunction, "OpenSSL error: {:?}", e)
@@ -43,12 +74,38 @@ pub fn fill_bytes(buffer: &mut [u8]) -> Fallible<()> {
 }
 
 #[cfg(not(feature="use-openssl"))]
-pub fn fill_bytes(buffer: &mut [u8]) -> Fallible<()> {
+fn system_fill_bytes(buffer: &mut [u8]) -> Fallible<()> {
     if let Err(e) = rand::thread_rng().try_fill(buffer) {
         fallible!(FailedFunction, "Rand error: {:?}", e)
     } else { Ok(()) }
 }
 
+// Iteration bounds for the fixed-iteration samplers.
+// A fixed-iteration sampler always runs each loop for exactly the bound,
+//    so that the number of iterations and random draws does not depend on the sample,
+//    and fails if the loop would have needed more iterations.
+// Each bound makes a single loop fail with probability less than 2^-50.
+
+/// Bernoulli(exp(-x)) trials, for x in [0, 1]. Runs out with probability x^18 / 18! < 2^-52.
+pub(crate) const FIXED_EXP1_TRIALS: usize = 18;
+/// Bernoulli(exp(-1)) trials, for the integer part of x in Bernoulli(exp(-x)).
+/// Larger x are rounded down to exp(-x) = 0, an error of less than exp(-36) < 2^-51.
+pub(crate) const FIXED_EXP_WHOLE_TRIALS: usize = 36;
+/// Bernoulli(exp(-1)) trials in a geometric(1 - exp(-1)) draw. Runs out with probability exp(-36) < 2^-51.
+pub(crate) const FIXED_GEOMETRIC_TRIALS: usize = 36;
+/// Proposals in a geometric(1 - exp(-x)) draw. Each is accepted with probability at least exp(-1),
+///     so all are rejected with probability less than (1 - exp(-1))^80 < 2^-52.
+pub(crate) const FIXED_GEOMETRIC_PROPOSALS: usize = 80;
+/// Proposals in a discrete gaussian draw. Each is accepted with probability at least 1/4,
+///     so all are rejected with probability less than (3/4)^128 < 2^-53.
+pub(crate) const FIXED_GAUSSIAN_PROPOSALS: usize = 128;
+/// Proposals in a uniform integer draw. Each is accepted with probability at least 1/2,
+///     so all are rejected with probability at most 2^-64.
+pub(crate) const FIXED_UNIFORM_TRIALS: usize = 64;
+/// Extra bits drawn when sampling a big integer uniformly below `upper` by reduction mod `upper`.
+///     The result is within a statistical distance of 2^-64 of uniform.
+pub(crate) const FIXED_UNIFORM_SLACK_BITS: usize = 64;
+
 pub struct GeneratorOpenDP {
     pub error: Fallible<()>,
 }
@@ -0,0 +1,80 @@
+//! Reproducible sampling from a seed, for regression tests.
+//!
+//! Only compiled with the `seeded-sampling` feature, which is rejected in release builds.
+//! Releases drawn from a seeded source are NOT differentially private:
+//! anyone who knows the seed can reproduce the noise, and subtract it.
+
+use std::sync::{Arc, Mutex};
+
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+
+use crate::error::Fallible;
+
+use super::{set_entropy_source, with_entropy_source, EntropySource};
+
+/// An entropy source that expands a seed into a reproducible byte stream.
+///
+/// The stream is only stable for a fixed version of the `rand` crate.
+pub struct SeededEntropy {
+    rng: Mutex<StdRng>,
+}
+
+impl SeededEntropy {
+    pub fn new(seed: u64) -> Self {
+        SeededEntropy { rng: Mutex::new(StdRng::seed_from_u64(seed)) }
+    }
+}
+
+impl EntropySource for SeededEntropy {
+    fn fill_bytes(&self, buffer: &mut [u8]) -> Fallible<()> {
+        self.rng.lock()
+            .map_err(|_| err!(FailedFunction, "seeded entropy is poisoned"))?
+            .fill_bytes(buffer);
+        Ok(())
+    }
+}
+
+/// Make all samplers in the process reproducible from `seed`.
+pub fn set_seed(seed: u64) -> Fallible<()> {
+    set_entropy_source(Some(Arc::new(SeededEntropy::new(seed))))
+}
+
+/// Evaluate `f`, while all samplers on the current thread are reproducible from `seed`.
+pub fn with_seed<R>(seed: u64, f: impl FnOnce() -> R) -> R {
+    with_entropy_source(Arc::new(SeededEntropy::new(seed)), f)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::traits::samplers::{SampleBernoulli, SampleGeometric, SampleUniform, Shuffle};
+    use crate::traits::samplers::{SampleDiscreteGaussianZ2k, SampleDiscreteLaplaceZ2k};
+
+    fn draw() -> Fallible<(bool, u32, f64, Vec<i32>)> {
+        let mut shuffled = (0..20).collect::<Vec<i32>>();
+        shuffled.shuffle()?;
+        Ok((
+            bool::sample_bernoulli(0.3, false)?,
+            u32::sample_geometric(0, true, 0.2, None)?,
+            f64::sample_standard_uniform(false)?,
+            shuffled))
+    }
+
+    #[test]
+    fn test_seeded_draws_are_reproducible() -> Fallible<()> {
+        assert_eq!(with_seed(42, draw)?, with_seed(42, draw)?);
+        assert_ne!(with_seed(42, draw)?.3, with_seed(43, draw)?.3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_seeded_z2k_is_reproducible() -> Fallible<()> {
+        let draw = || -> Fallible<(f64, f64)> {
+            Ok((f64::sample_discrete_laplace_Z2k(0., 1., -20)?,
+                f64::sample_discrete_gaussian_Z2k(0., 1., -20)?))
+        };
+        assert_eq!(with_seed(7, draw)?, with_seed(7, draw)?);
+        Ok(())
+    }
+}
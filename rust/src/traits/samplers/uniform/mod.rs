@@ -1,8 +1,10 @@
 use std::{mem::size_of, ops::Sub};
 
 use crate::{error::Fallible, traits::{FloatBits, ExactIntCast, InfDiv}};
+#[cfg(not(feature="use-mpfr"))]
+use crate::error::ExplainUnwrap;
 
-use super::{fill_bytes, sample_geometric_buffer};
+use super::{fill_bytes, sample_geometric_buffer, FIXED_UNIFORM_SLACK_BITS, FIXED_UNIFORM_TRIALS};
 
 #[cfg(feature="use-mpfr")]
 use super::GeneratorOpenDP;
@@ -125,6 +127,17 @@ pub trait SampleUniformInt: Sized {
 pub trait SampleUniformIntBelow: Sized {
     /// sample uniformly from [0, upper)
     fn sample_uniform_int_below(upper: Self) -> Fallible<Self>;
+
+    /// sample uniformly from [0, upper), with a number of iterations and draws that does not depend on the sample
+    ///
+    /// Fails, or deviates from uniform, with probability at most 2^-64.
+    fn sample_uniform_int_below_fixed_iteration(upper: Self) -> Fallible<Self>;
+}
+
+// v % upper is unbiased for any v <= threshold, because
+// [0, threshold] evenly folds into [0, upper) (MAX + 1) / upper times
+fn rejection_threshold<T: num::PrimInt>(upper: T) -> T {
+    T::max_value() - (T::max_value() % upper + T::one()) % upper
 }
 
 macro_rules! impl_sample_uniform_unsigned_int {
@@ -138,16 +151,28 @@ macro_rules! impl_sample_uniform_unsigned_int {
         }
         impl SampleUniformIntBelow for $ty {
             fn sample_uniform_int_below(upper: Self) -> Fallible<Self> {
-                // v % upper is unbiased for any v < MAX - MAX % upper, because
-                // MAX - MAX % upper evenly folds into [0, upper) RAND_MAX/upper times
+                let threshold = rejection_threshold(upper);
                 loop {
                     // algorithm is only valid when sample_uniform_int is non-negative
                     let v = Self::sample_uniform_int()?;
-                    if v <= Self::MAX - Self::MAX % upper {
+                    if v <= threshold {
                         return Ok(v % upper)
                     }
                 }
             }
+            fn sample_uniform_int_below_fixed_iteration(upper: Self) -> Fallible<Self> {
+                let threshold = rejection_threshold(upper);
+                // each trial is accepted with probability at least 1/2.
+                // Always take every trial, and keep the first accepted sample
+                let mut sample = None;
+                for _ in 0..FIXED_UNIFORM_TRIALS {
+                    let v = Self::sample_uniform_int()?;
+                    if v <= threshold && sample.is_none() {
+                        sample = Some(v % upper)
+                    }
+                }
+                sample.ok_or_else(|| err!(FailedFunction, "fixed-iteration uniform sampler ran out of trials"))
+            }
         }
     )+)
 }
@@ -163,6 +188,55 @@ impl SampleUniformIntBelow for rug::Integer {
         };
         rng.error.map(|_| ret)
     }
+    fn sample_uniform_int_below_fixed_iteration(upper: Self) -> Fallible<Self> {
+        if upper <= 0 {
+            return fallible!(FailedFunction, "upper must be positive")
+        }
+        // draw slack bits beyond the width of upper, and reduce.
+        // The bias of the reduction is at most upper / 2^(bits + slack) < 2^-slack
+        let bits = upper.significant_bits() + FIXED_UNIFORM_SLACK_BITS as u32;
+        let mut rng = GeneratorOpenDP::new();
+        let ret = {
+            let mut state = ThreadRandState::new_custom(&mut rng);
+            rug::Integer::from(rug::Integer::random_bits(bits, &mut state)) % upper
+        };
+        rng.error.map(|_| ret)
+    }
+}
+
+#[cfg(not(feature="use-mpfr"))]
+impl SampleUniformIntBelow for num::BigInt {
+    fn sample_uniform_int_below(upper: Self) -> Fallible<Self> {
+        use num::Signed;
+        if !upper.is_positive() {
+            return fallible!(FailedFunction, "upper must be positive")
+        }
+        // rejection sample from the smallest power of two that is at least `upper`
+        let bits = upper.bits() as usize;
+        let mut buffer = vec![0u8; (bits + 7) / 8];
+        let mask = u8::MAX >> (buffer.len() * 8 - bits);
+        loop {
+            fill_bytes(&mut buffer)?;
+            // the last byte is the most significant, because the buffer is little-endian
+            *buffer.last_mut().unwrap_assert("upper is positive, so the buffer is non-empty") &= mask;
+            let v = num::BigInt::from_bytes_le(num::bigint::Sign::Plus, &buffer);
+            if v < upper {
+                return Ok(v)
+            }
+        }
+    }
+    fn sample_uniform_int_below_fixed_iteration(upper: Self) -> Fallible<Self> {
+        use num::Signed;
+        if !upper.is_positive() {
+            return fallible!(FailedFunction, "upper must be positive")
+        }
+        // draw slack bits beyond the width of upper, and reduce.
+        // The bias of the reduction is at most upper / 2^(bits + slack) < 2^-slack
+        let bits = upper.bits() as usize + FIXED_UNIFORM_SLACK_BITS;
+        let mut buffer = vec![0u8; (bits + 7) / 8];
+        fill_bytes(&mut buffer)?;
+        Ok(num::BigInt::from_bytes_le(num::bigint::Sign::Plus, &buffer) % upper)
+    }
 }
 
 #[cfg(test)]
@@ -170,6 +244,19 @@ mod test_uniform_int {
     use super::*;
     use std::collections::HashMap;
 
+    #[test]
+    fn test_rejection_threshold() {
+        // the accepted range must be a nonempty multiple of upper
+        (1..=u8::MAX).for_each(|upper| {
+            let accepted = rejection_threshold(upper) as u16 + 1;
+            assert_eq!(accepted % upper as u16, 0);
+            assert!(256 - accepted < upper as u16);
+        });
+        assert_eq!(rejection_threshold(3u8), 254);
+        assert_eq!(rejection_threshold(128u8), 255);
+        assert_eq!(rejection_threshold(u64::MAX), u64::MAX - 1);
+    }
+
     #[test]
     #[ignore]
     fn test_sample_uniform_int_below() -> Fallible<()> {
@@ -184,6 +271,15 @@ mod test_uniform_int {
         Ok(())
     }
 
+    #[test]
+    fn test_sample_uniform_int_below_fixed_iteration() -> Fallible<()> {
+        (0..1000).try_for_each(|_| {
+            assert!(u8::sample_uniform_int_below_fixed_iteration(3)? < 3);
+            assert!(u64::sample_uniform_int_below_fixed_iteration(7)? < 7);
+            Fallible::Ok(())
+        })
+    }
+
     #[test]
     #[ignore]
     fn test_sample_uniform_int() -> Fallible<()> {
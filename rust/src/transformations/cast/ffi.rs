@@ -60,7 +60,7 @@ mod tests {
     use crate::core;
     use crate::error::Fallible;
     use crate::ffi::any::{AnyObject, Downcast};
-    use crate::ffi::util::ToCharP;
+    use crate::ffi::util::{self, ToCharP};
 
     use super::*;
 
@@ -71,7 +71,7 @@ mod tests {
             "f64".to_char_p(),
         ))?;
         let arg = AnyObject::new_raw(vec![1, 2, 3]);
-        let res = core::opendp_core__transformation_invoke(&transformation, arg);
+        let res = core::opendp_core__transformation_invoke(&transformation, arg, util::from_bool(false));
         let res: Vec<Option<f64>> = Fallible::from(res)?.downcast()?;
         assert_eq!(res, vec![Some(1.0), Some(2.0), Some(3.0)]);
         Ok(())
@@ -84,7 +84,7 @@ mod tests {
             "i32".to_char_p(),
         ))?;
         let arg = AnyObject::new_raw(vec!["a".to_string(), "1".to_string()]);
-        let res = core::opendp_core__transformation_invoke(&transformation, arg);
+        let res = core::opendp_core__transformation_invoke(&transformation, arg, util::from_bool(false));
         let res: Vec<i32> = Fallible::from(res)?.downcast()?;
         assert_eq!(res, vec![0, 1]);
         Ok(())
@@ -97,7 +97,7 @@ mod tests {
             "f64".to_char_p(),
         ))?;
         let arg = AnyObject::new_raw(vec!["a".to_string(), "1".to_string()]);
-        let res = core::opendp_core__transformation_invoke(&transformation, arg);
+        let res = core::opendp_core__transformation_invoke(&transformation, arg, util::from_bool(false));
         let res: Vec<f64> = Fallible::from(res)?.downcast()?;
         assert!(res[0].is_nan());
         assert_eq!(res[1], 1.);
@@ -1,7 +1,7 @@
 use crate::{
     core::{Domain, Function, StabilityMap, Transformation},
     metrics::IntDistance,
-    domains::SizedDomain,
+    domains::{CollectionSize, SizedDomain},
     error::Fallible, 
     traits::samplers::Shuffle,
 };
@@ -57,7 +57,7 @@ pub fn make_metric_unbounded<D, MI>(
 ) -> Fallible<Transformation<SizedDomain<D>, SizedDomain<D>, MI, MI::UnboundedMetric>>
 where
     D: Domain,
-    D::Carrier: Clone,
+    D::Carrier: Clone + CollectionSize,
     MI: BoundedMetric<Distance = IntDistance>,
 {
     Ok(Transformation::new(
@@ -75,7 +75,7 @@ pub fn make_metric_bounded<D, MI>(
 ) -> Fallible<Transformation<SizedDomain<D>, SizedDomain<D>, MI, MI::BoundedMetric>>
 where
     D: Domain,
-    D::Carrier: Clone,
+    D::Carrier: Clone + CollectionSize,
     MI: UnboundedMetric<Distance = IntDistance>,
 {
     Ok(Transformation::new(
@@ -61,7 +61,7 @@ mod tests {
             "f64".to_char_p(),
         ))?;
         let arg = AnyObject::new_raw(vec![-1.0, 5.0, 11.0]);
-        let res = core::opendp_core__transformation_invoke(&transformation, arg);
+        let res = core::opendp_core__transformation_invoke(&transformation, arg, util::from_bool(false));
         let res: Vec<f64> = Fallible::from(res)?.downcast()?;
         assert_eq!(res, vec![0.0, 5.0, 10.0]);
         Ok(())
@@ -5,9 +5,9 @@ use std::collections::Bound;
 
 use crate::core::Transformation;
 use crate::metrics::SymmetricDistance;
-use crate::domains::{AllDomain, BoundedDomain, VectorDomain};
+use crate::domains::{AllDomain, BoundedDomain, VectorDomain, LpBoundedDomain, lp_norm};
 use crate::error::*;
-use crate::traits::{CheckNull, TotalOrd};
+use crate::traits::{CheckNull, TotalOrd, Float};
 use crate::transformations::{make_row_by_row, make_row_by_row_fallible};
 
 pub fn make_clamp<T: 'static + Clone + TotalOrd + CheckNull>(
@@ -28,10 +28,42 @@ pub fn make_unclamp<T: 'static + Clone + TotalOrd + CheckNull>(
         |arg| arg.clone())
 }
 
+/// Make a Transformation that rescales each row so that its Lp norm is at most `norm`.
+///
+/// Rows whose norm is already at most `norm` are left unchanged.
+/// Rows whose norm cannot be computed (for example, because it overflows) are replaced with zeros.
+pub fn make_clamp_norm<const P: usize, T: 'static + Float>(
+    norm: T
+) -> Fallible<Transformation<VectorDomain<VectorDomain<AllDomain<T>>>, VectorDomain<LpBoundedDomain<P, T>>, SymmetricDistance, SymmetricDistance>> {
+    make_row_by_row(
+        VectorDomain::new_all(),
+        LpBoundedDomain::new(norm)?,
+        move |arg: &Vec<T>| {
+            let row_norm = match lp_norm::<P, T>(arg) {
+                Ok(row_norm) if row_norm.is_finite() => row_norm,
+                _ => return vec![T::zero(); arg.len()]
+            };
+            if row_norm <= norm {
+                return arg.clone()
+            }
+            // round the scale down, and then round the magnitude of each element down,
+            //     so that the norm of the result is no greater than `norm`
+            let scale = match norm.neg_inf_div(&row_norm) {
+                Ok(scale) => scale,
+                Err(_) => return vec![T::zero(); arg.len()]
+            };
+            arg.iter().map(|v| v.abs().neg_inf_mul(&scale)
+                .map(|mag| if v.is_sign_negative() { -mag } else { mag })
+                .unwrap_or_else(|_| T::zero())).collect()
+        })
+}
+
 
 #[cfg(test)]
 mod tests {
     use crate::transformations::{make_clamp, make_unclamp};
+    use crate::core::Domain;
+    use crate::domains::lp_norm;
 
     use super::*;
 
@@ -53,4 +85,23 @@ mod tests {
         assert!(chained.check(&1, &1)?);
         Ok(())
     }
+
+    #[test]
+    fn test_make_clamp_norm() -> Fallible<()> {
+        let clamp = make_clamp_norm::<2, f64>(1.)?;
+        let arg = vec![vec![3., 4.], vec![0.3, -0.4], vec![]];
+        let ret = clamp.invoke(&arg)?;
+        assert!(lp_norm::<2, f64>(&ret[0])? <= 1.);
+        assert!((ret[0][0] - 0.6).abs() < 1e-12 && (ret[0][1] - 0.8).abs() < 1e-12);
+        assert_eq!(ret[1], vec![0.3, -0.4]);
+        assert_eq!(ret[2], Vec::<f64>::new());
+        assert!(clamp.output_domain.member(&ret)?);
+        assert!(clamp.check(&1, &1)?);
+
+        let clamp = make_clamp_norm::<1, f64>(2.)?;
+        let ret = clamp.invoke(&vec![vec![-3., 1.]])?;
+        assert!(lp_norm::<1, f64>(&ret[0])? <= 2.);
+        assert!(ret[0][0] < 0.);
+        Ok(())
+    }
 }
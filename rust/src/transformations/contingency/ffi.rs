@@ -0,0 +1,87 @@
+use std::convert::TryFrom;
+use std::os::raw::c_char;
+
+use crate::core::{DatasetMetric, FfiResult, IntoAnyTransformationFfiResultExt};
+use crate::metrics::{ChangeOneDistance, IntDistance, L1Distance, L2Distance, SymmetricDistance};
+use crate::ffi::any::{AnyObject, AnyTransformation, Downcast};
+use crate::ffi::util::Type;
+use crate::traits::{DistanceConstant, Hashable, Number};
+use crate::transformations::{ContingencyTableConstant, make_contingency_tables};
+
+#[no_mangle]
+pub extern "C" fn opendp_transformations__make_contingency_tables(
+    keys: *const AnyObject,
+    categories: *const AnyObject,
+    marginals: *const AnyObject,
+    MI: *const c_char, MO: *const c_char,
+    K: *const c_char, TA: *const c_char, TC: *const c_char,
+) -> FfiResult<*mut AnyTransformation> {
+    fn monomorphize<MI, MO, K, TA, TC>(
+        keys: *const AnyObject,
+        categories: *const AnyObject,
+        marginals: *const AnyObject,
+    ) -> FfiResult<*mut AnyTransformation>
+        where MI: 'static + DatasetMetric,
+              MO: 'static + ContingencyTableConstant<MI>,
+              MO::Distance: DistanceConstant<IntDistance>,
+              K: Hashable,
+              TA: Hashable,
+              TC: Number {
+        let keys = try_!(try_as_ref!(keys).downcast_ref::<Vec<K>>()).clone();
+        let categories = try_!(try_as_ref!(categories).downcast_ref::<Vec<Vec<TA>>>()).clone();
+        let marginals = try_!(try_as_ref!(marginals).downcast_ref::<Vec<Vec<K>>>()).clone();
+        make_contingency_tables::<MI, MO, K, TA, TC>(keys, categories, marginals).into_any()
+    }
+    let MI = try_!(Type::try_from(MI));
+    let MO = try_!(Type::try_from(MO));
+    let K = try_!(Type::try_from(K));
+    let TA = try_!(Type::try_from(TA));
+    let TC = try_!(Type::try_from(TC));
+    // the key and category types are limited to the common types of dataframe columns, to bound the number of monomorphizations
+    dispatch!(monomorphize, [
+        (MI, [SymmetricDistance, ChangeOneDistance]),
+        (MO, [L1Distance<i32>, L1Distance<i64>, L1Distance<f64>, L2Distance<f64>]),
+        (K, [String, i32, i64, bool]),
+        (TA, [String, i32, i64, bool]),
+        (TC, [i32, i64])
+    ], (keys, categories, marginals))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::core::opendp_core__transformation_invoke;
+    use crate::data::Column;
+    use crate::error::Fallible;
+    use crate::ffi::any::{AnyObject, Downcast};
+    use crate::ffi::util;
+    use crate::ffi::util::ToCharP;
+    use crate::transformations::DataFrame;
+
+    use super::*;
+
+    #[test]
+    fn test_make_contingency_tables() -> Fallible<()> {
+        let transformation = Result::from(opendp_transformations__make_contingency_tables(
+            util::into_raw(AnyObject::new(vec!["a".to_string(), "b".to_string()])),
+            util::into_raw(AnyObject::new(vec![
+                vec!["1".to_string(), "2".to_string()],
+                vec!["1".to_string(), "0".to_string()]])),
+            util::into_raw(AnyObject::new(vec![vec!["a".to_string()], vec!["a".to_string(), "b".to_string()]])),
+            "SymmetricDistance".to_char_p(),
+            "L1Distance<i32>".to_char_p(),
+            "String".to_char_p(),
+            "String".to_char_p(),
+            "i32".to_char_p(),
+        ))?;
+        let arg: DataFrame<String> = vec![
+            ("a".to_string(), Column::new(vec!["1".to_string(), "2".to_string(), "2".to_string()])),
+            ("b".to_string(), Column::new(vec!["1".to_string(), "0".to_string(), "1".to_string()])),
+        ].into_iter().collect();
+        let arg = AnyObject::new_raw(arg);
+        let res = opendp_core__transformation_invoke(&transformation, arg, util::from_bool(false));
+        let res: Vec<i32> = Fallible::from(res)?.downcast()?;
+        assert_eq!(res, vec![1, 2, 1, 0, 1, 1]);
+        Ok(())
+    }
+}
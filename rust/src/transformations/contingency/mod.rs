@@ -0,0 +1,244 @@
+#[cfg(feature="ffi")]
+mod ffi;
+
+use std::collections::{HashMap, HashSet};
+
+use crate::core::{DatasetMetric, Function, SensitivityMetric, StabilityMap, Transformation};
+use crate::domains::{AllDomain, VectorDomain};
+use crate::error::*;
+use crate::metrics::{ChangeOneDistance, IntDistance, L1Distance, L2Distance, SymmetricDistance};
+use crate::traits::{DistanceConstant, Float, Hashable, Number};
+use crate::transformations::{DataFrame, DataFrameDomain};
+
+/// Bounds the change in a concatenation of `num_tables` contingency tables, per unit change in the input dataset metric.
+pub trait ContingencyTableConstant<MI: DatasetMetric>: SensitivityMetric {
+    fn get_stability_constant(num_tables: usize) -> Fallible<Self::Distance>;
+}
+
+// adding or removing a record changes one cell in each table by one
+impl<Q: Number> ContingencyTableConstant<SymmetricDistance> for L1Distance<Q> {
+    fn get_stability_constant(num_tables: usize) -> Fallible<Q> {
+        Q::exact_int_cast(num_tables)
+    }
+}
+// changing a record decrements one cell and increments another cell in each table
+impl<Q: Number> ContingencyTableConstant<ChangeOneDistance> for L1Distance<Q> {
+    fn get_stability_constant(num_tables: usize) -> Fallible<Q> {
+        Q::exact_int_cast(num_tables)?.inf_mul(&Q::exact_int_cast(2)?)
+    }
+}
+// sqrt(sum over tables of 1^2)
+impl<Q: Float> ContingencyTableConstant<SymmetricDistance> for L2Distance<Q> {
+    fn get_stability_constant(num_tables: usize) -> Fallible<Q> {
+        Q::exact_int_cast(num_tables)?.inf_sqrt()
+    }
+}
+// sqrt(sum over tables of (1^2 + 1^2))
+impl<Q: Float> ContingencyTableConstant<ChangeOneDistance> for L2Distance<Q> {
+    fn get_stability_constant(num_tables: usize) -> Fallible<Q> {
+        Q::exact_int_cast(num_tables)?.inf_mul(&Q::exact_int_cast(2)?)?.inf_sqrt()
+    }
+}
+
+/// Make a Transformation that computes a flattened contingency table over the columns `keys` of a dataframe.
+///
+/// Column `keys[i]` must be a `Vec<TA>`, whose public set of values is `categories[i]`.
+/// The table is laid out in row-major order, so the category of the last key varies fastest.
+/// Records with a value outside of the categories of any key are not counted.
+pub fn make_contingency_table<MI, MO, K, TA, TC>(
+    keys: Vec<K>,
+    categories: Vec<Vec<TA>>,
+) -> Fallible<Transformation<DataFrameDomain<K>, VectorDomain<AllDomain<TC>>, MI, MO>>
+    where MI: DatasetMetric,
+          MO: ContingencyTableConstant<MI>,
+          MO::Distance: DistanceConstant<IntDistance>,
+          K: Hashable,
+          TA: Hashable,
+          TC: Number {
+    make_contingency_tables(keys.clone(), categories, vec![keys])
+}
+
+/// Make a Transformation that computes many marginals of a dataframe at once.
+///
+/// Each marginal is a subset of `keys`, and is computed as in [`make_contingency_table`].
+/// The flattened tables are concatenated in the same order as `marginals`.
+/// Since each record contributes to every table, the sensitivity scales with the number of marginals:
+/// linearly under the L1 distance, and by the square root under the L2 distance.
+pub fn make_contingency_tables<MI, MO, K, TA, TC>(
+    keys: Vec<K>,
+    categories: Vec<Vec<TA>>,
+    marginals: Vec<Vec<K>>,
+) -> Fallible<Transformation<DataFrameDomain<K>, VectorDomain<AllDomain<TC>>, MI, MO>>
+    where MI: DatasetMetric,
+          MO: ContingencyTableConstant<MI>,
+          MO::Distance: DistanceConstant<IntDistance>,
+          K: Hashable,
+          TA: Hashable,
+          TC: Number {
+    if keys.len() != categories.len() {
+        return fallible!(MakeTransformation, "there must be one set of categories for each key")
+    }
+    if !are_distinct(&keys) {
+        return fallible!(MakeTransformation, "keys must be distinct")
+    }
+    if !categories.iter().all(|cats| are_distinct(cats)) {
+        return fallible!(MakeTransformation, "categories must be distinct")
+    }
+    if marginals.is_empty() {
+        return fallible!(MakeTransformation, "there must be at least one marginal")
+    }
+
+    // position of each key in `keys`
+    let positions = keys.iter().enumerate()
+        .map(|(i, k)| (k.clone(), i)).collect::<HashMap<K, usize>>();
+
+    // for each marginal, the position and stride of each of its keys, and the number of cells
+    let layouts = marginals.iter().map(|marginal| {
+        if !are_distinct(marginal) {
+            return fallible!(MakeTransformation, "keys in a marginal must be distinct")
+        }
+        let mut size = 1usize;
+        let mut layout = marginal.iter().rev().map(|key| {
+            let pos = *positions.get(key)
+                .ok_or_else(|| err!(MakeTransformation, "marginal key does not exist: {:?}", key))?;
+            let stride = size;
+            size = size.checked_mul(categories[pos].len())
+                .ok_or_else(|| err!(MakeTransformation, "contingency table is too large"))?;
+            Ok((pos, stride))
+        }).collect::<Fallible<Vec<(usize, usize)>>>()?;
+        layout.reverse();
+        Ok((layout, size))
+    }).collect::<Fallible<Vec<(Vec<(usize, usize)>, usize)>>>()?;
+
+    let total_size = layouts.iter().try_fold(0usize, |acc, (_, size)| acc.checked_add(*size))
+        .ok_or_else(|| err!(MakeTransformation, "contingency tables are too large"))?;
+
+    // index of each category within its key
+    let indexes = categories.into_iter()
+        .map(|cats| cats.into_iter().enumerate().map(|(i, c)| (c, i)).collect())
+        .collect::<Vec<HashMap<TA, usize>>>();
+
+    let stability_constant = MO::get_stability_constant(marginals.len())?;
+
+    Ok(Transformation::new(
+        DataFrameDomain::new_all(),
+        VectorDomain::new_all(),
+        Function::new_fallible(move |arg: &DataFrame<K>| -> Fallible<Vec<TC>> {
+            let columns = keys.iter().map(|key| arg.get(key)
+                .ok_or_else(|| err!(FailedFunction, "column does not exist: {:?}", key))?
+                .as_form::<Vec<TA>>())
+                .collect::<Fallible<Vec<&Vec<TA>>>>()?;
+
+            let num_records = columns.first().map(|c| c.len()).unwrap_or(0);
+            if columns.iter().any(|c| c.len() != num_records) {
+                return fallible!(FailedFunction, "columns must have the same length")
+            }
+
+            // category index of each value, or None if the value is not a category
+            let cells = columns.iter().zip(indexes.iter())
+                .map(|(column, index)| column.iter().map(|v| index.get(v).cloned()).collect())
+                .collect::<Vec<Vec<Option<usize>>>>();
+
+            let mut counts = vec![TC::zero(); total_size];
+            let mut offset = 0;
+            layouts.iter().for_each(|(layout, size)| {
+                (0..num_records).for_each(|r| {
+                    let idx = layout.iter()
+                        .try_fold(0, |acc, (pos, stride)| Some(acc + cells[*pos][r]? * stride));
+                    if let Some(idx) = idx {
+                        let count = &mut counts[offset + idx];
+                        *count = TC::one().saturating_add(count)
+                    }
+                });
+                offset += size;
+            });
+            Ok(counts)
+        }),
+        MI::default(),
+        MO::default(),
+        StabilityMap::new_from_constant(stability_constant)))
+}
+
+fn are_distinct<T: Hashable>(values: &[T]) -> bool {
+    let mut uniques = HashSet::new();
+    values.iter().all(move |v| uniques.insert(v))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::Column;
+    use crate::measurements::make_base_discrete_laplace;
+
+    fn dataframe() -> DataFrame<&'static str> {
+        vec![
+            ("a", Column::new(vec!["x", "y", "x", "z", "x"])),
+            ("b", Column::new(vec!["0", "1", "1", "1", "2"])),
+        ].into_iter().collect()
+    }
+
+    #[test]
+    fn test_make_contingency_table() -> Fallible<()> {
+        let trans = make_contingency_table::<SymmetricDistance, L1Distance<i32>, _, _, i32>(
+            vec!["a", "b"], vec![vec!["x", "y"], vec!["0", "1"]])?;
+        // ("z", _) and (_, "2") are not counted
+        assert_eq!(trans.invoke(&dataframe())?, vec![1, 1, 0, 1]);
+        assert!(trans.check(&1, &1)?);
+        assert!(!trans.check(&1, &0)?);
+
+        let trans = make_contingency_table::<ChangeOneDistance, L2Distance<f64>, _, _, i32>(
+            vec!["a"], vec![vec!["x", "y", "z"]])?;
+        assert_eq!(trans.invoke(&dataframe())?, vec![3, 1, 1]);
+        assert!(trans.check(&1, &2f64.sqrt())?);
+        assert!(!trans.check(&1, &1.4)?);
+
+        assert!(make_contingency_table::<SymmetricDistance, L1Distance<i32>, _, _, i32>(
+            vec!["a", "a"], vec![vec!["x"], vec!["y"]]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_make_contingency_tables() -> Fallible<()> {
+        let marginals = vec![vec!["a"], vec!["b"], vec!["b", "a"]];
+        let trans = make_contingency_tables::<ChangeOneDistance, L1Distance<i32>, _, _, i32>(
+            vec!["a", "b"], vec![vec!["x", "y"], vec!["0", "1"]], marginals)?;
+        assert_eq!(trans.invoke(&dataframe())?, vec![
+            3, 1,
+            1, 3,
+            1, 0, 1, 1,
+        ]);
+        assert!(trans.check(&1, &6)?);
+        assert!(!trans.check(&1, &5)?);
+
+        let trans = make_contingency_tables::<SymmetricDistance, L2Distance<f64>, _, _, i32>(
+            vec!["a", "b"], vec![vec!["x", "y"], vec!["0", "1"]], vec![vec!["a"], vec!["b"]])?;
+        assert!(trans.check(&1, &2f64.sqrt())?);
+
+        assert!(make_contingency_tables::<SymmetricDistance, L1Distance<i32>, _, _, i32>(
+            vec!["a"], vec![vec!["x"]], vec![vec!["c"]]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_make_contingency_tables_release() -> Fallible<()> {
+        let meas = (make_contingency_tables::<SymmetricDistance, L1Distance<i32>, _, _, i32>(
+            vec!["a", "b"], vec![vec!["x", "y"], vec!["0", "1"]], vec![vec!["a"], vec!["b"]])?
+            >> make_base_discrete_laplace(1.)?)?;
+        assert_eq!(meas.invoke(&dataframe())?.len(), 4);
+        assert!(meas.check(&1, &2.)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_make_contingency_tables_gaussian_release() -> Fallible<()> {
+        use crate::measurements::make_base_discrete_gaussian;
+        use crate::measures::ZeroConcentratedDivergence;
+        let meas = (make_contingency_tables::<SymmetricDistance, L2Distance<f64>, _, _, i32>(
+            vec!["a", "b"], vec![vec!["x", "y"], vec!["0", "1"]], vec![vec!["a"], vec!["b"]])?
+            >> make_base_discrete_gaussian::<_, ZeroConcentratedDivergence<_>>(1.)?)?;
+        assert_eq!(meas.invoke(&dataframe())?.len(), 4);
+        assert!(meas.check(&1, &1.0000001)?);
+        Ok(())
+    }
+}
@@ -1,15 +1,16 @@
 use std::convert::TryFrom;
-use std::os::raw::c_char;
+use std::os::raw::{c_char, c_uint};
 
-use crate::core::SensitivityMetric;
+use crate::core::{Function, SensitivityMetric, Transformation};
+use crate::domains::{AllDomain, HistogramDomain, VectorDomain};
 use crate::core::{FfiResult, IntoAnyTransformationFfiResultExt};
-use crate::metrics::{L1Distance, L2Distance};
+use crate::metrics::{L1Distance, L2Distance, SymmetricDistance};
 use crate::err;
 use crate::ffi::any::{AnyObject, AnyTransformation};
 use crate::ffi::any::Downcast;
 use crate::ffi::util::{Type, c_bool, to_bool};
 use crate::traits::{Number, Hashable, Primitive, Float};
-use crate::transformations::{CountByCategoriesConstant, CountByConstant, make_count, make_count_by, make_count_by_categories, make_count_distinct};
+use crate::transformations::{CountByCategoriesConstant, CountByConstant, make_count, make_count_by, make_count_by_categories, make_count_distinct, make_histogram, make_uniform_histogram};
 
 #[no_mangle]
 pub extern "C" fn opendp_transformations__make_count(
@@ -70,7 +71,7 @@ pub extern "C" fn opendp_transformations__make_count_by_categories(
                   TI: Hashable,
                   TO: Number {
             let categories = try_!(try_as_ref!(categories).downcast_ref::<Vec<TI>>()).clone();
-            make_count_by_categories::<MO, TI, TO>(categories, null_category).into_any()
+            make_count_by_categories::<MO, Vec<TI>, TO>(categories, null_category).into_any()
         }
         dispatch!(monomorphize2, [
             (MO, [L1Distance<QO>, L2Distance<QO>]),
@@ -116,3 +117,94 @@ pub extern "C" fn opendp_transformations__make_count_by(
     let QO = try_!(MO.get_atom());
     dispatch!(monomorphize, [(QO, @floats)], (MO, TK, TV))
 }
+
+/// Histograms are released from FFI as only the counts, since the edges are known to the caller.
+fn into_counts<MO, TA, TC>(
+    histogram: Transformation<VectorDomain<AllDomain<TA>>, HistogramDomain<TA, TC>, SymmetricDistance, MO>
+) -> Transformation<VectorDomain<AllDomain<TA>>, VectorDomain<AllDomain<TC>>, SymmetricDistance, MO>
+    where MO: SensitivityMetric,
+          TA: Number,
+          TC: Number {
+    let function = histogram.function;
+    Transformation::new(
+        histogram.input_domain,
+        VectorDomain::new_all(),
+        Function::new_fallible(move |arg: &Vec<TA>| function.eval(arg).map(|(_edges, counts)| counts)),
+        histogram.input_metric,
+        histogram.output_metric,
+        histogram.stability_map)
+}
+
+#[no_mangle]
+pub extern "C" fn opendp_transformations__make_histogram(
+    edges: *const AnyObject,
+    MO: *const c_char, TA: *const c_char, TC: *const c_char,
+) -> FfiResult<*mut AnyTransformation> {
+    fn monomorphize<QO>(
+        edges: *const AnyObject,
+        MO: Type, TA: Type, TC: Type,
+    ) -> FfiResult<*mut AnyTransformation>
+        where QO: Number {
+        fn monomorphize2<MO, TA, TC>(
+            edges: *const AnyObject,
+        ) -> FfiResult<*mut AnyTransformation>
+            where MO: 'static + SensitivityMetric + CountByCategoriesConstant<MO::Distance>,
+                  MO::Distance: Number,
+                  TA: Number,
+                  TC: Number {
+            let edges = try_!(try_as_ref!(edges).downcast_ref::<Vec<TA>>()).clone();
+            make_histogram::<MO, TA, TC>(edges).map(into_counts).into_any()
+        }
+        dispatch!(monomorphize2, [
+            (MO, [L1Distance<QO>, L2Distance<QO>]),
+            (TA, @numbers),
+            (TC, @numbers)
+        ], (edges))
+    }
+    let MO = try_!(Type::try_from(MO));
+    let TA = try_!(Type::try_from(TA));
+    let TC = try_!(Type::try_from(TC));
+    let QO = try_!(MO.get_atom());
+    dispatch!(monomorphize, [
+        (QO, @numbers)
+    ], (edges, MO, TA, TC))
+}
+
+#[no_mangle]
+pub extern "C" fn opendp_transformations__make_uniform_histogram(
+    bounds: *const AnyObject,
+    bins: c_uint,
+    MO: *const c_char, TA: *const c_char, TC: *const c_char,
+) -> FfiResult<*mut AnyTransformation> {
+    fn monomorphize<QO>(
+        bounds: *const AnyObject,
+        bins: usize,
+        MO: Type, TA: Type, TC: Type,
+    ) -> FfiResult<*mut AnyTransformation>
+        where QO: Number {
+        fn monomorphize2<MO, TA, TC>(
+            bounds: *const AnyObject,
+            bins: usize,
+        ) -> FfiResult<*mut AnyTransformation>
+            where MO: 'static + SensitivityMetric + CountByCategoriesConstant<MO::Distance>,
+                  MO::Distance: Number,
+                  TA: 'static + Float,
+                  TC: Number {
+            let bounds = *try_!(try_as_ref!(bounds).downcast_ref::<(TA, TA)>());
+            make_uniform_histogram::<MO, TA, TC>(bounds, bins).map(into_counts).into_any()
+        }
+        dispatch!(monomorphize2, [
+            (MO, [L1Distance<QO>, L2Distance<QO>]),
+            (TA, @floats),
+            (TC, @numbers)
+        ], (bounds, bins))
+    }
+    let bins = bins as usize;
+    let MO = try_!(Type::try_from(MO));
+    let TA = try_!(Type::try_from(TA));
+    let TC = try_!(Type::try_from(TC));
+    let QO = try_!(MO.get_atom());
+    dispatch!(monomorphize, [
+        (QO, @numbers)
+    ], (bounds, bins, MO, TA, TC))
+}
@@ -3,12 +3,13 @@ mod ffi;
 
 use std::collections::{HashMap, HashSet};
 use std::collections::hash_map::Entry;
+use std::cmp::Ordering;
 
 use num::One;
 
 use crate::core::{Function, SensitivityMetric, StabilityMap, Transformation};
 use crate::metrics::{AbsoluteDistance, SymmetricDistance, LpDistance};
-use crate::domains::{AllDomain, MapDomain, VectorDomain};
+use crate::domains::{AllDomain, Categories, HistogramDomain, MapDomain, VectorDomain};
 use crate::error::*;
 use crate::traits::{Number, Hashable, Primitive, Float};
 
@@ -52,24 +53,31 @@ impl<const P: usize, Q: One> CountByCategoriesConstant<Q> for LpDistance<P, Q> {
 }
 
 // count with unknown n, known categories
-pub fn make_count_by_categories<MO, TI, TO>(
-    categories: Vec<TI>,
+/// Make a Transformation that counts the number of records in each category of `categories`.
+///
+/// `categories` may be a `Vec`, or a [`CategoricalDomain`](crate::domains::CategoricalDomain) that is carried by the transformation.
+/// Counts are emitted in the order of the categories.
+/// If `null_category` is set, the count of records outside of the categories is appended.
+pub fn make_count_by_categories<MO, C, TO>(
+    categories: C,
     null_category: bool
-) -> Fallible<Transformation<VectorDomain<AllDomain<TI>>, VectorDomain<AllDomain<TO>>, SymmetricDistance, MO>>
+) -> Fallible<Transformation<VectorDomain<C::AtomDomain>, VectorDomain<AllDomain<TO>>, SymmetricDistance, MO>>
     where MO: CountByCategoriesConstant<MO::Distance> + SensitivityMetric,
           MO::Distance: Number,
-          TI: Hashable,
+          C: Categories,
+          C::Atom: Hashable,
           TO: Number {
+    let (categories, atom_domain, _) = categories.into_parts()?;
     let mut uniques = HashSet::new();
     if categories.iter().any(move |x| !uniques.insert(x)) {
         return fallible!(MakeTransformation, "categories must be distinct")
     }
     Ok(Transformation::new(
+        VectorDomain::new(atom_domain),
         VectorDomain::new_all(),
-        VectorDomain::new_all(),
-        Function::new(move |data: &Vec<TI>| {
+        Function::new(move |data: &Vec<C::Atom>| {
             let mut counts = categories.iter()
-                .map(|cat| (cat, TO::zero())).collect::<HashMap<&TI, TO>>();
+                .map(|cat| (cat, TO::zero())).collect::<HashMap<&C::Atom, TO>>();
             let mut null_count = TO::zero();
 
             data.iter().for_each(|v| {
@@ -90,6 +98,78 @@ pub fn make_count_by_categories<MO, TI, TO>(
         StabilityMap::new_from_constant(MO::get_stability_constant())))
 }
 
+/// Make a Transformation that computes a histogram of the data over bins defined by `edges`.
+///
+/// The bins are `[edges[i], edges[i + 1])`. The output also includes an underflow bin `(-inf, edges[0])`
+/// at the beginning, and an overflow bin `[edges[n - 1], inf)` at the end, so there is one more count than edges.
+/// Values that cannot be compared with the edges, like NaN, are counted in the overflow bin.
+/// The output is a pair of the edges and the counts, which can be passed through noise mechanisms
+/// directly to `make_histogram_cdf` or `make_quantiles_from_histogram`.
+pub fn make_histogram<MO, TA, TC>(
+    edges: Vec<TA>
+) -> Fallible<Transformation<VectorDomain<AllDomain<TA>>, HistogramDomain<TA, TC>, SymmetricDistance, MO>>
+    where MO: CountByCategoriesConstant<MO::Distance> + SensitivityMetric,
+          MO::Distance: Number,
+          TA: Number,
+          TC: Number {
+    if edges.is_empty() {
+        return fallible!(MakeTransformation, "there must be at least one edge")
+    }
+    if !edges.windows(2).all(|pair| pair[0] < pair[1]) {
+        return fallible!(MakeTransformation, "edges must be unique and ordered")
+    }
+    Ok(Transformation::new(
+        VectorDomain::new_all(),
+        HistogramDomain::new(),
+        Function::new(move |data: &Vec<TA>| {
+            let mut counts = vec![TC::zero(); edges.len() + 1];
+            data.iter().for_each(|v| {
+                // the number of edges that v is not less than
+                let idx = edges.partition_point(|edge| v.partial_cmp(edge) != Some(Ordering::Less));
+                let count = &mut counts[idx];
+                *count = TC::one().saturating_add(count)
+            });
+            (edges.clone(), counts)
+        }),
+        SymmetricDistance,
+        MO::default(),
+        // each added or removed record changes exactly one count by one
+        StabilityMap::new_from_constant(MO::get_stability_constant())))
+}
+
+/// Make a Transformation that computes a histogram of the data over `bins` equal-width bins spanning `bounds`.
+///
+/// The output layout is the same as in [`make_histogram`], with edges given by [`uniform_edges`].
+pub fn make_uniform_histogram<MO, TA, TC>(
+    bounds: (TA, TA), bins: usize
+) -> Fallible<Transformation<VectorDomain<AllDomain<TA>>, HistogramDomain<TA, TC>, SymmetricDistance, MO>>
+    where MO: CountByCategoriesConstant<MO::Distance> + SensitivityMetric,
+          MO::Distance: Number,
+          TA: Float,
+          TC: Number {
+    make_histogram(uniform_edges(bounds, bins)?)
+}
+
+/// Computes the `bins + 1` edges of `bins` equal-width bins spanning `bounds`.
+pub fn uniform_edges<TA: Float>(bounds: (TA, TA), bins: usize) -> Fallible<Vec<TA>> {
+    let (lower, upper) = bounds;
+    if !lower.is_finite() || !upper.is_finite() || lower >= upper {
+        return fallible!(MakeTransformation, "bounds must be finite and increasing")
+    }
+    if bins == 0 {
+        return fallible!(MakeTransformation, "there must be at least one bin")
+    }
+    let bins_ = TA::exact_int_cast(bins)?;
+    let mut edges = (0..bins)
+        .map(|i| Ok(lower + (upper - lower) * TA::exact_int_cast(i)? / bins_))
+        .collect::<Fallible<Vec<TA>>>()?;
+    edges.push(upper);
+    if !edges.windows(2).all(|pair| pair[0] < pair[1]) {
+        return fallible!(MakeTransformation, "bins are too narrow to be represented")
+    }
+    Ok(edges)
+}
+
 pub trait CountByConstant<QO> {
     fn get_stability_constant() -> Fallible<QO>;
 }
@@ -126,10 +206,53 @@ pub fn make_count_by<MO, TK, TV>(
 
 #[cfg(test)]
 mod tests {
-    use crate::metrics::L2Distance;
+    use crate::metrics::{L1Distance, L2Distance};
     use crate::transformations::count::make_count_by_categories;
 
     use super::*;
+    use crate::domains::CategoricalDomain;
+
+    #[test]
+    fn test_make_histogram() -> Fallible<()> {
+        let transformation = make_histogram::<L1Distance<i32>, f64, i32>(vec![0., 1., 2.])?;
+        let arg = vec![-1., 0., 0.5, 1., 1.5, 2., 10., f64::NAN];
+        assert_eq!(transformation.invoke(&arg)?, (vec![0., 1., 2.], vec![1, 2, 2, 3]));
+        assert!(transformation.check(&1, &1)?);
+
+        let transformation = make_uniform_histogram::<L2Distance<f64>, f64, f64>((0., 10.), 5)?;
+        let (edges, counts) = transformation.invoke(&vec![0., 3., 9.99])?;
+        assert_eq!(edges, uniform_edges((0., 10.), 5)?);
+        assert_eq!(counts, vec![0., 1., 1., 0., 0., 1., 0.]);
+        assert!(transformation.check(&1, &1.)?);
+
+        assert_eq!(uniform_edges((0., 1.), 4)?, vec![0., 0.25, 0.5, 0.75, 1.]);
+        assert!(make_histogram::<L1Distance<i32>, f64, i32>(vec![1., 0.]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_make_histogram_quantiles() -> Fallible<()> {
+        use crate::measurements::make_base_laplace;
+        use crate::transformations::{make_histogram_cdf, make_quantiles_from_histogram, Interpolation};
+        let edges = vec![0., 10., 20., 30.];
+        let histogram = make_histogram::<L1Distance<f64>, f64, f64>(edges.clone())?;
+        let data = vec![5., 15., 15., 25.];
+
+        // the edges round-trip through the noise mechanism
+        let noisy = (histogram.clone() >> make_base_laplace(1., None)?)?;
+        let (noisy_edges, noisy_counts) = noisy.invoke(&data)?;
+        assert_eq!(noisy_edges, edges);
+        assert_eq!(noisy_counts.len(), edges.len() + 1);
+
+        // without noise, the consumers read the edges from the histogram
+        let exact = (histogram >> make_base_laplace(0., Some(-1078))?)?;
+        let quantiles = (exact.clone() >> make_quantiles_from_histogram(vec![0.5], Interpolation::Nearest)?)?;
+        assert_eq!(quantiles.invoke(&data)?, vec![10.]);
+
+        let cdf = (exact >> make_histogram_cdf()?)?;
+        assert_eq!(cdf.invoke(&data)?, (edges, vec![0., 0.25, 0.75, 1., 1.]));
+        Ok(())
+    }
 
     #[test]
     fn test_make_count_l1() {
@@ -160,7 +283,7 @@ mod tests {
 
     #[test]
     fn test_make_count_by_categories() {
-        let transformation = make_count_by_categories::<L2Distance<f64>, i64, i8>(
+        let transformation = make_count_by_categories::<L2Distance<f64>, Vec<i64>, i8>(
             vec![2, 1, 3], true
         ).unwrap_test();
         let arg = vec![1, 2, 3, 4, 5, 1, 1, 1, 2];
@@ -172,6 +295,24 @@ mod tests {
         assert!(transformation.check(&5, &5.0).unwrap_test());
     }
 
+    #[test]
+    fn test_make_count_by_categorical_domain() -> Fallible<()> {
+        let domain = CategoricalDomain::new(vec![2, 1, 3])?;
+        let transformation = make_count_by_categories::<L1Distance<i32>, _, i8>(domain.clone(), false)?;
+        assert_eq!(transformation.invoke(&vec![1, 2, 3, 4, 1, 1])?, vec![1, 3, 1]);
+        assert!(transformation.check(&1, &1)?);
+
+        // categories are carried from the upstream transformation
+        let chain = (crate::transformations::make_index(domain, -1)?
+            >> make_count_by_categories::<L1Distance<i32>, _, i8>(CategoricalDomain::new(vec![2, 1, 3])?, true)?)?;
+        assert_eq!(chain.invoke(&vec![0, 0, 2, 5])?, vec![2, 0, 1, 1]);
+
+        // categories in a different order are not compatible
+        assert!((crate::transformations::make_index(CategoricalDomain::new(vec![1, 2, 3])?, -1)?
+            >> make_count_by_categories::<L1Distance<i32>, _, i8>(CategoricalDomain::new(vec![2, 1, 3])?, false)?).is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_make_count_by() -> Fallible<()> {
         let arg = vec![true, true, true, false, true, false, false, false, true, true];
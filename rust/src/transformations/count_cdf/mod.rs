@@ -4,10 +4,10 @@ use num::Zero;
 
 use crate::{
     core::{Function, Transformation},
-    domains::{AllDomain, VectorDomain},
+    domains::{AllDomain, HistogramDomain, VectorDomain},
     error::Fallible,
     metrics::AgnosticMetric,
-    traits::{Float, Number, RoundCast},
+    traits::{CheckNull, Float, Number, RoundCast},
 };
 
 use super::postprocess::make_postprocess;
@@ -66,12 +66,80 @@ where
     TA: Number + RoundCast<F>,
     F: Float + RoundCast<TA>,
 {
+    check_bin_edges(&bin_edges)?;
+    check_alphas(&alphas)?;
+    make_postprocess(
+        VectorDomain::new_all(),
+        VectorDomain::new_all(),
+        Function::new_fallible(move |arg: &Vec<TA>| {
+            quantiles_from_counts(&bin_edges, &alphas, &interpolation, arg)
+        }),
+    )
+}
+
+/// Constructs a [`Transformation`] that retrieves quantiles from a histogram,
+/// like [`make_quantiles_from_counts`], with the bin edges taken from the histogram itself.
+pub fn make_quantiles_from_histogram<TA, F>(
+    alphas: Vec<F>,
+    interpolation: Interpolation,
+) -> Fallible<
+    Transformation<
+        HistogramDomain<TA, TA>,
+        VectorDomain<AllDomain<TA>>,
+        AgnosticMetric,
+        AgnosticMetric,
+    >,
+>
+where
+    TA: Number + RoundCast<F>,
+    F: Float + RoundCast<TA>,
+{
+    check_alphas(&alphas)?;
+    make_postprocess(
+        HistogramDomain::new(),
+        VectorDomain::new_all(),
+        Function::new_fallible(move |(bin_edges, counts): &(Vec<TA>, Vec<TA>)| {
+            check_bin_edges(bin_edges)?;
+            quantiles_from_counts(bin_edges, &alphas, &interpolation, counts)
+        }),
+    )
+}
+
+/// Constructs a [`Transformation`] that maps the counts of a histogram into a cumulative distribution,
+/// like [`make_cdf`], and keeps the bin edges.
+pub fn make_histogram_cdf<TA, TC>() -> Fallible<
+    Transformation<
+        HistogramDomain<TA, TC>,
+        HistogramDomain<TA, TC>,
+        AgnosticMetric,
+        AgnosticMetric,
+    >,
+>
+where
+    TA: 'static + Clone + CheckNull,
+    TC: Float,
+{
+    let cdf = make_cdf::<TC>()?;
+    make_postprocess(
+        HistogramDomain::new(),
+        HistogramDomain::new(),
+        Function::new_fallible(move |(bin_edges, counts): &(Vec<TA>, Vec<TC>)| {
+            Ok((bin_edges.clone(), cdf.invoke(counts)?))
+        }),
+    )
+}
+
+fn check_bin_edges<TA: PartialOrd>(bin_edges: &[TA]) -> Fallible<()> {
     if bin_edges.len().is_zero() {
         return fallible!(MakeTransformation, "bin_edges.len() must be positive");
     }
     if bin_edges.windows(2).any(|w| w[0] >= w[1]) {
         return fallible!(MakeTransformation, "bin_edges must be increasing");
     }
+    Ok(())
+}
+
+fn check_alphas<F: Float>(alphas: &[F]) -> Fallible<()> {
     if alphas.windows(2).any(|w| w[0] >= w[1]) {
         return fallible!(MakeTransformation, "alphas must be increasing");
     }
@@ -91,75 +159,82 @@ where
             );
         }
     }
-    make_postprocess(
-        VectorDomain::new_all(),
-        VectorDomain::new_all(),
-        Function::new_fallible(move |arg: &Vec<TA>| {
-            // one fewer args than bin edges, or one greater args than bin edges are allowed
-            if abs_diff(bin_edges.len(), arg.len()) != 1 {
-                return fallible!(
-                    FailedFunction,
-                    "there must be one more bin edge than there are counts"
-                );
-            }
-            if arg.is_empty() {
-                return Ok(vec![bin_edges[0].clone(); alphas.len()]);
-            }
-            // if args includes extremal bins for (-inf, edge_0] and [edge_n, inf), discard them
-            let arg = if bin_edges.len() + 1 == arg.len() {
-                &arg[1..arg.len() - 1]
-            } else {
-                &arg[..]
-            };
-            // compute the cumulative sum of the input counts
-            let cumsum = (arg.iter())
-                .scan(TA::zero(), |acc, v| {
-                    *acc += v.clone();
-                    Some(acc.clone())
-                })
-                .map(F::round_cast)
-                .collect::<Fallible<Vec<F>>>()?;
+    Ok(())
+}
 
-            // reuse the last element of the cumsum
-            let sum = cumsum[cumsum.len() - 1];
+fn quantiles_from_counts<TA, F>(
+    bin_edges: &[TA],
+    alphas: &[F],
+    interpolation: &Interpolation,
+    arg: &[TA],
+) -> Fallible<Vec<TA>>
+where
+    TA: Number + RoundCast<F>,
+    F: Float + RoundCast<TA>,
+{
+    // one fewer args than bin edges, or one greater args than bin edges are allowed
+    if abs_diff(bin_edges.len(), arg.len()) != 1 {
+        return fallible!(
+            FailedFunction,
+            "there must be one more bin edge than there are counts"
+        );
+    }
+    if arg.is_empty() {
+        return Ok(vec![bin_edges[0]; alphas.len()]);
+    }
+    // if args includes extremal bins for (-inf, edge_0] and [edge_n, inf), discard them
+    let arg = if bin_edges.len() + 1 == arg.len() {
+        &arg[1..arg.len() - 1]
+    } else {
+        arg
+    };
+    // compute the cumulative sum of the input counts
+    let cumsum = (arg.iter())
+        .scan(TA::zero(), |acc, v| {
+            *acc += v.clone();
+            Some(acc.clone())
+        })
+        .map(F::round_cast)
+        .collect::<Fallible<Vec<F>>>()?;
 
-            let cdf: Vec<F> = cumsum.into_iter().map(|v| v / sum).collect();
-
-            // each index is the number of bins whose combined mass is less than the alpha_edge mass
-            let mut indices = vec![0; alphas.len()];
-            count_lt_recursive(indices.as_mut_slice(), alphas.as_slice(), cdf.as_slice(), 0);
-
-            indices
-                .into_iter()
-                .zip(&alphas)
-                .map(|(idx, &alpha)| {
-                    // Want to find the cumulative values to the left and right of edge
-                    // When no elements less than edge, consider cumulative value to be zero
-                    let left_cdf = if idx == 0 { F::zero() } else { cdf[idx - 1] };
-                    let right_cdf = cdf[idx];
-
-                    // println!("x's {:?}, {:?}", edge, (left.clone(), right.clone()));
-                    // println!("y's {:?}", (&bin_edges[idx], &bin_edges[idx + 1]));
-                    match interpolation {
-                        Interpolation::Nearest => {
-                            // if edge nearer to right than to left, then increment index
-                            Ok(bin_edges[idx + (alpha - left_cdf > right_cdf - alpha) as usize])
-                        }
-                        Interpolation::Linear => {
-                            let left_edge = F::round_cast(bin_edges[idx])?;
-                            let right_edge = F::round_cast(bin_edges[idx + 1])?;
-
-                            // find the interpolant between the bin edges.
-                            // denominator is never zero because bin edges is strictly increasing
-                            let t = (alpha - left_cdf) / (right_cdf - left_cdf);
-                            let v = (F::one() - t) * left_edge + t * right_edge;
-                            TA::round_cast(v)
-                        }
-                    }
-                })
-                .collect()
-        }),
-    )
+    // reuse the last element of the cumsum
+    let sum = cumsum[cumsum.len() - 1];
+
+    let cdf: Vec<F> = cumsum.into_iter().map(|v| v / sum).collect();
+
+    // each index is the number of bins whose combined mass is less than the alpha_edge mass
+    let mut indices = vec![0; alphas.len()];
+    count_lt_recursive(indices.as_mut_slice(), alphas, cdf.as_slice(), 0);
+
+    indices
+        .into_iter()
+        .zip(alphas)
+        .map(|(idx, &alpha)| {
+            // Want to find the cumulative values to the left and right of edge
+            // When no elements less than edge, consider cumulative value to be zero
+            let left_cdf = if idx == 0 { F::zero() } else { cdf[idx - 1] };
+            let right_cdf = cdf[idx];
+
+            // println!("x's {:?}, {:?}", edge, (left.clone(), right.clone()));
+            // println!("y's {:?}", (&bin_edges[idx], &bin_edges[idx + 1]));
+            match interpolation {
+                Interpolation::Nearest => {
+                    // if edge nearer to right than to left, then increment index
+                    Ok(bin_edges[idx + (alpha - left_cdf > right_cdf - alpha) as usize])
+                }
+                Interpolation::Linear => {
+                    let left_edge = F::round_cast(bin_edges[idx])?;
+                    let right_edge = F::round_cast(bin_edges[idx + 1])?;
+
+                    // find the interpolant between the bin edges.
+                    // denominator is never zero because bin edges is strictly increasing
+                    let t = (alpha - left_cdf) / (right_cdf - left_cdf);
+                    let v = (F::one() - t) * left_edge + t * right_edge;
+                    TA::round_cast(v)
+                }
+            }
+        })
+        .collect()
 }
 
 fn abs_diff<T: PartialOrd + Sub<Output = T>>(a: T, b: T) -> T {
@@ -6,7 +6,10 @@ use crate::{
         any::{AnyObject, AnyTransformation, Downcast},
         util::Type,
     },
-    transformations::{make_sized_bounded_covariance, Pairwise, Sequential, UncheckedSum},
+    transformations::{
+        make_sized_bounded_covariance, make_bounded_scatter_matrix, make_sized_bounded_covariance_matrix,
+        make_psd_projection, Pairwise, Sequential, UncheckedSum
+    },
     traits::Float
 };
 
@@ -55,3 +58,133 @@ pub extern "C" fn opendp_transformations__make_sized_bounded_covariance(
         (T, @floats)
     ], (size, bounds_0, bounds_1, ddof, S))
 }
+
+// no entry in bootstrap.json because there's no way to get data into it
+#[no_mangle]
+pub extern "C" fn opendp_transformations__make_bounded_scatter_matrix(
+    dim: c_uint,
+    size_limit: c_uint,
+    norm: *const AnyObject,
+    S: *const c_char,
+) -> FfiResult<*mut AnyTransformation> {
+    fn monomorphize<T>(
+        dim: usize,
+        size_limit: usize,
+        norm: *const AnyObject,
+        S: Type,
+    ) -> FfiResult<*mut AnyTransformation>
+    where
+        T: 'static + Float,
+    {
+        fn monomorphize2<S>(
+            dim: usize,
+            size_limit: usize,
+            norm: S::Item,
+        ) -> FfiResult<*mut AnyTransformation>
+        where
+            S: UncheckedSum,
+            S::Item: 'static + Float,
+        {
+            make_bounded_scatter_matrix::<S>(dim, size_limit, norm).into_any()
+        }
+        let norm = *try_!(try_as_ref!(norm).downcast_ref::<T>());
+        dispatch!(monomorphize2, [
+            (S, [Sequential<T>, Pairwise<T>])
+        ], (dim, size_limit, norm))
+    }
+    let dim = dim as usize;
+    let size_limit = size_limit as usize;
+    let S = try_!(Type::try_from(S));
+    let T = try_!(S.get_atom());
+    dispatch!(monomorphize, [
+        (T, @floats)
+    ], (dim, size_limit, norm, S))
+}
+
+// no entry in bootstrap.json because there's no way to get data into it
+#[no_mangle]
+pub extern "C" fn opendp_transformations__make_sized_bounded_covariance_matrix(
+    size: c_uint,
+    dim: c_uint,
+    norm: *const AnyObject,
+    ddof: c_uint,
+    S: *const c_char,
+) -> FfiResult<*mut AnyTransformation> {
+    fn monomorphize<T>(
+        size: usize,
+        dim: usize,
+        norm: *const AnyObject,
+        ddof: usize,
+        S: Type,
+    ) -> FfiResult<*mut AnyTransformation>
+    where
+        T: 'static + Float,
+    {
+        fn monomorphize2<S>(
+            size: usize,
+            dim: usize,
+            norm: S::Item,
+            ddof: usize,
+        ) -> FfiResult<*mut AnyTransformation>
+        where
+            S: UncheckedSum,
+            S::Item: 'static + Float,
+        {
+            make_sized_bounded_covariance_matrix::<S>(size, dim, norm, ddof).into_any()
+        }
+        let norm = *try_!(try_as_ref!(norm).downcast_ref::<T>());
+        dispatch!(monomorphize2, [
+            (S, [Sequential<T>, Pairwise<T>])
+        ], (size, dim, norm, ddof))
+    }
+    let size = size as usize;
+    let dim = dim as usize;
+    let ddof = ddof as usize;
+    let S = try_!(Type::try_from(S));
+    let T = try_!(S.get_atom());
+    dispatch!(monomorphize, [
+        (T, @floats)
+    ], (size, dim, norm, ddof, S))
+}
+
+#[no_mangle]
+pub extern "C" fn opendp_transformations__make_psd_projection(
+    dim: c_uint,
+    T: *const c_char,
+) -> FfiResult<*mut AnyTransformation> {
+    fn monomorphize<T>(dim: usize) -> FfiResult<*mut AnyTransformation>
+    where
+        T: 'static + Float,
+    {
+        make_psd_projection::<T>(dim).into_any()
+    }
+    let dim = dim as usize;
+    let T = try_!(Type::try_from(T));
+    dispatch!(monomorphize, [
+        (T, @floats)
+    ], (dim))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::core;
+    use crate::error::Fallible;
+    use crate::ffi::any::{AnyObject, Downcast};
+    use crate::ffi::util::{self, ToCharP};
+
+    use super::*;
+
+    #[test]
+    fn test_make_psd_projection_ffi() -> Fallible<()> {
+        let transformation = Result::from(opendp_transformations__make_psd_projection(
+            2 as c_uint,
+            "f64".to_char_p(),
+        ))?;
+        let arg = AnyObject::new_raw(vec![2., 1., 2.]);
+        let res = core::opendp_core__transformation_invoke(&transformation, arg, util::from_bool(false));
+        let res: Vec<f64> = Fallible::from(res)?.downcast()?;
+        assert!(res.iter().zip([2., 1., 2.].iter()).all(|(a, b)| (a - b).abs() < 1e-9));
+        Ok(())
+    }
+}
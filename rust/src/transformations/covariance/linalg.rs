@@ -0,0 +1,133 @@
+use num::Float;
+
+use crate::error::Fallible;
+
+/// Number of entries in the upper triangle (including the diagonal) of a `dim` x `dim` matrix.
+pub(crate) fn upper_triangle_len(dim: usize) -> usize {
+    dim * (dim + 1) / 2
+}
+
+/// Flattens the upper triangle (including the diagonal) of a square matrix in row-major order.
+pub(crate) fn to_upper_triangle<T: Copy>(matrix: &[Vec<T>]) -> Vec<T> {
+    matrix.iter().enumerate()
+        .flat_map(|(i, row)| row[i..].iter().copied())
+        .collect()
+}
+
+/// Expands a flattened upper triangle (including the diagonal) into a full symmetric matrix.
+pub(crate) fn from_upper_triangle<T: Float>(dim: usize, upper: &[T]) -> Fallible<Vec<Vec<T>>> {
+    if upper.len() != upper_triangle_len(dim) {
+        return fallible!(FailedFunction, "expected {} entries in the upper triangle, got {}", upper_triangle_len(dim), upper.len())
+    }
+    let mut matrix = vec![vec![T::zero(); dim]; dim];
+    (0..dim).flat_map(|i| (i..dim).map(move |j| (i, j)))
+        .zip(upper.iter())
+        .for_each(|((i, j), v)| {
+            matrix[i][j] = *v;
+            matrix[j][i] = *v;
+        });
+    Ok(matrix)
+}
+
+/// Computes the eigendecomposition of a symmetric matrix with the cyclic Jacobi method.
+///
+/// Returns the eigenvalues, and a matrix whose columns are the corresponding eigenvectors.
+pub(crate) fn symmetric_eigen<T: Float>(matrix: &[Vec<T>]) -> (Vec<T>, Vec<Vec<T>>) {
+    const MAX_SWEEPS: usize = 100;
+    let dim = matrix.len();
+    let mut a = matrix.to_vec();
+    let mut v: Vec<Vec<T>> = (0..dim)
+        .map(|i| (0..dim).map(|j| if i == j { T::one() } else { T::zero() }).collect())
+        .collect();
+
+    let _2 = T::one() + T::one();
+    for _ in 0..MAX_SWEEPS {
+        let off_diagonal = (0..dim)
+            .flat_map(|i| (0..dim).filter(move |j| *j != i).map(move |j| (i, j)))
+            .fold(T::zero(), |acc, (i, j)| acc + a[i][j] * a[i][j]);
+        if off_diagonal <= T::epsilon() * T::epsilon() {
+            break;
+        }
+
+        for p in 0..dim {
+            for q in p + 1..dim {
+                if a[p][q].is_zero() {
+                    continue;
+                }
+                // choose the rotation that zeroes a[p][q]
+                let theta = (a[q][q] - a[p][p]) / (_2 * a[p][q]);
+                let t = theta.signum() / (theta.abs() + (theta * theta + T::one()).sqrt());
+                let c = (t * t + T::one()).sqrt().recip();
+                let s = t * c;
+
+                // a = J^T a J
+                for row in a.iter_mut() {
+                    let (akp, akq) = (row[p], row[q]);
+                    row[p] = c * akp - s * akq;
+                    row[q] = s * akp + c * akq;
+                }
+                let (head, tail) = a.split_at_mut(q);
+                for (apk, aqk) in head[p].iter_mut().zip(tail[0].iter_mut()) {
+                    let (x, y) = (*apk, *aqk);
+                    *apk = c * x - s * y;
+                    *aqk = s * x + c * y;
+                }
+                // v = v J
+                for row in v.iter_mut() {
+                    let (vkp, vkq) = (row[p], row[q]);
+                    row[p] = c * vkp - s * vkq;
+                    row[q] = s * vkp + c * vkq;
+                }
+            }
+        }
+    }
+    ((0..dim).map(|i| a[i][i]).collect(), v)
+}
+
+/// Projects a symmetric matrix onto the cone of positive semi-definite matrices,
+/// by clamping negative eigenvalues to zero.
+pub(crate) fn project_psd<T: Float>(matrix: &[Vec<T>]) -> Vec<Vec<T>> {
+    let dim = matrix.len();
+    let (values, vectors) = symmetric_eigen(matrix);
+    let values: Vec<T> = values.into_iter().map(|l| l.max(T::zero())).collect();
+
+    // v diag(values) v^T
+    (0..dim).map(|i| (0..dim).map(|j| (0..dim)
+        .fold(T::zero(), |acc, k| acc + vectors[i][k] * values[k] * vectors[j][k]))
+        .collect())
+        .collect()
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_symmetric_eigen() {
+        let matrix = vec![
+            vec![4., 1., 2.],
+            vec![1., 3., 0.],
+            vec![2., 0., 5.]];
+        let (values, vectors) = symmetric_eigen(&matrix);
+
+        // a v_k = l_k v_k
+        (0..3).for_each(|k| (0..3).for_each(|i| {
+            let av: f64 = (0..3).map(|j| matrix[i][j] * vectors[j][k]).sum();
+            assert!((av - values[k] * vectors[i][k]).abs() < 1e-9);
+        }));
+    }
+
+    #[test]
+    fn test_project_psd() -> Fallible<()> {
+        let matrix = from_upper_triangle(2, &[1., 2., 1.])?;
+        let projected = project_psd(&matrix);
+        // eigenvalues are 3 and -1, so the projection is 3/2 * [[1, 1], [1, 1]]
+        assert!(to_upper_triangle(&projected).iter().all(|v| (v - 1.5).abs() < 1e-9));
+
+        let psd = vec![vec![2., 1.], vec![1., 2.]];
+        let projected = project_psd(&psd);
+        assert!((0..2).all(|i| (0..2).all(|j| (projected[i][j] - psd[i][j]).abs() < 1e-9)));
+        Ok(())
+    }
+}
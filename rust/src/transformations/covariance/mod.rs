@@ -1,19 +1,22 @@
 use crate::{
     core::{StabilityMap, Transformation, Function},
-    metrics::{AbsoluteDistance, SymmetricDistance},
-    domains::{AllDomain, BoundedDomain, SizedDomain, VectorDomain},
+    metrics::{AbsoluteDistance, AgnosticMetric, IntDistance, L2Distance, SymmetricDistance},
+    domains::{AllDomain, BoundedDomain, L2BoundedDomain, SizedDomain, VectorDomain},
     error::Fallible,
-    traits::{ExactIntCast, InfCast, InfAdd, InfDiv, InfMul, InfSub, Float},
+    traits::{ExactIntCast, InfCast, InfAdd, InfDiv, InfMul, InfSqrt, InfSub, Float, samplers::Shuffle},
 };
 
 use num::{Zero, One};
 
-use super::UncheckedSum;
+use super::{UncheckedSum, make_postprocess};
 
 
 #[cfg(feature="ffi")]
 pub mod ffi;
 
+mod linalg;
+pub(crate) use linalg::*;
+
 
 type CovarianceDomain<T> = SizedDomain<VectorDomain<BoundedDomain<(T, T)>>>;
 
@@ -115,6 +118,167 @@ where
     ))
 }
 
+type MatrixTrans<DI, T> = Transformation<DI, VectorDomain<AllDomain<T>>, SymmetricDistance, L2Distance<T>>;
+
+/// Truncates or zero-pads each row to `dim`, which can only decrease its norm.
+fn resize_rows<T: Float>(data: &[Vec<T>], dim: usize) -> Vec<Vec<T>> {
+    data.iter()
+        .map(|row| (0..dim).map(|j| row.get(j).copied().unwrap_or_else(T::zero)).collect())
+        .collect()
+}
+
+/// Make a Transformation that computes the scatter matrix, X^T X, of rows with bounded L2 norm.
+///
+/// The output is the upper triangle of the `dim` x `dim` matrix (including the diagonal), flattened in row-major order.
+/// Rows are truncated or zero-padded to `dim`.
+/// If there are more than `size_limit` rows, a random subset of `size_limit` rows is used.
+///
+/// The output distance is bounded in terms of the Frobenius norm of the matrix,
+/// which is no smaller than the L2 norm of the upper triangle.
+pub fn make_bounded_scatter_matrix<S>(
+    dim: usize,
+    size_limit: usize,
+    norm: S::Item,
+) -> Fallible<MatrixTrans<VectorDomain<L2BoundedDomain<S::Item>>, S::Item>>
+where
+    S: UncheckedSum,
+    S::Item: 'static + Float,
+{
+    let input_domain = VectorDomain::new(L2BoundedDomain::new(norm)?);
+
+    // each entry of each outer product x x^T is bounded in magnitude by ||x||_2^2 <= norm^2
+    let norm_sq = norm.inf_mul(&norm)?;
+    if S::float_sum_can_overflow(size_limit, (-norm_sq, norm_sq))? {
+        return fallible!(MakeTransformation, "potential for overflow when computing function")
+    }
+
+    // The error of each entry is bounded by γ * sum_i |x_ij x_ik|.
+    // By the triangle inequality, the Frobenius norm of the error is bounded by γ * sum_i ||x_i||_2^2 <= γ * n * norm^2
+    let relaxation = S::relaxation(size_limit, S::Item::zero(), norm_sq)?;
+
+    Ok(Transformation::new(
+        input_domain,
+        VectorDomain::new_all(),
+        Function::new_fallible(move |arg: &Vec<Vec<S::Item>>| {
+            let mut data = arg.clone();
+            if arg.len() > size_limit {
+                data.shuffle()?
+            }
+            let data = resize_rows(&data[..size_limit.min(data.len())], dim);
+
+            Ok((0..dim).flat_map(|j| (j..dim).map(move |k| (j, k)))
+                .map(|(j, k)| S::unchecked_sum(&data.iter()
+                    .map(|row| row[j] * row[k])
+                    .collect::<Vec<_>>()))
+                .collect())
+        }),
+        SymmetricDistance,
+        L2Distance::default(),
+        // adding or removing x changes the matrix by x x^T, where ||x x^T||_F = ||x||_2^2 <= norm^2
+        StabilityMap::new_fallible(move |d_in: &IntDistance| {
+            S::Item::inf_cast(*d_in)?
+                .inf_mul(&norm_sq)?
+                .inf_add(&relaxation)
+        }),
+    ))
+}
+
+/// Make a Transformation that computes the covariance matrix of a known number of rows with bounded L2 norm.
+///
+/// The output is the upper triangle of the `dim` x `dim` matrix (including the diagonal), flattened in row-major order.
+/// Rows are truncated or zero-padded to `dim`.
+///
+/// The output distance is bounded in terms of the Frobenius norm of the matrix,
+/// which is no smaller than the L2 norm of the upper triangle.
+pub fn make_sized_bounded_covariance_matrix<S>(
+    size: usize,
+    dim: usize,
+    norm: S::Item,
+    ddof: usize,
+) -> Fallible<MatrixTrans<SizedDomain<VectorDomain<L2BoundedDomain<S::Item>>>, S::Item>>
+where
+    S: UncheckedSum,
+    S::Item: 'static + Float,
+{
+    if size == 0 {
+        return fallible!(MakeTransformation, "size must be greater than zero")
+    }
+    if ddof >= size {
+        return fallible!(MakeTransformation, "size - ddof must be greater than zero")
+    }
+    let input_domain = SizedDomain::new(VectorDomain::new(L2BoundedDomain::new(norm)?), size);
+
+    let _size = S::Item::exact_int_cast(size)?;
+    let _ddof = S::Item::exact_int_cast(ddof)?;
+    let _1 = S::Item::one();
+    let _2 = S::Item::exact_int_cast(2)?;
+
+    // DERIVE RELAXATION TERM
+    // By the triangle inequality, the L2 norm of the error of the mean is at most γ * n * norm / n
+    let mean_error = S::error(size, S::Item::zero(), norm)?.inf_div(&_size)?;
+
+    // Each row is within distance `range` of the approximate mean of any subset of the rows
+    let range = _2.inf_mul(&norm)?.inf_add(&mean_error)?;
+    let range_sq = range.inf_mul(&range)?;
+
+    // Let S' be the scatter matrix of the n - 1 rows shared between neighbors, with mean m.
+    // Then S = S' + (n - 1) / n (x - m)(x - m)^T, so replacing x with y changes S by
+    //     (n - 1) / n ((y - m)(y - m)^T - (x - m)(x - m)^T),
+    // whose Frobenius norm is at most (n - 1) / n * sqrt(||y - m||^4 + ||x - m||^4) <= (n - 1) / n * sqrt(2) * range^2
+    let sensitivity = _2.inf_sqrt()?
+        .inf_mul(&range_sq)?
+        .inf_mul(&_size.inf_sub(&_1)?)?
+        .inf_div(&_size)?
+        .inf_div(&_size.neg_inf_sub(&_ddof)?)?;
+
+    let relaxation = S::relaxation(size, S::Item::zero(), range_sq)?;
+
+    // OVERFLOW CHECKS
+    if S::float_sum_can_overflow(size, (-norm, norm))? || S::float_sum_can_overflow(size, (-range_sq, range_sq))? {
+        return fallible!(MakeTransformation, "potential for overflow when computing function")
+    }
+
+    Ok(Transformation::new(
+        input_domain,
+        VectorDomain::new_all(),
+        Function::new(move |arg: &Vec<Vec<S::Item>>| {
+            let data = resize_rows(arg, dim);
+            let means = (0..dim)
+                .map(|j| S::unchecked_sum(&data.iter().map(|row| row[j]).collect::<Vec<_>>()) / _size)
+                .collect::<Vec<_>>();
+
+            (0..dim).flat_map(|j| (j..dim).map(move |k| (j, k)))
+                .map(|(j, k)| S::unchecked_sum(&data.iter()
+                    .map(|row| (row[j] - means[j]) * (row[k] - means[k]))
+                    .collect::<Vec<_>>()) / (_size - _ddof))
+                .collect()
+        }),
+        SymmetricDistance,
+        L2Distance::default(),
+        // d_in / 2 * sensitivity + relaxation
+        StabilityMap::new_fallible(move |d_in: &IntDistance| {
+            S::Item::inf_cast(d_in / 2)?
+                .inf_mul(&sensitivity)?
+                .inf_add(&relaxation)
+        }),
+    ))
+}
+
+/// Postprocessing transformation that projects a noisy symmetric matrix onto the nearest positive semi-definite matrix
+/// (in Frobenius norm), by clamping negative eigenvalues to zero.
+///
+/// Both the input and output are the upper triangle of a `dim` x `dim` matrix (including the diagonal),
+/// flattened in row-major order, as released by [`make_sized_bounded_covariance_matrix`] or [`make_bounded_scatter_matrix`].
+pub fn make_psd_projection<T: 'static + Float>(
+    dim: usize
+) -> Fallible<Transformation<VectorDomain<AllDomain<T>>, VectorDomain<AllDomain<T>>, AgnosticMetric, AgnosticMetric>> {
+    make_postprocess(
+        VectorDomain::new_all(),
+        VectorDomain::new_all(),
+        Function::new_fallible(move |arg: &Vec<T>|
+            Ok(to_upper_triangle(&project_psd(&from_upper_triangle(dim, arg)?)))))
+}
+
 #[cfg(test)]
 mod test {
     use crate::transformations::Pairwise;
@@ -141,4 +305,41 @@ mod test {
             .check(&1, &(100. * 4. / 25.))?);
         Ok(())
     }
+
+    #[test]
+    fn test_make_bounded_scatter_matrix() -> Fallible<()> {
+        let trans = make_bounded_scatter_matrix::<Pairwise<f64>>(2, 10, 1.)?;
+        let ret = trans.invoke(&vec![vec![0.6, 0.8], vec![1., 0.], vec![0., -1., 0.]])?;
+        let expected = [0.36 + 1., 0.48, 0.64 + 1.];
+        assert!(ret.iter().zip(expected.iter()).all(|(a, b)| (a - b).abs() < 1e-12));
+        assert!(trans.check(&1, &1.000001)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_make_sized_bounded_covariance_matrix() -> Fallible<()> {
+        let arg = vec![vec![0.2, 0.1], vec![0.4, 0.3], vec![0.6, 0.5], vec![0.8, 0.7]];
+        let trans = make_sized_bounded_covariance_matrix::<Pairwise<f64>>(4, 2, 2., 1)?;
+        let ret = trans.invoke(&arg)?;
+
+        // matches the pairwise covariance
+        let pair = make_sized_bounded_covariance::<Pairwise<f64>>(4, (0., 1.), (0., 1.), 1)?
+            .invoke(&arg.iter().map(|r| (r[0], r[1])).collect())?;
+        assert!((ret[1] - pair).abs() < 1e-12);
+        assert!((ret[0] - ret[2]).abs() < 1e-12);
+
+        // sqrt(2) * (2 * 2)^2 * (3 / 4) / 3
+        assert!(trans.check(&2, &(2f64.sqrt() * 16. / 4. + 1e-6))?);
+        assert!(!trans.check(&2, &(2f64.sqrt() * 16. / 4. - 1e-6))?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_make_psd_projection() -> Fallible<()> {
+        let post = make_psd_projection::<f64>(2)?;
+        let ret = post.invoke(&vec![1., 2., 1.])?;
+        assert!(ret.iter().all(|v| (v - 1.5).abs() < 1e-9));
+        assert!(post.invoke(&vec![1., 2.]).is_err());
+        Ok(())
+    }
 }
@@ -79,7 +79,7 @@ mod tests {
 
     use crate::core;
     use crate::ffi::any::{AnyObject, Downcast};
-    use crate::ffi::util::ToCharP;
+    use crate::ffi::util::{self, ToCharP};
 
     use super::*;
 
@@ -102,7 +102,7 @@ mod tests {
         let arg = AnyObject::new_raw(dataframe(vec![
             ("A", Column::new(to_owned(&["1", "", "1"]))),
         ]));
-        let res = core::opendp_core__transformation_invoke(&transformation, arg);
+        let res = core::opendp_core__transformation_invoke(&transformation, arg, util::from_bool(false));
         let res: HashMap<String, Column> = Fallible::from(res)?.downcast()?;
         
         let subset = res
@@ -127,7 +127,7 @@ mod tests {
         let arg = AnyObject::new_raw(dataframe(vec![
             ("A", Column::new(to_owned(&["yes", "no", "yes"]))),
         ]));
-        let res = core::opendp_core__transformation_invoke(&transformation, arg);
+        let res = core::opendp_core__transformation_invoke(&transformation, arg, util::from_bool(false));
         let res: HashMap<String, Column> = Fallible::from(res)?.downcast()?;
         
         let subset = res
@@ -95,7 +95,7 @@ mod tests {
             "String".to_char_p(),
         ))?;
         let arg = AnyObject::new_raw("1, 1.0\n2, 2.0\n3, 3.0".to_owned());
-        let res = core::opendp_core__transformation_invoke(&transformation, arg);
+        let res = core::opendp_core__transformation_invoke(&transformation, arg, util::from_bool(false));
         let res: HashMap<String, Column> = Fallible::from(res)?.downcast()?;
         assert_eq!(
             res,
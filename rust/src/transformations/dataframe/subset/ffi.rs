@@ -45,7 +45,7 @@ mod tests {
 
     use crate::core;
     use crate::ffi::any::{AnyObject, Downcast};
-    use crate::ffi::util::ToCharP;
+    use crate::ffi::util::{self, ToCharP};
 
     use super::*;
 
@@ -68,7 +68,7 @@ mod tests {
             ("A", Column::new(vec![true, false, false])),
             ("B", Column::new(to_owned(&["1.0", "2.0", "3.0"])))
         ]));
-        let res = core::opendp_core__transformation_invoke(&transformation, arg);
+        let res = core::opendp_core__transformation_invoke(&transformation, arg, util::from_bool(false));
         let res: HashMap<String, Column> = Fallible::from(res)?.downcast()?;
         
         let subset = res
@@ -0,0 +1,192 @@
+//! Transformations that parse strings into dates and timestamps, and extract parts of them.
+//!
+//! Dates are represented by [`NaiveDate`], and timestamps by [`NaiveDateTime`], both without a time zone.
+//! Both may be bounded with [`BoundedDomain`](crate::domains::BoundedDomain), clamped with [`make_clamp`](crate::transformations::make_clamp),
+//! and binned with [`make_find_bin`](crate::transformations::make_find_bin).
+
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, Timelike};
+
+use crate::core::{DatasetMetric, Transformation};
+use crate::domains::{AllDomain, OptionNullDomain, VectorDomain};
+use crate::error::*;
+use crate::traits::CheckNull;
+use crate::transformations::make_row_by_row;
+
+/// A date or time type that can be parsed from a string.
+pub trait ParseTime: Sized {
+    /// Parse an ISO-8601 string, like `2022-09-01` or `2022-09-01T13:45:00`.
+    fn parse_iso(s: &str) -> Option<Self>;
+    /// Parse a string with a strftime-style `format`, like `%d/%m/%Y %H:%M`.
+    fn parse_format(s: &str, format: &str) -> Option<Self>;
+}
+
+impl ParseTime for NaiveDate {
+    fn parse_iso(s: &str) -> Option<Self> {
+        s.parse().ok()
+    }
+    fn parse_format(s: &str, format: &str) -> Option<Self> {
+        NaiveDate::parse_from_str(s, format).ok()
+    }
+}
+
+impl ParseTime for NaiveDateTime {
+    // timestamps with an offset, like `2022-09-01T13:45:00+02:00`, are converted to UTC
+    fn parse_iso(s: &str) -> Option<Self> {
+        s.parse().ok()
+            .or_else(|| DateTime::parse_from_rfc3339(s).ok().map(|t| t.naive_utc()))
+    }
+    fn parse_format(s: &str, format: &str) -> Option<Self> {
+        NaiveDateTime::parse_from_str(s, format).ok()
+    }
+}
+
+/// Make a Transformation that parses strings into dates or timestamps.
+/// Strings that fail to parse are replaced with null.
+///
+/// # Arguments
+/// * `format` - strftime-style format of the strings. Parses ISO-8601 if not set.
+///
+/// # Generics
+/// * `M` - Dataset metric.
+/// * `TO` - Type to parse into, either `NaiveDate` or `NaiveDateTime`.
+pub fn make_parse_time<M, TO>(
+    format: Option<String>
+) -> Fallible<Transformation<VectorDomain<AllDomain<String>>, VectorDomain<OptionNullDomain<AllDomain<TO>>>, M, M>>
+    where M: DatasetMetric,
+          TO: ParseTime + CheckNull {
+    make_row_by_row(
+        AllDomain::new(),
+        OptionNullDomain::new(AllDomain::new()),
+        move |v: &String| match &format {
+            Some(format) => TO::parse_format(v, format),
+            None => TO::parse_iso(v)
+        })
+}
+
+/// A part of a date or timestamp.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DatePart {
+    Year,
+    /// From 1 (January) to 12.
+    Month,
+    /// From 1 to 31.
+    Day,
+    /// From 0 (Monday) to 6.
+    Weekday,
+    /// From 0 to 23.
+    Hour,
+}
+
+/// A date or time type from which parts may be extracted.
+pub trait DateParts {
+    /// Returns true if `part` is defined on this type.
+    fn has_part(part: DatePart) -> bool;
+    /// Retrieve `part`, or None if it is not defined on this type.
+    fn date_part(&self, part: DatePart) -> Option<i32>;
+}
+
+impl DateParts for NaiveDate {
+    fn has_part(part: DatePart) -> bool {
+        part != DatePart::Hour
+    }
+    fn date_part(&self, part: DatePart) -> Option<i32> {
+        Some(match part {
+            DatePart::Year => self.year(),
+            DatePart::Month => self.month() as i32,
+            DatePart::Day => self.day() as i32,
+            DatePart::Weekday => self.weekday().num_days_from_monday() as i32,
+            DatePart::Hour => return None
+        })
+    }
+}
+
+impl DateParts for NaiveDateTime {
+    fn has_part(_part: DatePart) -> bool {
+        true
+    }
+    fn date_part(&self, part: DatePart) -> Option<i32> {
+        match part {
+            DatePart::Hour => Some(self.hour() as i32),
+            part => self.date().date_part(part)
+        }
+    }
+}
+
+/// Make a Transformation that extracts `part` from each date or timestamp.
+///
+/// # Arguments
+/// * `part` - The part to extract.
+///
+/// # Generics
+/// * `M` - Dataset metric.
+/// * `TIA` - Atomic input type, either `NaiveDate` or `NaiveDateTime`.
+pub fn make_date_part<M, TIA>(
+    part: DatePart
+) -> Fallible<Transformation<VectorDomain<AllDomain<TIA>>, VectorDomain<AllDomain<i32>>, M, M>>
+    where M: DatasetMetric,
+          TIA: 'static + DateParts + CheckNull {
+    if !TIA::has_part(part) {
+        return fallible!(MakeTransformation, "{:?} is not defined on {}", part, std::any::type_name::<TIA>())
+    }
+    make_row_by_row(
+        AllDomain::new(), AllDomain::new(),
+        move |v: &TIA| v.date_part(part)
+            .unwrap_assert("parts are checked when the transformation is constructed"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metrics::SymmetricDistance;
+    use crate::transformations::{make_clamp, make_find_bin};
+    use crate::error::ExplainUnwrap;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap_test()
+    }
+
+    fn datetime(date: NaiveDate, h: u32, m: u32, s: u32) -> NaiveDateTime {
+        date.and_hms_opt(h, m, s).unwrap_test()
+    }
+
+    #[test]
+    fn test_make_parse_time() -> Fallible<()> {
+        let transformation = make_parse_time::<SymmetricDistance, NaiveDate>(None)?;
+        let arg = vec!["2022-09-01".to_string(), "yesterday".to_string()];
+        assert_eq!(transformation.invoke(&arg)?, vec![Some(date(2022, 9, 1)), None]);
+
+        let transformation = make_parse_time::<SymmetricDistance, NaiveDateTime>(None)?;
+        let arg = vec!["2022-09-01T13:45:00".to_string(), "2022-09-01T13:45:00+02:00".to_string()];
+        assert_eq!(transformation.invoke(&arg)?, vec![
+            Some(datetime(date(2022, 9, 1), 13, 45, 0)),
+            Some(datetime(date(2022, 9, 1), 11, 45, 0))]);
+
+        let transformation = make_parse_time::<SymmetricDistance, NaiveDateTime>(Some("%d/%m/%Y %H:%M".to_string()))?;
+        let arg = vec!["01/09/2022 13:45".to_string(), "2022-09-01".to_string()];
+        assert_eq!(transformation.invoke(&arg)?, vec![Some(datetime(date(2022, 9, 1), 13, 45, 0)), None]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_make_date_part() -> Fallible<()> {
+        let timestamp = datetime(date(2022, 9, 1), 13, 45, 0);
+        let parts = [DatePart::Year, DatePart::Month, DatePart::Day, DatePart::Weekday, DatePart::Hour];
+        let extracted = parts.iter()
+            .map(|part| Ok(make_date_part::<SymmetricDistance, NaiveDateTime>(*part)?.invoke(&vec![timestamp])?[0]))
+            .collect::<Fallible<Vec<_>>>()?;
+        assert_eq!(extracted, vec![2022, 9, 1, 3, 13]);
+
+        assert!(make_date_part::<SymmetricDistance, NaiveDate>(DatePart::Hour).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_clamp_and_bin_dates() -> Fallible<()> {
+        let clamp = make_clamp((date(2022, 1, 1), date(2022, 12, 31)))?;
+        assert_eq!(clamp.invoke(&vec![date(2021, 6, 1), date(2022, 6, 1)])?, vec![date(2022, 1, 1), date(2022, 6, 1)]);
+
+        let bin = make_find_bin(vec![date(2022, 4, 1), date(2022, 7, 1), date(2022, 10, 1)])?;
+        assert_eq!(bin.invoke(&vec![date(2022, 1, 1), date(2022, 8, 15), date(2022, 12, 31)])?, vec![0, 2, 3]);
+        Ok(())
+    }
+}
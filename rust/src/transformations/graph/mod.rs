@@ -0,0 +1,436 @@
+//! Transformations on undirected graphs, represented as edge lists in a [`GraphDomain`].
+//!
+//! Under [`EdgeDistance`], neighboring graphs differ by an edge.
+//! Under [`NodeDistance`], neighboring graphs differ by a node and all of its edges.
+//! Node-level stability is only finite when the degree bound is public:
+//! use [`make_node_to_edge_distance`] on a degree-bounded domain, then chain into the edge-level statistics.
+//! There is no node-level counterpart of [`make_degree_truncate`] or [`make_degree_project`] on graphs of unbounded degree:
+//! a node adjacent to `k` nodes of degree `max_degree` pushes each of them over the bound,
+//! so the number of edges that must change grows with `k`, and no stability constant holds.
+//! The same is true of any greedy projection, as an added node may start a chain of displaced edges of any length.
+//! The degrees only change at the ends of these chains, so [`make_projected_degree_histogram`]
+//! releases a degree histogram under [`NodeDistance`] on graphs of unbounded degree.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::core::{Function, SensitivityMetric, StabilityMap, Transformation};
+use crate::domains::{AllDomain, GraphDomain, VectorDomain};
+use crate::error::*;
+use crate::metrics::{AbsoluteDistance, EdgeDistance, IntDistance, L1Distance, L2Distance, NodeDistance};
+use crate::traits::{DistanceConstant, ExactIntCast, Float, Hashable, InfAdd, InfMul, Number};
+
+fn degrees<T: Hashable>(edges: &[(T, T)]) -> HashMap<&T, usize> {
+    let mut degrees = HashMap::new();
+    edges.iter().flat_map(|(a, b)| [a, b])
+        .for_each(|node| *degrees.entry(node).or_insert(0) += 1);
+    degrees
+}
+
+/// Make a Transformation that removes every node with degree greater than `max_degree`, along with all of its edges.
+///
+/// Adding an edge may push each of its endpoints over the degree bound,
+/// so the truncated graphs differ by at most `2 * max_degree + 1` edges.
+/// The same does not hold under [`NodeDistance`], as a node may push any number of its neighbors over the bound.
+///
+/// # Arguments
+/// * `max_degree` - Greatest degree of any node in the output graph.
+///
+/// # Generics
+/// * `T` - Type of node identifiers.
+pub fn make_degree_truncate<T>(
+    max_degree: usize
+) -> Fallible<Transformation<GraphDomain<T>, GraphDomain<T>, EdgeDistance, EdgeDistance>>
+    where T: Hashable {
+    let stability = IntDistance::exact_int_cast(max_degree)?
+        .inf_mul(&2)?.inf_add(&1)?;
+    Ok(Transformation::new(
+        GraphDomain::new(),
+        GraphDomain::new_degree_bounded(max_degree),
+        Function::new(move |arg: &Vec<(T, T)>| {
+            let degrees = degrees(arg);
+            arg.iter()
+                .filter(|(a, b)| degrees[a] <= max_degree && degrees[b] <= max_degree)
+                .cloned().collect()
+        }),
+        EdgeDistance::default(),
+        EdgeDistance::default(),
+        StabilityMap::new_from_constant(stability)))
+}
+
+/// Make a Transformation that retains each edge only if it is among the first `max_degree` edges of both of its endpoints.
+///
+/// Edges are ordered by their endpoints, so the edges retained at a node only depend on the edges incident to that node.
+/// Adding an edge may displace at most one edge at each endpoint,
+/// so the projected graphs differ by at most three edges, regardless of `max_degree`.
+///
+/// # Arguments
+/// * `max_degree` - Greatest degree of any node in the output graph.
+///
+/// # Generics
+/// * `T` - Type of node identifiers.
+pub fn make_degree_project<T>(
+    max_degree: usize
+) -> Fallible<Transformation<GraphDomain<T>, GraphDomain<T>, EdgeDistance, EdgeDistance>>
+    where T: Hashable + Ord {
+    Ok(Transformation::new(
+        GraphDomain::new(),
+        GraphDomain::new_degree_bounded(max_degree),
+        Function::new(move |arg: &Vec<(T, T)>| {
+            let keys = arg.iter()
+                .map(|(a, b)| if a < b { (a, b) } else { (b, a) })
+                .collect::<Vec<_>>();
+
+            let mut incident = HashMap::<&T, Vec<usize>>::new();
+            keys.iter().enumerate().for_each(|(i, (a, b))| {
+                incident.entry(*a).or_default().push(i);
+                incident.entry(*b).or_default().push(i);
+            });
+
+            let mut votes = vec![0u8; arg.len()];
+            incident.into_values().for_each(|mut edges| {
+                edges.sort_by_key(|i| keys[*i]);
+                edges.into_iter().take(max_degree).for_each(|i| votes[i] += 1);
+            });
+
+            arg.iter().zip(votes)
+                .filter(|(_, v)| *v == 2)
+                .map(|(edge, _)| edge.clone())
+                .collect()
+        }),
+        EdgeDistance::default(),
+        EdgeDistance::default(),
+        StabilityMap::new_from_constant(3)))
+}
+
+/// Make a Transformation that relates node-level to edge-level distances on graphs with a public degree bound.
+///
+/// Removing a node removes at most `max_degree` edges.
+/// The data is passed through unchanged.
+///
+/// # Arguments
+/// * `max_degree` - Greatest degree of any node in the input graph.
+///
+/// # Generics
+/// * `T` - Type of node identifiers.
+pub fn make_node_to_edge_distance<T>(
+    max_degree: usize
+) -> Fallible<Transformation<GraphDomain<T>, GraphDomain<T>, NodeDistance, EdgeDistance>>
+    where T: Hashable {
+    Ok(Transformation::new(
+        GraphDomain::new_degree_bounded(max_degree),
+        GraphDomain::new_degree_bounded(max_degree),
+        Function::new(|arg: &Vec<(T, T)>| arg.clone()),
+        NodeDistance::default(),
+        EdgeDistance::default(),
+        StabilityMap::new_from_constant(IntDistance::exact_int_cast(max_degree)?)))
+}
+
+/// Make a Transformation that counts the number of edges in a graph.
+///
+/// # Arguments
+/// * `max_degree` - Greatest degree of any node in the input graph, if known.
+///
+/// # Generics
+/// * `T` - Type of node identifiers.
+/// * `TO` - Type of the count.
+pub fn make_edge_count<T, TO>(
+    max_degree: Option<usize>
+) -> Fallible<Transformation<GraphDomain<T>, AllDomain<TO>, EdgeDistance, AbsoluteDistance<TO>>>
+    where T: Hashable,
+          TO: Number {
+    Ok(Transformation::new(
+        max_degree.map_or_else(GraphDomain::new, GraphDomain::new_degree_bounded),
+        AllDomain::new(),
+        Function::new(|arg: &Vec<(T, T)>|
+            TO::exact_int_cast(arg.len()).unwrap_or(TO::MAX_CONSECUTIVE)),
+        EdgeDistance::default(),
+        AbsoluteDistance::default(),
+        StabilityMap::new_from_constant(TO::one())))
+}
+
+/// Bounds the change in a degree histogram, per edge added or removed.
+pub trait DegreeHistogramConstant: SensitivityMetric {
+    fn get_stability_constant() -> Fallible<Self::Distance>;
+}
+
+// in the worst case, both endpoints move from the same bin to the next bin
+impl<Q: Number> DegreeHistogramConstant for L1Distance<Q> {
+    fn get_stability_constant() -> Fallible<Q> {
+        Q::exact_int_cast(4)
+    }
+}
+// sqrt(2^2 + 2^2)
+impl<Q: Float> DegreeHistogramConstant for L2Distance<Q> {
+    fn get_stability_constant() -> Fallible<Q> {
+        Q::exact_int_cast(8)?.inf_sqrt()
+    }
+}
+
+/// Make a Transformation that counts the number of nodes with each degree from 1 to `max_degree`.
+///
+/// The count of nodes with degree `k` is at index `k - 1`.
+/// Nodes without edges are not represented in an edge list, and are not counted.
+///
+/// # Arguments
+/// * `max_degree` - Greatest degree of any node in the input graph.
+///
+/// # Generics
+/// * `MO` - Output metric, either `L1Distance<Q>` or `L2Distance<Q>`.
+/// * `T` - Type of node identifiers.
+/// * `TO` - Type of the counts.
+pub fn make_degree_histogram<MO, T, TO>(
+    max_degree: usize
+) -> Fallible<Transformation<GraphDomain<T>, VectorDomain<AllDomain<TO>>, EdgeDistance, MO>>
+    where MO: DegreeHistogramConstant,
+          MO::Distance: DistanceConstant<IntDistance>,
+          T: Hashable,
+          TO: Number {
+    if max_degree == 0 {
+        return fallible!(MakeTransformation, "max_degree must be positive")
+    }
+    Ok(Transformation::new(
+        GraphDomain::new_degree_bounded(max_degree),
+        VectorDomain::new_all(),
+        Function::new(move |arg: &Vec<(T, T)>| {
+            let mut histogram = vec![TO::zero(); max_degree];
+            for degree in degrees(arg).into_values() {
+                // degrees beyond the bound are not members of the input domain
+                if let Some(count) = histogram.get_mut(degree - 1) {
+                    *count = TO::one().saturating_add(count);
+                }
+            }
+            histogram
+        }),
+        EdgeDistance::default(),
+        MO::default(),
+        StabilityMap::new_from_constant(MO::get_stability_constant()?)))
+}
+
+/// Bounds the change in the degree histogram of an edge-addition projection, per node added or removed.
+pub trait ProjectedDegreeHistogramConstant: SensitivityMetric {
+    fn get_stability_constant(max_degree: usize) -> Fallible<Self::Distance>;
+}
+
+// the added node enters a bin, and each of its at most `max_degree` projected edges
+// changes the degree of one other node by one, moving it between two bins
+impl<Q: Number> ProjectedDegreeHistogramConstant for L1Distance<Q> {
+    fn get_stability_constant(max_degree: usize) -> Fallible<Q> {
+        let max_degree = Q::exact_int_cast(max_degree)?;
+        max_degree.inf_add(&max_degree)?.inf_add(&Q::one())
+    }
+}
+// in the worst case, the added node enters bin k + 1, and every other change moves a node from bin k to k + 1
+impl<Q: Float> ProjectedDegreeHistogramConstant for L2Distance<Q> {
+    fn get_stability_constant(max_degree: usize) -> Fallible<Q> {
+        let max_degree = Q::exact_int_cast(max_degree)?;
+        let max_degree_1 = max_degree.inf_add(&Q::one())?;
+        max_degree_1.inf_mul(&max_degree_1)?
+            .inf_add(&max_degree.inf_mul(&max_degree)?)?
+            .inf_sqrt()
+    }
+}
+
+/// Make a Transformation that projects a graph to `max_degree` by edge addition,
+/// and counts the number of nodes in the projection with each degree from 1 to `max_degree`.
+///
+/// Edges are visited in a fixed order, by their greater endpoint and then their lesser endpoint,
+/// and an edge is added to the projection if neither endpoint has `max_degree` projected edges yet.
+/// As the order of node identifiers is public, adding a node changes the degree of the node itself,
+/// and of at most one other node for each of its projected edges, so the stability only depends on `max_degree`.
+/// The projected graphs themselves may differ by any number of edges.
+///
+/// # Arguments
+/// * `max_degree` - Greatest degree of any node in the projected graph.
+///
+/// # Generics
+/// * `MO` - Output metric, either `L1Distance<Q>` or `L2Distance<Q>`.
+/// * `T` - Type of node identifiers.
+/// * `TO` - Type of the counts.
+pub fn make_projected_degree_histogram<MO, T, TO>(
+    max_degree: usize
+) -> Fallible<Transformation<GraphDomain<T>, VectorDomain<AllDomain<TO>>, NodeDistance, MO>>
+    where MO: ProjectedDegreeHistogramConstant,
+          MO::Distance: DistanceConstant<IntDistance>,
+          T: Hashable + Ord,
+          TO: Number {
+    if max_degree == 0 {
+        return fallible!(MakeTransformation, "max_degree must be positive")
+    }
+    Ok(Transformation::new(
+        GraphDomain::new(),
+        VectorDomain::new_all(),
+        Function::new(move |arg: &Vec<(T, T)>| {
+            let mut keys = arg.iter()
+                .map(|(a, b)| if a < b { (b, a) } else { (a, b) })
+                .collect::<Vec<_>>();
+            keys.sort();
+
+            let mut degrees = HashMap::<&T, usize>::new();
+            keys.into_iter().for_each(|(a, b)| {
+                if degrees.get(a).map_or(true, |d| *d < max_degree)
+                    && degrees.get(b).map_or(true, |d| *d < max_degree) {
+                    *degrees.entry(a).or_insert(0) += 1;
+                    *degrees.entry(b).or_insert(0) += 1;
+                }
+            });
+
+            let mut histogram = vec![TO::zero(); max_degree];
+            degrees.into_values().for_each(|degree| {
+                let count = &mut histogram[degree - 1];
+                *count = TO::one().saturating_add(count);
+            });
+            histogram
+        }),
+        NodeDistance,
+        MO::default(),
+        StabilityMap::new_from_constant(MO::get_stability_constant(max_degree)?)))
+}
+
+/// Make a Transformation that counts the number of triangles in a graph.
+///
+/// An edge added between two nodes of degree at most `max_degree`
+/// closes at most `max_degree - 1` triangles.
+///
+/// # Arguments
+/// * `max_degree` - Greatest degree of any node in the input graph.
+///
+/// # Generics
+/// * `T` - Type of node identifiers.
+/// * `TO` - Type of the count.
+pub fn make_triangle_count<T, TO>(
+    max_degree: usize
+) -> Fallible<Transformation<GraphDomain<T>, AllDomain<TO>, EdgeDistance, AbsoluteDistance<TO>>>
+    where T: Hashable,
+          TO: Number {
+    Ok(Transformation::new(
+        GraphDomain::new_degree_bounded(max_degree),
+        AllDomain::new(),
+        Function::new(|arg: &Vec<(T, T)>| {
+            let mut neighbors = HashMap::<&T, HashSet<&T>>::new();
+            arg.iter().for_each(|(a, b)| {
+                neighbors.entry(a).or_default().insert(b);
+                neighbors.entry(b).or_default().insert(a);
+            });
+            // each triangle is closed by each of its three edges
+            let closed: usize = arg.iter()
+                .map(|(a, b)| neighbors[a].intersection(&neighbors[b]).count())
+                .sum();
+            TO::exact_int_cast(closed / 3).unwrap_or(TO::MAX_CONSECUTIVE)
+        }),
+        EdgeDistance::default(),
+        AbsoluteDistance::default(),
+        StabilityMap::new_from_constant(TO::exact_int_cast(max_degree.saturating_sub(1))?)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Domain;
+    use crate::measurements::make_base_discrete_laplace;
+
+    fn edges(pairs: &[(i32, i32)]) -> Vec<(i32, i32)> {
+        pairs.to_vec()
+    }
+
+    #[test]
+    fn test_graph_domain() -> Fallible<()> {
+        let domain = GraphDomain::<i32>::new_degree_bounded(2);
+        assert!(domain.member(&edges(&[(0, 1), (1, 2)]))?);
+        assert!(!domain.member(&edges(&[(0, 0)]))?);
+        assert!(!domain.member(&edges(&[(0, 1), (1, 0)]))?);
+        assert!(!domain.member(&edges(&[(0, 1), (0, 2), (0, 3)]))?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_make_degree_truncate() -> Fallible<()> {
+        let transformation = make_degree_truncate::<i32>(2)?;
+        let star = edges(&[(0, 1), (0, 2), (0, 3), (1, 2)]);
+        assert_eq!(transformation.invoke(&star)?, edges(&[(1, 2)]));
+        assert!(transformation.check(&1, &5)?);
+        assert!(!transformation.check(&1, &4)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_degree_truncate_not_node_stable() -> Fallible<()> {
+        let transformation = make_degree_truncate::<i32>(2)?;
+        // k stars, each centered on a node of degree 2
+        let k = 10;
+        let stars = (0..k).flat_map(|i| [(i * 3, i * 3 + 1), (i * 3, i * 3 + 2)]).collect::<Vec<_>>();
+        // adding one node adjacent to every center removes every edge
+        let mut neighbor = stars.clone();
+        neighbor.extend((0..k).map(|i| (-1, i * 3)));
+
+        assert_eq!(transformation.invoke(&stars)?, stars);
+        assert!(transformation.invoke(&neighbor)?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_make_degree_project() -> Fallible<()> {
+        let transformation = make_degree_project::<i32>(2)?;
+        let star = edges(&[(0, 3), (2, 0), (0, 1), (1, 2)]);
+        let projected = transformation.invoke(&star)?;
+        assert_eq!(projected, edges(&[(2, 0), (0, 1), (1, 2)]));
+        assert!(GraphDomain::new_degree_bounded(2).member(&projected)?);
+        assert!(transformation.check(&1, &3)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_make_projected_degree_histogram() -> Fallible<()> {
+        let transformation = make_projected_degree_histogram::<L1Distance<i32>, i32, i32>(1)?;
+        // a path, where the edge (0, 1) displaces (1, 2), which frees 2 for (2, 3), and so on
+        let path = (1..8).map(|i| (i, i + 1)).collect::<Vec<_>>();
+        let mut neighbor = path.clone();
+        neighbor.push((0, 1));
+
+        assert_eq!(transformation.invoke(&path)?, vec![8]);
+        assert_eq!(transformation.invoke(&neighbor)?, vec![8]);
+        assert!(transformation.check(&1, &3)?);
+        assert!(!transformation.check(&1, &2)?);
+
+        let transformation = make_projected_degree_histogram::<L2Distance<f64>, i32, i32>(2)?;
+        let star = edges(&[(0, 1), (0, 2), (0, 3), (1, 2)]);
+        assert_eq!(transformation.invoke(&star)?, vec![0, 3]);
+        // sqrt(3^2 + 2^2)
+        assert!(transformation.check(&1, &3.6056)?);
+        assert!(!transformation.check(&1, &3.6055)?);
+
+        let chain = (make_projected_degree_histogram::<L1Distance<i32>, i32, i32>(2)?
+            >> make_base_discrete_laplace::<VectorDomain<AllDomain<i32>>, f64>(1.)?)?;
+        chain.invoke(&star)?;
+        assert!(chain.check(&1, &5.)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_graph_statistics() -> Fallible<()> {
+        // two triangles sharing the edge (1, 2)
+        let graph = edges(&[(0, 1), (0, 2), (1, 2), (1, 3), (2, 3)]);
+
+        let count = make_edge_count::<i32, i32>(Some(3))?;
+        assert_eq!(count.invoke(&graph)?, 5);
+
+        let histogram = make_degree_histogram::<L1Distance<i32>, i32, i32>(3)?;
+        assert_eq!(histogram.invoke(&graph)?, vec![0, 2, 2]);
+        assert!(histogram.check(&1, &4)?);
+
+        let triangles = make_triangle_count::<i32, i32>(3)?;
+        assert_eq!(triangles.invoke(&graph)?, 2);
+        assert!(triangles.check(&1, &2)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_node_level_triangle_count() -> Fallible<()> {
+        let chain = (make_node_to_edge_distance::<i32>(3)?
+            >> make_triangle_count::<i32, i32>(3)?
+            >> make_base_discrete_laplace::<AllDomain<i32>, f64>(1.)?)?;
+        chain.invoke(&edges(&[(0, 1), (0, 2), (1, 2)]))?;
+        assert!(chain.check(&1, &6.)?);
+        assert!(!chain.check(&1, &5.)?);
+        Ok(())
+    }
+}
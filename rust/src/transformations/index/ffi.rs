@@ -19,7 +19,7 @@ pub extern "C" fn opendp_transformations__make_find(
     ) -> FfiResult<*mut AnyTransformation>
         where TIA: Hashable {
         let categories = try_!(try_as_ref!(categories).downcast_ref::<Vec<TIA>>()).clone();
-        make_find::<TIA>(categories).into_any()
+        make_find::<Vec<TIA>>(categories).into_any()
     }
     let TIA = try_!(Type::try_from(TIA));
     dispatch!(monomorphize, [
@@ -58,7 +58,7 @@ pub extern "C" fn opendp_transformations__make_index(
         where TOA: Primitive {
         let edges = try_!(try_as_ref!(edges).downcast_ref::<Vec<TOA>>()).clone();
         let null = try_!(try_as_ref!(null).downcast_ref::<TOA>()).clone();
-        make_index::<TOA>(edges, null).into_any()
+        make_index::<Vec<TOA>>(edges, null).into_any()
     }
     let TOA = try_!(Type::try_from(TOA));
     dispatch!(monomorphize, [
@@ -6,17 +6,23 @@ use std::iter::FromIterator;
 
 use crate::core::Transformation;
 use crate::metrics::SymmetricDistance;
-use crate::domains::{AllDomain, VectorDomain, OptionNullDomain};
+use crate::domains::{AllDomain, Categories, VectorDomain, OptionNullDomain};
 use crate::error::Fallible;
-use crate::traits::{Hashable, Number, Primitive};
+use crate::traits::{CheckNull, Hashable, Primitive, TotalOrd};
 use crate::transformations::make_row_by_row;
 
-pub fn make_find<TIA>(
-    categories: Vec<TIA>
-) -> Fallible<Transformation<VectorDomain<AllDomain<TIA>>, VectorDomain<OptionNullDomain<AllDomain<usize>>>, SymmetricDistance, SymmetricDistance>>
-    where TIA: Hashable {
+/// Make a Transformation that finds the index of each value in `categories`.
+///
+/// `categories` may be a `Vec`, or a [`CategoricalDomain`](crate::domains::CategoricalDomain) that is carried by the transformation.
+/// Values that are not a category map to null.
+pub fn make_find<C>(
+    categories: C
+) -> Fallible<Transformation<VectorDomain<C::AtomDomain>, VectorDomain<OptionNullDomain<C::IndexDomain>>, SymmetricDistance, SymmetricDistance>>
+    where C: Categories,
+          C::Atom: Hashable {
+    let (categories, atom_domain, index_domain) = categories.into_parts()?;
     let categories_len = categories.len();
-    let indexes = HashMap::<TIA, usize>::from_iter(categories.into_iter()
+    let indexes = HashMap::<C::Atom, usize>::from_iter(categories.into_iter()
         .enumerate().map(|(i, v)| (v, i)));
 
     if indexes.len() != categories_len {
@@ -24,14 +30,14 @@ pub fn make_find<TIA>(
     }
 
     make_row_by_row(
-        AllDomain::new(), OptionNullDomain::new(AllDomain::new()),
+        atom_domain, OptionNullDomain::new(index_domain),
         move |v| indexes.get(v).cloned())
 }
 
 pub fn make_find_bin<TIA>(
     edges: Vec<TIA>
 ) -> Fallible<Transformation<VectorDomain<AllDomain<TIA>>, VectorDomain<AllDomain<usize>>, SymmetricDistance, SymmetricDistance>>
-    where TIA: Number {
+    where TIA: 'static + TotalOrd + CheckNull {
     if !edges.windows(2).all(|pair| pair[0] < pair[1]) {
         return fallible!(MakeTransformation, "edges must be unique and ordered")
     }
@@ -42,19 +48,25 @@ pub fn make_find_bin<TIA>(
             .unwrap_or(edges.len()))
 }
 
-pub fn make_index<TOA>(
-    categories: Vec<TOA>, null: TOA
-) -> Fallible<Transformation<VectorDomain<AllDomain<usize>>, VectorDomain<AllDomain<TOA>>, SymmetricDistance, SymmetricDistance>>
-    where TOA: Primitive {
+/// Make a Transformation that maps each index to the corresponding category in `categories`.
+///
+/// `categories` may be a `Vec`, or a [`CategoricalDomain`](crate::domains::CategoricalDomain) that is carried by the transformation.
+/// Indexes that are out of range map to `null`.
+pub fn make_index<C>(
+    categories: C, null: C::Atom
+) -> Fallible<Transformation<VectorDomain<C::IndexDomain>, VectorDomain<C::AtomDomain>, SymmetricDistance, SymmetricDistance>>
+    where C: Categories,
+          C::Atom: Primitive {
+    let (categories, atom_domain, index_domain) = categories.into_parts()?;
     make_row_by_row(
-        AllDomain::new(), AllDomain::new(),
+        index_domain, atom_domain,
         move |v| categories.get(*v).unwrap_or(&null).clone())
 }
 
-
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::domains::CategoricalDomain;
 
     #[test]
     fn test_find() -> Fallible<()> {
@@ -82,4 +94,20 @@ mod test {
             vec!["A", "B", "NA", "B", "NA"]);
         Ok(())
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_categorical_find_index() -> Fallible<()> {
+        let domain = CategoricalDomain::new(vec!["A", "B", "C"])?;
+        let find = make_find(domain.clone())?;
+        assert_eq!(find.output_domain.element_domain, OptionNullDomain::new(CategoricalDomain::new(vec![0, 1, 2])?));
+        // values that are not a category map to null
+        assert_eq!(find.invoke(&vec!["C", "A", "D"])?, vec![Some(2), Some(0), None]);
+
+        let index = make_index(domain.clone(), "NA")?;
+        assert_eq!(index.input_domain.element_domain, CategoricalDomain::new(vec![0, 1, 2])?);
+        assert_eq!(index.output_domain.element_domain, domain);
+        // indexes that are out of range map to the null value
+        assert_eq!(index.invoke(&vec![2, 0, 3])?, vec!["C", "A", "NA"]);
+        Ok(())
+    }
+}
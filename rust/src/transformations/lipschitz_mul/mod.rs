@@ -1,7 +1,8 @@
-use num::One;
+use num::{One, ToPrimitive};
 
 use crate::{
-    core::{Domain, Function, Metric, StabilityMap, Transformation},
+    accuracy::NoiseDistribution,
+    core::{Domain, Function, Metric, NoiseMap, StabilityMap, Transformation},
     metrics::{AbsoluteDistance, LpDistance},
     domains::{AllDomain, VectorDomain},
     error::Fallible,
@@ -53,6 +54,11 @@ where
     // greatest possible error is the ulp of the greatest possible output
     let output_ulp = _2.inf_pow(&max_unbiased_exponent.inf_sub(&mantissa_bits)?)?;
 
+    // When postprocessing a noisy release whose noiseless value lies within the bounds, clamping can only reduce the noise.
+    // The noise is then scaled by the constant, and the multiplication is off by at most the ulp of the greatest output.
+    let noise_factor = constant.to_f64().ok_or_else(|| err!(FailedCast, "failed to cast constant to f64"))?;
+    let noise_bias = output_ulp.to_f64().ok_or_else(|| err!(FailedCast, "failed to cast ulp to f64"))?;
+
     Ok(Transformation::new(
         D::default(),
         D::default(),
@@ -62,7 +68,10 @@ where
         StabilityMap::new_fallible(move |d_in| {
             constant.alerting_abs()?.inf_mul(d_in)?.inf_add(&output_ulp)
         }),
-    ))
+    ).with_noise_map(NoiseMap::new(move |noise: &NoiseDistribution| NoiseDistribution::Biased {
+        bias: noise_bias,
+        noise: Box::new(noise.clone().scale_by(noise_factor)),
+    })))
 }
 
 /// Implemented for any domain that supports multiplication lipschitz extensions
@@ -4,14 +4,14 @@ use std::os::raw::c_char;
 use num::One;
 
 use crate::core::{DatasetMetric, SensitivityMetric};
-use crate::core::{FfiResult, IntoAnyTransformationFfiResultExt};
+use crate::core::{CallbackFn, FfiResult, IntoAnyTransformationFfiResultExt, wrap_callback};
 use crate::metrics::{AbsoluteDistance, L1Distance, L2Distance, ChangeOneDistance, SymmetricDistance, InsertDeleteDistance, HammingDistance};
 use crate::domains::{AllDomain, InherentNull, InherentNullDomain, OptionNullDomain, VectorDomain};
 use crate::err;
 use crate::ffi::any::{AnyObject, AnyTransformation, Downcast};
 use crate::ffi::util::{Type, TypeContents};
 use crate::traits::{CheckNull, DistanceConstant, Primitive};
-use crate::transformations::{make_identity, make_is_equal, make_is_null};
+use crate::transformations::{make_identity, make_is_equal, make_is_null, make_filter_map, make_filter};
 
 #[no_mangle]
 pub extern "C" fn opendp_transformations__make_identity(
@@ -116,8 +116,54 @@ pub extern "C" fn opendp_transformations__make_is_null(
 }
 
 
+#[no_mangle]
+pub extern "C" fn opendp_transformations__make_filter_map(
+    func: CallbackFn,
+    TIA: *const c_char,
+    TOA: *const c_char,
+) -> FfiResult<*mut AnyTransformation> {
+    let TIA = try_!(Type::try_from(TIA));
+    let TOA = try_!(Type::try_from(TOA));
+
+    fn monomorphize<TIA, TOA>(func: CallbackFn) -> FfiResult<*mut AnyTransformation>
+        where TIA: Primitive, TOA: Primitive {
+        let func = wrap_callback(func);
+        // rows for which the function fails are dropped, so that failures do not depend on the data
+        make_filter_map::<AllDomain<TIA>, AllDomain<TOA>>(
+            AllDomain::new(),
+            AllDomain::new(),
+            move |arg: &TIA| func(&AnyObject::new(arg.clone()))
+                .and_then(|out| out.downcast::<TOA>())
+                .ok()).into_any()
+    }
+    dispatch!(monomorphize, [(TIA, @primitives), (TOA, @primitives)], (func))
+}
+
+#[no_mangle]
+pub extern "C" fn opendp_transformations__make_filter(
+    predicate: CallbackFn,
+    T: *const c_char,
+) -> FfiResult<*mut AnyTransformation> {
+    let T = try_!(Type::try_from(T));
+
+    fn monomorphize<T>(predicate: CallbackFn) -> FfiResult<*mut AnyTransformation>
+        where T: Primitive {
+        let predicate = wrap_callback(predicate);
+        // rows for which the predicate fails are dropped, so that failures do not depend on the data
+        make_filter::<AllDomain<T>>(
+            AllDomain::new(),
+            move |arg: &T| predicate(&AnyObject::new(arg.clone()))
+                .and_then(|keep| keep.downcast::<bool>())
+                .unwrap_or(false)).into_any()
+    }
+    dispatch!(monomorphize, [(T, @primitives)], (predicate))
+}
+
 #[cfg(test)]
 mod tests {
+    use std::ptr::null_mut;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
     use crate::core;
     use crate::error::Fallible;
     use crate::ffi::any::{AnyObject, Downcast};
@@ -133,7 +179,7 @@ mod tests {
             "SymmetricDistance".to_char_p(),
         ))?;
         let arg = AnyObject::new_raw(vec![123]);
-        let res = core::opendp_core__transformation_invoke(&transformation, arg);
+        let res = core::opendp_core__transformation_invoke(&transformation, arg, util::from_bool(false));
         let res: Vec<i32> = Fallible::from(res)?.downcast()?;
         assert_eq!(res, vec![123]);
         Ok(())
@@ -146,9 +192,93 @@ mod tests {
             "i32".to_char_p(),
         ))?;
         let arg = AnyObject::new_raw(vec![1, 2, 3]);
-        let res = core::opendp_core__transformation_invoke(&transformation, arg);
+        let res = core::opendp_core__transformation_invoke(&transformation, arg, util::from_bool(false));
         let res: Vec<bool> = Fallible::from(res)?.downcast()?;
         assert_eq!(res, vec![true, false, false]);
         Ok(())
     }
+
+    extern "C" fn double(arg: *const AnyObject) -> *mut FfiResult<*mut AnyObject> {
+        let arg = match util::as_ref(arg) {
+            Some(arg) => arg,
+            // a null argument releases the callback
+            None => return null_mut()
+        };
+        let arg = arg.downcast_ref::<i32>().and_then(|v| if *v == 0 {
+            fallible!(FailedFunction, "zero")
+        } else {
+            Ok(AnyObject::new(v * 2))
+        });
+        util::into_raw(FfiResult::from(arg))
+    }
+
+    extern "C" fn is_positive(arg: *const AnyObject) -> *mut FfiResult<*mut AnyObject> {
+        let arg = match util::as_ref(arg) {
+            Some(arg) => arg,
+            None => return null_mut()
+        };
+        let arg = arg.downcast_ref::<i32>().map(|v| AnyObject::new(*v > 0));
+        util::into_raw(FfiResult::from(arg))
+    }
+
+    static RELEASED: AtomicUsize = AtomicUsize::new(0);
+
+    extern "C" fn count_releases(arg: *const AnyObject) -> *mut FfiResult<*mut AnyObject> {
+        if arg.is_null() {
+            RELEASED.fetch_add(1, Ordering::SeqCst);
+            return null_mut()
+        }
+        util::into_raw(FfiResult::Ok(AnyObject::new_raw(true)))
+    }
+
+    #[test]
+    fn test_make_filter_map() -> Fallible<()> {
+        let transformation = Result::from(opendp_transformations__make_filter_map(
+            double,
+            "i32".to_char_p(),
+            "i32".to_char_p(),
+        ))?;
+        let arg = AnyObject::new_raw(vec![1, 2, 0, 3]);
+        let res = core::opendp_core__transformation_invoke(&transformation, arg, util::from_bool(false));
+        let res: Vec<i32> = Fallible::from(res)?.downcast()?;
+        // the row on which the callback fails is dropped
+        assert_eq!(res, vec![2, 4, 6]);
+
+        // the callback output fails to downcast when the output type doesn't match
+        let transformation = Result::from(opendp_transformations__make_filter_map(
+            double,
+            "i32".to_char_p(),
+            "f64".to_char_p(),
+        ))?;
+        let arg = AnyObject::new_raw(vec![1]);
+        let res = core::opendp_core__transformation_invoke(&transformation, arg, util::from_bool(false));
+        let res: Vec<f64> = Fallible::from(res)?.downcast()?;
+        assert!(res.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_callback_release() -> Fallible<()> {
+        let transformation = Result::from(opendp_transformations__make_filter(
+            count_releases,
+            "i32".to_char_p(),
+        ))?;
+        assert_eq!(RELEASED.load(Ordering::SeqCst), 0);
+        drop(transformation);
+        assert_eq!(RELEASED.load(Ordering::SeqCst), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_make_filter() -> Fallible<()> {
+        let transformation = Result::from(opendp_transformations__make_filter(
+            is_positive,
+            "i32".to_char_p(),
+        ))?;
+        let arg = AnyObject::new_raw(vec![-1, 2, 0, 3]);
+        let res = core::opendp_core__transformation_invoke(&transformation, arg, util::from_bool(false));
+        let res: Vec<i32> = Fallible::from(res)?.downcast()?;
+        assert_eq!(res, vec![2, 3]);
+        Ok(())
+    }
 }
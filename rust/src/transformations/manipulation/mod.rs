@@ -3,7 +3,7 @@ mod ffi;
 
 use num::One;
 
-use crate::core::{Domain, Function, Metric, StabilityMap, Transformation, DatasetMetric};
+use crate::core::{Domain, Function, Metric, NoiseMap, StabilityMap, Transformation, DatasetMetric};
 use crate::error::*;
 use crate::traits::{DistanceConstant, CheckNull};
 use crate::domains::{VectorDomain, AllDomain};
@@ -11,7 +11,9 @@ use crate::metrics::SymmetricDistance;
 
 
 /// Constructs a [`Transformation`] representing an arbitrary row-by-row transformation.
-pub(crate) fn make_row_by_row<DIA, DOA, M>(
+///
+/// Since each row is mapped independently, the transformation is 1-stable under any dataset metric.
+pub fn make_row_by_row<DIA, DOA, M>(
     atom_input_domain: DIA,
     atom_output_domain: DOA,
     atom_function: impl 'static + Fn(&DIA::Carrier) -> DOA::Carrier
@@ -29,7 +31,9 @@ pub(crate) fn make_row_by_row<DIA, DOA, M>(
         StabilityMap::new_from_constant(1)))
 }
 
-/// Constructs a [`Transformation`] representing an arbitrary row-by-row transformation.
+/// Constructs a [`Transformation`] representing an arbitrary fallible row-by-row transformation.
+///
+/// If `atom_function` fails on any row, the entire transformation fails.
 pub(crate) fn make_row_by_row_fallible<DIA, DOA, M>(
     atom_input_domain: DIA,
     atom_output_domain: DOA,
@@ -48,6 +52,46 @@ pub(crate) fn make_row_by_row_fallible<DIA, DOA, M>(
         StabilityMap::new_from_constant(1)))
 }
 
+/// Constructs a [`Transformation`] that applies `atom_function` to each row,
+/// and drops the rows for which it returns `None`.
+///
+/// Adding or removing a row changes the output by at most one row, so the transformation is 1-stable.
+pub fn make_filter_map<DIA, DOA>(
+    atom_input_domain: DIA,
+    atom_output_domain: DOA,
+    atom_function: impl 'static + Fn(&DIA::Carrier) -> Option<DOA::Carrier>
+) -> Fallible<Transformation<VectorDomain<DIA>, VectorDomain<DOA>, SymmetricDistance, SymmetricDistance>>
+    where DIA: Domain, DOA: Domain,
+          DIA::Carrier: 'static {
+    Ok(Transformation::new(
+        VectorDomain::new(atom_input_domain),
+        VectorDomain::new(atom_output_domain),
+        Function::new(move |arg: &Vec<DIA::Carrier>|
+            arg.iter().filter_map(&atom_function).collect()),
+        SymmetricDistance,
+        SymmetricDistance,
+        StabilityMap::new_from_constant(1)))
+}
+
+/// Constructs a [`Transformation`] that keeps only the rows that satisfy `predicate`.
+///
+/// Adding or removing a row changes the output by at most one row, so the transformation is 1-stable.
+pub fn make_filter<DA>(
+    atom_domain: DA,
+    predicate: impl 'static + Fn(&DA::Carrier) -> bool
+) -> Fallible<Transformation<VectorDomain<DA>, VectorDomain<DA>, SymmetricDistance, SymmetricDistance>>
+    where DA: Domain,
+          DA::Carrier: 'static + Clone {
+    Ok(Transformation::new(
+        VectorDomain::new(atom_domain.clone()),
+        VectorDomain::new(atom_domain),
+        Function::new(move |arg: &Vec<DA::Carrier>|
+            arg.iter().filter(|v| predicate(v)).cloned().collect()),
+        SymmetricDistance,
+        SymmetricDistance,
+        StabilityMap::new_from_constant(1)))
+}
+
 /// Constructs a [`Transformation`] representing the identity function.
 pub fn make_identity<D, M>(domain: D, metric: M) -> Fallible<Transformation<D, D, M, M>>
     where D: Domain, D::Carrier: Clone,
@@ -58,7 +102,8 @@ pub fn make_identity<D, M>(domain: D, metric: M) -> Fallible<Transformation<D, D
         Function::new(|arg: &D::Carrier| arg.clone()),
         metric.clone(),
         metric,
-        StabilityMap::new_from_constant(M::Distance::one())))
+        StabilityMap::new_from_constant(M::Distance::one()))
+        .with_noise_map(NoiseMap::new(|noise| noise.clone())))
 }
 
 /// A [`Transformation`] that checks equality elementwise with `value`.
@@ -98,6 +143,37 @@ mod tests {
         assert_eq!(ret, 99);
     }
 
+    #[test]
+    fn test_row_by_row() -> Fallible<()> {
+        let trans = make_row_by_row::<_, _, SymmetricDistance>(
+            AllDomain::new(), AllDomain::new(), |v: &i32| v.to_string())?;
+        assert_eq!(trans.invoke(&vec![1, 2])?, vec!["1".to_string(), "2".to_string()]);
+        assert!(trans.check(&1, &1)?);
+
+        let trans = make_row_by_row_fallible::<_, AllDomain<i32>, SymmetricDistance>(
+            AllDomain::new(), AllDomain::new(), |v: &String| v.parse::<i32>().map_err(|e| err!(FailedFunction, "{}", e)))?;
+        assert_eq!(trans.invoke(&vec!["1".to_string()])?, vec![1]);
+        assert!(trans.invoke(&vec!["a".to_string()]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_filter_map() -> Fallible<()> {
+        let trans = make_filter_map(
+            AllDomain::new(), AllDomain::new(), |v: &String| v.parse::<i32>().ok())?;
+        assert_eq!(trans.invoke(&vec!["1".to_string(), "a".to_string(), "3".to_string()])?, vec![1, 3]);
+        assert!(trans.check(&1, &1)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_filter() -> Fallible<()> {
+        let filter = make_filter(AllDomain::new(), |v: &i32| v % 2 == 0)?;
+        assert_eq!(filter.invoke(&vec![1, 2, 3, 4])?, vec![2, 4]);
+        assert!(filter.check(&1, &1)?);
+        Ok(())
+    }
+
     #[test]
     fn test_is_equal() -> Fallible<()> {
         let is_equal = make_is_equal("alpha".to_string())?;
@@ -1,5 +1,5 @@
 use std::convert::TryFrom;
-use std::os::raw::{c_char, c_uint};
+use std::os::raw::{c_char, c_uint, c_void};
 
 use num::Float;
 
@@ -11,7 +11,8 @@ use crate::ffi::any::{AnyObject, AnyTransformation, Downcast};
 use crate::ffi::util::Type;
 use crate::traits::{ExactIntCast, InfMul};
 use crate::transformations::{
-    make_sized_bounded_mean, LipschitzMulFloatDomain, LipschitzMulFloatMetric, MakeSizedBoundedSum,
+    make_size_bounded_mean, make_sized_bounded_mean, make_sized_bounded_trimmed_mean, LipschitzMulFloatDomain, LipschitzMulFloatMetric,
+    MakeSizedBoundedSum, Pairwise, Sequential, UncheckedSum,
 };
 
 #[no_mangle]
@@ -43,6 +44,80 @@ pub extern "C" fn opendp_transformations__make_sized_bounded_mean(
     ], (size, bounds))
 }
 
+#[no_mangle]
+pub extern "C" fn opendp_transformations__make_sized_bounded_trimmed_mean(
+    size: c_uint,
+    bounds: *const AnyObject,
+    trim: *const c_void,
+    S: *const c_char,
+) -> FfiResult<*mut AnyTransformation> {
+    fn monomorphize<T>(
+        S: Type,
+        size: usize,
+        bounds: *const AnyObject,
+        trim: *const c_void,
+    ) -> FfiResult<*mut AnyTransformation>
+    where
+        T: 'static + crate::traits::Float,
+        AllDomain<T>: LipschitzMulFloatDomain<Atom = T>,
+        AbsoluteDistance<T>: LipschitzMulFloatMetric<Distance = T>,
+    {
+        fn monomorphize2<S>(
+            size: usize,
+            bounds: (S::Item, S::Item),
+            trim: S::Item,
+        ) -> FfiResult<*mut AnyTransformation>
+        where
+            S: UncheckedSum,
+            S::Item: 'static + crate::traits::Float,
+            AllDomain<S::Item>: LipschitzMulFloatDomain<Atom = S::Item>,
+            AbsoluteDistance<S::Item>: LipschitzMulFloatMetric<Distance = S::Item>,
+        {
+            make_sized_bounded_trimmed_mean::<S>(size, bounds, trim).into_any()
+        }
+        let bounds = *try_!(try_as_ref!(bounds).downcast_ref::<(T, T)>());
+        let trim = *try_as_ref!(trim as *const T);
+        dispatch!(monomorphize2, [(S, [Sequential<T>, Pairwise<T>])], (size, bounds, trim))
+    }
+    let size = size as usize;
+    let S = try_!(Type::try_from(S));
+    let T = try_!(S.get_atom());
+    dispatch!(monomorphize, [(T, @floats)], (S, size, bounds, trim))
+}
+
+#[no_mangle]
+pub extern "C" fn opendp_transformations__make_size_bounded_mean(
+    size_bounds: *const AnyObject,
+    bounds: *const AnyObject,
+    S: *const c_char,
+) -> FfiResult<*mut AnyTransformation> {
+    fn monomorphize<T>(
+        S: Type,
+        size_bounds: (usize, usize),
+        bounds: *const AnyObject,
+    ) -> FfiResult<*mut AnyTransformation>
+    where
+        T: 'static + crate::traits::Float,
+    {
+        fn monomorphize2<S>(
+            size_bounds: (usize, usize),
+            bounds: (S::Item, S::Item),
+        ) -> FfiResult<*mut AnyTransformation>
+        where
+            S: UncheckedSum,
+            S::Item: 'static + crate::traits::Float,
+        {
+            make_size_bounded_mean::<S>(size_bounds, bounds).into_any()
+        }
+        let bounds = *try_!(try_as_ref!(bounds).downcast_ref::<(T, T)>());
+        dispatch!(monomorphize2, [(S, [Sequential<T>, Pairwise<T>])], (size_bounds, bounds))
+    }
+    let size_bounds = *try_!(try_as_ref!(size_bounds).downcast_ref::<(usize, usize)>());
+    let S = try_!(Type::try_from(S));
+    let T = try_!(S.get_atom());
+    dispatch!(monomorphize, [(T, @floats)], (S, size_bounds, bounds))
+}
+
 #[cfg(test)]
 mod tests {
     use crate::core;
@@ -62,7 +137,36 @@ mod tests {
             "f64".to_char_p(),
         ))?;
         let arg = AnyObject::new_raw(vec![1.0, 2.0, 3.0]);
-        let res = core::opendp_core__transformation_invoke(&transformation, arg);
+        let res = core::opendp_core__transformation_invoke(&transformation, arg, util::from_bool(false));
+        let res: f64 = Fallible::from(res)?.downcast()?;
+        assert_eq!(res, 2.0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_make_sized_bounded_trimmed_mean() -> Fallible<()> {
+        let transformation = Result::from(opendp_transformations__make_sized_bounded_trimmed_mean(
+            4 as c_uint,
+            util::into_raw(AnyObject::new((0., 10.))),
+            util::into_raw(0.25) as *const c_void,
+            "Pairwise<f64>".to_char_p(),
+        ))?;
+        let arg = AnyObject::new_raw(vec![10., 1., 2., 0.]);
+        let res = core::opendp_core__transformation_invoke(&transformation, arg, util::from_bool(false));
+        let res: f64 = Fallible::from(res)?.downcast()?;
+        assert_eq!(res, 1.5);
+        Ok(())
+    }
+
+    #[test]
+    fn test_make_size_bounded_mean() -> Fallible<()> {
+        let transformation = Result::from(opendp_transformations__make_size_bounded_mean(
+            util::into_raw(AnyObject::new((1usize, 10usize))),
+            util::into_raw(AnyObject::new((0., 10.))),
+            "Pairwise<f64>".to_char_p(),
+        ))?;
+        let arg = AnyObject::new_raw(vec![1.0, 2.0, 3.0]);
+        let res = core::opendp_core__transformation_invoke(&transformation, arg, util::from_bool(false));
         let res: f64 = Fallible::from(res)?.downcast()?;
         assert_eq!(res, 2.0);
         Ok(())
@@ -1,17 +1,17 @@
 #[cfg(feature = "ffi")]
 mod ffi;
 
-use num::Float;
+use num::{Float, One, ToPrimitive, Zero};
 
-use crate::core::{Metric, Transformation};
-use crate::metrics::AbsoluteDistance;
+use crate::core::{Function, Metric, StabilityMap, Transformation};
+use crate::metrics::{AbsoluteDistance, IntDistance, SymmetricDistance};
 use crate::domains::{AllDomain, BoundedDomain, SizedDomain, VectorDomain};
 use crate::error::Fallible;
-use crate::traits::{ExactIntCast, InfMul};
+use crate::traits::{AlertingAbs, ExactIntCast, FloatBits, InfAdd, InfCast, InfDiv, InfMul, InfPow, InfSub, TotalOrd};
 
 use super::{
     make_lipschitz_float_mul, make_sized_bounded_sum, LipschitzMulFloatDomain,
-    LipschitzMulFloatMetric, MakeSizedBoundedSum,
+    LipschitzMulFloatMetric, MakeSizedBoundedSum, UncheckedSum,
 };
 
 pub fn make_sized_bounded_mean<MI, T>(
@@ -41,11 +41,199 @@ where
         >> make_lipschitz_float_mul(size_.recip(), sum_bounds)?
 }
 
+/// Make a Transformation that computes the mean of bounded data,
+/// after dropping the `trim` fraction of smallest and largest records.
+///
+/// # Arguments
+/// * `size` - Number of records in input data.
+/// * `bounds` - Tuple of inclusive lower and upper bounds of the input data.
+/// * `trim` - Fraction of records to drop from each tail. Must be in [0, 0.5).
+pub fn make_sized_bounded_trimmed_mean<S>(
+    size: usize,
+    bounds: (S::Item, S::Item),
+    trim: S::Item,
+) -> Fallible<
+    Transformation<
+        SizedDomain<VectorDomain<BoundedDomain<S::Item>>>,
+        AllDomain<S::Item>,
+        SymmetricDistance,
+        AbsoluteDistance<S::Item>,
+    >,
+>
+where
+    S: UncheckedSum,
+    S::Item: 'static + crate::traits::Float,
+    AllDomain<S::Item>: LipschitzMulFloatDomain<Atom = S::Item>,
+    AbsoluteDistance<S::Item>: LipschitzMulFloatMetric<Distance = S::Item>,
+{
+    if size == 0 {
+        return fallible!(MakeTransformation, "dataset size must be positive");
+    }
+    if !(S::Item::zero()..S::Item::exact_int_cast(2)?.recip()).contains(&trim) {
+        return fallible!(MakeTransformation, "trim must be in [0, 0.5)");
+    }
+    // number of records dropped from each tail
+    let num_trimmed = (S::Item::exact_int_cast(size)? * trim).floor().to_usize()
+        .ok_or_else(|| err!(MakeTransformation, "failed to compute the number of trimmed records"))?;
+    let num_kept = size - 2 * num_trimmed;
+    if num_kept == 0 {
+        return fallible!(MakeTransformation, "trim must leave at least one record");
+    }
+
+    let num_kept_ = S::Item::exact_int_cast(num_kept)?;
+    let sum_bounds = (num_kept_.neg_inf_mul(&bounds.0)?, num_kept_.inf_mul(&bounds.1)?);
+    make_sized_bounded_trimmed_sum::<S>(size, bounds, num_trimmed)?
+        >> make_lipschitz_float_mul(num_kept_.recip(), sum_bounds)?
+}
+
+/// Make a Transformation that sums the bounded data that remains after dropping the
+/// `num_trimmed` smallest and `num_trimmed` largest records.
+fn make_sized_bounded_trimmed_sum<S>(
+    size: usize,
+    bounds: (S::Item, S::Item),
+    num_trimmed: usize,
+) -> Fallible<
+    Transformation<
+        SizedDomain<VectorDomain<BoundedDomain<S::Item>>>,
+        AllDomain<S::Item>,
+        SymmetricDistance,
+        AbsoluteDistance<S::Item>,
+    >,
+>
+where
+    S: UncheckedSum,
+    S::Item: 'static + crate::traits::Float,
+{
+    let num_kept = size - 2 * num_trimmed;
+    if S::float_sum_can_overflow(num_kept, bounds)? {
+        return fallible!(MakeTransformation, "potential for overflow when computing function");
+    }
+
+    let (lower, upper) = bounds;
+    let ideal_sensitivity = upper.inf_sub(&lower)?;
+    let relaxation = S::relaxation(num_kept, lower, upper)?;
+
+    Ok(Transformation::new(
+        SizedDomain::new(VectorDomain::new(BoundedDomain::new_closed(bounds)?), size),
+        AllDomain::new(),
+        Function::new_fallible(move |arg: &Vec<S::Item>| {
+            let mut sorted = arg.clone();
+            let mut error = None;
+            sorted.sort_by(|l, r| l.total_cmp(r).unwrap_or_else(|e| {
+                error.get_or_insert(e);
+                std::cmp::Ordering::Equal
+            }));
+            if let Some(e) = error {
+                return Err(e);
+            }
+            let end = sorted.len().saturating_sub(num_trimmed);
+            Ok(S::unchecked_sum(&sorted[num_trimmed.min(end)..end]))
+        }),
+        SymmetricDistance,
+        AbsoluteDistance::default(),
+        StabilityMap::new_fallible(move |d_in: &IntDistance| {
+            // Changing one record x to x' shifts each of the order statistics between x and x' by one position,
+            //     all in the same direction, so the change in the sum of any window of order statistics telescopes:
+            //     |TS(v) - TS(v')| <= x_(j) - x_(i) <= U - L
+            // d_out =  |TS*(v) - TS*(v')| where TS* is the finite trimmed sum and TS the ideal trimmed sum
+            //       <= d_in / 2 * (U - L) + relaxation
+            S::Item::inf_cast(d_in / 2)?
+                .inf_mul(&ideal_sensitivity)?
+                .inf_add(&relaxation)
+        }),
+    ))
+}
+
+/// Make a Transformation that computes the mean of a dataset whose size is bounded by `size_bounds`.
+///
+/// The sensitivity shrinks with the lower size bound, while the upper size bound rules out overflow and bounds the floating-point error.
+/// The mean of an empty dataset is the midpoint of `bounds`.
+///
+/// # Arguments
+/// * `size_bounds` - Tuple of inclusive lower and upper bounds on the number of records in input data.
+/// * `bounds` - Tuple of inclusive lower and upper bounds of the input data.
+pub fn make_size_bounded_mean<S>(
+    size_bounds: (usize, usize),
+    bounds: (S::Item, S::Item),
+) -> Fallible<
+    Transformation<
+        VectorDomain<BoundedDomain<S::Item>>,
+        AllDomain<S::Item>,
+        SymmetricDistance,
+        AbsoluteDistance<S::Item>,
+    >,
+>
+where
+    S: UncheckedSum,
+    S::Item: 'static + crate::traits::Float,
+{
+    let (min_size, max_size) = size_bounds;
+    if max_size == 0 {
+        return fallible!(MakeTransformation, "upper size bound must be positive");
+    }
+    if S::float_sum_can_overflow(max_size, bounds)? {
+        return fallible!(MakeTransformation, "potential for overflow when computing function");
+    }
+    let max_size_ = S::Item::exact_int_cast(max_size)?;
+    let _2 = S::Item::exact_int_cast(2)?;
+
+    let (lower, upper) = bounds;
+    let midpoint = (lower / _2 + upper / _2).total_clamp(lower, upper)?;
+
+    // Order the additions before the removals, so that every intermediate dataset has at least `min_size` records.
+    // Adding a record v to a dataset of k >= min_size records changes the mean by |v - mean| / (k + 1) <= (U - L) / (min_size + 1).
+    // This also holds for an empty dataset, whose mean is the midpoint.
+    let ideal_sensitivity = upper.inf_sub(&lower)?
+        .inf_div(&S::Item::exact_int_cast(min_size)?.inf_add(&S::Item::one())?)?;
+
+    // The sum of at most max_size records is off by at most `error`,
+    //     and dividing by at least max(min_size, 1) records keeps the error of the sum within error / max(min_size, 1).
+    let min_divisor = S::Item::exact_int_cast(min_size.max(1))?;
+    let sum_error = S::error(max_size, lower, upper)?.inf_div(&min_divisor)?;
+
+    // The division is off by at most half the ulp of the greatest possible quotient w.
+    let output_mag = lower.alerting_abs()?.total_max(upper)?.inf_add(&sum_error)?;
+    let mantissa_bits = S::Item::exact_int_cast(S::Item::MANTISSA_BITS)?;
+    let exponent_bias = S::Item::exact_int_cast(S::Item::EXPONENT_BIAS)?;
+    let max_unbiased_exponent = S::Item::exact_int_cast(output_mag.raw_exponent())?.neg_inf_sub(&exponent_bias)?;
+    let output_ulp = _2.inf_pow(&max_unbiased_exponent.inf_sub(&mantissa_bits)?)?;
+
+    // each mean is off from the ideal mean by at most sum_error + ulp(w) / 2
+    let relaxation = sum_error.inf_add(&sum_error)?.inf_add(&output_ulp)?;
+
+    Ok(Transformation::new(
+        VectorDomain::new_size_bounded(BoundedDomain::new_closed(bounds)?, size_bounds)?,
+        AllDomain::new(),
+        Function::new(move |arg: &Vec<S::Item>| {
+            // records beyond the upper size bound are outside the input domain, and are not included
+            let arg = &arg[..arg.len().min(max_size)];
+            if arg.is_empty() {
+                return midpoint;
+            }
+            // the length is at most max_size, which is exactly representable
+            let size_ = S::Item::exact_int_cast(arg.len()).unwrap_or(max_size_);
+            S::unchecked_sum(arg) / size_
+        }),
+        SymmetricDistance,
+        AbsoluteDistance::default(),
+        StabilityMap::new_fallible(move |d_in: &IntDistance| {
+            // d_out =  |M*(v) - M*(v')| where M* is the finite mean and M the ideal mean
+            //       <= |M*(v) - M(v)| + |M(v) - M(v')| + |M(v') - M*(v')|
+            //       <= d_in * (U - L) / (min_size + 1) + relaxation
+            S::Item::inf_cast(*d_in)?
+                .inf_mul(&ideal_sensitivity)?
+                .inf_add(&relaxation)
+        }),
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use crate::metrics::SymmetricDistance;
-    use crate::error::ExplainUnwrap;
-    use crate::transformations::mean::make_sized_bounded_mean;
+    use crate::domains::BoundedDomain;
+    use crate::error::{ExplainUnwrap, Fallible};
+    use crate::transformations::mean::{make_size_bounded_mean, make_sized_bounded_mean, make_sized_bounded_trimmed_mean};
+    use crate::transformations::{make_truncate, Pairwise};
 
     #[test]
     fn test_make_bounded_mean_hamming() {
@@ -68,4 +256,39 @@ mod tests {
         assert_eq!(ret, expected);
         assert!(transformation.check(&1, &1.).unwrap_test())
     }
+
+    #[test]
+    fn test_make_sized_bounded_trimmed_mean() -> Fallible<()> {
+        let transformation = make_sized_bounded_trimmed_mean::<Pairwise<f64>>(6, (0., 100.), 0.2)?;
+        // one record is trimmed from each tail
+        let ret = transformation.invoke(&vec![100., 1., 2., 3., 4., 0.])?;
+        assert_eq!(ret, 2.5);
+        // d_in / 2 * (U - L) / 4
+        assert!(transformation.check(&2, &25.0001)?);
+        assert!(!transformation.check(&2, &24.9)?);
+
+        assert!(make_sized_bounded_trimmed_mean::<Pairwise<f64>>(6, (0., 100.), 0.5).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_make_size_bounded_mean() -> Fallible<()> {
+        let transformation = make_size_bounded_mean::<Pairwise<f64>>((4, 10), (0., 10.))?;
+        assert_eq!(transformation.invoke(&vec![1., 2., 3., 6.])?, 3.);
+        // d_in * (U - L) / (min_size + 1)
+        assert!(transformation.check(&1, &2.000001)?);
+        assert!(!transformation.check(&1, &1.9)?);
+        // records beyond the upper size bound are not included
+        assert_eq!(transformation.invoke(&[vec![1.; 10], vec![10.; 5]].concat())?, 1.);
+
+        // may be chained after truncation, which has a lower size bound of zero
+        let chain = (make_truncate::<_, SymmetricDistance, SymmetricDistance>(3, BoundedDomain::new_closed((0., 10.))?)?
+            >> make_size_bounded_mean::<Pairwise<f64>>((0, 3), (0., 10.))?)?;
+        assert_eq!(chain.invoke(&vec![])?, 5.);
+        assert_eq!(chain.invoke(&vec![2., 4.])?, 3.);
+        assert!(chain.check(&1, &20.000001)?);
+
+        assert!(make_size_bounded_mean::<Pairwise<f64>>((0, 0), (0., 10.)).is_err());
+        Ok(())
+    }
 }
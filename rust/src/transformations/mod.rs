@@ -8,6 +8,31 @@ pub mod covariance;
 #[cfg(feature="contrib")]
 pub use crate::transformations::covariance::*;
 
+#[cfg(feature="contrib")]
+pub mod regression;
+#[cfg(feature="contrib")]
+pub use crate::transformations::regression::*;
+
+#[cfg(feature="contrib")]
+pub mod product;
+#[cfg(feature="contrib")]
+pub use crate::transformations::product::*;
+
+#[cfg(feature="contrib")]
+pub mod graph;
+#[cfg(feature="contrib")]
+pub use crate::transformations::graph::*;
+
+#[cfg(feature="contrib")]
+pub mod window;
+#[cfg(feature="contrib")]
+pub use crate::transformations::window::*;
+
+#[cfg(feature="contrib")]
+pub mod datetime;
+#[cfg(feature="contrib")]
+pub use crate::transformations::datetime::*;
+
 #[cfg(feature="contrib")]
 pub mod b_ary_tree;
 #[cfg(feature="contrib")]
@@ -43,6 +68,11 @@ pub mod count;
 #[cfg(feature="contrib")]
 pub use crate::transformations::count::*;
 
+#[cfg(feature="contrib")]
+pub mod contingency;
+#[cfg(feature="contrib")]
+pub use crate::transformations::contingency::*;
+
 #[cfg(feature="contrib")]
 pub mod count_cdf;
 #[cfg(feature="contrib")]
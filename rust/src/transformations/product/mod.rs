@@ -0,0 +1,104 @@
+use crate::core::{Transformation, Function, StabilityMap, Domain, DatasetMetric};
+use crate::domains::{VectorDomain, ProductDomain, ProductField, ProductZip};
+use crate::error::Fallible;
+use crate::metrics::{InsertDeleteDistance, IntDistance};
+use crate::transformations::{make_row_by_row, IsMetricOrdered};
+
+/// Make a Transformation that projects each record onto the field at position `I`.
+///
+/// # Arguments
+/// * `input_domain` - Domain of each record.
+///
+/// # Generics
+/// * `I` - Position of the field to retain.
+/// * `DS` - Tuple of field domains.
+/// * `M` - Dataset metric.
+pub fn make_project_field<const I: usize, DS, M>(
+    input_domain: ProductDomain<DS>
+) -> Fallible<Transformation<VectorDomain<ProductDomain<DS>>, VectorDomain<<ProductDomain<DS> as ProductField<I>>::FieldDomain>, M, M>>
+    where ProductDomain<DS>: ProductField<I>,
+          <ProductDomain<DS> as Domain>::Carrier: 'static,
+          <<ProductDomain<DS> as ProductField<I>>::FieldDomain as Domain>::Carrier: Clone,
+          M: DatasetMetric {
+    let output_domain = input_domain.field_domain().clone();
+    make_row_by_row(
+        input_domain, output_domain,
+        |record| <ProductDomain<DS> as ProductField<I>>::field(record).clone())
+}
+
+/// Make a Transformation that zips a tuple of equal-length columns into a vector of records.
+///
+/// The columns are row-aligned: the distance between two tuples of columns is the `InsertDeleteDistance`
+/// between their rows, so inserting or deleting a row inserts or deletes the value at the same position in every column.
+/// Each input row is then exactly one output record.
+/// Columns that are each only known up to their own ordering, like under `SymmetricDistance`, may not be zipped.
+///
+/// # Arguments
+/// * `input_domain` - Domain of the tuple of columns.
+///
+/// # Generics
+/// * `DS` - Tuple of column domains, each a `VectorDomain`.
+/// * `MO` - Output dataset metric. Either `SymmetricDistance` or `InsertDeleteDistance`.
+pub fn make_zip_fields<DS, MO>(
+    input_domain: ProductDomain<DS>
+) -> Fallible<Transformation<ProductDomain<DS>, VectorDomain<<ProductDomain<DS> as ProductZip>::RowDomain>, InsertDeleteDistance, MO>>
+    where ProductDomain<DS>: ProductZip,
+          MO: IsMetricOrdered<Distance = IntDistance> {
+    let output_domain = VectorDomain::new(input_domain.row_domain());
+    Ok(Transformation::new(
+        input_domain,
+        output_domain,
+        Function::new_fallible(|arg: &<ProductDomain<DS> as Domain>::Carrier|
+            <ProductDomain<DS> as ProductZip>::zip(arg)),
+        InsertDeleteDistance,
+        MO::default(),
+        // the symmetric distance between the records is no greater than their insert-delete distance
+        StabilityMap::new_from_constant(1)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domains::{AllDomain, BoundedDomain, CategoricalDomain};
+    use crate::metrics::SymmetricDistance;
+
+    fn record_domain() -> Fallible<ProductDomain<(BoundedDomain<u8>, BoundedDomain<f64>, CategoricalDomain<String>)>> {
+        Ok(ProductDomain::new((
+            BoundedDomain::new_closed((0, 120))?,
+            BoundedDomain::new_closed((0., 1e6))?,
+            CategoricalDomain::new(vec!["CA".to_string(), "WA".to_string()])?)))
+    }
+
+    #[test]
+    fn test_product_domain() -> Fallible<()> {
+        let domain = record_domain()?;
+        assert!(domain.member(&(30, 5e4, "CA".to_string()))?);
+        assert!(!domain.member(&(130, 5e4, "CA".to_string()))?);
+        assert!(!domain.member(&(30, 5e4, "OR".to_string()))?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_make_project_field() -> Fallible<()> {
+        let domain = record_domain()?;
+        let transformation = make_project_field::<1, _, SymmetricDistance>(domain)?;
+        let arg = vec![(30, 5e4, "CA".to_string()), (40, 7e4, "WA".to_string())];
+        assert_eq!(transformation.invoke(&arg)?, vec![5e4, 7e4]);
+        assert!(transformation.check(&1, &1)?);
+        assert!(!transformation.check(&2, &1)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_make_zip_fields() -> Fallible<()> {
+        let domain = ProductDomain::new((
+            VectorDomain::new(AllDomain::<i32>::new()),
+            VectorDomain::new(AllDomain::<bool>::new())));
+        let transformation = make_zip_fields::<_, SymmetricDistance>(domain)?;
+        assert_eq!(transformation.invoke(&(vec![1, 2], vec![true, false]))?, vec![(1, true), (2, false)]);
+        assert!(transformation.invoke(&(vec![1, 2], vec![true])).is_err());
+        assert!(transformation.check(&1, &1)?);
+        assert!(!transformation.check(&2, &1)?);
+        Ok(())
+    }
+}
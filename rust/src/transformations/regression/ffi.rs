@@ -0,0 +1,111 @@
+use std::{os::raw::{c_char, c_uint, c_void}, convert::TryFrom};
+
+use crate::{
+    core::{FfiResult, IntoAnyTransformationFfiResultExt},
+    ffi::{
+        any::{AnyObject, AnyTransformation, Downcast},
+        util::Type,
+    },
+    transformations::{make_bounded_regression_statistics, make_regression_solver, Pairwise, Sequential, UncheckedSum},
+    traits::Float
+};
+
+// no entry in bootstrap.json because there's no way to get data into it
+#[no_mangle]
+pub extern "C" fn opendp_transformations__make_bounded_regression_statistics(
+    dim: c_uint,
+    size_limit: c_uint,
+    norm: *const AnyObject,
+    label_bounds: *const AnyObject,
+    P: c_uint,
+    S: *const c_char,
+) -> FfiResult<*mut AnyTransformation> {
+    fn monomorphize<T>(
+        dim: usize,
+        size_limit: usize,
+        norm: *const AnyObject,
+        label_bounds: *const AnyObject,
+        P: usize,
+        S: Type,
+    ) -> FfiResult<*mut AnyTransformation>
+    where
+        T: 'static + Float,
+    {
+        fn monomorphize2<S>(
+            dim: usize,
+            size_limit: usize,
+            norm: S::Item,
+            label_bounds: (S::Item, S::Item),
+            P: usize,
+        ) -> FfiResult<*mut AnyTransformation>
+        where
+            S: UncheckedSum,
+            S::Item: 'static + Float,
+        {
+            match P {
+                1 => make_bounded_regression_statistics::<1, S>(dim, size_limit, norm, label_bounds).into_any(),
+                2 => make_bounded_regression_statistics::<2, S>(dim, size_limit, norm, label_bounds).into_any(),
+                _ => err!(FFI, "P must be 1 or 2").into()
+            }
+        }
+        let norm = *try_!(try_as_ref!(norm).downcast_ref::<T>());
+        let label_bounds = *try_!(try_as_ref!(label_bounds).downcast_ref::<(T, T)>());
+        dispatch!(monomorphize2, [
+            (S, [Sequential<T>, Pairwise<T>])
+        ], (dim, size_limit, norm, label_bounds, P))
+    }
+    let dim = dim as usize;
+    let size_limit = size_limit as usize;
+    let P = P as usize;
+    let S = try_!(Type::try_from(S));
+    let T = try_!(S.get_atom());
+    dispatch!(monomorphize, [
+        (T, @floats)
+    ], (dim, size_limit, norm, label_bounds, P, S))
+}
+
+#[no_mangle]
+pub extern "C" fn opendp_transformations__make_regression_solver(
+    dim: c_uint,
+    regularization: *const c_void,
+    T: *const c_char,
+) -> FfiResult<*mut AnyTransformation> {
+    fn monomorphize<T>(dim: usize, regularization: *const c_void) -> FfiResult<*mut AnyTransformation>
+    where
+        T: 'static + Float,
+    {
+        let regularization = *try_as_ref!(regularization as *const T);
+        make_regression_solver::<T>(dim, regularization).into_any()
+    }
+    let dim = dim as usize;
+    let T = try_!(Type::try_from(T));
+    dispatch!(monomorphize, [
+        (T, @floats)
+    ], (dim, regularization))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::core;
+    use crate::error::Fallible;
+    use crate::ffi::any::{AnyObject, Downcast};
+    use crate::ffi::util::{self, ToCharP};
+
+    use super::*;
+
+    #[test]
+    fn test_make_regression_solver_ffi() -> Fallible<()> {
+        let transformation = Result::from(opendp_transformations__make_regression_solver(
+            1 as c_uint,
+            util::into_raw(1e-9f64) as *const c_void,
+            "f64".to_char_p(),
+        ))?;
+        // X^T X = 2, X^T y = 4
+        let arg = AnyObject::new_raw(vec![2., 4.]);
+        let res = core::opendp_core__transformation_invoke(&transformation, arg, util::from_bool(false));
+        let res: Vec<f64> = Fallible::from(res)?.downcast()?;
+        assert!((res[0] - 2.).abs() < 1e-6);
+        Ok(())
+    }
+}
@@ -0,0 +1,223 @@
+#[cfg(feature="ffi")]
+mod ffi;
+
+use num::Zero;
+
+use crate::{
+    core::{Function, StabilityMap, Transformation},
+    domains::{AllDomain, BoundedDomain, LpBoundedDomain, ProductDomain, VectorDomain},
+    error::Fallible,
+    metrics::{AgnosticMetric, IntDistance, LpDistance, SymmetricDistance},
+    traits::{AlertingAbs, Float, InfAdd, InfCast, InfMul, InfSqrt, TotalOrd, samplers::Shuffle},
+};
+
+use super::{UncheckedSum, make_postprocess, upper_triangle_len, from_upper_triangle, project_psd};
+
+type RegressionDomain<const P: usize, T> = VectorDomain<ProductDomain<(LpBoundedDomain<P, T>, BoundedDomain<T>)>>;
+
+/// Make a Transformation that computes the sufficient statistics for linear regression, X^T X and X^T y.
+///
+/// Each record is a pair of a feature vector with bounded Lp norm, and a bounded label.
+/// The output is the upper triangle of X^T X (including the diagonal) flattened in row-major order,
+/// followed by the `dim` entries of X^T y.
+/// Feature vectors are truncated or zero-padded to `dim`.
+/// If there are more than `size_limit` records, a random subset of `size_limit` records is used.
+///
+/// # Arguments
+/// * `dim` - Number of features.
+/// * `size_limit` - Upper bound on the number of records to sum over.
+/// * `norm` - Upper bound on the Lp norm of each feature vector.
+/// * `label_bounds` - Lower and upper bounds on each label.
+pub fn make_bounded_regression_statistics<const P: usize, S>(
+    dim: usize,
+    size_limit: usize,
+    norm: S::Item,
+    label_bounds: (S::Item, S::Item),
+) -> Fallible<Transformation<RegressionDomain<P, S::Item>, VectorDomain<AllDomain<S::Item>>, SymmetricDistance, LpDistance<P, S::Item>>>
+where
+    S: UncheckedSum,
+    S::Item: 'static + Float,
+{
+    let input_domain = VectorDomain::new(ProductDomain::new((
+        LpBoundedDomain::new(norm)?,
+        BoundedDomain::new_closed(label_bounds)?)));
+
+    let label_norm = label_bounds.0.alerting_abs()?.total_max(label_bounds.1.alerting_abs()?)?;
+
+    // Adding or removing a record (x, y) changes the statistics by (upper(x x^T), y x).
+    //     When P = 1: ||upper(x x^T)||_1 <= ||x||_1^2 and ||y x||_1 <= |y| ||x||_1,
+    //         so the sensitivity is norm * (norm + label_norm)
+    //     When P = 2: ||upper(x x^T)||_2 <= ||x x^T||_F = ||x||_2^2 and ||y x||_2 <= |y| ||x||_2,
+    //         so the sensitivity is norm * sqrt(norm^2 + label_norm^2)
+    let norm_sq = norm.inf_mul(&norm)?;
+    let sensitivity = match P {
+        1 => norm.inf_mul(&norm.inf_add(&label_norm)?)?,
+        2 => norm.inf_mul(&norm_sq.inf_add(&label_norm.inf_mul(&label_norm)?)?.inf_sqrt()?)?,
+        _ => return fallible!(MakeTransformation, "only L1 and L2 distances are supported")
+    };
+
+    // each entry of each record's contribution is bounded in magnitude by norm * max(norm, label_norm)
+    let magnitude = norm.inf_mul(&norm.total_max(label_norm)?)?;
+    if S::float_sum_can_overflow(size_limit, (-magnitude, magnitude))? {
+        return fallible!(MakeTransformation, "potential for overflow when computing function")
+    }
+
+    // The error of each entry is bounded by γ times the sum of the magnitudes of the contributions.
+    // By the triangle inequality, the Lp norm of the error is bounded by
+    //     γ * sum_i ||upper(x_i x_i^T)||_p + ||y_i x_i||_p <= γ * n * norm * (norm + label_norm)
+    let relaxation = S::relaxation(size_limit, S::Item::zero(), norm.inf_mul(&norm.inf_add(&label_norm)?)?)?;
+
+    Ok(Transformation::new(
+        input_domain,
+        VectorDomain::new_all(),
+        Function::new_fallible(move |arg: &Vec<(Vec<S::Item>, S::Item)>| {
+            let mut data = arg.clone();
+            if arg.len() > size_limit {
+                data.shuffle()?
+            }
+            // features are truncated or zero-padded to `dim`, which can only decrease their norm
+            let data = data[..size_limit.min(data.len())].iter()
+                .map(|(x, y)| ((0..dim).map(|j| x.get(j).copied().unwrap_or_else(S::Item::zero)).collect(), *y))
+                .collect::<Vec<(Vec<S::Item>, S::Item)>>();
+
+            let xtx = (0..dim).flat_map(|j| (j..dim).map(move |k| (j, k)))
+                .map(|(j, k)| S::unchecked_sum(&data.iter().map(|(x, _)| x[j] * x[k]).collect::<Vec<_>>()));
+            let xty = (0..dim)
+                .map(|j| S::unchecked_sum(&data.iter().map(|(x, y)| x[j] * *y).collect::<Vec<_>>()));
+            Ok(xtx.chain(xty).collect())
+        }),
+        SymmetricDistance,
+        LpDistance::default(),
+        StabilityMap::new_fallible(move |d_in: &IntDistance| {
+            S::Item::inf_cast(*d_in)?
+                .inf_mul(&sensitivity)?
+                .inf_add(&relaxation)
+        }),
+    ))
+}
+
+/// Postprocessing transformation that solves the regularized normal equations, (X^T X + λI) β = X^T y, for the coefficients β.
+///
+/// The input is the output of [`make_bounded_regression_statistics`] after noise has been added.
+/// The noisy X^T X is first projected onto the positive semi-definite cone,
+/// so that the system is always solvable when the regularization is positive.
+///
+/// # Arguments
+/// * `dim` - Number of features.
+/// * `regularization` - The ridge penalty λ added to the diagonal of X^T X. Must be positive.
+pub fn make_regression_solver<T: 'static + Float>(
+    dim: usize,
+    regularization: T,
+) -> Fallible<Transformation<VectorDomain<AllDomain<T>>, VectorDomain<AllDomain<T>>, AgnosticMetric, AgnosticMetric>> {
+    if regularization <= T::zero() || !regularization.is_finite() {
+        return fallible!(MakeTransformation, "regularization must be positive and finite")
+    }
+    make_postprocess(
+        VectorDomain::new_all(),
+        VectorDomain::new_all(),
+        Function::new_fallible(move |arg: &Vec<T>| {
+            let num_xtx = upper_triangle_len(dim);
+            if arg.len() != num_xtx + dim {
+                return fallible!(FailedFunction, "expected {} statistics, got {}", num_xtx + dim, arg.len())
+            }
+            let mut xtx = project_psd(&from_upper_triangle(dim, &arg[..num_xtx])?);
+            (0..dim).for_each(|i| xtx[i][i] += regularization);
+            solve_positive_definite(xtx, arg[num_xtx..].to_vec())
+        }))
+}
+
+/// Solves a x = b for x, where a is symmetric positive definite, via the Cholesky decomposition.
+fn solve_positive_definite<T: Float>(a: Vec<Vec<T>>, b: Vec<T>) -> Fallible<Vec<T>> {
+    let dim = b.len();
+
+    // a = l l^T
+    let mut l = vec![vec![T::zero(); dim]; dim];
+    for i in 0..dim {
+        for j in 0..=i {
+            let dot = (0..j).fold(T::zero(), |acc, k| acc + l[i][k] * l[j][k]);
+            if i == j {
+                let pivot = a[i][i] - dot;
+                if pivot <= T::zero() || pivot.is_nan() {
+                    return fallible!(FailedFunction, "matrix is not positive definite")
+                }
+                l[i][i] = pivot.sqrt();
+            } else {
+                l[i][j] = (a[i][j] - dot) / l[j][j];
+            }
+        }
+    }
+
+    // forward substitution: l z = b
+    let mut z = vec![T::zero(); dim];
+    for i in 0..dim {
+        z[i] = (b[i] - (0..i).fold(T::zero(), |acc, k| acc + l[i][k] * z[k])) / l[i][i];
+    }
+
+    // back substitution: l^T x = z
+    let mut x = vec![T::zero(); dim];
+    for i in (0..dim).rev() {
+        x[i] = (z[i] - (i + 1..dim).fold(T::zero(), |acc, k| acc + l[k][i] * x[k])) / l[i][i];
+    }
+    Ok(x)
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::transformations::Pairwise;
+
+    #[test]
+    fn test_make_bounded_regression_statistics() -> Fallible<()> {
+        let trans = make_bounded_regression_statistics::<2, Pairwise<f64>>(2, 100, 1., (-2., 2.))?;
+        let data = vec![(vec![1., 0.], 2.), (vec![0., 1.], -1.), (vec![0.6, 0.8], 1.)];
+        let ret = trans.invoke(&data)?;
+        let expected = [1. + 0.36, 0.48, 1. + 0.64, 2. + 0.6, -1. + 0.8];
+        assert!(ret.iter().zip(expected.iter()).all(|(a, b)| (a - b).abs() < 1e-12));
+
+        // sqrt(1 + 4)
+        assert!(trans.check(&1, &(5f64.sqrt() + 1e-6))?);
+        assert!(!trans.check(&1, &(5f64.sqrt() - 1e-6))?);
+
+        let trans = make_bounded_regression_statistics::<1, Pairwise<f64>>(2, 100, 1., (-2., 2.))?;
+        assert!(trans.check(&1, &(3. + 1e-6))?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_make_regression_solver() -> Fallible<()> {
+        // y = 2 x_0 - x_1
+        let data: Vec<(Vec<f64>, f64)> = (0..20)
+            .map(|i| {
+                let x = vec![(i as f64 / 20.).cos() / 2., (i as f64 / 7.).sin() / 2.];
+                let y = 2. * x[0] - x[1];
+                (x, y)
+            })
+            .collect();
+        let trans = make_bounded_regression_statistics::<2, Pairwise<f64>>(2, 100, 1., (-2., 2.))?;
+        let solver = make_regression_solver(2, 1e-9)?;
+        let coefs = solver.invoke(&trans.invoke(&data)?)?;
+        assert!((coefs[0] - 2.).abs() < 1e-5 && (coefs[1] + 1.).abs() < 1e-5);
+
+        assert!(solver.invoke(&vec![1., 2.]).is_err());
+        assert!(make_regression_solver(2, 0.).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_private_linear_regression() -> Fallible<()> {
+        use crate::combinators::make_chain_tm;
+        use crate::measurements::make_base_laplace;
+
+        let meas = (
+            make_bounded_regression_statistics::<1, Pairwise<f64>>(2, 100, 1., (-1., 1.))? >>
+            make_base_laplace::<VectorDomain<_>>(1., None)?
+        )?;
+        let meas = make_chain_tm(&make_regression_solver(2, 1.)?, &meas)?;
+
+        let coefs = meas.invoke(&vec![(vec![0.5, 0.5], 1.), (vec![-0.5, 0.], -1.)])?;
+        assert_eq!(coefs.len(), 2);
+        assert!(meas.check(&1, &2.0001)?);
+        Ok(())
+    }
+}
@@ -9,7 +9,7 @@ use crate::ffi::any::{AnyObject, AnyTransformation};
 use crate::ffi::any::Downcast;
 use crate::ffi::util::Type;
 use crate::traits::{CheckNull, TotalOrd};
-use crate::transformations::make_resize;
+use crate::transformations::{make_resize, make_truncate};
 use crate::transformations::resize::IsMetricOrdered;
 
 #[no_mangle]
@@ -72,6 +72,35 @@ pub extern "C" fn opendp_transformations__make_resize(
     ], (size, constant))
 }
 
+#[no_mangle]
+pub extern "C" fn opendp_transformations__make_truncate(
+    max_size: c_uint, bounds: *const AnyObject,
+    MI: *const c_char,
+    MO: *const c_char,
+    TA: *const c_char,
+) -> FfiResult<*mut AnyTransformation> {
+    fn monomorphize<MI, MO, TA>(
+        max_size: usize, bounds: *const AnyObject,
+    ) -> FfiResult<*mut AnyTransformation>
+        where
+            TA: 'static + Clone + CheckNull + TotalOrd,
+            MI: 'static + IsMetricOrdered<Distance=IntDistance>,
+            MO: 'static + IsMetricOrdered<Distance=IntDistance>, {
+        let bounds = try_!(try_as_ref!(bounds).downcast_ref::<(TA, TA)>()).clone();
+        let atom_domain = try_!(BoundedDomain::new_closed(bounds));
+        make_truncate::<_, MI, MO>(max_size, atom_domain).into_any()
+    }
+    let max_size = max_size as usize;
+    let MI = try_!(Type::try_from(MI));
+    let MO = try_!(Type::try_from(MO));
+    let TA = try_!(Type::try_from(TA));
+    dispatch!(monomorphize, [
+        (MI, [SymmetricDistance, InsertDeleteDistance]),
+        (MO, [SymmetricDistance, InsertDeleteDistance]),
+        (TA, @numbers)
+    ], (max_size, bounds))
+}
+
 
 #[cfg(test)]
 mod tests {
@@ -93,7 +122,7 @@ mod tests {
             "i32".to_char_p(),
         ))?;
         let arg = AnyObject::new_raw(vec![1, 2, 3]);
-        let res = opendp_core__transformation_invoke(&transformation, arg);
+        let res = opendp_core__transformation_invoke(&transformation, arg, util::from_bool(false));
         let res: Vec<i32> = Fallible::from(res)?.downcast()?;
         assert_eq!(res, vec![1, 2, 3, 0]);
         Ok(())
@@ -111,9 +140,25 @@ mod tests {
             "i32".to_char_p(),
         ))?;
         let arg = AnyObject::new_raw(vec![1, 2, 3]);
-        let res = opendp_core__transformation_invoke(&transformation, arg);
+        let res = opendp_core__transformation_invoke(&transformation, arg, util::from_bool(false));
         let res: Vec<i32> = Fallible::from(res)?.downcast()?;
         assert_eq!(res, vec![1, 2, 3, 0]);
         Ok(())
     }
+
+    #[test]
+    fn test_make_truncate() -> Fallible<()> {
+        let transformation = Result::from(opendp_transformations__make_truncate(
+            2 as c_uint,
+            util::into_raw(AnyObject::new((0i32, 10))),
+            "InsertDeleteDistance".to_char_p(),
+            "InsertDeleteDistance".to_char_p(),
+            "i32".to_char_p(),
+        ))?;
+        let arg = AnyObject::new_raw(vec![1, 2, 3]);
+        let res = opendp_core__transformation_invoke(&transformation, arg, util::from_bool(false));
+        let res: Vec<i32> = Fallible::from(res)?.downcast()?;
+        assert_eq!(res, vec![1, 2]);
+        Ok(())
+    }
 }
@@ -76,6 +76,44 @@ where
     ))
 }
 
+/// Make a Transformation that truncates datasets to at most `max_size` records.
+///
+/// The output domain is a VectorDomain with size bounds `(0, max_size)`,
+/// which downstream transformations may use in place of an exact size.
+pub fn make_truncate<DA, MI, MO>(
+    max_size: usize,
+    atom_domain: DA,
+) -> Fallible<Transformation<VectorDomain<DA>, VectorDomain<DA>, MI, MO>>
+where
+    DA: 'static + Clone + Domain,
+    DA::Carrier: 'static + Clone,
+    MI: IsMetricOrdered<Distance = IntDistance>,
+    MO: IsMetricOrdered<Distance = IntDistance>,
+{
+    Ok(Transformation::new(
+        VectorDomain::new(atom_domain.clone()),
+        VectorDomain::new_size_bounded(atom_domain, (0, max_size))?,
+        Function::new_fallible(move |arg: &Vec<DA::Carrier>| {
+            if arg.len() <= max_size {
+                return Ok(arg.clone())
+            }
+            let mut data = arg.clone();
+            // if input metric is not ordered, then shuffle so that the slice is a random draw from the data
+            if !MI::ORDERED {
+                data.shuffle()?;
+            }
+            data.truncate(max_size);
+            Ok(data)
+        }),
+        MI::default(),
+        MO::default(),
+        // Consider when a dataset has `max_size` records.
+        // A neighboring dataset that differs by one addition of `value` may be truncated to
+        // a dataset where `value` replaces one of the original records, or distance 2.
+        StabilityMap::new_from_constant(2),
+    ))
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -96,4 +134,17 @@ mod test {
         assert!(!trans.check(&1, &1)?);
         Ok(())
     }
+
+    #[test]
+    fn test_truncate() -> Fallible<()> {
+        let trans = make_truncate::<_, SymmetricDistance, SymmetricDistance>(3, AllDomain::new())?;
+        assert_eq!(trans.invoke(&vec!["A"; 2])?, vec!["A"; 2]);
+        assert_eq!(trans.invoke(&vec!["A"; 4])?, vec!["A"; 3]);
+        assert!(trans.output_domain.member(&vec!["A"; 3])?);
+        assert!(!trans.output_domain.member(&vec!["A"; 4])?);
+
+        assert!(trans.check(&1, &2)?);
+        assert!(!trans.check(&1, &1)?);
+        Ok(())
+    }
 }
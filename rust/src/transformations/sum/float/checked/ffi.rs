@@ -8,7 +8,7 @@ use crate::ffi::any::{AnyObject, AnyTransformation, Downcast};
 use crate::ffi::util::Type;
 use crate::traits::Float;
 use crate::transformations::{
-    make_bounded_float_checked_sum, make_sized_bounded_float_checked_sum, Pairwise,
+    make_bounded_float_checked_sum, make_size_bounded_float_checked_sum, make_sized_bounded_float_checked_sum, Pairwise,
     Sequential, UncheckedSum, CanFloatSumOverflow,
 };
 
@@ -81,6 +81,40 @@ pub extern "C" fn opendp_transformations__make_sized_bounded_float_checked_sum(
 }
 
 
+#[no_mangle]
+pub extern "C" fn opendp_transformations__make_size_bounded_float_checked_sum(
+    size_bounds: *const AnyObject,
+    bounds: *const AnyObject,
+    S: *const c_char,
+) -> FfiResult<*mut AnyTransformation> {
+    fn monomorphize<T>(
+        S: Type,
+        size_bounds: (usize, usize),
+        bounds: *const AnyObject,
+    ) -> FfiResult<*mut AnyTransformation>
+    where
+        T: 'static + Float,
+    {
+        fn monomorphize2<S>(
+            size_bounds: (usize, usize),
+            bounds: (S::Item, S::Item),
+        ) -> FfiResult<*mut AnyTransformation>
+        where
+            S: UncheckedSum,
+            S::Item: 'static + Float,
+        {
+            make_size_bounded_float_checked_sum::<S>(size_bounds, bounds).into_any()
+        }
+        let bounds = *try_!(try_as_ref!(bounds).downcast_ref::<(T, T)>());
+        dispatch!(monomorphize2, [(S, [Sequential<T>, Pairwise<T>])], (size_bounds, bounds))
+    }
+    let size_bounds = *try_!(try_as_ref!(size_bounds).downcast_ref::<(usize, usize)>());
+    let S = try_!(Type::try_from(S));
+    let T = try_!(S.get_atom());
+    dispatch!(monomorphize, [(T, @floats)], (S, size_bounds, bounds))
+}
+
+
 
 #[cfg(test)]
 mod tests {
@@ -100,7 +134,7 @@ mod tests {
             "Pairwise<f64>".to_char_p(),
         ))?;
         let arg = AnyObject::new_raw(vec![1.0, 2.0, 3.0]);
-        let res = core::opendp_core__transformation_invoke(&transformation, arg);
+        let res = core::opendp_core__transformation_invoke(&transformation, arg, util::from_bool(false));
         let res: f64 = Fallible::from(res)?.downcast()?;
         assert_eq!(res, 6.0);
         Ok(())
@@ -114,7 +148,21 @@ mod tests {
             "Sequential<f64>".to_char_p(),
         ))?;
         let arg = AnyObject::new_raw(vec![1.0, 2.0, 3.0]);
-        let res = core::opendp_core__transformation_invoke(&transformation, arg);
+        let res = core::opendp_core__transformation_invoke(&transformation, arg, util::from_bool(false));
+        let res: f64 = Fallible::from(res)?.downcast()?;
+        assert_eq!(res, 6.0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_make_size_bounded_float_checked_sum_ffi() -> Fallible<()> {
+        let transformation = Result::from(opendp_transformations__make_size_bounded_float_checked_sum(
+            util::into_raw(AnyObject::new((0usize, 5usize))),
+            util::into_raw(AnyObject::new((0., 10.))),
+            "Pairwise<f64>".to_char_p(),
+        ))?;
+        let arg = AnyObject::new_raw(vec![1.0, 2.0, 3.0]);
+        let res = core::opendp_core__transformation_invoke(&transformation, arg, util::from_bool(false));
         let res: f64 = Fallible::from(res)?.downcast()?;
         assert_eq!(res, 6.0);
         Ok(())
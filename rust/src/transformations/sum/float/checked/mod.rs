@@ -108,6 +108,57 @@ where
     ))
 }
 
+/// Make a Transformation that computes the sum of a dataset whose size is bounded by `size_bounds`.
+///
+/// Overflow is ruled out, and the floating-point error is bounded, with the upper size bound.
+/// Unlike [`make_bounded_float_checked_sum`], data within the size bounds is never truncated,
+/// and unlike [`make_sized_bounded_float_checked_sum`], an exact size is not needed.
+pub fn make_size_bounded_float_checked_sum<S>(
+    size_bounds: (usize, usize),
+    bounds: (S::Item, S::Item),
+) -> Fallible<
+    Transformation<
+        VectorDomain<BoundedDomain<S::Item>>,
+        AllDomain<S::Item>,
+        SymmetricDistance,
+        AbsoluteDistance<S::Item>,
+    >,
+>
+where
+    S: UncheckedSum,
+    S::Item: 'static + Float,
+{
+    let max_size = size_bounds.1;
+    if S::float_sum_can_overflow(max_size, bounds)? {
+        return fallible!(
+            MakeTransformation,
+            "potential for overflow when computing function"
+        );
+    }
+
+    let (lower, upper) = bounds;
+    let ideal_sensitivity = lower.alerting_abs()?.total_max(upper)?;
+    let relaxation = S::relaxation(max_size, lower, upper)?;
+
+    Ok(Transformation::new(
+        VectorDomain::new_size_bounded(BoundedDomain::new_closed(bounds)?, size_bounds)?,
+        AllDomain::new(),
+        // records beyond the upper size bound are outside the input domain, and are not summed
+        Function::new(move |arg: &Vec<S::Item>| S::unchecked_sum(&arg[..arg.len().min(max_size)])),
+        SymmetricDistance::default(),
+        AbsoluteDistance::default(),
+        StabilityMap::new_fallible(move |d_in: &IntDistance| {
+            // d_out =  |BS*(v) - BS*(v')| where BS* is the finite sum and BS the ideal sum
+            //       <= |BS*(v) - BS(v)| + |BS(v) - BS(v')| + |BS(v') - BS*(v')|
+            //       <= d_in * max(|L|, U) + 2 * error
+            //       =  d_in * max(|L|, U) + relaxation
+            S::Item::inf_cast(*d_in)?
+                .inf_mul(&ideal_sensitivity)?
+                .inf_add(&relaxation)
+        }),
+    ))
+}
+
 pub trait UncheckedSum: SumRelaxation + CanFloatSumOverflow {
     fn unchecked_sum(arg: &[Self::Item]) -> Self::Item;
 }
@@ -258,6 +309,19 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_make_size_bounded_float_checked_sum() -> Fallible<()> {
+        let trans = make_size_bounded_float_checked_sum::<Sequential<f64>>((0, 4), (1., 10.))?;
+        assert_eq!(trans.invoke(&vec![1., 2., 3.])?, 6.);
+        assert_eq!(trans.invoke(&vec![1.; 5])?, 4.);
+        assert!(trans.check(&1, &10.000001)?);
+
+        // the upper size bound is enough to rule out overflow
+        assert!(make_size_bounded_float_checked_sum::<Pairwise<f32>>((0, 100000000), (1e20, 1e30)).is_err());
+        assert!(make_size_bounded_float_checked_sum::<Pairwise<f32>>((0, 100), (1e20, 1e30)).is_ok());
+        Ok(())
+    }
 }
 
 
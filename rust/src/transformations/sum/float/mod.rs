@@ -6,6 +6,9 @@ pub use checked::*;
 mod ordered;
 pub use ordered::*;
 
+mod vector;
+pub use vector::*;
+
 use crate::{
     error::Fallible,
     traits::{Float, InfAdd},
@@ -98,7 +98,7 @@ mod tests {
             "Pairwise<f64>".to_char_p(),
         ))?;
         let arg = AnyObject::new_raw(vec![1., 2., 3.]);
-        let res = core::opendp_core__transformation_invoke(&transformation, arg);
+        let res = core::opendp_core__transformation_invoke(&transformation, arg, util::from_bool(false));
         let res: f64 = Fallible::from(res)?.downcast()?;
         assert_eq!(res, 6.);
         Ok(())
@@ -112,7 +112,7 @@ mod tests {
             "Pairwise<f64>".to_char_p(),
         ))?;
         let arg = AnyObject::new_raw(vec![1., 2., 3.]);
-        let res = core::opendp_core__transformation_invoke(&transformation, arg);
+        let res = core::opendp_core__transformation_invoke(&transformation, arg, util::from_bool(false));
         let res: f64 = Fallible::from(res)?.downcast()?;
         assert_eq!(res, 6.);
         Ok(())
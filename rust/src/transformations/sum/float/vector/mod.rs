@@ -0,0 +1,115 @@
+use crate::{
+    core::{Function, Transformation, StabilityMap},
+    metrics::{IntDistance, LpDistance, SymmetricDistance},
+    domains::{AllDomain, LpBoundedDomain, VectorDomain},
+    error::Fallible,
+    traits::{InfAdd, InfCast, InfMul, samplers::Shuffle},
+};
+
+use num::Zero;
+
+use super::{Float, UncheckedSum};
+
+pub fn make_bounded_vector_sum<const P: usize, S>(
+    dim: usize,
+    size_limit: usize,
+    norm: S::Item,
+) -> Fallible<
+    Transformation<
+        VectorDomain<LpBoundedDomain<P, S::Item>>,
+        VectorDomain<AllDomain<S::Item>>,
+        SymmetricDistance,
+        LpDistance<P, S::Item>,
+    >,
+>
+where
+    S: UncheckedSum,
+    S::Item: 'static + Float,
+{
+    let input_domain = VectorDomain::new(LpBoundedDomain::new(norm)?);
+
+    // each coordinate of each row is bounded in magnitude by the norm
+    if S::float_sum_can_overflow(size_limit, (-norm, norm))? {
+        return fallible!(
+            MakeTransformation,
+            "potential for overflow when computing function"
+        );
+    }
+
+    // The error of each coordinate sum is bounded by γ * sum_i |x_ij|,
+    //     and γ * n * max(|L|, U) is the bound given by S::error.
+    // By the triangle inequality, the Lp norm of the error vector is bounded by γ * sum_i ||x_i||_p <= γ * n * norm,
+    //     which is independent of the dimension.
+    let relaxation = S::relaxation(size_limit, S::Item::zero(), norm)?;
+
+    Ok(Transformation::new(
+        input_domain,
+        VectorDomain::new_all(),
+        Function::new_fallible(move |arg: &Vec<Vec<S::Item>>| {
+            let mut data = arg.clone();
+            if arg.len() > size_limit {
+                data.shuffle()?
+            }
+            let data = &data[..size_limit.min(data.len())];
+
+            // rows are truncated or zero-padded to `dim`, which can only decrease their norm
+            Ok((0..dim).map(|j| S::unchecked_sum(&data.iter()
+                .map(|row| row.get(j).copied().unwrap_or_else(S::Item::zero))
+                .collect::<Vec<_>>()))
+                .collect())
+        }),
+        SymmetricDistance,
+        LpDistance::default(),
+        StabilityMap::new_fallible(move |d_in: &IntDistance| {
+            // d_out =  ||BS*(v) - BS*(v')||_p where BS* is the finite sum and BS the ideal sum
+            //       <= ||BS*(v) - BS(v)||_p + ||BS(v) - BS(v')||_p + ||BS(v') - BS*(v')||_p
+            //       <= d_in * norm + 2 * error
+            //       =  d_in * norm + relaxation
+            S::Item::inf_cast(*d_in)?
+                .inf_mul(&norm)?
+                .inf_add(&relaxation)
+        }),
+    ))
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::transformations::{make_clamp_norm, Pairwise};
+
+    #[test]
+    fn test_make_bounded_vector_sum() -> Fallible<()> {
+        let trans = make_bounded_vector_sum::<2, Pairwise<f64>>(2, 10, 1.)?;
+        let sum = trans.invoke(&vec![vec![0.5, 0.5], vec![1.], vec![0., -1., 7.]])?;
+        assert_eq!(sum, vec![1.5, -0.5]);
+        assert!(trans.check(&1, &1.0000001)?);
+        assert!(!trans.check(&2, &1.0000001)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_make_clamp_norm_vector_sum() -> Fallible<()> {
+        let chain = (
+            make_clamp_norm::<1, f64>(1.)? >>
+            make_bounded_vector_sum::<1, Pairwise<f64>>(3, 100, 1.)?
+        )?;
+        let sum = chain.invoke(&vec![vec![2., 0., 0.], vec![0., -0.5, 0.]])?;
+        assert_eq!(sum, vec![1., -0.5, 0.]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_make_bounded_vector_sum_gaussian() -> Fallible<()> {
+        use crate::measurements::make_base_gaussian;
+        use crate::measures::ZeroConcentratedDivergence;
+        let meas = (
+            make_clamp_norm::<2, f64>(1.)? >>
+            make_bounded_vector_sum::<2, Pairwise<f64>>(2, 100, 1.)? >>
+            make_base_gaussian::<VectorDomain<_>, ZeroConcentratedDivergence<_>>(1., None)?
+        )?;
+        meas.invoke(&vec![vec![3., 4.], vec![1., 0.]])?;
+        assert!(meas.check(&1, &0.5000001)?);
+        Ok(())
+    }
+}
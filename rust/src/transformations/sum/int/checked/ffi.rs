@@ -9,7 +9,7 @@ use crate::err;
 use crate::ffi::any::{AnyObject, AnyTransformation, Downcast};
 use crate::ffi::util::Type;
 use crate::traits::{CheckNull, DistanceConstant, ExactIntCast, InfDiv, InfSub, AlertingAbs};
-use crate::transformations::make_sized_bounded_int_checked_sum;
+use crate::transformations::{make_size_bounded_int_checked_sum, make_sized_bounded_int_checked_sum};
 use crate::transformations::sum::int::AddIsExact;
 
 #[no_mangle]
@@ -38,6 +38,32 @@ pub extern "C" fn opendp_transformations__make_sized_bounded_int_checked_sum(
     dispatch!(monomorphize, [(T, @integers)], (size, bounds))
 }
 
+#[no_mangle]
+pub extern "C" fn opendp_transformations__make_size_bounded_int_checked_sum(
+    size_bounds: *const AnyObject,
+    bounds: *const AnyObject,
+    T: *const c_char,
+) -> FfiResult<*mut AnyTransformation> {
+    fn monomorphize<T>(size_bounds: (usize, usize), bounds: *const AnyObject) -> FfiResult<*mut AnyTransformation>
+    where
+        T: 'static
+            + DistanceConstant<IntDistance>
+            + ExactIntCast<usize>
+            + AlertingAbs
+            + InfSub
+            + CheckNull
+            + InfDiv
+            + AddIsExact,
+        for<'a> T: Sum<&'a T>,
+    {
+        let bounds = try_!(try_as_ref!(bounds).downcast_ref::<(T, T)>()).clone();
+        make_size_bounded_int_checked_sum::<T>(size_bounds, bounds).into_any()
+    }
+    let size_bounds = *try_!(try_as_ref!(size_bounds).downcast_ref::<(usize, usize)>());
+    let T = try_!(Type::try_from(T));
+    dispatch!(monomorphize, [(T, @integers)], (size_bounds, bounds))
+}
+
 #[cfg(test)]
 mod tests {
     use crate::core;
@@ -56,7 +82,21 @@ mod tests {
             "i32".to_char_p(),
         ))?;
         let arg = AnyObject::new_raw(vec![1, 2, 3]);
-        let res = core::opendp_core__transformation_invoke(&transformation, arg);
+        let res = core::opendp_core__transformation_invoke(&transformation, arg, util::from_bool(false));
+        let res: i32 = Fallible::from(res)?.downcast()?;
+        assert_eq!(res, 6);
+        Ok(())
+    }
+
+    #[test]
+    fn test_make_size_bounded_int_checked_sum_ffi() -> Fallible<()> {
+        let transformation = Result::from(opendp_transformations__make_size_bounded_int_checked_sum(
+            util::into_raw(AnyObject::new((0usize, 5usize))),
+            util::into_raw(AnyObject::new((0i32, 10i32))),
+            "i32".to_char_p(),
+        ))?;
+        let arg = AnyObject::new_raw(vec![1, 2, 3]);
+        let res = core::opendp_core__transformation_invoke(&transformation, arg, util::from_bool(false));
         let res: i32 = Fallible::from(res)?.downcast()?;
         assert_eq!(res, 6);
         Ok(())
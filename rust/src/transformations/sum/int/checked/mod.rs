@@ -5,7 +5,7 @@ use crate::{
     metrics::{AbsoluteDistance, IntDistance, SymmetricDistance},
     domains::{AllDomain, BoundedDomain, SizedDomain, VectorDomain},
     error::Fallible,
-    traits::{CheckNull, DistanceConstant, InfDiv, InfSub},
+    traits::{AlertingAbs, CheckNull, DistanceConstant, InfDiv, InfSub},
     transformations::CanIntSumOverflow,
 };
 
@@ -52,6 +52,45 @@ where
     ))
 }
 
+/// Make a Transformation that computes the sum of a dataset whose size is bounded by `size_bounds`.
+///
+/// Overflow is ruled out with the upper size bound, so an exact size is not needed.
+pub fn make_size_bounded_int_checked_sum<T>(
+    size_bounds: (usize, usize),
+    bounds: (T, T),
+) -> Fallible<
+    Transformation<
+        VectorDomain<BoundedDomain<T>>,
+        AllDomain<T>,
+        SymmetricDistance,
+        AbsoluteDistance<T>,
+    >,
+>
+where
+    T: DistanceConstant<IntDistance> + InfSub + CheckNull + InfDiv + AlertingAbs + AddIsExact + CanIntSumOverflow,
+    for<'a> T: Sum<&'a T>,
+{
+    let max_size = size_bounds.1;
+    if T::int_sum_can_overflow(max_size, bounds.clone())? {
+        return fallible!(
+            MakeTransformation,
+            "potential for overflow when computing function"
+        );
+    }
+
+    let (lower, upper) = bounds.clone();
+    let sensitivity = lower.alerting_abs()?.total_max(upper)?;
+    Ok(Transformation::new(
+        VectorDomain::new_size_bounded(BoundedDomain::new_closed(bounds)?, size_bounds)?,
+        AllDomain::new(),
+        // records beyond the upper size bound are outside the input domain, and are not summed
+        Function::new(move |arg: &Vec<T>| arg.iter().take(max_size).sum()),
+        SymmetricDistance::default(),
+        AbsoluteDistance::default(),
+        StabilityMap::new_from_constant(sensitivity),
+    ))
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -66,4 +105,18 @@ mod test {
         assert!(make_sized_bounded_int_checked_sum::<u8>(2, (0, 255)).is_err());
         Ok(())
     }
+
+    #[test]
+    fn test_make_size_bounded_int_checked_sum() -> Fallible<()> {
+        let trans = make_size_bounded_int_checked_sum((0, 4), (1, 10))?;
+        assert_eq!(trans.invoke(&vec![1, 2, 3])?, 6);
+        assert_eq!(trans.invoke(&vec![1; 5])?, 4);
+        assert!(trans.check(&1, &10)?);
+        assert!(!trans.check(&1, &9)?);
+
+        // the upper size bound rules out overflow
+        assert!(make_size_bounded_int_checked_sum::<u8>((0, 25), (0, 10)).is_ok());
+        assert!(make_size_bounded_int_checked_sum::<u8>((0, 26), (0, 10)).is_err());
+        Ok(())
+    }
 }
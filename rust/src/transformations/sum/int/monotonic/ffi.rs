@@ -79,7 +79,7 @@ mod tests {
             "i32".to_char_p(),
         ))?;
         let arg = AnyObject::new_raw(vec![1i32, 2, 3]);
-        let res = core::opendp_core__transformation_invoke(&transformation, arg);
+        let res = core::opendp_core__transformation_invoke(&transformation, arg, util::from_bool(false));
         let res: i32 = Fallible::from(res)?.downcast()?;
         assert_eq!(res, 6);
         Ok(())
@@ -93,7 +93,7 @@ mod tests {
             "i32".to_char_p(),
         ))?;
         let arg = AnyObject::new_raw(vec![1i32, 2, 3]);
-        let res = core::opendp_core__transformation_invoke(&transformation, arg);
+        let res = core::opendp_core__transformation_invoke(&transformation, arg, util::from_bool(false));
         let res: i32 = Fallible::from(res)?.downcast()?;
         assert_eq!(res, 6);
         Ok(())
@@ -0,0 +1,271 @@
+//! Transformations that aggregate timestamped records into fixed windows.
+//!
+//! Window `i` spans the half-open interval `[origin + i * stride, origin + i * stride + width)`.
+//! Windows are tumbling when `stride == width`, and sliding when `stride < width`.
+//!
+//! The windowed aggregates take data grouped by user, where each element of the input vector holds all records of one user.
+//! Under [`SymmetricDistance`], neighboring datasets then differ by all of the records of a user.
+
+use std::collections::HashMap;
+use std::ops::Range;
+
+use crate::core::{Function, SensitivityMetric, StabilityMap, Transformation, DatasetMetric};
+use crate::domains::{AllDomain, BoundedDomain, ProductDomain, VectorDomain};
+use crate::error::*;
+use crate::metrics::{IntDistance, L1Distance, L2Distance, SymmetricDistance};
+use crate::traits::{AlertingAbs, CheckNull, DistanceConstant, ExactIntCast, Float, InfCast, InfMul, Number, SaturatingAdd, TotalOrd};
+use crate::transformations::{make_row_by_row_fallible, IsMonotonic};
+
+/// Make a Transformation that replaces each timestamp with the index of its tumbling window of length `width`.
+///
+/// Timestamps before `origin` are assigned negative indexes.
+/// The transformation fails if an index doesn't fit in an `i64`, which can only happen when `width` is one.
+///
+/// # Arguments
+/// * `origin` - Start of window zero.
+/// * `width` - Length of each window. Must be positive.
+///
+/// # Generics
+/// * `M` - Dataset metric.
+pub fn make_time_bucket<M>(
+    origin: i64, width: i64
+) -> Fallible<Transformation<VectorDomain<AllDomain<i64>>, VectorDomain<AllDomain<i64>>, M, M>>
+    where M: DatasetMetric {
+    if width <= 0 {
+        return fallible!(MakeTransformation, "width must be positive")
+    }
+    make_row_by_row_fallible(
+        AllDomain::new(), AllDomain::new(),
+        move |t: &i64| i64::exact_int_cast((*t as i128 - origin as i128).div_euclid(width as i128)))
+}
+
+/// Bounds the change in a vector of per-window aggregates, when one user is added or removed.
+pub trait WindowAggregateConstant: SensitivityMetric {
+    /// `per_window` bounds the change in the aggregate of any one window.
+    fn get_stability_constant(num_windows: usize, per_window: Self::Distance) -> Fallible<Self::Distance>;
+}
+
+// a user may change every window by up to `per_window`
+impl<Q: Number> WindowAggregateConstant for L1Distance<Q> {
+    fn get_stability_constant(num_windows: usize, per_window: Q) -> Fallible<Q> {
+        Q::exact_int_cast(num_windows)?.inf_mul(&per_window)
+    }
+}
+// sqrt(num_windows * per_window^2)
+impl<Q: Float> WindowAggregateConstant for L2Distance<Q> {
+    fn get_stability_constant(num_windows: usize, per_window: Q) -> Fallible<Q> {
+        Q::exact_int_cast(num_windows)?.inf_sqrt()?.inf_mul(&per_window)
+    }
+}
+
+#[derive(Clone)]
+struct Windows {
+    origin: i64,
+    width: i64,
+    stride: i64,
+    num_windows: usize,
+}
+
+impl Windows {
+    fn new(origin: i64, width: i64, stride: i64, num_windows: usize) -> Fallible<Self> {
+        if width <= 0 || stride <= 0 {
+            return fallible!(MakeTransformation, "width and stride must be positive")
+        }
+        if num_windows == 0 {
+            return fallible!(MakeTransformation, "there must be at least one window")
+        }
+        Ok(Windows { origin, width, stride, num_windows })
+    }
+
+    /// The indexes of all windows that contain the timestamp `t`.
+    fn containing(&self, t: i64) -> Range<usize> {
+        let offset = t as i128 - self.origin as i128;
+        let (width, stride) = (self.width as i128, self.stride as i128);
+        let first = (offset - width).div_euclid(stride) + 1;
+        let last = offset.div_euclid(stride);
+        let clip = |i: i128| i.clamp(0, self.num_windows as i128) as usize;
+        clip(first)..clip(last + 1)
+    }
+}
+
+/// Make a Transformation that counts the records in each window, where each user contributes at most `max_contributions` records to each window.
+///
+/// # Arguments
+/// * `origin` - Start of window zero.
+/// * `width` - Length of each window.
+/// * `stride` - Distance between the starts of consecutive windows.
+/// * `num_windows` - Number of windows, and length of the output vector.
+/// * `max_contributions` - Greatest number of records counted from one user in any one window.
+///
+/// # Generics
+/// * `MO` - Output metric, either `L1Distance<Q>` or `L2Distance<Q>`.
+/// * `TO` - Type of the counts.
+pub fn make_window_count<MO, TO>(
+    origin: i64, width: i64, stride: i64, num_windows: usize,
+    max_contributions: usize,
+) -> Fallible<Transformation<VectorDomain<VectorDomain<AllDomain<i64>>>, VectorDomain<AllDomain<TO>>, SymmetricDistance, MO>>
+    where MO: WindowAggregateConstant,
+          MO::Distance: DistanceConstant<IntDistance> + ExactIntCast<usize>,
+          TO: Number {
+    let windows = Windows::new(origin, width, stride, num_windows)?;
+    let per_window = MO::Distance::exact_int_cast(max_contributions)?;
+
+    Ok(Transformation::new(
+        VectorDomain::new(VectorDomain::new_all()),
+        VectorDomain::new_all(),
+        Function::new(move |users: &Vec<Vec<i64>>| {
+            let mut counts = vec![TO::zero(); windows.num_windows];
+            users.iter().for_each(|timestamps| {
+                let mut contributions = HashMap::<usize, usize>::new();
+                timestamps.iter()
+                    .flat_map(|t| windows.containing(*t))
+                    .for_each(|i| *contributions.entry(i).or_insert(0) += 1);
+
+                contributions.into_iter().for_each(|(i, count)| {
+                    let count = TO::exact_int_cast(count.min(max_contributions))
+                        .unwrap_or(TO::MAX_CONSECUTIVE);
+                    counts[i] = counts[i].saturating_add(&count);
+                });
+            });
+            counts
+        }),
+        SymmetricDistance::default(),
+        MO::default(),
+        StabilityMap::new_from_constant(MO::get_stability_constant(num_windows, per_window)?)))
+}
+
+/// Make a Transformation that sums the values in each window, where each user contributes at most `max_contributions` records to each window.
+///
+/// Each record is a pair of a timestamp and a value.
+/// When a user has more than `max_contributions` records in a window, their earliest records are summed.
+/// Sums saturate, so `bounds` must share the same sign.
+///
+/// # Arguments
+/// * `origin` - Start of window zero.
+/// * `width` - Length of each window.
+/// * `stride` - Distance between the starts of consecutive windows.
+/// * `num_windows` - Number of windows, and length of the output vector.
+/// * `max_contributions` - Greatest number of records summed from one user in any one window.
+/// * `bounds` - Lower and upper bounds on each value.
+///
+/// # Generics
+/// * `MO` - Output metric, either `L1Distance<Q>` or `L2Distance<Q>`.
+/// * `T` - Type of the values.
+pub fn make_window_sum<MO, T>(
+    origin: i64, width: i64, stride: i64, num_windows: usize,
+    max_contributions: usize,
+    bounds: (T, T),
+) -> Fallible<Transformation<
+    VectorDomain<VectorDomain<ProductDomain<(AllDomain<i64>, BoundedDomain<T>)>>>,
+    VectorDomain<AllDomain<T>>,
+    SymmetricDistance, MO>>
+    where MO: WindowAggregateConstant,
+          MO::Distance: DistanceConstant<IntDistance> + ExactIntCast<usize> + InfCast<T>,
+          T: 'static + TotalOrd + Clone + CheckNull + num::Zero + SaturatingAdd + AlertingAbs + IsMonotonic {
+    let windows = Windows::new(origin, width, stride, num_windows)?;
+    if !T::is_monotonic(bounds.clone()) {
+        return fallible!(MakeTransformation, "monotonic summation requires bounds to share the same sign")
+    }
+    let (lower, upper) = bounds.clone();
+    let per_window = MO::Distance::inf_cast(lower.alerting_abs()?.total_max(upper)?)?
+        .inf_mul(&MO::Distance::exact_int_cast(max_contributions)?)?;
+
+    Ok(Transformation::new(
+        VectorDomain::new(VectorDomain::new(ProductDomain::new((
+            AllDomain::new(), BoundedDomain::new_closed(bounds)?)))),
+        VectorDomain::new_all(),
+        Function::new(move |users: &Vec<Vec<(i64, T)>>| {
+            let mut sums = vec![T::zero(); windows.num_windows];
+            users.iter().for_each(|records| {
+                let mut records = records.iter().collect::<Vec<_>>();
+                records.sort_by_key(|(t, _)| *t);
+
+                let mut contributions = HashMap::<usize, usize>::new();
+                records.into_iter().for_each(|(t, v)| windows.containing(*t).for_each(|i| {
+                    let count = contributions.entry(i).or_insert(0);
+                    if *count < max_contributions {
+                        *count += 1;
+                        sums[i] = sums[i].saturating_add(v);
+                    }
+                }));
+            });
+            sums
+        }),
+        SymmetricDistance::default(),
+        MO::default(),
+        StabilityMap::new_from_constant(MO::get_stability_constant(num_windows, per_window)?)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::measurements::make_base_discrete_laplace;
+
+    const DAY: i64 = 86_400;
+
+    #[test]
+    fn test_make_time_bucket() -> Fallible<()> {
+        let transformation = make_time_bucket::<SymmetricDistance>(0, DAY)?;
+        assert_eq!(transformation.invoke(&vec![0, DAY - 1, DAY, -1])?, vec![0, 0, 1, -1]);
+        assert!(make_time_bucket::<SymmetricDistance>(0, 0).is_err());
+
+        // the index of i64::MAX overflows when the origin is negative
+        let transformation = make_time_bucket::<SymmetricDistance>(-1, 1)?;
+        assert!(transformation.invoke(&vec![i64::MAX]).is_err());
+        assert_eq!(transformation.invoke(&vec![i64::MAX - 1])?, vec![i64::MAX]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_windows_containing() -> Fallible<()> {
+        let sliding = Windows::new(0, 3, 1, 5)?;
+        assert_eq!(sliding.containing(0), 0..1);
+        assert_eq!(sliding.containing(2), 0..3);
+        assert_eq!(sliding.containing(6), 4..5);
+        assert_eq!(sliding.containing(7), 5..5);
+
+        let gapped = Windows::new(0, 1, 2, 3)?;
+        assert_eq!(gapped.containing(1), 1..1);
+        assert_eq!(gapped.containing(2), 1..2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_make_window_count() -> Fallible<()> {
+        let transformation = make_window_count::<L1Distance<i64>, i64>(0, DAY, DAY, 3, 2)?;
+        let users = vec![
+            vec![0, 1, 2, DAY],
+            vec![2 * DAY, 3 * DAY],
+        ];
+        assert_eq!(transformation.invoke(&users)?, vec![2, 1, 1]);
+        assert!(transformation.check(&1, &6)?);
+        assert!(!transformation.check(&1, &5)?);
+
+        let transformation = make_window_count::<L2Distance<f64>, i64>(0, DAY, DAY, 4, 3)?;
+        assert!(transformation.check(&1, &6.)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_make_window_sum() -> Fallible<()> {
+        let transformation = make_window_sum::<L1Distance<i64>, i64>(0, 2 * DAY, DAY, 2, 1, (0, 10))?;
+        let users = vec![
+            vec![(DAY, 3), (0, 5)],
+            vec![(DAY + 1, 7)],
+        ];
+        assert_eq!(transformation.invoke(&users)?, vec![12, 10]);
+        assert!(transformation.check(&1, &20)?);
+        assert!(!transformation.check(&1, &19)?);
+        assert!(make_window_sum::<L1Distance<i64>, i64>(0, DAY, DAY, 2, 1, (-1, 10)).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_daily_activity_series() -> Fallible<()> {
+        let chain = (make_window_count::<L1Distance<i64>, i64>(0, DAY, DAY, 7, 1)?
+            >> make_base_discrete_laplace::<VectorDomain<AllDomain<i64>>, f64>(7.)?)?;
+        assert_eq!(chain.invoke(&vec![vec![0], vec![DAY]])?.len(), 7);
+        assert!(chain.check(&1, &1.)?);
+        Ok(())
+    }
+}